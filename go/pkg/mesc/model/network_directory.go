@@ -0,0 +1,1869 @@
+// Code generated by python/generate_network_names.py. DO NOT EDIT.
+
+// Default mapping between chain_id's and network names
+//
+// This file is generated by `python/generate_network_names.py` in the MESC repo.
+//
+// The same set of network names is used for each MESC implementation.
+//
+// Do not edit this file manually.
+
+package model
+
+// NetworkInfo describes a network in the network directory.
+type NetworkInfo struct {
+	ChainID     ChainID
+	Name        string
+	DisplayName string
+	Aliases     []string
+	Testnet     bool
+	Parent      ChainID
+	Currency    string
+	Explorer    string
+}
+
+// NetworkDirectory lists the networks known to every MESC implementation.
+var NetworkDirectory = []NetworkInfo{
+	{ChainID: "1", Name: "ethereum", DisplayName: "Ethereum Mainnet", Aliases: []string{"mainnet", "eth", "ethereum_mainnet"}, Currency: "ETH", Explorer: "https://etherscan.io"},
+	{ChainID: "2", Name: "expanse", DisplayName: "Expanse"},
+	{ChainID: "3", Name: "ropsten", DisplayName: "Ropsten", Testnet: true},
+	{ChainID: "4", Name: "rinkeby", DisplayName: "Rinkeby", Testnet: true},
+	{ChainID: "5", Name: "goerli", DisplayName: "Goerli", Aliases: []string{"eth_goerli"}, Testnet: true, Currency: "ETH", Explorer: "https://goerli.etherscan.io"},
+	{ChainID: "6", Name: "kotti_testnet", DisplayName: "Kotti Testnet", Testnet: true},
+	{ChainID: "7", Name: "thai", DisplayName: "Thai"},
+	{ChainID: "8", Name: "ubiq", DisplayName: "Ubiq"},
+	{ChainID: "9", Name: "ubiq_testnet", DisplayName: "Ubiq Testnet", Testnet: true},
+	{ChainID: "10", Name: "optimism", DisplayName: "OP Mainnet", Aliases: []string{"op", "op_mainnet", "optimism_mainnet"}, Parent: "1", Currency: "ETH", Explorer: "https://optimistic.etherscan.io"},
+	{ChainID: "11", Name: "metadium", DisplayName: "Metadium"},
+	{ChainID: "12", Name: "metadium_testnet", DisplayName: "Metadium Testnet", Testnet: true},
+	{ChainID: "13", Name: "diode_testnet_staging", DisplayName: "Diode Testnet Staging", Testnet: true},
+	{ChainID: "14", Name: "flare", DisplayName: "Flare"},
+	{ChainID: "15", Name: "diode_prenet", DisplayName: "Diode Prenet"},
+	{ChainID: "16", Name: "songbird_testnet_coston", DisplayName: "Songbird Testnet Coston", Testnet: true},
+	{ChainID: "17", Name: "thaifi", DisplayName: "Thaifi"},
+	{ChainID: "18", Name: "thundercore_testnet", DisplayName: "Thundercore Testnet", Testnet: true},
+	{ChainID: "19", Name: "songbird_canary", DisplayName: "Songbird Canary"},
+	{ChainID: "20", Name: "elastos", DisplayName: "Elastos"},
+	{ChainID: "21", Name: "elastos_testnet", DisplayName: "Elastos Testnet", Testnet: true},
+	{ChainID: "22", Name: "ela_did", DisplayName: "Ela Did"},
+	{ChainID: "23", Name: "ela_did_testnet", DisplayName: "Ela Did Testnet", Testnet: true},
+	{ChainID: "24", Name: "kardia", DisplayName: "Kardia"},
+	{ChainID: "25", Name: "cronos", DisplayName: "Cronos"},
+	{ChainID: "26", Name: "genesis_testnet", DisplayName: "Genesis Testnet", Testnet: true},
+	{ChainID: "27", Name: "shiba", DisplayName: "Shiba"},
+	{ChainID: "28", Name: "boba_rinkeby_testnet", DisplayName: "Boba Rinkeby Testnet", Testnet: true},
+	{ChainID: "29", Name: "genesis", DisplayName: "Genesis"},
+	{ChainID: "30", Name: "rootstock", DisplayName: "Rootstock"},
+	{ChainID: "31", Name: "rootstock_testnet", DisplayName: "Rootstock Testnet", Testnet: true},
+	{ChainID: "32", Name: "gooddata_testnet", DisplayName: "Gooddata Testnet", Testnet: true},
+	{ChainID: "33", Name: "gooddata", DisplayName: "Gooddata"},
+	{ChainID: "34", Name: "secure", DisplayName: "Secure"},
+	{ChainID: "35", Name: "tbwg", DisplayName: "Tbwg"},
+	{ChainID: "36", Name: "dx", DisplayName: "Dx"},
+	{ChainID: "37", Name: "xpla", DisplayName: "Xpla"},
+	{ChainID: "38", Name: "valorbit", DisplayName: "Valorbit"},
+	{ChainID: "39", Name: "u2u_solaris", DisplayName: "U2u Solaris"},
+	{ChainID: "40", Name: "telos_evm", DisplayName: "Telos Evm"},
+	{ChainID: "41", Name: "telos_evm_testnet", DisplayName: "Telos Evm Testnet", Testnet: true},
+	{ChainID: "42", Name: "lukso", DisplayName: "Lukso"},
+	{ChainID: "43", Name: "darwinia_pangolin_testnet", DisplayName: "Darwinia Pangolin Testnet", Testnet: true},
+	{ChainID: "44", Name: "crab", DisplayName: "Crab"},
+	{ChainID: "45", Name: "darwinia_pangoro_testnet", DisplayName: "Darwinia Pangoro Testnet", Testnet: true},
+	{ChainID: "46", Name: "darwinia", DisplayName: "Darwinia"},
+	{ChainID: "47", Name: "acria_intelli", DisplayName: "Acria Intelli"},
+	{ChainID: "48", Name: "ennothem_proterozoic", DisplayName: "Ennothem Proterozoic"},
+	{ChainID: "49", Name: "ennothem_testnet_pioneer", DisplayName: "Ennothem Testnet Pioneer", Testnet: true},
+	{ChainID: "50", Name: "xdc", DisplayName: "Xdc"},
+	{ChainID: "51", Name: "xdc_apothem", DisplayName: "Xdc Apothem"},
+	{ChainID: "52", Name: "coinex", DisplayName: "Coinex"},
+	{ChainID: "53", Name: "coinex_testnet", DisplayName: "Coinex Testnet", Testnet: true},
+	{ChainID: "54", Name: "openpiece", DisplayName: "Openpiece"},
+	{ChainID: "55", Name: "zyx", DisplayName: "Zyx"},
+	{ChainID: "56", Name: "bsc", DisplayName: "BNB Smart Chain Mainnet", Aliases: []string{"bnb", "bnb_smart_chain"}, Currency: "BNB", Explorer: "https://bscscan.com"},
+	{ChainID: "57", Name: "syscoin", DisplayName: "Syscoin"},
+	{ChainID: "58", Name: "ontology", DisplayName: "Ontology"},
+	{ChainID: "59", Name: "eos_evm_legacy", DisplayName: "Eos Evm Legacy"},
+	{ChainID: "60", Name: "go", DisplayName: "Go"},
+	{ChainID: "61", Name: "ethereum_classic", DisplayName: "Ethereum Classic"},
+	{ChainID: "62", Name: "morden_testnet", DisplayName: "Morden Testnet", Testnet: true},
+	{ChainID: "63", Name: "mordor_testnet", DisplayName: "Mordor Testnet", Testnet: true},
+	{ChainID: "64", Name: "ellaism", DisplayName: "Ellaism"},
+	{ChainID: "65", Name: "okex_testnet", DisplayName: "Okex Testnet", Testnet: true},
+	{ChainID: "66", Name: "okx", DisplayName: "Okx"},
+	{ChainID: "67", Name: "db_testnet", DisplayName: "Db Testnet", Testnet: true},
+	{ChainID: "68", Name: "soterone", DisplayName: "Soterone"},
+	{ChainID: "69", Name: "optimism_kovan", DisplayName: "Optimism Kovan", Testnet: true},
+	{ChainID: "70", Name: "hoo", DisplayName: "Hoo"},
+	{ChainID: "71", Name: "conflux_espace_testnet", DisplayName: "Conflux Espace Testnet", Testnet: true},
+	{ChainID: "72", Name: "dx_testnet", DisplayName: "Dx Testnet", Testnet: true},
+	{ChainID: "73", Name: "fncy", DisplayName: "Fncy"},
+	{ChainID: "74", Name: "id", DisplayName: "Id"},
+	{ChainID: "75", Name: "decimal", DisplayName: "Decimal"},
+	{ChainID: "76", Name: "mix", DisplayName: "Mix"},
+	{ChainID: "77", Name: "poa_sokol", DisplayName: "Poa Sokol"},
+	{ChainID: "78", Name: "primus", DisplayName: "Primus"},
+	{ChainID: "79", Name: "zenith", DisplayName: "Zenith"},
+	{ChainID: "80", Name: "gene", DisplayName: "Gene"},
+	{ChainID: "81", Name: "japan_open", DisplayName: "Japan Open"},
+	{ChainID: "82", Name: "meter", DisplayName: "Meter"},
+	{ChainID: "83", Name: "meter_testnet", DisplayName: "Meter Testnet", Testnet: true},
+	{ChainID: "84", Name: "linqto_devnet", DisplayName: "Linqto Devnet", Testnet: true},
+	{ChainID: "85", Name: "gate_testnet", DisplayName: "Gate Testnet", Testnet: true},
+	{ChainID: "86", Name: "gate", DisplayName: "Gate"},
+	{ChainID: "87", Name: "nova", DisplayName: "Nova"},
+	{ChainID: "88", Name: "viction", DisplayName: "Viction"},
+	{ChainID: "89", Name: "viction_testnet", DisplayName: "Viction Testnet", Testnet: true},
+	{ChainID: "90", Name: "garizon_stage0", DisplayName: "Garizon Stage0"},
+	{ChainID: "91", Name: "garizon_stage1", DisplayName: "Garizon Stage1"},
+	{ChainID: "92", Name: "garizon_stage2", DisplayName: "Garizon Stage2"},
+	{ChainID: "93", Name: "garizon_stage3", DisplayName: "Garizon Stage3"},
+	{ChainID: "94", Name: "swissdlt", DisplayName: "Swissdlt"},
+	{ChainID: "95", Name: "camdl", DisplayName: "Camdl"},
+	{ChainID: "96", Name: "bitkub", DisplayName: "Bitkub"},
+	{ChainID: "97", Name: "bnb_testnet", DisplayName: "BNB Smart Chain Testnet", Aliases: []string{"bsc_testnet"}, Testnet: true, Currency: "tBNB", Explorer: "https://testnet.bscscan.com"},
+	{ChainID: "98", Name: "six_protocol", DisplayName: "Six Protocol"},
+	{ChainID: "99", Name: "poa_core", DisplayName: "Poa Core"},
+	{ChainID: "100", Name: "gnosis", DisplayName: "Gnosis", Aliases: []string{"xdai"}, Currency: "XDAI", Explorer: "https://gnosisscan.io"},
+	{ChainID: "101", Name: "etherinc", DisplayName: "Etherinc"},
+	{ChainID: "102", Name: "web3games_testnet", DisplayName: "Web3games Testnet", Testnet: true},
+	{ChainID: "103", Name: "worldland", DisplayName: "Worldland"},
+	{ChainID: "104", Name: "kaiba_lightning_testnet", DisplayName: "Kaiba Lightning Testnet", Testnet: true},
+	{ChainID: "105", Name: "web3games_devnet", DisplayName: "Web3games Devnet", Testnet: true},
+	{ChainID: "106", Name: "velas_evm", DisplayName: "Velas Evm"},
+	{ChainID: "107", Name: "nebula_testnet", DisplayName: "Nebula Testnet", Testnet: true},
+	{ChainID: "108", Name: "thundercore", DisplayName: "Thundercore"},
+	{ChainID: "109", Name: "shibarium", DisplayName: "Shibarium"},
+	{ChainID: "110", Name: "proton_testnet", DisplayName: "Proton Testnet", Testnet: true},
+	{ChainID: "111", Name: "etherlite", DisplayName: "Etherlite"},
+	{ChainID: "112", Name: "coinbit", DisplayName: "Coinbit"},
+	{ChainID: "113", Name: "dehvo", DisplayName: "Dehvo"},
+	{ChainID: "114", Name: "flare_testnet_coston2", DisplayName: "Flare Testnet Coston2", Testnet: true},
+	{ChainID: "116", Name: "debank", DisplayName: "Debank"},
+	{ChainID: "117", Name: "uptick", DisplayName: "Uptick"},
+	{ChainID: "118", Name: "arcology_testnet", DisplayName: "Arcology Testnet", Testnet: true},
+	{ChainID: "119", Name: "enuls", DisplayName: "Enuls"},
+	{ChainID: "120", Name: "enuls_testnet", DisplayName: "Enuls Testnet", Testnet: true},
+	{ChainID: "121", Name: "real", DisplayName: "Real"},
+	{ChainID: "122", Name: "fuse", DisplayName: "Fuse"},
+	{ChainID: "123", Name: "fuse_sparknet", DisplayName: "Fuse Sparknet"},
+	{ChainID: "124", Name: "decentralized_web", DisplayName: "Decentralized Web"},
+	{ChainID: "125", Name: "oy_testnet", DisplayName: "Oy Testnet", Testnet: true},
+	{ChainID: "126", Name: "oy", DisplayName: "Oy"},
+	{ChainID: "127", Name: "factory_127", DisplayName: "Factory 127"},
+	{ChainID: "128", Name: "huobi_eco", DisplayName: "Huobi Eco"},
+	{ChainID: "129", Name: "innovator", DisplayName: "Innovator"},
+	{ChainID: "131", Name: "engram_testnet", DisplayName: "Engram Testnet", Testnet: true},
+	{ChainID: "132", Name: "namefi", DisplayName: "Namefi"},
+	{ChainID: "133", Name: "hashkey_testnet", DisplayName: "Hashkey Testnet", Testnet: true},
+	{ChainID: "134", Name: "iexec", DisplayName: "Iexec"},
+	{ChainID: "135", Name: "alyx_testnet", DisplayName: "Alyx Testnet", Testnet: true},
+	{ChainID: "136", Name: "deam", DisplayName: "Deam"},
+	{ChainID: "137", Name: "polygon", DisplayName: "Polygon Mainnet", Aliases: []string{"matic", "polygon_pos"}, Currency: "POL", Explorer: "https://polygonscan.com"},
+	{ChainID: "138", Name: "defi_oracle_meta", DisplayName: "Defi Oracle Meta"},
+	{ChainID: "139", Name: "woop", DisplayName: "Woop"},
+	{ChainID: "140", Name: "eternal", DisplayName: "Eternal"},
+	{ChainID: "141", Name: "openpiece_testnet", DisplayName: "Openpiece Testnet", Testnet: true},
+	{ChainID: "142", Name: "dax", DisplayName: "Dax"},
+	{ChainID: "144", Name: "phi_v2", DisplayName: "Phi V2"},
+	{ChainID: "145", Name: "soraai_testnet", DisplayName: "Soraai Testnet", Testnet: true},
+	{ChainID: "147", Name: "flag", DisplayName: "Flag"},
+	{ChainID: "148", Name: "shimmerevm", DisplayName: "Shimmerevm"},
+	{ChainID: "150", Name: "six_protocol_testnet", DisplayName: "Six Protocol Testnet", Testnet: true},
+	{ChainID: "151", Name: "redbelly", DisplayName: "Redbelly"},
+	{ChainID: "152", Name: "redbelly_devnet", DisplayName: "Redbelly Devnet", Testnet: true},
+	{ChainID: "153", Name: "redbelly_testnet", DisplayName: "Redbelly Testnet", Testnet: true},
+	{ChainID: "154", Name: "redbelly_tge", DisplayName: "Redbelly Tge"},
+	{ChainID: "155", Name: "tenet_testnet", DisplayName: "Tenet Testnet", Testnet: true},
+	{ChainID: "156", Name: "oeblock_testnet", DisplayName: "Oeblock Testnet", Testnet: true},
+	{ChainID: "157", Name: "puppynet_shibarium", DisplayName: "Puppynet Shibarium"},
+	{ChainID: "158", Name: "roburna", DisplayName: "Roburna"},
+	{ChainID: "159", Name: "roburna_testnet", DisplayName: "Roburna Testnet", Testnet: true},
+	{ChainID: "160", Name: "armonia_eva", DisplayName: "Armonia Eva"},
+	{ChainID: "161", Name: "armonia_eva_testnet", DisplayName: "Armonia Eva Testnet", Testnet: true},
+	{ChainID: "162", Name: "lightstreams_testnet", DisplayName: "Lightstreams Testnet", Testnet: true},
+	{ChainID: "163", Name: "lightstreams", DisplayName: "Lightstreams"},
+	{ChainID: "164", Name: "omni_omega_testnet", DisplayName: "Omni Omega Testnet", Testnet: true},
+	{ChainID: "166", Name: "omni", DisplayName: "Omni"},
+	{ChainID: "167", Name: "atoshi_testnet", DisplayName: "Atoshi Testnet", Testnet: true},
+	{ChainID: "168", Name: "aioz", DisplayName: "Aioz"},
+	{ChainID: "169", Name: "manta_pacific", DisplayName: "Manta Pacific"},
+	{ChainID: "170", Name: "hoo_testnet", DisplayName: "Hoo Testnet", Testnet: true},
+	{ChainID: "171", Name: "co2e_ledger", DisplayName: "Co2e Ledger"},
+	{ChainID: "172", Name: "latam_block_resil_testnet", DisplayName: "Latam Block Resil Testnet", Testnet: true},
+	{ChainID: "176", Name: "dc", DisplayName: "Dc"},
+	{ChainID: "178", Name: "abey_testnet", DisplayName: "Abey Testnet", Testnet: true},
+	{ChainID: "179", Name: "abey", DisplayName: "Abey"},
+	{ChainID: "180", Name: "ame", DisplayName: "Ame"},
+	{ChainID: "181", Name: "waterfall", DisplayName: "Waterfall"},
+	{ChainID: "183", Name: "ethernity", DisplayName: "Ethernity"},
+	{ChainID: "184", Name: "dojima_testnet", DisplayName: "Dojima Testnet", Testnet: true},
+	{ChainID: "185", Name: "mint", DisplayName: "Mint"},
+	{ChainID: "186", Name: "seele", DisplayName: "Seele"},
+	{ChainID: "187", Name: "dojima", DisplayName: "Dojima"},
+	{ChainID: "188", Name: "bmc", DisplayName: "Bmc"},
+	{ChainID: "189", Name: "bmc_testnet", DisplayName: "Bmc Testnet", Testnet: true},
+	{ChainID: "190", Name: "cmdao_bbq", DisplayName: "Cmdao Bbq"},
+	{ChainID: "191", Name: "filefilego", DisplayName: "Filefilego"},
+	{ChainID: "193", Name: "crypto_emergency", DisplayName: "Crypto Emergency"},
+	{ChainID: "195", Name: "x_layer_testnet", DisplayName: "X Layer Testnet", Testnet: true},
+	{ChainID: "196", Name: "x_layer", DisplayName: "X Layer"},
+	{ChainID: "197", Name: "neutrinos_testnet", DisplayName: "Neutrinos Testnet", Testnet: true},
+	{ChainID: "198", Name: "bit", DisplayName: "Bit"},
+	{ChainID: "199", Name: "bittorrent", DisplayName: "Bittorrent"},
+	{ChainID: "200", Name: "arbitrum_on_xdai", DisplayName: "Arbitrum On Xdai"},
+	{ChainID: "201", Name: "moac_testnet", DisplayName: "Moac Testnet", Testnet: true},
+	{ChainID: "202", Name: "edgeless_testnet", DisplayName: "Edgeless Testnet", Testnet: true},
+	{ChainID: "204", Name: "opbnb", DisplayName: "Opbnb"},
+	{ChainID: "205", Name: "ekaash", DisplayName: "Ekaash"},
+	{ChainID: "206", Name: "vinu_testnet", DisplayName: "Vinu Testnet", Testnet: true},
+	{ChainID: "207", Name: "vinu", DisplayName: "Vinu"},
+	{ChainID: "208", Name: "structx", DisplayName: "Structx"},
+	{ChainID: "210", Name: "bitnet", DisplayName: "Bitnet"},
+	{ChainID: "211", Name: "freight_trust", DisplayName: "Freight Trust"},
+	{ChainID: "212", Name: "mapo_makalu", DisplayName: "Mapo Makalu"},
+	{ChainID: "213", Name: "b2_hub", DisplayName: "B2 Hub"},
+	{ChainID: "214", Name: "shinarium", DisplayName: "Shinarium"},
+	{ChainID: "217", Name: "siriusnet_v2", DisplayName: "Siriusnet V2"},
+	{ChainID: "218", Name: "soterone_old", DisplayName: "Soterone Old"},
+	{ChainID: "220", Name: "scalind_testnet", DisplayName: "Scalind Testnet", Testnet: true},
+	{ChainID: "221", Name: "blockex", DisplayName: "Blockex"},
+	{ChainID: "222", Name: "permission", DisplayName: "Permission"},
+	{ChainID: "223", Name: "b2", DisplayName: "B2"},
+	{ChainID: "224", Name: "viridis_testnet", DisplayName: "Viridis Testnet", Testnet: true},
+	{ChainID: "225", Name: "la", DisplayName: "La"},
+	{ChainID: "226", Name: "la_testnet", DisplayName: "La Testnet", Testnet: true},
+	{ChainID: "228", Name: "mind", DisplayName: "Mind"},
+	{ChainID: "230", Name: "swapdex", DisplayName: "Swapdex"},
+	{ChainID: "233", Name: "ethernity_testnet", DisplayName: "Ethernity Testnet", Testnet: true},
+	{ChainID: "234", Name: "protojumbo_testnet", DisplayName: "Protojumbo Testnet", Testnet: true},
+	{ChainID: "236", Name: "deam_testnet", DisplayName: "Deam Testnet", Testnet: true},
+	{ChainID: "238", Name: "blast", DisplayName: "Blast"},
+	{ChainID: "242", Name: "plinga", DisplayName: "Plinga"},
+	{ChainID: "246", Name: "energy_web", DisplayName: "Energy Web"},
+	{ChainID: "248", Name: "oasys", DisplayName: "Oasys"},
+	{ChainID: "250", Name: "fantom_opera", DisplayName: "Fantom Opera", Aliases: []string{"fantom", "ftm"}, Currency: "FTM", Explorer: "https://ftmscan.com"},
+	{ChainID: "252", Name: "fraxtal", DisplayName: "Fraxtal"},
+	{ChainID: "254", Name: "swan", DisplayName: "Swan"},
+	{ChainID: "255", Name: "kroma", DisplayName: "Kroma"},
+	{ChainID: "256", Name: "huobi_eco_testnet", DisplayName: "Huobi Eco Testnet", Testnet: true},
+	{ChainID: "258", Name: "setheum", DisplayName: "Setheum"},
+	{ChainID: "259", Name: "neonlink", DisplayName: "Neonlink"},
+	{ChainID: "261", Name: "guru_testnet", DisplayName: "Guru Testnet", Testnet: true},
+	{ChainID: "262", Name: "sur_block", DisplayName: "Sur Block"},
+	{ChainID: "266", Name: "neura", DisplayName: "Neura"},
+	{ChainID: "267", Name: "neura_testnet", DisplayName: "Neura Testnet", Testnet: true},
+	{ChainID: "268", Name: "neura_devnet", DisplayName: "Neura Devnet", Testnet: true},
+	{ChainID: "269", Name: "high_performance_block", DisplayName: "High Performance Block"},
+	{ChainID: "271", Name: "egoncoin", DisplayName: "Egoncoin"},
+	{ChainID: "278", Name: "xfair.ai", DisplayName: "Xfair.ai"},
+	{ChainID: "279", Name: "bpx_block", DisplayName: "Bpx Block"},
+	{ChainID: "282", Name: "cronos_zkevm_testnet", DisplayName: "Cronos Zkevm Testnet", Testnet: true},
+	{ChainID: "288", Name: "boba", DisplayName: "Boba"},
+	{ChainID: "291", Name: "orderly", DisplayName: "Orderly"},
+	{ChainID: "295", Name: "hedera", DisplayName: "Hedera"},
+	{ChainID: "296", Name: "hedera_testnet", DisplayName: "Hedera Testnet", Testnet: true},
+	{ChainID: "297", Name: "hedera_previewnet", DisplayName: "Hedera Previewnet"},
+	{ChainID: "298", Name: "hedera_localnet", DisplayName: "Hedera Localnet"},
+	{ChainID: "300", Name: "zksync_sepolia_testnet", DisplayName: "zkSync Sepolia Testnet", Aliases: []string{"zksync_sepolia"}, Testnet: true, Parent: "11155111", Currency: "ETH", Explorer: "https://sepolia.explorer.zksync.io"},
+	{ChainID: "301", Name: "bobaopera", DisplayName: "Bobaopera"},
+	{ChainID: "302", Name: "zkcandy_sepolia_testnet", DisplayName: "Zkcandy Sepolia Testnet", Testnet: true},
+	{ChainID: "303", Name: "neuro_testnet", DisplayName: "Neuro Testnet", Testnet: true},
+	{ChainID: "305", Name: "zksats", DisplayName: "Zksats"},
+	{ChainID: "307", Name: "lovely_testnet", DisplayName: "Lovely Testnet", Testnet: true},
+	{ChainID: "308", Name: "furtheon", DisplayName: "Furtheon"},
+	{ChainID: "309", Name: "wyzth_testnet", DisplayName: "Wyzth Testnet", Testnet: true},
+	{ChainID: "311", Name: "omax", DisplayName: "Omax"},
+	{ChainID: "313", Name: "neuro", DisplayName: "Neuro"},
+	{ChainID: "314", Name: "filecoin", DisplayName: "Filecoin"},
+	{ChainID: "321", Name: "kcc", DisplayName: "Kcc"},
+	{ChainID: "322", Name: "kcc_testnet", DisplayName: "Kcc Testnet", Testnet: true},
+	{ChainID: "323", Name: "cosvm", DisplayName: "Cosvm"},
+	{ChainID: "324", Name: "zksync", DisplayName: "zkSync Mainnet", Aliases: []string{"zksync_era", "era"}, Parent: "1", Currency: "ETH", Explorer: "https://explorer.zksync.io"},
+	{ChainID: "325", Name: "grvt", DisplayName: "Grvt"},
+	{ChainID: "326", Name: "grvt_sepolia_testnet", DisplayName: "Grvt Sepolia Testnet", Testnet: true},
+	{ChainID: "333", Name: "web3q", DisplayName: "Web3q"},
+	{ChainID: "335", Name: "dfk_test", DisplayName: "Dfk Test", Testnet: true},
+	{ChainID: "336", Name: "shiden", DisplayName: "Shiden"},
+	{ChainID: "338", Name: "cronos_testnet", DisplayName: "Cronos Testnet", Testnet: true},
+	{ChainID: "345", Name: "tsc", DisplayName: "Tsc"},
+	{ChainID: "360", Name: "shape", DisplayName: "Shape"},
+	{ChainID: "361", Name: "theta", DisplayName: "Theta"},
+	{ChainID: "363", Name: "theta_sapphire_testnet", DisplayName: "Theta Sapphire Testnet", Testnet: true},
+	{ChainID: "364", Name: "theta_amber_testnet", DisplayName: "Theta Amber Testnet", Testnet: true},
+	{ChainID: "365", Name: "theta_testnet", DisplayName: "Theta Testnet", Testnet: true},
+	{ChainID: "369", Name: "pulse", DisplayName: "Pulse"},
+	{ChainID: "371", Name: "consta_testnet", DisplayName: "Consta Testnet", Testnet: true},
+	{ChainID: "380", Name: "zkamoeba_testnet", DisplayName: "Zkamoeba Testnet", Testnet: true},
+	{ChainID: "381", Name: "zkamoeba", DisplayName: "Zkamoeba"},
+	{ChainID: "385", Name: "lisinski", DisplayName: "Lisinski"},
+	{ChainID: "388", Name: "cronos_zkevm", DisplayName: "Cronos Zkevm"},
+	{ChainID: "395", Name: "camdl_testnet", DisplayName: "Camdl Testnet", Testnet: true},
+	{ChainID: "397", Name: "near", DisplayName: "Near"},
+	{ChainID: "398", Name: "near_testnet", DisplayName: "Near Testnet", Testnet: true},
+	{ChainID: "399", Name: "nativ3", DisplayName: "Nativ3"},
+	{ChainID: "400", Name: "hyperon_testnet", DisplayName: "Hyperon Testnet", Testnet: true},
+	{ChainID: "401", Name: "ozone_testnet", DisplayName: "Ozone Testnet", Testnet: true},
+	{ChainID: "404", Name: "syndr_l3", DisplayName: "Syndr L3"},
+	{ChainID: "411", Name: "pepe", DisplayName: "Pepe"},
+	{ChainID: "416", Name: "sx", DisplayName: "Sx"},
+	{ChainID: "418", Name: "latestnet", DisplayName: "Latestnet", Testnet: true},
+	{ChainID: "420", Name: "optimism_goerli_testnet", DisplayName: "Optimism Goerli Testnet", Testnet: true},
+	{ChainID: "422", Name: "viridis", DisplayName: "Viridis"},
+	{ChainID: "424", Name: "pgn_public_goods", DisplayName: "Pgn Public Goods"},
+	{ChainID: "427", Name: "zeeth", DisplayName: "Zeeth"},
+	{ChainID: "428", Name: "geso_verse", DisplayName: "Geso Verse"},
+	{ChainID: "434", Name: "boyaa", DisplayName: "Boyaa"},
+	{ChainID: "443", Name: "ten_testnet", DisplayName: "Ten Testnet", Testnet: true},
+	{ChainID: "444", Name: "synapse_testnet", DisplayName: "Synapse Testnet", Testnet: true},
+	{ChainID: "456", Name: "arzio", DisplayName: "Arzio"},
+	{ChainID: "462", Name: "areon_testnet", DisplayName: "Areon Testnet", Testnet: true},
+	{ChainID: "463", Name: "areon", DisplayName: "Areon"},
+	{ChainID: "480", Name: "world", DisplayName: "World"},
+	{ChainID: "499", Name: "rupaya", DisplayName: "Rupaya"},
+	{ChainID: "500", Name: "camino_c", DisplayName: "Camino C"},
+	{ChainID: "501", Name: "columbus_test", DisplayName: "Columbus Test", Testnet: true},
+	{ChainID: "510", Name: "syndicate", DisplayName: "Syndicate"},
+	{ChainID: "512", Name: "double_a", DisplayName: "Double A"},
+	{ChainID: "513", Name: "double_a_testnet", DisplayName: "Double A Testnet", Testnet: true},
+	{ChainID: "516", Name: "gear_zero", DisplayName: "Gear Zero"},
+	{ChainID: "520", Name: "xt", DisplayName: "Xt"},
+	{ChainID: "529", Name: "fire", DisplayName: "Fire"},
+	{ChainID: "530", Name: "fxcore", DisplayName: "Fxcore"},
+	{ChainID: "534", Name: "candle", DisplayName: "Candle"},
+	{ChainID: "537", Name: "optrust", DisplayName: "Optrust"},
+	{ChainID: "542", Name: "paw_testnet", DisplayName: "Paw Testnet", Testnet: true},
+	{ChainID: "545", Name: "evm_on_flow_testnet", DisplayName: "Evm On Flow Testnet", Testnet: true},
+	{ChainID: "555", Name: "vela1", DisplayName: "Vela1"},
+	{ChainID: "558", Name: "tao", DisplayName: "Tao"},
+	{ChainID: "568", Name: "doge_testnet", DisplayName: "Doge Testnet", Testnet: true},
+	{ChainID: "570", Name: "rollux", DisplayName: "Rollux"},
+	{ChainID: "571", Name: "meta", DisplayName: "Meta"},
+	{ChainID: "579", Name: "filenova", DisplayName: "Filenova"},
+	{ChainID: "588", Name: "metis_stardust_testnet", DisplayName: "Metis Stardust Testnet", Testnet: true},
+	{ChainID: "592", Name: "astar", DisplayName: "Astar"},
+	{ChainID: "595", Name: "acala_mandala_testnet_tc9", DisplayName: "Acala Mandala Testnet Tc9", Testnet: true},
+	{ChainID: "596", Name: "karura_testnet", DisplayName: "Karura Testnet", Testnet: true},
+	{ChainID: "597", Name: "acala_testnet", DisplayName: "Acala Testnet", Testnet: true},
+	{ChainID: "599", Name: "metis_goerli_testnet", DisplayName: "Metis Goerli Testnet", Testnet: true},
+	{ChainID: "600", Name: "meshnyan_testnet", DisplayName: "Meshnyan Testnet", Testnet: true},
+	{ChainID: "601", Name: "vine_testnet", DisplayName: "Vine Testnet", Testnet: true},
+	{ChainID: "610", Name: "darwin_devnet", DisplayName: "Darwin Devnet", Testnet: true},
+	{ChainID: "612", Name: "eiob", DisplayName: "Eiob"},
+	{ChainID: "614", Name: "graphlinq_block", DisplayName: "Graphlinq Block"},
+	{ChainID: "624", Name: "binary", DisplayName: "Binary"},
+	{ChainID: "625", Name: "binary_sepolia", DisplayName: "Binary Sepolia", Testnet: true},
+	{ChainID: "634", Name: "avocado", DisplayName: "Avocado"},
+	{ChainID: "646", Name: "previewnet", DisplayName: "Previewnet"},
+	{ChainID: "647", Name: "sx_testnet", DisplayName: "Sx Testnet", Testnet: true},
+	{ChainID: "648", Name: "endurance", DisplayName: "Endurance"},
+	{ChainID: "653", Name: "kali_testnet", DisplayName: "Kali Testnet", Testnet: true},
+	{ChainID: "654", Name: "kali", DisplayName: "Kali"},
+	{ChainID: "662", Name: "ultronsmart", DisplayName: "Ultronsmart"},
+	{ChainID: "666", Name: "pixie_testnet", DisplayName: "Pixie Testnet", Testnet: true},
+	{ChainID: "667", Name: "laos_arrakis", DisplayName: "Laos Arrakis"},
+	{ChainID: "668", Name: "junca", DisplayName: "Junca"},
+	{ChainID: "669", Name: "junca_testnet", DisplayName: "Junca Testnet", Testnet: true},
+	{ChainID: "686", Name: "karura", DisplayName: "Karura"},
+	{ChainID: "690", Name: "redstone", DisplayName: "Redstone"},
+	{ChainID: "700", Name: "star_social_testnet", DisplayName: "Star Social Testnet", Testnet: true},
+	{ChainID: "701", Name: "darwinia_koi_testnet", DisplayName: "Darwinia Koi Testnet", Testnet: true},
+	{ChainID: "707", Name: "block_station", DisplayName: "Block Station"},
+	{ChainID: "708", Name: "block_station_testnet", DisplayName: "Block Station Testnet", Testnet: true},
+	{ChainID: "710", Name: "highbury", DisplayName: "Highbury"},
+	{ChainID: "711", Name: "tucana", DisplayName: "Tucana"},
+	{ChainID: "712", Name: "birdee_2", DisplayName: "Birdee 2"},
+	{ChainID: "713", Name: "vrcscan", DisplayName: "Vrcscan"},
+	{ChainID: "719", Name: "shibarium_beta", DisplayName: "Shibarium Beta"},
+	{ChainID: "721", Name: "lycan", DisplayName: "Lycan"},
+	{ChainID: "727", Name: "blucrates", DisplayName: "Blucrates"},
+	{ChainID: "730", Name: "lovely", DisplayName: "Lovely"},
+	{ChainID: "740", Name: "canto_testnet", DisplayName: "Canto Testnet", Testnet: true},
+	{ChainID: "741", Name: "vention_testnet", DisplayName: "Vention Testnet", Testnet: true},
+	{ChainID: "742", Name: "script_testnet", DisplayName: "Script Testnet", Testnet: true},
+	{ChainID: "747", Name: "evm_on_flow", DisplayName: "Evm On Flow"},
+	{ChainID: "766", Name: "ql1", DisplayName: "Ql1"},
+	{ChainID: "776", Name: "open_testnet", DisplayName: "Open Testnet", Testnet: true},
+	{ChainID: "777", Name: "cheapeth", DisplayName: "Cheapeth"},
+	{ChainID: "786", Name: "maal", DisplayName: "Maal"},
+	{ChainID: "787", Name: "acala", DisplayName: "Acala"},
+	{ChainID: "788", Name: "aero_testnet", DisplayName: "Aero Testnet", Testnet: true},
+	{ChainID: "789", Name: "patex", DisplayName: "Patex"},
+	{ChainID: "799", Name: "rupaya_testnet", DisplayName: "Rupaya Testnet", Testnet: true},
+	{ChainID: "800", Name: "lucid_block", DisplayName: "Lucid Block"},
+	{ChainID: "803", Name: "haic", DisplayName: "Haic"},
+	{ChainID: "808", Name: "portal_fantasy_test", DisplayName: "Portal Fantasy Test", Testnet: true},
+	{ChainID: "810", Name: "haven1_testnet", DisplayName: "Haven1 Testnet", Testnet: true},
+	{ChainID: "813", Name: "qitmeer", DisplayName: "Qitmeer"},
+	{ChainID: "814", Name: "fire_zkevm", DisplayName: "Fire Zkevm"},
+	{ChainID: "818", Name: "beone", DisplayName: "Beone"},
+	{ChainID: "820", Name: "callisto", DisplayName: "Callisto"},
+	{ChainID: "822", Name: "runic_testnet", DisplayName: "Runic Testnet", Testnet: true},
+	{ChainID: "831", Name: "checkdot_block_devnet", DisplayName: "Checkdot Block Devnet", Testnet: true},
+	{ChainID: "841", Name: "taraxa", DisplayName: "Taraxa"},
+	{ChainID: "842", Name: "taraxa_testnet", DisplayName: "Taraxa Testnet", Testnet: true},
+	{ChainID: "852", Name: "hongkong", DisplayName: "Hongkong"},
+	{ChainID: "859", Name: "zeeth_dev", DisplayName: "Zeeth Dev"},
+	{ChainID: "868", Name: "fantasia", DisplayName: "Fantasia"},
+	{ChainID: "876", Name: "bandai_namco_research_verse", DisplayName: "Bandai Namco Research Verse"},
+	{ChainID: "877", Name: "dexit", DisplayName: "Dexit"},
+	{ChainID: "880", Name: "ambros", DisplayName: "Ambros"},
+	{ChainID: "888", Name: "wan", DisplayName: "Wan"},
+	{ChainID: "898", Name: "maxi_testnet", DisplayName: "Maxi Testnet", Testnet: true},
+	{ChainID: "899", Name: "maxi", DisplayName: "Maxi"},
+	{ChainID: "900", Name: "garizon_testnet_stage0", DisplayName: "Garizon Testnet Stage0", Testnet: true},
+	{ChainID: "901", Name: "garizon_testnet_stage1", DisplayName: "Garizon Testnet Stage1", Testnet: true},
+	{ChainID: "902", Name: "garizon_testnet_stage2", DisplayName: "Garizon Testnet Stage2", Testnet: true},
+	{ChainID: "903", Name: "garizon_testnet_stage3", DisplayName: "Garizon Testnet Stage3", Testnet: true},
+	{ChainID: "909", Name: "portal_fantasy", DisplayName: "Portal Fantasy"},
+	{ChainID: "910", Name: "decentrabone_layer1_testnet", DisplayName: "Decentrabone Layer1 Testnet", Testnet: true},
+	{ChainID: "911", Name: "taproot", DisplayName: "Taproot"},
+	{ChainID: "917", Name: "rinia_testnet", DisplayName: "Rinia Testnet", Testnet: true},
+	{ChainID: "919", Name: "mode_testnet", DisplayName: "Mode Testnet", Testnet: true},
+	{ChainID: "927", Name: "yidark", DisplayName: "Yidark"},
+	{ChainID: "940", Name: "pulse_testnet", DisplayName: "Pulse Testnet", Testnet: true},
+	{ChainID: "941", Name: "pulse_testnet_v2b", DisplayName: "Pulse Testnet V2b", Testnet: true},
+	{ChainID: "942", Name: "pulse_testnet_v3", DisplayName: "Pulse Testnet V3", Testnet: true},
+	{ChainID: "943", Name: "pulse_testnet_v4", DisplayName: "Pulse Testnet V4", Testnet: true},
+	{ChainID: "945", Name: "subtensor_evm_testnet", DisplayName: "Subtensor Evm Testnet", Testnet: true},
+	{ChainID: "956", Name: "munode_testnet", DisplayName: "Munode Testnet", Testnet: true},
+	{ChainID: "957", Name: "lyra", DisplayName: "Lyra"},
+	{ChainID: "963", Name: "btc20", DisplayName: "Btc20"},
+	{ChainID: "969", Name: "ethxy", DisplayName: "Ethxy"},
+	{ChainID: "970", Name: "oort", DisplayName: "Oort"},
+	{ChainID: "971", Name: "oort_huygens", DisplayName: "Oort Huygens"},
+	{ChainID: "972", Name: "oort_ascraeus", DisplayName: "Oort Ascraeus"},
+	{ChainID: "973", Name: "palm", DisplayName: "Palm"},
+	{ChainID: "977", Name: "nepal_block", DisplayName: "Nepal Block"},
+	{ChainID: "979", Name: "ethxy_testnet", DisplayName: "Ethxy Testnet", Testnet: true},
+	{ChainID: "980", Name: "top_evm", DisplayName: "Top Evm"},
+	{ChainID: "985", Name: "memo", DisplayName: "Memo"},
+	{ChainID: "989", Name: "top", DisplayName: "Top"},
+	{ChainID: "990", Name: "eliberty", DisplayName: "Eliberty"},
+	{ChainID: "995", Name: "5ire", DisplayName: "5ire"},
+	{ChainID: "996", Name: "bifrost_polkadot", DisplayName: "Bifrost Polkadot"},
+	{ChainID: "997", Name: "5ire_thunder_testnet", DisplayName: "5ire Thunder Testnet", Testnet: true},
+	{ChainID: "998", Name: "lucky", DisplayName: "Lucky"},
+	{ChainID: "999", Name: "wan_testnet", DisplayName: "Wan Testnet", Testnet: true},
+	{ChainID: "1000", Name: "gton", DisplayName: "Gton"},
+	{ChainID: "1001", Name: "kaia_testnet_kairos", DisplayName: "Kaia Testnet Kairos", Testnet: true},
+	{ChainID: "1003", Name: "tectum_emission_token", DisplayName: "Tectum Emission Token"},
+	{ChainID: "1004", Name: "t_ekta", DisplayName: "T Ekta"},
+	{ChainID: "1005", Name: "lemontestnet", DisplayName: "Lemontestnet", Testnet: true},
+	{ChainID: "1006", Name: "lemon", DisplayName: "Lemon"},
+	{ChainID: "1007", Name: "newton_testnet", DisplayName: "Newton Testnet", Testnet: true},
+	{ChainID: "1008", Name: "eurus", DisplayName: "Eurus"},
+	{ChainID: "1009", Name: "jumbo", DisplayName: "Jumbo"},
+	{ChainID: "1010", Name: "evrice", DisplayName: "Evrice"},
+	{ChainID: "1011", Name: "rebus", DisplayName: "Rebus"},
+	{ChainID: "1012", Name: "newton", DisplayName: "Newton"},
+	{ChainID: "1022", Name: "sakura", DisplayName: "Sakura"},
+	{ChainID: "1023", Name: "clover_testnet", DisplayName: "Clover Testnet", Testnet: true},
+	{ChainID: "1024", Name: "clv_para", DisplayName: "Clv Para"},
+	{ChainID: "1028", Name: "bittorrent_testnet", DisplayName: "Bittorrent Testnet", Testnet: true},
+	{ChainID: "1030", Name: "conflux_espace", DisplayName: "Conflux Espace"},
+	{ChainID: "1031", Name: "proxy_testnet", DisplayName: "Proxy Testnet", Testnet: true},
+	{ChainID: "1038", Name: "bronos_testnet", DisplayName: "Bronos Testnet", Testnet: true},
+	{ChainID: "1039", Name: "bronos", DisplayName: "Bronos"},
+	{ChainID: "1073", Name: "shimmerevm_testnet", DisplayName: "Shimmerevm Testnet", Testnet: true},
+	{ChainID: "1075", Name: "iota_evm_testnet", DisplayName: "Iota Evm Testnet", Testnet: true},
+	{ChainID: "1079", Name: "mintara_testnet", DisplayName: "Mintara Testnet", Testnet: true},
+	{ChainID: "1080", Name: "mintara", DisplayName: "Mintara"},
+	{ChainID: "1088", Name: "metis_andromeda", DisplayName: "Metis Andromeda"},
+	{ChainID: "1089", Name: "humans.ai", DisplayName: "Humans.ai"},
+	{ChainID: "1099", Name: "moac", DisplayName: "Moac"},
+	{ChainID: "1100", Name: "dymension", DisplayName: "Dymension"},
+	{ChainID: "1101", Name: "polygon_zkevm", DisplayName: "Polygon zkEVM", Parent: "1", Currency: "ETH", Explorer: "https://zkevm.polygonscan.com"},
+	{ChainID: "1107", Name: "blxq_testnet", DisplayName: "Blxq Testnet", Testnet: true},
+	{ChainID: "1108", Name: "blxq", DisplayName: "Blxq"},
+	{ChainID: "1111", Name: "wemix", DisplayName: "Wemix"},
+	{ChainID: "1112", Name: "wemix_testnet", DisplayName: "Wemix Testnet", Testnet: true},
+	{ChainID: "1113", Name: "b2_hub_testnet", DisplayName: "B2 Hub Testnet", Testnet: true},
+	{ChainID: "1115", Name: "core_block_testnet", DisplayName: "Core Block Testnet", Testnet: true},
+	{ChainID: "1116", Name: "core_block", DisplayName: "Core Block"},
+	{ChainID: "1117", Name: "dogcoin", DisplayName: "Dogcoin"},
+	{ChainID: "1123", Name: "b2_testnet", DisplayName: "B2 Testnet", Testnet: true},
+	{ChainID: "1130", Name: "defi_evm", DisplayName: "Defi Evm"},
+	{ChainID: "1131", Name: "defi_evm_testnet", DisplayName: "Defi Evm Testnet", Testnet: true},
+	{ChainID: "1133", Name: "defimeta_changi_testnet", DisplayName: "Defimeta Changi Testnet", Testnet: true},
+	{ChainID: "1135", Name: "lisk", DisplayName: "Lisk"},
+	{ChainID: "1138", Name: "amstar_testnet", DisplayName: "Amstar Testnet", Testnet: true},
+	{ChainID: "1139", Name: "math", DisplayName: "Math"},
+	{ChainID: "1140", Name: "math_testnet", DisplayName: "Math Testnet", Testnet: true},
+	{ChainID: "1147", Name: "flag_testnet", DisplayName: "Flag Testnet", Testnet: true},
+	{ChainID: "1149", Name: "symplexia", DisplayName: "Symplexia"},
+	{ChainID: "1170", Name: "origin_testnet", DisplayName: "Origin Testnet", Testnet: true},
+	{ChainID: "1177", Name: "smart_host_teknoloji_testnet", DisplayName: "Smart Host Teknoloji Testnet", Testnet: true},
+	{ChainID: "1188", Name: "clubmos", DisplayName: "Clubmos"},
+	{ChainID: "1197", Name: "iora", DisplayName: "Iora"},
+	{ChainID: "1200", Name: "cuckoo", DisplayName: "Cuckoo"},
+	{ChainID: "1201", Name: "evanesco_testnet", DisplayName: "Evanesco Testnet", Testnet: true},
+	{ChainID: "1202", Name: "world_trade_technical", DisplayName: "World Trade Technical"},
+	{ChainID: "1209", Name: "saitablocksbc", DisplayName: "Saitablocksbc"},
+	{ChainID: "1210", Name: "cuckoo_sepolia", DisplayName: "Cuckoo Sepolia", Testnet: true},
+	{ChainID: "1213", Name: "popcateum", DisplayName: "Popcateum"},
+	{ChainID: "1214", Name: "enter", DisplayName: "Enter"},
+	{ChainID: "1221", Name: "cycle_testnet", DisplayName: "Cycle Testnet", Testnet: true},
+	{ChainID: "1223", Name: "cycle_testnet_jellyfish", DisplayName: "Cycle Testnet Jellyfish", Testnet: true},
+	{ChainID: "1225", Name: "hybrid_testnet", DisplayName: "Hybrid Testnet", Testnet: true},
+	{ChainID: "1227", Name: "bitcoin_protocol_testnet", DisplayName: "Bitcoin Protocol Testnet", Testnet: true},
+	{ChainID: "1228", Name: "cycle_testnet_cuttlefish", DisplayName: "Cycle Testnet Cuttlefish", Testnet: true},
+	{ChainID: "1229", Name: "exzo", DisplayName: "Exzo"},
+	{ChainID: "1230", Name: "ultron_testnet", DisplayName: "Ultron Testnet", Testnet: true},
+	{ChainID: "1231", Name: "ultron", DisplayName: "Ultron"},
+	{ChainID: "1234", Name: "step", DisplayName: "Step"},
+	{ChainID: "1235", Name: "itx", DisplayName: "Itx"},
+	{ChainID: "1243", Name: "arc", DisplayName: "Arc"},
+	{ChainID: "1244", Name: "arc_testnet", DisplayName: "Arc Testnet", Testnet: true},
+	{ChainID: "1246", Name: "om_platform", DisplayName: "Om Platform"},
+	{ChainID: "1248", Name: "dogether", DisplayName: "Dogether"},
+	{ChainID: "1252", Name: "cic_testnet", DisplayName: "Cic Testnet", Testnet: true},
+	{ChainID: "1260", Name: "metacces_testnet", DisplayName: "Metacces Testnet", Testnet: true},
+	{ChainID: "1280", Name: "halo", DisplayName: "Halo"},
+	{ChainID: "1284", Name: "moonbeam", DisplayName: "Moonbeam"},
+	{ChainID: "1285", Name: "moonriver", DisplayName: "Moonriver"},
+	{ChainID: "1286", Name: "moonrock_old", DisplayName: "Moonrock Old"},
+	{ChainID: "1287", Name: "moonbase_alpha", DisplayName: "Moonbase Alpha"},
+	{ChainID: "1288", Name: "moonrock", DisplayName: "Moonrock"},
+	{ChainID: "1291", Name: "swisstronik_testnet", DisplayName: "Swisstronik Testnet", Testnet: true},
+	{ChainID: "1294", Name: "bobabeam", DisplayName: "Bobabeam"},
+	{ChainID: "1297", Name: "bobabase_testnet", DisplayName: "Bobabase Testnet", Testnet: true},
+	{ChainID: "1311", Name: "dos_fuji_subnet", DisplayName: "Dos Fuji Subnet", Testnet: true},
+	{ChainID: "1313", Name: "jaiho", DisplayName: "Jaiho"},
+	{ChainID: "1314", Name: "alyx", DisplayName: "Alyx"},
+	{ChainID: "1319", Name: "aia", DisplayName: "Aia"},
+	{ChainID: "1320", Name: "aia_testnet", DisplayName: "Aia Testnet", Testnet: true},
+	{ChainID: "1328", Name: "sei_testnet", DisplayName: "Sei Testnet", Testnet: true},
+	{ChainID: "1329", Name: "sei", DisplayName: "Sei"},
+	{ChainID: "1337", Name: "geth_testnet", DisplayName: "Geth Testnet", Testnet: true},
+	{ChainID: "1338", Name: "elysium_testnet", DisplayName: "Elysium Testnet", Testnet: true},
+	{ChainID: "1339", Name: "elysium", DisplayName: "Elysium"},
+	{ChainID: "1343", Name: "blitz_subnet", DisplayName: "Blitz Subnet"},
+	{ChainID: "1353", Name: "cic", DisplayName: "Cic"},
+	{ChainID: "1369", Name: "zafirium", DisplayName: "Zafirium"},
+	{ChainID: "1370", Name: "ramestta", DisplayName: "Ramestta"},
+	{ChainID: "1377", Name: "pingaksha_testnet", DisplayName: "Pingaksha Testnet", Testnet: true},
+	{ChainID: "1379", Name: "kalar", DisplayName: "Kalar"},
+	{ChainID: "1388", Name: "amstar", DisplayName: "Amstar"},
+	{ChainID: "1392", Name: "joseon", DisplayName: "Joseon"},
+	{ChainID: "1402", Name: "polygon_zkevm_testnet_old", DisplayName: "Polygon Zkevm Testnet Old", Testnet: true},
+	{ChainID: "1422", Name: "polygon_zkevm_testnet_pre_audit_upgraded", DisplayName: "Polygon Zkevm Testnet Pre Audit Upgraded", Testnet: true},
+	{ChainID: "1433", Name: "rikeza", DisplayName: "Rikeza"},
+	{ChainID: "1440", Name: "living_assets", DisplayName: "Living Assets"},
+	{ChainID: "1442", Name: "polygon_zkevm_testnet", DisplayName: "Polygon Zkevm Testnet", Testnet: true},
+	{ChainID: "1452", Name: "gil_testnet", DisplayName: "Gil Testnet", Testnet: true},
+	{ChainID: "1453", Name: "meta_istanbul", DisplayName: "Meta Istanbul"},
+	{ChainID: "1455", Name: "ctex_scan_block", DisplayName: "Ctex Scan Block"},
+	{ChainID: "1456", Name: "zkbase", DisplayName: "Zkbase"},
+	{ChainID: "1490", Name: "vitruveo", DisplayName: "Vitruveo"},
+	{ChainID: "1499", Name: "idos_games_testnet", DisplayName: "Idos Games Testnet", Testnet: true},
+	{ChainID: "1501", Name: "bevm_canary", DisplayName: "Bevm Canary"},
+	{ChainID: "1506", Name: "sherpax", DisplayName: "Sherpax"},
+	{ChainID: "1507", Name: "sherpax_testnet", DisplayName: "Sherpax Testnet", Testnet: true},
+	{ChainID: "1513", Name: "story_testnet", DisplayName: "Story Testnet", Testnet: true},
+	{ChainID: "1515", Name: "beagle_messaging", DisplayName: "Beagle Messaging"},
+	{ChainID: "1559", Name: "tenet", DisplayName: "Tenet"},
+	{ChainID: "1570", Name: "star_testnet", DisplayName: "Star Testnet", Testnet: true},
+	{ChainID: "1578", Name: "star", DisplayName: "Star"},
+	{ChainID: "1617", Name: "ethereum_inscription", DisplayName: "Ethereum Inscription"},
+	{ChainID: "1618", Name: "catecoin", DisplayName: "Catecoin"},
+	{ChainID: "1620", Name: "atheios", DisplayName: "Atheios"},
+	{ChainID: "1625", Name: "gravity_alpha", DisplayName: "Gravity Alpha"},
+	{ChainID: "1648", Name: "pivotal", DisplayName: "Pivotal"},
+	{ChainID: "1657", Name: "bta", DisplayName: "Bta"},
+	{ChainID: "1662", Name: "liqui", DisplayName: "Liqui"},
+	{ChainID: "1663", Name: "horizen_gobi_testnet", DisplayName: "Horizen Gobi Testnet", Testnet: true},
+	{ChainID: "1686", Name: "mint_testnet", DisplayName: "Mint Testnet", Testnet: true},
+	{ChainID: "1687", Name: "mint_sepolia_testnet", DisplayName: "Mint Sepolia Testnet", Testnet: true},
+	{ChainID: "1688", Name: "ludan", DisplayName: "Ludan"},
+	{ChainID: "1701", Name: "anytype_evm", DisplayName: "Anytype Evm"},
+	{ChainID: "1707", Name: "tbsi", DisplayName: "Tbsi"},
+	{ChainID: "1708", Name: "tbsi_testnet", DisplayName: "Tbsi Testnet", Testnet: true},
+	{ChainID: "1717", Name: "doric", DisplayName: "Doric"},
+	{ChainID: "1718", Name: "palette", DisplayName: "Palette"},
+	{ChainID: "1729", Name: "reya", DisplayName: "Reya"},
+	{ChainID: "1740", Name: "metal_l2_testnet", DisplayName: "Metal L2 Testnet", Testnet: true},
+	{ChainID: "1750", Name: "metal_l2", DisplayName: "Metal L2"},
+	{ChainID: "1773", Name: "party", DisplayName: "Party"},
+	{ChainID: "1777", Name: "gauss", DisplayName: "Gauss"},
+	{ChainID: "1789", Name: "zkbase_sepolia_testnet", DisplayName: "Zkbase Sepolia Testnet", Testnet: true},
+	{ChainID: "1804", Name: "kerleano", DisplayName: "Kerleano"},
+	{ChainID: "1807", Name: "rabbit_analog_testnet", DisplayName: "Rabbit Analog Testnet", Testnet: true},
+	{ChainID: "1811", Name: "lif3_testnet", DisplayName: "Lif3 Testnet", Testnet: true},
+	{ChainID: "1818", Name: "cube", DisplayName: "Cube"},
+	{ChainID: "1819", Name: "cube_testnet", DisplayName: "Cube Testnet", Testnet: true},
+	{ChainID: "1821", Name: "ruby", DisplayName: "Ruby"},
+	{ChainID: "1853", Name: "highoctane_subnet", DisplayName: "Highoctane Subnet"},
+	{ChainID: "1856", Name: "teslafunds", DisplayName: "Teslafunds"},
+	{ChainID: "1875", Name: "white", DisplayName: "White"},
+	{ChainID: "1881", Name: "gitshock_cartenz_testnet", DisplayName: "Gitshock Cartenz Testnet", Testnet: true},
+	{ChainID: "1890", Name: "lightlink_phoenix", DisplayName: "Lightlink Phoenix"},
+	{ChainID: "1891", Name: "lightlink_pegasus_testnet", DisplayName: "Lightlink Pegasus Testnet", Testnet: true},
+	{ChainID: "1898", Name: "bon", DisplayName: "Bon"},
+	{ChainID: "1899", Name: "redefi_layer_2", DisplayName: "Redefi Layer 2"},
+	{ChainID: "1904", Name: "sports", DisplayName: "Sports"},
+	{ChainID: "1907", Name: "bitci", DisplayName: "Bitci"},
+	{ChainID: "1908", Name: "bitci_testnet", DisplayName: "Bitci Testnet", Testnet: true},
+	{ChainID: "1909", Name: "merkle_scan", DisplayName: "Merkle Scan"},
+	{ChainID: "1911", Name: "scalind", DisplayName: "Scalind"},
+	{ChainID: "1912", Name: "ruby_testnet", DisplayName: "Ruby Testnet", Testnet: true},
+	{ChainID: "1918", Name: "upb_crescdi_testnet", DisplayName: "Upb Crescdi Testnet", Testnet: true},
+	{ChainID: "1945", Name: "onus_testnet", DisplayName: "Onus Testnet", Testnet: true},
+	{ChainID: "1946", Name: "minato", DisplayName: "Minato"},
+	{ChainID: "1951", Name: "d", DisplayName: "D"},
+	{ChainID: "1953", Name: "selendra_testnet", DisplayName: "Selendra Testnet", Testnet: true},
+	{ChainID: "1954", Name: "dexilla_testnet", DisplayName: "Dexilla Testnet", Testnet: true},
+	{ChainID: "1956", Name: "aiw3_testnet", DisplayName: "Aiw3 Testnet", Testnet: true},
+	{ChainID: "1961", Name: "selendra", DisplayName: "Selendra"},
+	{ChainID: "1967", Name: "eleanor", DisplayName: "Eleanor"},
+	{ChainID: "1969", Name: "super_testnet", DisplayName: "Super Testnet", Testnet: true},
+	{ChainID: "1970", Name: "super", DisplayName: "Super"},
+	{ChainID: "1971", Name: "atelier", DisplayName: "Atelier"},
+	{ChainID: "1972", Name: "redecoin", DisplayName: "Redecoin"},
+	{ChainID: "1975", Name: "onus", DisplayName: "Onus"},
+	{ChainID: "1984", Name: "eurus_testnet", DisplayName: "Eurus Testnet", Testnet: true},
+	{ChainID: "1985", Name: "satoshie", DisplayName: "Satoshie"},
+	{ChainID: "1986", Name: "satoshie_testnet", DisplayName: "Satoshie Testnet", Testnet: true},
+	{ChainID: "1987", Name: "ethergem", DisplayName: "Ethergem"},
+	{ChainID: "1992", Name: "hubble_exchange", DisplayName: "Hubble Exchange"},
+	{ChainID: "1993", Name: "b3_sepolia_testnet", DisplayName: "B3 Sepolia Testnet", Testnet: true},
+	{ChainID: "1994", Name: "ekta", DisplayName: "Ekta"},
+	{ChainID: "1995", Name: "edexa_testnet", DisplayName: "Edexa Testnet", Testnet: true},
+	{ChainID: "1996", Name: "sanko", DisplayName: "Sanko"},
+	{ChainID: "1997", Name: "kyoto", DisplayName: "Kyoto"},
+	{ChainID: "1998", Name: "kyoto_testnet", DisplayName: "Kyoto Testnet", Testnet: true},
+	{ChainID: "2000", Name: "doge", DisplayName: "Doge"},
+	{ChainID: "2001", Name: "milkomeda_c1", DisplayName: "Milkomeda C1"},
+	{ChainID: "2002", Name: "milkomeda_a1", DisplayName: "Milkomeda A1"},
+	{ChainID: "2004", Name: "metalink", DisplayName: "Metalink"},
+	{ChainID: "2008", Name: "cloudwalk_testnet", DisplayName: "Cloudwalk Testnet", Testnet: true},
+	{ChainID: "2009", Name: "cloudwalk", DisplayName: "Cloudwalk"},
+	{ChainID: "2013", Name: "panarchy", DisplayName: "Panarchy"},
+	{ChainID: "2014", Name: "now_testnet", DisplayName: "Now Testnet", Testnet: true},
+	{ChainID: "2016", Name: "mainnetz", DisplayName: "Mainnetz"},
+	{ChainID: "2017", Name: "adiri", DisplayName: "Adiri"},
+	{ChainID: "2018", Name: "publicmint_devnet", DisplayName: "Publicmint Devnet", Testnet: true},
+	{ChainID: "2019", Name: "publicmint_testnet", DisplayName: "Publicmint Testnet", Testnet: true},
+	{ChainID: "2020", Name: "publicmint", DisplayName: "Publicmint"},
+	{ChainID: "2021", Name: "edgeware_edgeevm", DisplayName: "Edgeware Edgeevm"},
+	{ChainID: "2022", Name: "beresheet_bereevm_testnet", DisplayName: "Beresheet Bereevm Testnet", Testnet: true},
+	{ChainID: "2023", Name: "taycan_testnet", DisplayName: "Taycan Testnet", Testnet: true},
+	{ChainID: "2024", Name: "swan_saturn_testnet", DisplayName: "Swan Saturn Testnet", Testnet: true},
+	{ChainID: "2025", Name: "rangers_protocol", DisplayName: "Rangers Protocol"},
+	{ChainID: "2026", Name: "edgeless", DisplayName: "Edgeless"},
+	{ChainID: "2031", Name: "centrifuge", DisplayName: "Centrifuge"},
+	{ChainID: "2032", Name: "catalyst", DisplayName: "Catalyst"},
+	{ChainID: "2035", Name: "phala", DisplayName: "Phala"},
+	{ChainID: "2037", Name: "kiwi_subnet", DisplayName: "Kiwi Subnet"},
+	{ChainID: "2038", Name: "shrapnel_testnet", DisplayName: "Shrapnel Testnet", Testnet: true},
+	{ChainID: "2039", Name: "aleph_zero", DisplayName: "Aleph Zero"},
+	{ChainID: "2040", Name: "vanar", DisplayName: "Vanar"},
+	{ChainID: "2043", Name: "neuroweb", DisplayName: "Neuroweb"},
+	{ChainID: "2044", Name: "shrapnel_subnet", DisplayName: "Shrapnel Subnet"},
+	{ChainID: "2045", Name: "aiw3", DisplayName: "Aiw3"},
+	{ChainID: "2047", Name: "stratos_testnet", DisplayName: "Stratos Testnet", Testnet: true},
+	{ChainID: "2048", Name: "stratos", DisplayName: "Stratos"},
+	{ChainID: "2049", Name: "movo", DisplayName: "Movo"},
+	{ChainID: "2071", Name: "metacces", DisplayName: "Metacces"},
+	{ChainID: "2077", Name: "quokkacoin", DisplayName: "Quokkacoin"},
+	{ChainID: "2088", Name: "altair", DisplayName: "Altair"},
+	{ChainID: "2089", Name: "algol", DisplayName: "Algol"},
+	{ChainID: "2100", Name: "ecoball", DisplayName: "Ecoball"},
+	{ChainID: "2101", Name: "ecoball_testnet_espuma", DisplayName: "Ecoball Testnet Espuma", Testnet: true},
+	{ChainID: "2109", Name: "exosama", DisplayName: "Exosama"},
+	{ChainID: "2112", Name: "u", DisplayName: "U"},
+	{ChainID: "2121", Name: "catena", DisplayName: "Catena"},
+	{ChainID: "2122", Name: "metaplayerone", DisplayName: "Metaplayerone"},
+	{ChainID: "2124", Name: "metaplayerone_dubai_testnet", DisplayName: "Metaplayerone Dubai Testnet", Testnet: true},
+	{ChainID: "2136", Name: "bigshortbets_testnet", DisplayName: "Bigshortbets Testnet", Testnet: true},
+	{ChainID: "2137", Name: "bigshortbets", DisplayName: "Bigshortbets"},
+	{ChainID: "2138", Name: "defi_oracle_meta_testnet", DisplayName: "Defi Oracle Meta Testnet", Testnet: true},
+	{ChainID: "2140", Name: "oneness", DisplayName: "Oneness"},
+	{ChainID: "2141", Name: "oneness_testnet", DisplayName: "Oneness Testnet", Testnet: true},
+	{ChainID: "2151", Name: "bosagora", DisplayName: "Bosagora"},
+	{ChainID: "2152", Name: "findora", DisplayName: "Findora"},
+	{ChainID: "2153", Name: "findora_testnet", DisplayName: "Findora Testnet", Testnet: true},
+	{ChainID: "2154", Name: "findora_forge", DisplayName: "Findora Forge"},
+	{ChainID: "2162", Name: "anime_testnet", DisplayName: "Anime Testnet", Testnet: true},
+	{ChainID: "2192", Name: "snax", DisplayName: "Snax"},
+	{ChainID: "2199", Name: "moonsama", DisplayName: "Moonsama"},
+	{ChainID: "2202", Name: "antofy", DisplayName: "Antofy"},
+	{ChainID: "2203", Name: "bitcoin_evm", DisplayName: "Bitcoin Evm"},
+	{ChainID: "2213", Name: "evanesco", DisplayName: "Evanesco"},
+	{ChainID: "2221", Name: "kava_testnet", DisplayName: "Kava Testnet", Testnet: true},
+	{ChainID: "2222", Name: "kava", DisplayName: "Kava"},
+	{ChainID: "2223", Name: "v", DisplayName: "V"},
+	{ChainID: "2241", Name: "krest", DisplayName: "Krest"},
+	{ChainID: "2300", Name: "bomb", DisplayName: "Bomb"},
+	{ChainID: "2306", Name: "ebro", DisplayName: "Ebro"},
+	{ChainID: "2309", Name: "arevia", DisplayName: "Arevia"},
+	{ChainID: "2311", Name: "chronicle_vesuvius_lit_protocol_testnet", DisplayName: "Chronicle Vesuvius Lit Protocol Testnet", Testnet: true},
+	{ChainID: "2323", Name: "soma_testnet", DisplayName: "Soma Testnet", Testnet: true},
+	{ChainID: "2330", Name: "altcoin", DisplayName: "Altcoin"},
+	{ChainID: "2331", Name: "rss3_vsl_sepolia_testnet", DisplayName: "Rss3 Vsl Sepolia Testnet", Testnet: true},
+	{ChainID: "2332", Name: "soma", DisplayName: "Soma"},
+	{ChainID: "2340", Name: "atleta_olympia", DisplayName: "Atleta Olympia"},
+	{ChainID: "2342", Name: "omnia", DisplayName: "Omnia"},
+	{ChainID: "2355", Name: "silicon_zkevm", DisplayName: "Silicon Zkevm"},
+	{ChainID: "2358", Name: "kroma_sepolia", DisplayName: "Kroma Sepolia", Testnet: true},
+	{ChainID: "2370", Name: "nexis_testnet", DisplayName: "Nexis Testnet", Testnet: true},
+	{ChainID: "2399", Name: "bomb_testnet", DisplayName: "Bomb Testnet", Testnet: true},
+	{ChainID: "2400", Name: "tcg_verse", DisplayName: "Tcg Verse"},
+	{ChainID: "2410", Name: "karak", DisplayName: "Karak"},
+	{ChainID: "2415", Name: "xodex", DisplayName: "Xodex"},
+	{ChainID: "2425", Name: "king_of_legends", DisplayName: "King Of Legends"},
+	{ChainID: "2442", Name: "polygon_zkevm_cardona_testnet", DisplayName: "Polygon Zkevm Cardona Testnet", Testnet: true},
+	{ChainID: "2468", Name: "hybrid", DisplayName: "Hybrid"},
+	{ChainID: "2477", Name: "6degree_of_outreach", DisplayName: "6degree Of Outreach"},
+	{ChainID: "2484", Name: "unicorn_ultra_nebulas_testnet", DisplayName: "Unicorn Ultra Nebulas Testnet", Testnet: true},
+	{ChainID: "2511", Name: "karak_goerli", DisplayName: "Karak Goerli", Testnet: true},
+	{ChainID: "2522", Name: "fraxtal_testnet", DisplayName: "Fraxtal Testnet", Testnet: true},
+	{ChainID: "2525", Name: "inevm", DisplayName: "Inevm"},
+	{ChainID: "2559", Name: "kortho", DisplayName: "Kortho"},
+	{ChainID: "2569", Name: "techpay", DisplayName: "Techpay"},
+	{ChainID: "2606", Name: "pocrnet", DisplayName: "Pocrnet"},
+	{ChainID: "2611", Name: "redlight", DisplayName: "Redlight"},
+	{ChainID: "2612", Name: "ez_c", DisplayName: "Ez C"},
+	{ChainID: "2613", Name: "ez_c_testnet", DisplayName: "Ez C Testnet", Testnet: true},
+	{ChainID: "2625", Name: "white_testnet", DisplayName: "White Testnet", Testnet: true},
+	{ChainID: "2648", Name: "ailayer_testnet", DisplayName: "Ailayer Testnet", Testnet: true},
+	{ChainID: "2649", Name: "ailayer", DisplayName: "Ailayer"},
+	{ChainID: "2662", Name: "apex", DisplayName: "Apex"},
+	{ChainID: "2710", Name: "morph_testnet", DisplayName: "Morph Testnet", Testnet: true},
+	{ChainID: "2718", Name: "k_laos", DisplayName: "K Laos"},
+	{ChainID: "2730", Name: "xr_sepolia", DisplayName: "Xr Sepolia", Testnet: true},
+	{ChainID: "2731", Name: "elizabeth_testnet", DisplayName: "Elizabeth Testnet", Testnet: true},
+	{ChainID: "2748", Name: "nanon", DisplayName: "Nanon"},
+	{ChainID: "2777", Name: "gm", DisplayName: "Gm"},
+	{ChainID: "2810", Name: "morph_holesky", DisplayName: "Morph Holesky", Testnet: true},
+	{ChainID: "2868", Name: "hyperagi", DisplayName: "Hyperagi"},
+	{ChainID: "2882", Name: "chips", DisplayName: "Chips"},
+	{ChainID: "2888", Name: "boba_goerli_testnet", DisplayName: "Boba Goerli Testnet", Testnet: true},
+	{ChainID: "2889", Name: "aarma", DisplayName: "Aarma"},
+	{ChainID: "2907", Name: "elux", DisplayName: "Elux"},
+	{ChainID: "2911", Name: "hy", DisplayName: "Hy"},
+	{ChainID: "2941", Name: "xenon_testnet", DisplayName: "Xenon Testnet", Testnet: true},
+	{ChainID: "2999", Name: "bityuan", DisplayName: "Bityuan"},
+	{ChainID: "3000", Name: "cennznet_rata", DisplayName: "Cennznet Rata"},
+	{ChainID: "3001", Name: "cennznet_nikau", DisplayName: "Cennznet Nikau"},
+	{ChainID: "3003", Name: "canxium", DisplayName: "Canxium"},
+	{ChainID: "3011", Name: "playa3ull_games", DisplayName: "Playa3ull Games"},
+	{ChainID: "3031", Name: "orlando", DisplayName: "Orlando"},
+	{ChainID: "3033", Name: "rebus_testnet", DisplayName: "Rebus Testnet", Testnet: true},
+	{ChainID: "3068", Name: "bifrost", DisplayName: "Bifrost"},
+	{ChainID: "3073", Name: "movement_evm", DisplayName: "Movement Evm"},
+	{ChainID: "3084", Name: "xl_testnet", DisplayName: "Xl Testnet", Testnet: true},
+	{ChainID: "3100", Name: "immu3_evm", DisplayName: "Immu3 Evm"},
+	{ChainID: "3102", Name: "vulture_evm_beta", DisplayName: "Vulture Evm Beta"},
+	{ChainID: "3109", Name: "satoshivm_alpha", DisplayName: "Satoshivm Alpha"},
+	{ChainID: "3110", Name: "satoshivm_testnet", DisplayName: "Satoshivm Testnet", Testnet: true},
+	{ChainID: "3141", Name: "filecoin_hyperspace_testnet", DisplayName: "Filecoin Hyperspace Testnet", Testnet: true},
+	{ChainID: "3269", Name: "dubxcoin", DisplayName: "Dubxcoin"},
+	{ChainID: "3270", Name: "dubxcoin_testnet", DisplayName: "Dubxcoin Testnet", Testnet: true},
+	{ChainID: "3306", Name: "debounce_subnet_testnet", DisplayName: "Debounce Subnet Testnet", Testnet: true},
+	{ChainID: "3331", Name: "zcore_testnet", DisplayName: "Zcore Testnet", Testnet: true},
+	{ChainID: "3333", Name: "ethstorage_testnet", DisplayName: "Ethstorage Testnet", Testnet: true},
+	{ChainID: "3334", Name: "web3q_galileo", DisplayName: "Web3q Galileo"},
+	{ChainID: "3335", Name: "ethstorage", DisplayName: "Ethstorage"},
+	{ChainID: "3338", Name: "peaq", DisplayName: "Peaq"},
+	{ChainID: "3400", Name: "paribu_net", DisplayName: "Paribu Net"},
+	{ChainID: "3424", Name: "evolve", DisplayName: "Evolve"},
+	{ChainID: "3434", Name: "secure_testnet", DisplayName: "Secure Testnet", Testnet: true},
+	{ChainID: "3456", Name: "layeredge_testnet", DisplayName: "Layeredge Testnet", Testnet: true},
+	{ChainID: "3490", Name: "gtcscan", DisplayName: "Gtcscan"},
+	{ChainID: "3500", Name: "paribu_net_testnet", DisplayName: "Paribu Net Testnet", Testnet: true},
+	{ChainID: "3501", Name: "jfin", DisplayName: "Jfin"},
+	{ChainID: "3601", Name: "pandoproject", DisplayName: "Pandoproject"},
+	{ChainID: "3602", Name: "pandoproject_testnet", DisplayName: "Pandoproject Testnet", Testnet: true},
+	{ChainID: "3630", Name: "tycooncoin", DisplayName: "Tycooncoin"},
+	{ChainID: "3636", Name: "botanix_testnet", DisplayName: "Botanix Testnet", Testnet: true},
+	{ChainID: "3637", Name: "botanix", DisplayName: "Botanix"},
+	{ChainID: "3639", Name: "i", DisplayName: "I"},
+	{ChainID: "3645", Name: "i_testnet", DisplayName: "I Testnet", Testnet: true},
+	{ChainID: "3666", Name: "jouleverse", DisplayName: "Jouleverse"},
+	{ChainID: "3690", Name: "bittex", DisplayName: "Bittex"},
+	{ChainID: "3693", Name: "empire", DisplayName: "Empire"},
+	{ChainID: "3698", Name: "senjepowers_testnet", DisplayName: "Senjepowers Testnet", Testnet: true},
+	{ChainID: "3699", Name: "senjepowers", DisplayName: "Senjepowers"},
+	{ChainID: "3701", Name: "xpla_testnet", DisplayName: "Xpla Testnet", Testnet: true},
+	{ChainID: "3737", Name: "crossbell", DisplayName: "Crossbell"},
+	{ChainID: "3776", Name: "astar_zkevm", DisplayName: "Astar Zkevm"},
+	{ChainID: "3797", Name: "alvey", DisplayName: "Alvey"},
+	{ChainID: "3799", Name: "tangle_testnet", DisplayName: "Tangle Testnet", Testnet: true},
+	{ChainID: "3885", Name: "fire_zkevm_ghostrider", DisplayName: "Fire Zkevm Ghostrider"},
+	{ChainID: "3888", Name: "kaly", DisplayName: "Kaly"},
+	{ChainID: "3889", Name: "kaly_testnet", DisplayName: "Kaly Testnet", Testnet: true},
+	{ChainID: "3912", Name: "drac", DisplayName: "Drac"},
+	{ChainID: "3939", Name: "dos_tesnet", DisplayName: "Dos Tesnet"},
+	{ChainID: "3966", Name: "dyno", DisplayName: "Dyno"},
+	{ChainID: "3967", Name: "dyno_testnet", DisplayName: "Dyno Testnet", Testnet: true},
+	{ChainID: "3993", Name: "apex_testnet", DisplayName: "Apex Testnet", Testnet: true},
+	{ChainID: "3999", Name: "yuan", DisplayName: "Yuan"},
+	{ChainID: "4000", Name: "ozone", DisplayName: "Ozone"},
+	{ChainID: "4001", Name: "peperium_testnet", DisplayName: "Peperium Testnet", Testnet: true},
+	{ChainID: "4002", Name: "fantom_testnet", DisplayName: "Fantom Testnet", Testnet: true},
+	{ChainID: "4003", Name: "x1_fastnet", DisplayName: "X1 Fastnet"},
+	{ChainID: "4040", Name: "carbonium_testnet", DisplayName: "Carbonium Testnet", Testnet: true},
+	{ChainID: "4048", Name: "gan_testnet", DisplayName: "Gan Testnet", Testnet: true},
+	{ChainID: "4051", Name: "bobaopera_testnet", DisplayName: "Bobaopera Testnet", Testnet: true},
+	{ChainID: "4058", Name: "bahamut_ocean", DisplayName: "Bahamut Ocean"},
+	{ChainID: "4061", Name: "nahmii_3", DisplayName: "Nahmii 3"},
+	{ChainID: "4062", Name: "nahmii_3_testnet", DisplayName: "Nahmii 3 Testnet", Testnet: true},
+	{ChainID: "4078", Name: "muster", DisplayName: "Muster"},
+	{ChainID: "4080", Name: "tobe_testnet", DisplayName: "Tobe Testnet", Testnet: true},
+	{ChainID: "4088", Name: "zeroth", DisplayName: "Zeroth"},
+	{ChainID: "4090", Name: "fastex_bahamut_oasis_testnet", DisplayName: "Fastex Bahamut Oasis Testnet", Testnet: true},
+	{ChainID: "4096", Name: "bitindi_testnet", DisplayName: "Bitindi Testnet", Testnet: true},
+	{ChainID: "4099", Name: "bitindi", DisplayName: "Bitindi"},
+	{ChainID: "4102", Name: "aioz_testnet", DisplayName: "Aioz Testnet", Testnet: true},
+	{ChainID: "4139", Name: "humans.ai_testnet", DisplayName: "Humans.ai Testnet", Testnet: true},
+	{ChainID: "4141", Name: "tipboxcoin_testnet", DisplayName: "Tipboxcoin Testnet", Testnet: true},
+	{ChainID: "4157", Name: "crossfi_testnet", DisplayName: "Crossfi Testnet", Testnet: true},
+	{ChainID: "4162", Name: "sx_rollup", DisplayName: "Sx Rollup"},
+	{ChainID: "4181", Name: "phi_v1", DisplayName: "Phi V1"},
+	{ChainID: "4200", Name: "merlin", DisplayName: "Merlin"},
+	{ChainID: "4201", Name: "lukso_testnet", DisplayName: "Lukso Testnet", Testnet: true},
+	{ChainID: "4202", Name: "lisk_sepolia_testnet", DisplayName: "Lisk Sepolia Testnet", Testnet: true},
+	{ChainID: "4242", Name: "nexi", DisplayName: "Nexi"},
+	{ChainID: "4243", Name: "nexi_v2", DisplayName: "Nexi V2"},
+	{ChainID: "4269", Name: "laika_testnet", DisplayName: "Laika Testnet", Testnet: true},
+	{ChainID: "4328", Name: "bobafuji_testnet", DisplayName: "Bobafuji Testnet", Testnet: true},
+	{ChainID: "4337", Name: "beam", DisplayName: "Beam"},
+	{ChainID: "4400", Name: "credit", DisplayName: "Credit"},
+	{ChainID: "4444", Name: "htmlcoin", DisplayName: "Htmlcoin"},
+	{ChainID: "4460", Name: "orderly_sepolia_testnet", DisplayName: "Orderly Sepolia Testnet", Testnet: true},
+	{ChainID: "4488", Name: "hydra", DisplayName: "Hydra"},
+	{ChainID: "4544", Name: "emoney_testnet", DisplayName: "Emoney Testnet", Testnet: true},
+	{ChainID: "4613", Name: "very", DisplayName: "Very"},
+	{ChainID: "4653", Name: "gold", DisplayName: "Gold"},
+	{ChainID: "4689", Name: "iotex", DisplayName: "Iotex"},
+	{ChainID: "4690", Name: "iotex_testnet", DisplayName: "Iotex Testnet", Testnet: true},
+	{ChainID: "4759", Name: "meverse_testnet", DisplayName: "Meverse Testnet", Testnet: true},
+	{ChainID: "4777", Name: "blackfort_exchange_testnet", DisplayName: "Blackfort Exchange Testnet", Testnet: true},
+	{ChainID: "4801", Name: "world_sepolia_testnet", DisplayName: "World Sepolia Testnet", Testnet: true},
+	{ChainID: "4893", Name: "globel", DisplayName: "Globel"},
+	{ChainID: "4918", Name: "venidium_testnet", DisplayName: "Venidium Testnet", Testnet: true},
+	{ChainID: "4919", Name: "venidium", DisplayName: "Venidium"},
+	{ChainID: "4999", Name: "blackfort_exchange", DisplayName: "Blackfort Exchange"},
+	{ChainID: "5000", Name: "mantle", DisplayName: "Mantle", Parent: "1", Currency: "MNT", Explorer: "https://explorer.mantle.xyz"},
+	{ChainID: "5001", Name: "mantle_testnet", DisplayName: "Mantle Testnet", Testnet: true},
+	{ChainID: "5002", Name: "treasurenet_alpha", DisplayName: "Treasurenet Alpha"},
+	{ChainID: "5003", Name: "mantle_sepolia_testnet", DisplayName: "Mantle Sepolia Testnet", Testnet: true},
+	{ChainID: "5005", Name: "treasurenet_testnet", DisplayName: "Treasurenet Testnet", Testnet: true},
+	{ChainID: "5039", Name: "onigiri_test_subnet", DisplayName: "Onigiri Test Subnet", Testnet: true},
+	{ChainID: "5040", Name: "onigiri_subnet", DisplayName: "Onigiri Subnet"},
+	{ChainID: "5051", Name: "nollie_skate_testnet", DisplayName: "Nollie Skate Testnet", Testnet: true},
+	{ChainID: "5100", Name: "syndicate_testnet", DisplayName: "Syndicate Testnet", Testnet: true},
+	{ChainID: "5101", Name: "syndicate_frame", DisplayName: "Syndicate Frame"},
+	{ChainID: "5102", Name: "sic_testnet", DisplayName: "Sic Testnet", Testnet: true},
+	{ChainID: "5103", Name: "coordinape_testnet", DisplayName: "Coordinape Testnet", Testnet: true},
+	{ChainID: "5104", Name: "charmverse_testnet", DisplayName: "Charmverse Testnet", Testnet: true},
+	{ChainID: "5105", Name: "superloyalty_testnet", DisplayName: "Superloyalty Testnet", Testnet: true},
+	{ChainID: "5106", Name: "azra_testnet", DisplayName: "Azra Testnet", Testnet: true},
+	{ChainID: "5112", Name: "ham", DisplayName: "Ham"},
+	{ChainID: "5151", Name: "me_testnet", DisplayName: "Me Testnet", Testnet: true},
+	{ChainID: "5165", Name: "bahamut", DisplayName: "Bahamut"},
+	{ChainID: "5169", Name: "smart_layer", DisplayName: "Smart Layer"},
+	{ChainID: "5177", Name: "tl", DisplayName: "Tl"},
+	{ChainID: "5197", Name: "eraswap", DisplayName: "Eraswap"},
+	{ChainID: "5234", Name: "humanode", DisplayName: "Humanode"},
+	{ChainID: "5290", Name: "fire_old", DisplayName: "Fire Old"},
+	{ChainID: "5315", Name: "uzmi", DisplayName: "Uzmi"},
+	{ChainID: "5317", Name: "optrust_testnet", DisplayName: "Optrust Testnet", Testnet: true},
+	{ChainID: "5321", Name: "itx_testnet", DisplayName: "Itx Testnet", Testnet: true},
+	{ChainID: "5333", Name: "netsbo", DisplayName: "Netsbo"},
+	{ChainID: "5353", Name: "tritanium_testnet", DisplayName: "Tritanium Testnet", Testnet: true},
+	{ChainID: "5372", Name: "settlus_testnet", DisplayName: "Settlus Testnet", Testnet: true},
+	{ChainID: "5424", Name: "edexa", DisplayName: "Edexa"},
+	{ChainID: "5439", Name: "ego", DisplayName: "Ego"},
+	{ChainID: "5522", Name: "vex_evm_testnet", DisplayName: "Vex Evm Testnet", Testnet: true},
+	{ChainID: "5545", Name: "duck", DisplayName: "Duck"},
+	{ChainID: "5551", Name: "nahmii_2", DisplayName: "Nahmii 2"},
+	{ChainID: "5553", Name: "nahmii_2_testnet", DisplayName: "Nahmii 2 Testnet", Testnet: true},
+	{ChainID: "5555", Name: "verse", DisplayName: "Verse"},
+	{ChainID: "5611", Name: "opbnb_testnet", DisplayName: "Opbnb Testnet", Testnet: true},
+	{ChainID: "5615", Name: "arcturus_testneet", DisplayName: "Arcturus Testneet", Testnet: true},
+	{ChainID: "5616", Name: "arcturus_testnet", DisplayName: "Arcturus Testnet", Testnet: true},
+	{ChainID: "5656", Name: "qie_block", DisplayName: "Qie Block"},
+	{ChainID: "5675", Name: "filenova_testnet", DisplayName: "Filenova Testnet", Testnet: true},
+	{ChainID: "5678", Name: "tanssi_demo", DisplayName: "Tanssi Demo"},
+	{ChainID: "5700", Name: "syscoin_tanenbaum_testnet", DisplayName: "Syscoin Tanenbaum Testnet", Testnet: true},
+	{ChainID: "5729", Name: "hika_testnet", DisplayName: "Hika Testnet", Testnet: true},
+	{ChainID: "5758", Name: "satoshi_testnet", DisplayName: "Satoshi Testnet", Testnet: true},
+	{ChainID: "5777", Name: "ganache", DisplayName: "Ganache"},
+	{ChainID: "5845", Name: "tangle", DisplayName: "Tangle"},
+	{ChainID: "5851", Name: "ontology_testnet", DisplayName: "Ontology Testnet", Testnet: true},
+	{ChainID: "5858", Name: "chang_foundation", DisplayName: "Chang Foundation"},
+	{ChainID: "5869", Name: "wego_rubidium", DisplayName: "Wego Rubidium"},
+	{ChainID: "6000", Name: "bouncebit_testnet", DisplayName: "Bouncebit Testnet", Testnet: true},
+	{ChainID: "6001", Name: "bouncebit", DisplayName: "Bouncebit"},
+	{ChainID: "6065", Name: "tres_testnet", DisplayName: "Tres Testnet", Testnet: true},
+	{ChainID: "6066", Name: "tres", DisplayName: "Tres"},
+	{ChainID: "6102", Name: "cascadia_testnet", DisplayName: "Cascadia Testnet", Testnet: true},
+	{ChainID: "6118", Name: "uptn_testnet", DisplayName: "Uptn Testnet", Testnet: true},
+	{ChainID: "6119", Name: "uptn", DisplayName: "Uptn"},
+	{ChainID: "6283", Name: "laos", DisplayName: "Laos"},
+	{ChainID: "6321", Name: "aura_euphoria_testnet", DisplayName: "Aura Euphoria Testnet", Testnet: true},
+	{ChainID: "6322", Name: "aura", DisplayName: "Aura"},
+	{ChainID: "6363", Name: "digit_soul", DisplayName: "Digit Soul"},
+	{ChainID: "6398", Name: "connext_sepolia", DisplayName: "Connext Sepolia", Testnet: true},
+	{ChainID: "6502", Name: "peerpay", DisplayName: "Peerpay"},
+	{ChainID: "6550", Name: "flamma_testnet", DisplayName: "Flamma Testnet", Testnet: true},
+	{ChainID: "6552", Name: "scolcoin_wei_testnet", DisplayName: "Scolcoin Wei Testnet", Testnet: true},
+	{ChainID: "6565", Name: "fox_testnet", DisplayName: "Fox Testnet", Testnet: true},
+	{ChainID: "6626", Name: "pixie", DisplayName: "Pixie"},
+	{ChainID: "6660", Name: "latest_testnet", DisplayName: "Latest Testnet", Testnet: true},
+	{ChainID: "6661", Name: "cybria", DisplayName: "Cybria"},
+	{ChainID: "6666", Name: "cybria_testnet", DisplayName: "Cybria Testnet", Testnet: true},
+	{ChainID: "6667", Name: "stor", DisplayName: "Stor"},
+	{ChainID: "6678", Name: "edge_matrix", DisplayName: "Edge Matrix"},
+	{ChainID: "6688", Name: "irishub", DisplayName: "Irishub"},
+	{ChainID: "6699", Name: "ox", DisplayName: "Ox"},
+	{ChainID: "6701", Name: "paxb", DisplayName: "Paxb"},
+	{ChainID: "6779", Name: "compverse", DisplayName: "Compverse"},
+	{ChainID: "6805", Name: "race", DisplayName: "Race"},
+	{ChainID: "6806", Name: "race_testnet", DisplayName: "Race Testnet", Testnet: true},
+	{ChainID: "6868", Name: "pools", DisplayName: "Pools"},
+	{ChainID: "6880", Name: "mtt", DisplayName: "Mtt"},
+	{ChainID: "6942", Name: "laika", DisplayName: "Laika"},
+	{ChainID: "6969", Name: "tomb", DisplayName: "Tomb"},
+	{ChainID: "6999", Name: "polysmart", DisplayName: "Polysmart"},
+	{ChainID: "7000", Name: "zeta", DisplayName: "Zeta"},
+	{ChainID: "7001", Name: "zeta_testnet", DisplayName: "Zeta Testnet", Testnet: true},
+	{ChainID: "7007", Name: "bst", DisplayName: "Bst"},
+	{ChainID: "7027", Name: "ella_the_heart", DisplayName: "Ella The Heart"},
+	{ChainID: "7070", Name: "planq", DisplayName: "Planq"},
+	{ChainID: "7077", Name: "planq_atlas_testnet", DisplayName: "Planq Atlas Testnet", Testnet: true},
+	{ChainID: "7100", Name: "nume", DisplayName: "Nume"},
+	{ChainID: "7118", Name: "help_the_homeless", DisplayName: "Help The Homeless"},
+	{ChainID: "7171", Name: "bitrock", DisplayName: "Bitrock"},
+	{ChainID: "7210", Name: "nibiru_testnet_1", DisplayName: "Nibiru Testnet 1", Testnet: true},
+	{ChainID: "7222", Name: "nibiru_devnet_3", DisplayName: "Nibiru Devnet 3", Testnet: true},
+	{ChainID: "7244", Name: "zeus_testnet", DisplayName: "Zeus Testnet", Testnet: true},
+	{ChainID: "7300", Name: "xpla_verse", DisplayName: "Xpla Verse"},
+	{ChainID: "7331", Name: "klyntar", DisplayName: "Klyntar"},
+	{ChainID: "7332", Name: "horizen_eon", DisplayName: "Horizen Eon"},
+	{ChainID: "7341", Name: "shyft", DisplayName: "Shyft"},
+	{ChainID: "7484", Name: "raba", DisplayName: "Raba"},
+	{ChainID: "7518", Name: "meverse", DisplayName: "Meverse"},
+	{ChainID: "7560", Name: "cyber", DisplayName: "Cyber"},
+	{ChainID: "7575", Name: "adil_testnet", DisplayName: "Adil Testnet", Testnet: true},
+	{ChainID: "7576", Name: "adil_v2", DisplayName: "Adil V2"},
+	{ChainID: "7668", Name: "the_root", DisplayName: "The Root"},
+	{ChainID: "7672", Name: "the_root_porcini_testnet", DisplayName: "The Root Porcini Testnet", Testnet: true},
+	{ChainID: "7700", Name: "canto", DisplayName: "Canto"},
+	{ChainID: "7701", Name: "canto_tesnet", DisplayName: "Canto Tesnet"},
+	{ChainID: "7771", Name: "bitrock_testnet", DisplayName: "Bitrock Testnet", Testnet: true},
+	{ChainID: "7774", Name: "gdcc", DisplayName: "Gdcc"},
+	{ChainID: "7775", Name: "gdcc_testnet", DisplayName: "Gdcc Testnet", Testnet: true},
+	{ChainID: "7776", Name: "pandasea", DisplayName: "Pandasea"},
+	{ChainID: "7777", Name: "rise_of_the_warbots_testnet", DisplayName: "Rise Of The Warbots Testnet", Testnet: true},
+	{ChainID: "7778", Name: "orenium_protocol", DisplayName: "Orenium Protocol"},
+	{ChainID: "7798", Name: "openex_long_testnet", DisplayName: "Openex Long Testnet", Testnet: true},
+	{ChainID: "7860", Name: "maal_testnet", DisplayName: "Maal Testnet", Testnet: true},
+	{ChainID: "7862", Name: "maal_v2", DisplayName: "Maal V2"},
+	{ChainID: "7863", Name: "maal_testnet_v2", DisplayName: "Maal Testnet V2", Testnet: true},
+	{ChainID: "7878", Name: "hazlor_testnet", DisplayName: "Hazlor Testnet", Testnet: true},
+	{ChainID: "7887", Name: "kinto", DisplayName: "Kinto"},
+	{ChainID: "7895", Name: "ardenium_athena", DisplayName: "Ardenium Athena"},
+	{ChainID: "7923", Name: "dot_blox", DisplayName: "Dot Blox"},
+	{ChainID: "7924", Name: "mo", DisplayName: "Mo"},
+	{ChainID: "7979", Name: "dos", DisplayName: "Dos"},
+	{ChainID: "8000", Name: "teleport", DisplayName: "Teleport"},
+	{ChainID: "8001", Name: "teleport_testnet", DisplayName: "Teleport Testnet", Testnet: true},
+	{ChainID: "8008", Name: "polynomial", DisplayName: "Polynomial"},
+	{ChainID: "8017", Name: "isuncoin", DisplayName: "Isuncoin"},
+	{ChainID: "8029", Name: "mdgl_testnet", DisplayName: "Mdgl Testnet", Testnet: true},
+	{ChainID: "8047", Name: "boat", DisplayName: "Boat"},
+	{ChainID: "8054", Name: "karak_sepolia", DisplayName: "Karak Sepolia", Testnet: true},
+	{ChainID: "8080", Name: "shardeum_liberty_1.x", DisplayName: "Shardeum Liberty 1.x"},
+	{ChainID: "8081", Name: "shardeum_liberty_2.x", DisplayName: "Shardeum Liberty 2.x"},
+	{ChainID: "8082", Name: "shardeum_sphinx_1.x", DisplayName: "Shardeum Sphinx 1.x"},
+	{ChainID: "8086", Name: "bitcoin", DisplayName: "Bitcoin"},
+	{ChainID: "8087", Name: "e_dollar", DisplayName: "E Dollar"},
+	{ChainID: "8098", Name: "streamux_block", DisplayName: "Streamux Block"},
+	{ChainID: "8131", Name: "qitmeer_testnet", DisplayName: "Qitmeer Testnet", Testnet: true},
+	{ChainID: "8132", Name: "qitmeer_mixnet", DisplayName: "Qitmeer Mixnet"},
+	{ChainID: "8133", Name: "qitmeer_privnet", DisplayName: "Qitmeer Privnet"},
+	{ChainID: "8134", Name: "amana", DisplayName: "Amana"},
+	{ChainID: "8135", Name: "flana", DisplayName: "Flana"},
+	{ChainID: "8136", Name: "mizana", DisplayName: "Mizana"},
+	{ChainID: "8181", Name: "testnet_beone", DisplayName: "Testnet Beone", Testnet: true},
+	{ChainID: "8192", Name: "torus", DisplayName: "Torus"},
+	{ChainID: "8194", Name: "torus_testnet", DisplayName: "Torus Testnet", Testnet: true},
+	{ChainID: "8217", Name: "kaia", DisplayName: "Kaia"},
+	{ChainID: "8227", Name: "space_subnet", DisplayName: "Space Subnet"},
+	{ChainID: "8272", Name: "blockton_block", DisplayName: "Blockton Block"},
+	{ChainID: "8285", Name: "korthotest", DisplayName: "Korthotest", Testnet: true},
+	{ChainID: "8329", Name: "lorenzo", DisplayName: "Lorenzo"},
+	{ChainID: "8333", Name: "b3", DisplayName: "B3"},
+	{ChainID: "8387", Name: "dracones_financial_services", DisplayName: "Dracones Financial Services"},
+	{ChainID: "8428", Name: "that", DisplayName: "That"},
+	{ChainID: "8453", Name: "base", DisplayName: "Base", Aliases: []string{"base_mainnet"}, Parent: "1", Currency: "ETH", Explorer: "https://basescan.org"},
+	{ChainID: "8545", Name: "chakra_testnet", DisplayName: "Chakra Testnet", Testnet: true},
+	{ChainID: "8654", Name: "toki", DisplayName: "Toki"},
+	{ChainID: "8655", Name: "toki_testnet", DisplayName: "Toki Testnet", Testnet: true},
+	{ChainID: "8668", Name: "hela_official_runtime", DisplayName: "Hela Official Runtime"},
+	{ChainID: "8723", Name: "tool_global", DisplayName: "Tool Global"},
+	{ChainID: "8724", Name: "tool_global_testnet", DisplayName: "Tool Global Testnet", Testnet: true},
+	{ChainID: "8726", Name: "storage", DisplayName: "Storage"},
+	{ChainID: "8727", Name: "storage_testnet", DisplayName: "Storage Testnet", Testnet: true},
+	{ChainID: "8732", Name: "bullions", DisplayName: "Bullions"},
+	{ChainID: "8738", Name: "alph", DisplayName: "Alph"},
+	{ChainID: "8768", Name: "tmy", DisplayName: "Tmy"},
+	{ChainID: "8822", Name: "iota_evm", DisplayName: "Iota Evm"},
+	{ChainID: "8844", Name: "hydra_testnet", DisplayName: "Hydra Testnet", Testnet: true},
+	{ChainID: "8848", Name: "maro_block", DisplayName: "Maro Block"},
+	{ChainID: "8866", Name: "superlumio", DisplayName: "Superlumio"},
+	{ChainID: "8869", Name: "lif3", DisplayName: "Lif3"},
+	{ChainID: "8880", Name: "unique", DisplayName: "Unique"},
+	{ChainID: "8881", Name: "quartz_by_unique", DisplayName: "Quartz By Unique"},
+	{ChainID: "8882", Name: "opal_testnet_by_unique", DisplayName: "Opal Testnet By Unique", Testnet: true},
+	{ChainID: "8883", Name: "sapphire_by_unique", DisplayName: "Sapphire By Unique"},
+	{ChainID: "8886", Name: "avenium_testnet", DisplayName: "Avenium Testnet", Testnet: true},
+	{ChainID: "8888", Name: "xana", DisplayName: "Xana"},
+	{ChainID: "8889", Name: "vyvo", DisplayName: "Vyvo"},
+	{ChainID: "8890", Name: "orenium_testnet_protocol", DisplayName: "Orenium Testnet Protocol", Testnet: true},
+	{ChainID: "8898", Name: "mammoth", DisplayName: "Mammoth"},
+	{ChainID: "8899", Name: "jib", DisplayName: "Jib"},
+	{ChainID: "8911", Name: "algen", DisplayName: "Algen"},
+	{ChainID: "8912", Name: "algen_testnet", DisplayName: "Algen Testnet", Testnet: true},
+	{ChainID: "8921", Name: "algen_layer2", DisplayName: "Algen Layer2"},
+	{ChainID: "8922", Name: "algen_layer2_testnet", DisplayName: "Algen Layer2 Testnet", Testnet: true},
+	{ChainID: "8989", Name: "giant_mammoth", DisplayName: "Giant Mammoth"},
+	{ChainID: "8995", Name: "bloxberg", DisplayName: "Bloxberg"},
+	{ChainID: "9000", Name: "evmos_testnet", DisplayName: "Evmos Testnet", Testnet: true},
+	{ChainID: "9001", Name: "evmos", DisplayName: "Evmos"},
+	{ChainID: "9007", Name: "shido_testnet_block", DisplayName: "Shido Testnet Block", Testnet: true},
+	{ChainID: "9008", Name: "shido_block", DisplayName: "Shido Block"},
+	{ChainID: "9012", Name: "berylbit", DisplayName: "Berylbit"},
+	{ChainID: "9024", Name: "nexa_testnet_block", DisplayName: "Nexa Testnet Block", Testnet: true},
+	{ChainID: "9025", Name: "nexa_block", DisplayName: "Nexa Block"},
+	{ChainID: "9069", Name: "apex_fusion_nexus", DisplayName: "Apex Fusion Nexus"},
+	{ChainID: "9070", Name: "apex_fusion_nexus_testnet", DisplayName: "Apex Fusion Nexus Testnet", Testnet: true},
+	{ChainID: "9100", Name: "genesis_coin", DisplayName: "Genesis Coin"},
+	{ChainID: "9170", Name: "rinia_testnet_old", DisplayName: "Rinia Testnet Old", Testnet: true},
+	{ChainID: "9223", Name: "codefin", DisplayName: "Codefin"},
+	{ChainID: "9339", Name: "dogcoin_testnet", DisplayName: "Dogcoin Testnet", Testnet: true},
+	{ChainID: "9372", Name: "oasys_testnet", DisplayName: "Oasys Testnet", Testnet: true},
+	{ChainID: "9393", Name: "dela_sepolia_testnet", DisplayName: "Dela Sepolia Testnet", Testnet: true},
+	{ChainID: "9395", Name: "evoke", DisplayName: "Evoke"},
+	{ChainID: "9496", Name: "weavevm_testnet", DisplayName: "Weavevm Testnet", Testnet: true},
+	{ChainID: "9527", Name: "rangers_protocol_testnet_robin", DisplayName: "Rangers Protocol Testnet Robin", Testnet: true},
+	{ChainID: "9528", Name: "qeasyweb3_testnet", DisplayName: "Qeasyweb3 Testnet", Testnet: true},
+	{ChainID: "9559", Name: "neonlink_testnet", DisplayName: "Neonlink Testnet", Testnet: true},
+	{ChainID: "9700", Name: "oortdev", DisplayName: "Oortdev"},
+	{ChainID: "9728", Name: "boba_bnb_testnet", DisplayName: "Boba Bnb Testnet", Testnet: true},
+	{ChainID: "9768", Name: "mainnetz_testnet", DisplayName: "Mainnetz Testnet", Testnet: true},
+	{ChainID: "9779", Name: "pepenetwork", DisplayName: "Pepenetwork"},
+	{ChainID: "9789", Name: "tabi_testnet", DisplayName: "Tabi Testnet", Testnet: true},
+	{ChainID: "9790", Name: "carbon_evm", DisplayName: "Carbon Evm"},
+	{ChainID: "9792", Name: "carbon_evm_testnet", DisplayName: "Carbon Evm Testnet", Testnet: true},
+	{ChainID: "9797", Name: "optimusz7", DisplayName: "Optimusz7"},
+	{ChainID: "9818", Name: "imperium_testnet", DisplayName: "Imperium Testnet", Testnet: true},
+	{ChainID: "9819", Name: "imperium", DisplayName: "Imperium"},
+	{ChainID: "9876", Name: "binary_testnet", DisplayName: "Binary Testnet", Testnet: true},
+	{ChainID: "9888", Name: "dogelayer", DisplayName: "Dogelayer"},
+	{ChainID: "9897", Name: "arena_z_testnet", DisplayName: "Arena Z Testnet", Testnet: true},
+	{ChainID: "9898", Name: "larissa", DisplayName: "Larissa"},
+	{ChainID: "9911", Name: "espento", DisplayName: "Espento"},
+	{ChainID: "9966", Name: "uxer_testnet", DisplayName: "Uxer Testnet", Testnet: true},
+	{ChainID: "9977", Name: "mind_testnet", DisplayName: "Mind Testnet", Testnet: true},
+	{ChainID: "9980", Name: "combo", DisplayName: "Combo"},
+	{ChainID: "9981", Name: "volley", DisplayName: "Volley"},
+	{ChainID: "9990", Name: "agung", DisplayName: "Agung"},
+	{ChainID: "9997", Name: "altlayer_testnet", DisplayName: "Altlayer Testnet", Testnet: true},
+	{ChainID: "9998", Name: "ztc", DisplayName: "Ztc"},
+	{ChainID: "9999", Name: "myown_testnet", DisplayName: "Myown Testnet", Testnet: true},
+	{ChainID: "10000", Name: "smart_bitcoin_cash", DisplayName: "Smart Bitcoin Cash"},
+	{ChainID: "10001", Name: "smart_bitcoin_cash_testnet", DisplayName: "Smart Bitcoin Cash Testnet", Testnet: true},
+	{ChainID: "10024", Name: "gon", DisplayName: "Gon"},
+	{ChainID: "10081", Name: "japan_open_testnet", DisplayName: "Japan Open Testnet", Testnet: true},
+	{ChainID: "10086", Name: "sjatsh", DisplayName: "Sjatsh"},
+	{ChainID: "10096", Name: "metanova_verse", DisplayName: "Metanova Verse"},
+	{ChainID: "10101", Name: "block_genesis", DisplayName: "Block Genesis"},
+	{ChainID: "10200", Name: "gnosis_chiado_testnet", DisplayName: "Gnosis Chiado Testnet", Testnet: true},
+	{ChainID: "10201", Name: "maxx", DisplayName: "Maxx"},
+	{ChainID: "10222", Name: "glscan", DisplayName: "Glscan"},
+	{ChainID: "10242", Name: "arthera", DisplayName: "Arthera"},
+	{ChainID: "10243", Name: "arthera_testnet", DisplayName: "Arthera Testnet", Testnet: true},
+	{ChainID: "10248", Name: "0xtade", DisplayName: "0xtade"},
+	{ChainID: "10321", Name: "tao_evm", DisplayName: "Tao Evm"},
+	{ChainID: "10324", Name: "tao_evm_testnet", DisplayName: "Tao Evm Testnet", Testnet: true},
+	{ChainID: "10395", Name: "worldland_testnet", DisplayName: "Worldland Testnet", Testnet: true},
+	{ChainID: "10507", Name: "numbers", DisplayName: "Numbers"},
+	{ChainID: "10508", Name: "numbers_testnet", DisplayName: "Numbers Testnet", Testnet: true},
+	{ChainID: "10823", Name: "cryptocoinpay", DisplayName: "Cryptocoinpay"},
+	{ChainID: "10849", Name: "lamina1", DisplayName: "Lamina1"},
+	{ChainID: "10850", Name: "lamina1_identity", DisplayName: "Lamina1 Identity"},
+	{ChainID: "10946", Name: "quadrans_block", DisplayName: "Quadrans Block"},
+	{ChainID: "10947", Name: "quadrans_block_testnet", DisplayName: "Quadrans Block Testnet", Testnet: true},
+	{ChainID: "11000", Name: "kb", DisplayName: "Kb"},
+	{ChainID: "11011", Name: "shape_sepolia_testnet", DisplayName: "Shape Sepolia Testnet", Testnet: true},
+	{ChainID: "11110", Name: "astra", DisplayName: "Astra"},
+	{ChainID: "11111", Name: "wagmi", DisplayName: "Wagmi"},
+	{ChainID: "11115", Name: "astra_testnet", DisplayName: "Astra Testnet", Testnet: true},
+	{ChainID: "11119", Name: "hashbit", DisplayName: "Hashbit"},
+	{ChainID: "11124", Name: "abstract_testnet", DisplayName: "Abstract Testnet", Testnet: true},
+	{ChainID: "11221", Name: "shine", DisplayName: "Shine"},
+	{ChainID: "11227", Name: "jiritsu_testnet_subnet", DisplayName: "Jiritsu Testnet Subnet", Testnet: true},
+	{ChainID: "11235", Name: "haqq", DisplayName: "Haqq"},
+	{ChainID: "11437", Name: "shyft_testnet", DisplayName: "Shyft Testnet", Testnet: true},
+	{ChainID: "11501", Name: "bevm", DisplayName: "Bevm"},
+	{ChainID: "11503", Name: "bevm_testnet", DisplayName: "Bevm Testnet", Testnet: true},
+	{ChainID: "11521", Name: "sats", DisplayName: "Sats"},
+	{ChainID: "11612", Name: "sardis_testnet", DisplayName: "Sardis Testnet", Testnet: true},
+	{ChainID: "11822", Name: "artela_testnet", DisplayName: "Artela Testnet", Testnet: true},
+	{ChainID: "11891", Name: "polygon_supernet_arianee", DisplayName: "Polygon Supernet Arianee"},
+	{ChainID: "12001", Name: "fuse_testnet", DisplayName: "Fuse Testnet", Testnet: true},
+	{ChainID: "12009", Name: "satoshi", DisplayName: "Satoshi"},
+	{ChainID: "12020", Name: "aternos", DisplayName: "Aternos"},
+	{ChainID: "12051", Name: "singularity_zero_testnet", DisplayName: "Singularity Zero Testnet", Testnet: true},
+	{ChainID: "12052", Name: "singularity_zero", DisplayName: "Singularity Zero"},
+	{ChainID: "12123", Name: "brc", DisplayName: "Brc"},
+	{ChainID: "12306", Name: "fibonacci", DisplayName: "Fibonacci"},
+	{ChainID: "12321", Name: "blg_testnet", DisplayName: "Blg Testnet", Testnet: true},
+	{ChainID: "12324", Name: "l3x_protocol", DisplayName: "L3x Protocol"},
+	{ChainID: "12325", Name: "l3x_protocol_testnet", DisplayName: "L3x Protocol Testnet", Testnet: true},
+	{ChainID: "12345", Name: "step_testnet", DisplayName: "Step Testnet", Testnet: true},
+	{ChainID: "12553", Name: "rss3_vsl", DisplayName: "Rss3 Vsl"},
+	{ChainID: "12715", Name: "rikeza_testnet", DisplayName: "Rikeza Testnet", Testnet: true},
+	{ChainID: "12781", Name: "playdapp_testnet", DisplayName: "Playdapp Testnet", Testnet: true},
+	{ChainID: "12890", Name: "quantum_testnet", DisplayName: "Quantum Testnet", Testnet: true},
+	{ChainID: "12898", Name: "playfair_testnet_subnet", DisplayName: "Playfair Testnet Subnet", Testnet: true},
+	{ChainID: "13000", Name: "sps", DisplayName: "Sps"},
+	{ChainID: "13337", Name: "beam_testnet", DisplayName: "Beam Testnet", Testnet: true},
+	{ChainID: "13370", Name: "cannon_testnet", DisplayName: "Cannon Testnet", Testnet: true},
+	{ChainID: "13371", Name: "immutable_zkevm", DisplayName: "Immutable Zkevm"},
+	{ChainID: "13381", Name: "phoenix", DisplayName: "Phoenix"},
+	{ChainID: "13396", Name: "masa", DisplayName: "Masa"},
+	{ChainID: "13473", Name: "immutable_zkevm_testnet", DisplayName: "Immutable Zkevm Testnet", Testnet: true},
+	{ChainID: "13505", Name: "gravity_alpha_testnet_sepolia", DisplayName: "Gravity Alpha Testnet Sepolia", Testnet: true},
+	{ChainID: "13600", Name: "kronobit", DisplayName: "Kronobit"},
+	{ChainID: "13812", Name: "susono", DisplayName: "Susono"},
+	{ChainID: "14000", Name: "sps_testnet", DisplayName: "Sps Testnet", Testnet: true},
+	{ChainID: "14088", Name: "zeroth_testnet", DisplayName: "Zeroth Testnet", Testnet: true},
+	{ChainID: "14324", Name: "evolve_testnet", DisplayName: "Evolve Testnet", Testnet: true},
+	{ChainID: "14333", Name: "vitruveo_testnet", DisplayName: "Vitruveo Testnet", Testnet: true},
+	{ChainID: "14800", Name: "vana_moksha_testnet", DisplayName: "Vana Moksha Testnet", Testnet: true},
+	{ChainID: "14801", Name: "vana_satori_testnet", DisplayName: "Vana Satori Testnet", Testnet: true},
+	{ChainID: "14853", Name: "humanode_testnet_5_israfel", DisplayName: "Humanode Testnet 5 Israfel", Testnet: true},
+	{ChainID: "15003", Name: "immutable_zkevm_devnet", DisplayName: "Immutable Zkevm Devnet", Testnet: true},
+	{ChainID: "15257", Name: "poodl_testnet", DisplayName: "Poodl Testnet", Testnet: true},
+	{ChainID: "15259", Name: "poodl", DisplayName: "Poodl"},
+	{ChainID: "15430", Name: "kymtc", DisplayName: "Kymtc"},
+	{ChainID: "15551", Name: "loopnetwork", DisplayName: "Loopnetwork"},
+	{ChainID: "15555", Name: "trust_evm_testnet", DisplayName: "Trust Evm Testnet", Testnet: true},
+	{ChainID: "15557", Name: "eos_evm_testnet", DisplayName: "Eos Evm Testnet", Testnet: true},
+	{ChainID: "16000", Name: "metadot", DisplayName: "Metadot"},
+	{ChainID: "16001", Name: "metadot_testnet", DisplayName: "Metadot Testnet", Testnet: true},
+	{ChainID: "16116", Name: "defiverse", DisplayName: "Defiverse"},
+	{ChainID: "16166", Name: "cypherium", DisplayName: "Cypherium"},
+	{ChainID: "16180", Name: "plyr_phi", DisplayName: "Plyr Phi"},
+	{ChainID: "16350", Name: "incentiv_devnet", DisplayName: "Incentiv Devnet", Testnet: true},
+	{ChainID: "16481", Name: "pivotal_sepolia", DisplayName: "Pivotal Sepolia", Testnet: true},
+	{ChainID: "16507", Name: "genesys", DisplayName: "Genesys"},
+	{ChainID: "16600", Name: "0g_newton_testnet", DisplayName: "0g Newton Testnet", Testnet: true},
+	{ChainID: "16688", Name: "irishub_testnet", DisplayName: "Irishub Testnet", Testnet: true},
+	{ChainID: "16718", Name: "airdao", DisplayName: "Airdao"},
+	{ChainID: "16888", Name: "ivar_testnet", DisplayName: "Ivar Testnet", Testnet: true},
+	{ChainID: "17000", Name: "holesky", DisplayName: "Holesky", Aliases: []string{"eth_holesky"}, Testnet: true, Currency: "ETH", Explorer: "https://holesky.etherscan.io"},
+	{ChainID: "17001", Name: "redstone_holesky_testnet", DisplayName: "Redstone Holesky Testnet", Testnet: true},
+	{ChainID: "17069", Name: "garnet_holesky", DisplayName: "Garnet Holesky", Testnet: true},
+	{ChainID: "17071", Name: "on_points", DisplayName: "On Points"},
+	{ChainID: "17117", Name: "defiverse_testnet", DisplayName: "Defiverse Testnet", Testnet: true},
+	{ChainID: "17171", Name: "g8", DisplayName: "G8"},
+	{ChainID: "17172", Name: "eclipse_subnet", DisplayName: "Eclipse Subnet"},
+	{ChainID: "17180", Name: "palette_testnet", DisplayName: "Palette Testnet", Testnet: true},
+	{ChainID: "17217", Name: "konet", DisplayName: "Konet"},
+	{ChainID: "17777", Name: "eos_evm", DisplayName: "Eos Evm"},
+	{ChainID: "18000", Name: "frontier_of_dreams_testnet", DisplayName: "Frontier Of Dreams Testnet", Testnet: true},
+	{ChainID: "18122", Name: "smart_trades", DisplayName: "Smart Trades"},
+	{ChainID: "18159", Name: "proof_of_memes", DisplayName: "Proof Of Memes"},
+	{ChainID: "18181", Name: "g8_testnet", DisplayName: "G8 Testnet", Testnet: true},
+	{ChainID: "18231", Name: "unreal_old", DisplayName: "Unreal Old"},
+	{ChainID: "18233", Name: "unreal", DisplayName: "Unreal"},
+	{ChainID: "18686", Name: "mxc_zkevm_moon", DisplayName: "Mxc Zkevm Moon"},
+	{ChainID: "18888", Name: "titan_tkx", DisplayName: "Titan Tkx"},
+	{ChainID: "18889", Name: "titan_tkx_testnet", DisplayName: "Titan Tkx Testnet", Testnet: true},
+	{ChainID: "19011", Name: "home_verse", DisplayName: "Home Verse"},
+	{ChainID: "19077", Name: "blockx_atlantis_testnet", DisplayName: "Blockx Atlantis Testnet", Testnet: true},
+	{ChainID: "19191", Name: "blockx", DisplayName: "Blockx"},
+	{ChainID: "19224", Name: "decentraconnect_social", DisplayName: "Decentraconnect Social"},
+	{ChainID: "19527", Name: "magnet", DisplayName: "Magnet"},
+	{ChainID: "19600", Name: "lbry", DisplayName: "Lbry"},
+	{ChainID: "19845", Name: "btcix", DisplayName: "Btcix"},
+	{ChainID: "20001", Name: "camelark", DisplayName: "Camelark"},
+	{ChainID: "20041", Name: "niza", DisplayName: "Niza"},
+	{ChainID: "20073", Name: "niza_testnet", DisplayName: "Niza Testnet", Testnet: true},
+	{ChainID: "20729", Name: "callisto_testnet", DisplayName: "Callisto Testnet", Testnet: true},
+	{ChainID: "20736", Name: "p12", DisplayName: "P12"},
+	{ChainID: "20765", Name: "jono11_subnet", DisplayName: "Jono11 Subnet"},
+	{ChainID: "21004", Name: "c4ei", DisplayName: "C4ei"},
+	{ChainID: "21133", Name: "all_about_healthy", DisplayName: "All About Healthy"},
+	{ChainID: "21223", Name: "dcpay", DisplayName: "Dcpay"},
+	{ChainID: "21224", Name: "dcpay_testnet", DisplayName: "Dcpay Testnet", Testnet: true},
+	{ChainID: "21337", Name: "cennznet_azalea", DisplayName: "Cennznet Azalea"},
+	{ChainID: "21363", Name: "lestnet", DisplayName: "Lestnet"},
+	{ChainID: "21816", Name: "om", DisplayName: "Om"},
+	{ChainID: "21912", Name: "bsl", DisplayName: "Bsl"},
+	{ChainID: "22023", Name: "taycan", DisplayName: "Taycan"},
+	{ChainID: "22040", Name: "airdao_testnet", DisplayName: "Airdao Testnet", Testnet: true},
+	{ChainID: "22222", Name: "nautilus", DisplayName: "Nautilus"},
+	{ChainID: "22324", Name: "goldx_testnet", DisplayName: "Goldx Testnet", Testnet: true},
+	{ChainID: "22776", Name: "map_protocol", DisplayName: "Map Protocol"},
+	{ChainID: "23006", Name: "antofy_testnet", DisplayName: "Antofy Testnet", Testnet: true},
+	{ChainID: "23118", Name: "opside_testnet", DisplayName: "Opside Testnet", Testnet: true},
+	{ChainID: "23294", Name: "oasis_sapphire", DisplayName: "Oasis Sapphire"},
+	{ChainID: "23295", Name: "oasis_sapphire_testnet", DisplayName: "Oasis Sapphire Testnet", Testnet: true},
+	{ChainID: "23451", Name: "dreyerx", DisplayName: "Dreyerx"},
+	{ChainID: "23452", Name: "dreyerx_testnet", DisplayName: "Dreyerx Testnet", Testnet: true},
+	{ChainID: "23888", Name: "blast_testnet", DisplayName: "Blast Testnet", Testnet: true},
+	{ChainID: "24076", Name: "kymtc_testnet", DisplayName: "Kymtc Testnet", Testnet: true},
+	{ChainID: "24484", Name: "web", DisplayName: "Web"},
+	{ChainID: "24734", Name: "mintme.com", DisplayName: "Mintme.com"},
+	{ChainID: "25186", Name: "liquidlayer", DisplayName: "Liquidlayer"},
+	{ChainID: "25327", Name: "everclear", DisplayName: "Everclear"},
+	{ChainID: "25839", Name: "alvey_testnet", DisplayName: "Alvey Testnet", Testnet: true},
+	{ChainID: "25888", Name: "hammer", DisplayName: "Hammer"},
+	{ChainID: "25925", Name: "bitkub_testnet", DisplayName: "Bitkub Testnet", Testnet: true},
+	{ChainID: "26026", Name: "ferrum_testnet", DisplayName: "Ferrum Testnet", Testnet: true},
+	{ChainID: "26482", Name: "ducatusx_testnet", DisplayName: "Ducatusx Testnet", Testnet: true},
+	{ChainID: "26483", Name: "ducatusx", DisplayName: "Ducatusx"},
+	{ChainID: "26600", Name: "hertz", DisplayName: "Hertz"},
+	{ChainID: "26863", Name: "oasis", DisplayName: "Oasis"},
+	{ChainID: "27181", Name: "klaos_nova", DisplayName: "Klaos Nova"},
+	{ChainID: "27483", Name: "nanon_sepolia", DisplayName: "Nanon Sepolia", Testnet: true},
+	{ChainID: "27827", Name: "zeroone_subnet", DisplayName: "Zeroone Subnet"},
+	{ChainID: "28516", Name: "vizing_testnet", DisplayName: "Vizing Testnet", Testnet: true},
+	{ChainID: "28518", Name: "vizing", DisplayName: "Vizing"},
+	{ChainID: "28528", Name: "optimism_bedrock_goerli_alpha_testnet", DisplayName: "Optimism Bedrock Goerli Alpha Testnet", Testnet: true},
+	{ChainID: "28882", Name: "boba_sepolia", DisplayName: "Boba Sepolia", Testnet: true},
+	{ChainID: "29112", Name: "hy_testnet", DisplayName: "Hy Testnet", Testnet: true},
+	{ChainID: "29223", Name: "nexa_metanet", DisplayName: "Nexa Metanet"},
+	{ChainID: "29536", Name: "kai_testnet", DisplayName: "Kai Testnet", Testnet: true},
+	{ChainID: "29548", Name: "mch_verse", DisplayName: "Mch Verse"},
+	{ChainID: "30000", Name: "q", DisplayName: "Q"},
+	{ChainID: "30067", Name: "piece_testnet", DisplayName: "Piece Testnet", Testnet: true},
+	{ChainID: "30088", Name: "miyou", DisplayName: "Miyou"},
+	{ChainID: "30103", Name: "cerium_testnet", DisplayName: "Cerium Testnet", Testnet: true},
+	{ChainID: "30730", Name: "movement_evm_legacy", DisplayName: "Movement Evm Legacy"},
+	{ChainID: "30731", Name: "movement_evm_devnet", DisplayName: "Movement Evm Devnet", Testnet: true},
+	{ChainID: "30732", Name: "movement_evm_testnet", DisplayName: "Movement Evm Testnet", Testnet: true},
+	{ChainID: "31102", Name: "ethersocial", DisplayName: "Ethersocial"},
+	{ChainID: "31223", Name: "cloudtx", DisplayName: "Cloudtx"},
+	{ChainID: "31224", Name: "cloudtx_testnet", DisplayName: "Cloudtx Testnet", Testnet: true},
+	{ChainID: "31337", Name: "go_testnet", DisplayName: "Go Testnet", Testnet: true},
+	{ChainID: "31414", Name: "evoke_testnet", DisplayName: "Evoke Testnet", Testnet: true},
+	{ChainID: "31415", Name: "filecoin_wallaby_testnet", DisplayName: "Filecoin Wallaby Testnet", Testnet: true},
+	{ChainID: "31753", Name: "x", DisplayName: "X"},
+	{ChainID: "31754", Name: "x_testnet", DisplayName: "X Testnet", Testnet: true},
+	{ChainID: "32001", Name: "w3gamez_holesky_testnet", DisplayName: "W3gamez Holesky Testnet", Testnet: true},
+	{ChainID: "32382", Name: "santiment_intelligence", DisplayName: "Santiment Intelligence"},
+	{ChainID: "32520", Name: "bitgert", DisplayName: "Bitgert"},
+	{ChainID: "32659", Name: "fusion", DisplayName: "Fusion"},
+	{ChainID: "32769", Name: "zilliqa_evm", DisplayName: "Zilliqa Evm"},
+	{ChainID: "32990", Name: "zilliqa_evm_isolated_server", DisplayName: "Zilliqa Evm Isolated Server"},
+	{ChainID: "33033", Name: "entangle", DisplayName: "Entangle"},
+	{ChainID: "33101", Name: "zilliqa_evm_testnet", DisplayName: "Zilliqa Evm Testnet", Testnet: true},
+	{ChainID: "33103", Name: "zilliqa_2_evm_proto_testnet", DisplayName: "Zilliqa 2 Evm Proto Testnet", Testnet: true},
+	{ChainID: "33111", Name: "curtis", DisplayName: "Curtis"},
+	{ChainID: "33133", Name: "entangle_testnet", DisplayName: "Entangle Testnet", Testnet: true},
+	{ChainID: "33210", Name: "cloudverse_subnet", DisplayName: "Cloudverse Subnet"},
+	{ChainID: "33333", Name: "aves", DisplayName: "Aves"},
+	{ChainID: "33385", Name: "zilliqa_evm_devnet", DisplayName: "Zilliqa Evm Devnet", Testnet: true},
+	{ChainID: "33401", Name: "slingshot", DisplayName: "Slingshot"},
+	{ChainID: "33469", Name: "zilliqa_2_evm_devnet", DisplayName: "Zilliqa 2 Evm Devnet", Testnet: true},
+	{ChainID: "33979", Name: "funki", DisplayName: "Funki"},
+	{ChainID: "34443", Name: "mode", DisplayName: "Mode"},
+	{ChainID: "34504", Name: "zeus", DisplayName: "Zeus"},
+	{ChainID: "35011", Name: "j2o_taro", DisplayName: "J2o Taro"},
+	{ChainID: "35443", Name: "q_testnet", DisplayName: "Q Testnet", Testnet: true},
+	{ChainID: "38400", Name: "connectormanager", DisplayName: "Connectormanager"},
+	{ChainID: "38401", Name: "connectormanager_robin", DisplayName: "Connectormanager Robin"},
+	{ChainID: "39656", Name: "prm", DisplayName: "Prm"},
+	{ChainID: "39797", Name: "energi", DisplayName: "Energi"},
+	{ChainID: "39815", Name: "oho", DisplayName: "Oho"},
+	{ChainID: "40000", Name: "div", DisplayName: "Div"},
+	{ChainID: "41455", Name: "aleph_zero_evm", DisplayName: "Aleph Zero Evm"},
+	{ChainID: "41500", Name: "opulent_x_beta", DisplayName: "Opulent X Beta"},
+	{ChainID: "42069", Name: "pegglecoin", DisplayName: "Pegglecoin"},
+	{ChainID: "42072", Name: "agentlayer_testnet", DisplayName: "Agentlayer Testnet", Testnet: true},
+	{ChainID: "42161", Name: "arbitrum", DisplayName: "Arbitrum One", Aliases: []string{"arb", "arb1", "arbitrum_one"}, Parent: "1", Currency: "ETH", Explorer: "https://arbiscan.io"},
+	{ChainID: "42170", Name: "arbitrum_nova", DisplayName: "Arbitrum Nova", Parent: "1", Currency: "ETH", Explorer: "https://nova.arbiscan.io"},
+	{ChainID: "42220", Name: "celo", DisplayName: "Celo"},
+	{ChainID: "42261", Name: "oasis_emerald_testnet", DisplayName: "Oasis Emerald Testnet", Testnet: true},
+	{ChainID: "42262", Name: "oasis_emerald", DisplayName: "Oasis Emerald"},
+	{ChainID: "42355", Name: "goldx", DisplayName: "Goldx"},
+	{ChainID: "42421", Name: "asset_testnet", DisplayName: "Asset Testnet", Testnet: true},
+	{ChainID: "42766", Name: "zkfair", DisplayName: "Zkfair"},
+	{ChainID: "42793", Name: "etherlink", DisplayName: "Etherlink"},
+	{ChainID: "42801", Name: "gesoten_verse_testnet", DisplayName: "Gesoten Verse Testnet", Testnet: true},
+	{ChainID: "42888", Name: "kinto_testnet", DisplayName: "Kinto Testnet", Testnet: true},
+	{ChainID: "43110", Name: "athereum", DisplayName: "Athereum"},
+	{ChainID: "43111", Name: "hemi", DisplayName: "Hemi"},
+	{ChainID: "43113", Name: "avalanche_fuji_testnet", DisplayName: "Avalanche Fuji Testnet", Aliases: []string{"fuji"}, Testnet: true, Currency: "AVAX", Explorer: "https://testnet.snowtrace.io"},
+	{ChainID: "43114", Name: "avalanche", DisplayName: "Avalanche C-Chain", Aliases: []string{"avax", "avalanche_c"}, Currency: "AVAX", Explorer: "https://snowtrace.io"},
+	{ChainID: "43288", Name: "boba_avax", DisplayName: "Boba Avax"},
+	{ChainID: "43851", Name: "zkfair_testnet", DisplayName: "Zkfair Testnet", Testnet: true},
+	{ChainID: "44444", Name: "fren", DisplayName: "Fren"},
+	{ChainID: "44445", Name: "quantum", DisplayName: "Quantum"},
+	{ChainID: "44787", Name: "celo_alfajores_testnet", DisplayName: "Celo Alfajores Testnet", Testnet: true},
+	{ChainID: "45000", Name: "autobahn", DisplayName: "Autobahn"},
+	{ChainID: "45454", Name: "swamps_l2", DisplayName: "Swamps L2"},
+	{ChainID: "45510", Name: "deelance", DisplayName: "Deelance"},
+	{ChainID: "45513", Name: "blessnet", DisplayName: "Blessnet"},
+	{ChainID: "46688", Name: "fusion_testnet", DisplayName: "Fusion Testnet", Testnet: true},
+	{ChainID: "47763", Name: "neo_x", DisplayName: "Neo X"},
+	{ChainID: "47803", Name: "redefi_layer_1", DisplayName: "Redefi Layer 1"},
+	{ChainID: "47805", Name: "rei", DisplayName: "Rei"},
+	{ChainID: "48795", Name: "space_subnet_testnet", DisplayName: "Space Subnet Testnet", Testnet: true},
+	{ChainID: "48899", Name: "zircuit_testnet", DisplayName: "Zircuit Testnet", Testnet: true},
+	{ChainID: "48900", Name: "zircuit", DisplayName: "Zircuit"},
+	{ChainID: "49049", Name: "wireshape_floripa_testnet", DisplayName: "Wireshape Floripa Testnet", Testnet: true},
+	{ChainID: "49088", Name: "bifrost_testnet", DisplayName: "Bifrost Testnet", Testnet: true},
+	{ChainID: "49321", Name: "gunz_testnet", DisplayName: "Gunz Testnet", Testnet: true},
+	{ChainID: "49797", Name: "energi_testnet", DisplayName: "Energi Testnet", Testnet: true},
+	{ChainID: "50001", Name: "liveplex_oracleevm", DisplayName: "Liveplex Oracleevm"},
+	{ChainID: "50005", Name: "yooldo_verse", DisplayName: "Yooldo Verse"},
+	{ChainID: "50006", Name: "yooldo_verse_testnet", DisplayName: "Yooldo Verse Testnet", Testnet: true},
+	{ChainID: "50021", Name: "gton_testnet", DisplayName: "Gton Testnet", Testnet: true},
+	{ChainID: "50888", Name: "erbie", DisplayName: "Erbie"},
+	{ChainID: "51178", Name: "lumoz_testnet_alpha", DisplayName: "Lumoz Testnet Alpha", Testnet: true},
+	{ChainID: "51712", Name: "sardis", DisplayName: "Sardis"},
+	{ChainID: "52014", Name: "electroneum", DisplayName: "Electroneum"},
+	{ChainID: "53277", Name: "doid", DisplayName: "Doid"},
+	{ChainID: "53302", Name: "superseed_sepolia_testnet", DisplayName: "Superseed Sepolia Testnet", Testnet: true},
+	{ChainID: "53457", Name: "dodo_testnet", DisplayName: "Dodo Testnet", Testnet: true},
+	{ChainID: "53935", Name: "dfk", DisplayName: "Dfk"},
+	{ChainID: "54176", Name: "overprotocol", DisplayName: "Overprotocol"},
+	{ChainID: "54211", Name: "haqq_testnet", DisplayName: "Haqq Testnet", Testnet: true},
+	{ChainID: "54321", Name: "toronet_testnet", DisplayName: "Toronet Testnet", Testnet: true},
+	{ChainID: "55004", Name: "titan", DisplayName: "Titan"},
+	{ChainID: "55551", Name: "photon_aurora_testnet", DisplayName: "Photon Aurora Testnet", Testnet: true},
+	{ChainID: "55556", Name: "rei_testnet", DisplayName: "Rei Testnet", Testnet: true},
+	{ChainID: "55614", Name: "flamma", DisplayName: "Flamma"},
+	{ChainID: "56026", Name: "lambda", DisplayName: "Lambda"},
+	{ChainID: "56288", Name: "boba_bnb", DisplayName: "Boba Bnb"},
+	{ChainID: "56400", Name: "testnet_zeroone_subnet", DisplayName: "Testnet Zeroone Subnet", Testnet: true},
+	{ChainID: "56789", Name: "velo_labs", DisplayName: "Velo Labs"},
+	{ChainID: "56797", Name: "doid_testnet", DisplayName: "Doid Testnet", Testnet: true},
+	{ChainID: "57000", Name: "rollux_testnet", DisplayName: "Rollux Testnet", Testnet: true},
+	{ChainID: "57451", Name: "coinsec", DisplayName: "Coinsec"},
+	{ChainID: "58008", Name: "sepolia_pgn_public_goods", DisplayName: "Sepolia Pgn Public Goods", Testnet: true},
+	{ChainID: "58680", Name: "lumoz_quidditch_testnet", DisplayName: "Lumoz Quidditch Testnet", Testnet: true},
+	{ChainID: "59140", Name: "linea_goerli", DisplayName: "Linea Goerli", Testnet: true},
+	{ChainID: "59141", Name: "linea_sepolia", DisplayName: "Linea Sepolia", Testnet: true, Parent: "11155111", Currency: "ETH", Explorer: "https://sepolia.lineascan.build"},
+	{ChainID: "59144", Name: "linea", DisplayName: "Linea", Aliases: []string{"linea_mainnet"}, Parent: "1", Currency: "ETH", Explorer: "https://lineascan.build"},
+	{ChainID: "59902", Name: "metis_sepolia_testnet", DisplayName: "Metis Sepolia Testnet", Testnet: true},
+	{ChainID: "59971", Name: "genesys_code", DisplayName: "Genesys Code"},
+	{ChainID: "60000", Name: "thinkium_testnet_0", DisplayName: "Thinkium Testnet 0", Testnet: true},
+	{ChainID: "60001", Name: "thinkium_testnet_1", DisplayName: "Thinkium Testnet 1", Testnet: true},
+	{ChainID: "60002", Name: "thinkium_testnet_2", DisplayName: "Thinkium Testnet 2", Testnet: true},
+	{ChainID: "60103", Name: "thinkium_testnet_103", DisplayName: "Thinkium Testnet 103", Testnet: true},
+	{ChainID: "60808", Name: "bob", DisplayName: "Bob"},
+	{ChainID: "61022", Name: "orange", DisplayName: "Orange"},
+	{ChainID: "61406", Name: "kai", DisplayName: "Kai"},
+	{ChainID: "61800", Name: "axel_dev_net", DisplayName: "Axel Dev Net"},
+	{ChainID: "61803", Name: "etica", DisplayName: "Etica"},
+	{ChainID: "61916", Name: "doken_super", DisplayName: "Doken Super"},
+	{ChainID: "62049", Name: "optopia_testnet", DisplayName: "Optopia Testnet", Testnet: true},
+	{ChainID: "62050", Name: "optopia", DisplayName: "Optopia"},
+	{ChainID: "62298", Name: "citrea_devnet", DisplayName: "Citrea Devnet", Testnet: true},
+	{ChainID: "62320", Name: "celo_baklava_testnet", DisplayName: "Celo Baklava Testnet", Testnet: true},
+	{ChainID: "62621", Name: "multivac", DisplayName: "Multivac"},
+	{ChainID: "62831", Name: "plyr_tau_testnet", DisplayName: "Plyr Tau Testnet", Testnet: true},
+	{ChainID: "62850", Name: "laos_sigma_testnet", DisplayName: "Laos Sigma Testnet", Testnet: true},
+	{ChainID: "63000", Name: "ecredits", DisplayName: "Ecredits"},
+	{ChainID: "63001", Name: "ecredits_testnet", DisplayName: "Ecredits Testnet", Testnet: true},
+	{ChainID: "63002", Name: "esync_testnet", DisplayName: "Esync Testnet", Testnet: true},
+	{ChainID: "65349", Name: "cratd2c_testnet", DisplayName: "Cratd2c Testnet", Testnet: true},
+	{ChainID: "65357", Name: "vecno", DisplayName: "Vecno"},
+	{ChainID: "65450", Name: "scolcoin", DisplayName: "Scolcoin"},
+	{ChainID: "65536", Name: "automata", DisplayName: "Automata"},
+	{ChainID: "66665", Name: "creator_testnet", DisplayName: "Creator Testnet", Testnet: true},
+	{ChainID: "66988", Name: "janus_testnet", DisplayName: "Janus Testnet", Testnet: true},
+	{ChainID: "67390", Name: "siriusnet", DisplayName: "Siriusnet"},
+	{ChainID: "67588", Name: "cosmic", DisplayName: "Cosmic"},
+	{ChainID: "68770", Name: "dm2_verse", DisplayName: "Dm2 Verse"},
+	{ChainID: "68775", Name: "dm2_verse_testnet", DisplayName: "Dm2 Verse Testnet", Testnet: true},
+	{ChainID: "69420", Name: "condrieu", DisplayName: "Condrieu"},
+	{ChainID: "70000", Name: "thinkium_0", DisplayName: "Thinkium 0"},
+	{ChainID: "70001", Name: "thinkium_1", DisplayName: "Thinkium 1"},
+	{ChainID: "70002", Name: "thinkium_2", DisplayName: "Thinkium 2"},
+	{ChainID: "70103", Name: "thinkium_103", DisplayName: "Thinkium 103"},
+	{ChainID: "70700", Name: "proof_of_play_apex", DisplayName: "Proof Of Play Apex"},
+	{ChainID: "71111", Name: "guapcoinx", DisplayName: "Guapcoinx"},
+	{ChainID: "71393", Name: "polyjuice_testnet", DisplayName: "Polyjuice Testnet", Testnet: true},
+	{ChainID: "71401", Name: "godwoken_testnet_v1", DisplayName: "Godwoken Testnet V1", Testnet: true},
+	{ChainID: "71402", Name: "godwoken", DisplayName: "Godwoken"},
+	{ChainID: "72778", Name: "caga_crypto_ankara_testnet", DisplayName: "Caga Crypto Ankara Testnet", Testnet: true},
+	{ChainID: "72992", Name: "grok", DisplayName: "Grok"},
+	{ChainID: "73114", Name: "icb_testnet", DisplayName: "Icb Testnet", Testnet: true},
+	{ChainID: "73115", Name: "icb", DisplayName: "Icb"},
+	{ChainID: "73799", Name: "energy_web_volta_testnet", DisplayName: "Energy Web Volta Testnet", Testnet: true},
+	{ChainID: "73927", Name: "mixin_virtual_machine", DisplayName: "Mixin Virtual Machine"},
+	{ChainID: "75000", Name: "resincoin", DisplayName: "Resincoin"},
+	{ChainID: "75512", Name: "geek_verse", DisplayName: "Geek Verse"},
+	{ChainID: "75513", Name: "geek_verse_testnet", DisplayName: "Geek Verse Testnet", Testnet: true},
+	{ChainID: "77001", Name: "bora", DisplayName: "Bora"},
+	{ChainID: "77238", Name: "foundry_testnet", DisplayName: "Foundry Testnet", Testnet: true},
+	{ChainID: "77612", Name: "vention", DisplayName: "Vention"},
+	{ChainID: "77677", Name: "cycle_sailboat", DisplayName: "Cycle Sailboat"},
+	{ChainID: "77777", Name: "toronet", DisplayName: "Toronet"},
+	{ChainID: "78110", Name: "firenze_test", DisplayName: "Firenze Test", Testnet: true},
+	{ChainID: "78281", Name: "dragonfly_hexapod", DisplayName: "Dragonfly Hexapod"},
+	{ChainID: "78430", Name: "amplify_subnet", DisplayName: "Amplify Subnet"},
+	{ChainID: "78431", Name: "bulletin_subnet", DisplayName: "Bulletin Subnet"},
+	{ChainID: "78432", Name: "conduit_subnet", DisplayName: "Conduit Subnet"},
+	{ChainID: "78600", Name: "vanguard", DisplayName: "Vanguard"},
+	{ChainID: "79879", Name: "gold_testnet", DisplayName: "Gold Testnet", Testnet: true},
+	{ChainID: "80001", Name: "mumbai", DisplayName: "Mumbai", Testnet: true},
+	{ChainID: "80002", Name: "amoy", DisplayName: "Amoy", Aliases: []string{"polygon_amoy"}, Testnet: true, Currency: "POL", Explorer: "https://amoy.polygonscan.com"},
+	{ChainID: "80008", Name: "polynomia_sepolia", DisplayName: "Polynomia Sepolia", Testnet: true},
+	{ChainID: "80084", Name: "bera_bartio", DisplayName: "Bera Bartio"},
+	{ChainID: "80085", Name: "bera_artio", DisplayName: "Bera Artio"},
+	{ChainID: "80096", Name: "hizoco", DisplayName: "Hizoco"},
+	{ChainID: "81041", Name: "nordek", DisplayName: "Nordek"},
+	{ChainID: "81341", Name: "amana_testnet", DisplayName: "Amana Testnet", Testnet: true},
+	{ChainID: "81342", Name: "amana_mixnet", DisplayName: "Amana Mixnet"},
+	{ChainID: "81343", Name: "amana_privnet", DisplayName: "Amana Privnet"},
+	{ChainID: "81351", Name: "flana_testnet", DisplayName: "Flana Testnet", Testnet: true},
+	{ChainID: "81352", Name: "flana_mixnet", DisplayName: "Flana Mixnet"},
+	{ChainID: "81353", Name: "flana_privnet", DisplayName: "Flana Privnet"},
+	{ChainID: "81361", Name: "mizana_testnet", DisplayName: "Mizana Testnet", Testnet: true},
+	{ChainID: "81362", Name: "mizana_mixnet", DisplayName: "Mizana Mixnet"},
+	{ChainID: "81363", Name: "mizana_privnet", DisplayName: "Mizana Privnet"},
+	{ChainID: "82459", Name: "smart_layer_testnet", DisplayName: "Smart Layer Testnet", Testnet: true},
+	{ChainID: "83144", Name: "xprotocol_testnet", DisplayName: "Xprotocol Testnet", Testnet: true},
+	{ChainID: "83278", Name: "esa", DisplayName: "Esa"},
+	{ChainID: "83872", Name: "zedxion", DisplayName: "Zedxion"},
+	{ChainID: "84531", Name: "base_goerli_testnet", DisplayName: "Base Goerli Testnet", Testnet: true},
+	{ChainID: "84532", Name: "base_sepolia_testnet", DisplayName: "Base Sepolia Testnet", Aliases: []string{"base_sepolia"}, Testnet: true, Parent: "11155111", Currency: "ETH", Explorer: "https://sepolia.basescan.org"},
+	{ChainID: "84886", Name: "aerie", DisplayName: "Aerie"},
+	{ChainID: "85449", Name: "cybertrust", DisplayName: "Cybertrust"},
+	{ChainID: "88002", Name: "nautilus_proteus_testnet", DisplayName: "Nautilus Proteus Testnet", Testnet: true},
+	{ChainID: "88559", Name: "inoai", DisplayName: "Inoai"},
+	{ChainID: "88800", Name: "zkasino", DisplayName: "Zkasino"},
+	{ChainID: "88817", Name: "unit_zero_testnet", DisplayName: "Unit Zero Testnet", Testnet: true},
+	{ChainID: "88819", Name: "unit_zero_stagenet", DisplayName: "Unit Zero Stagenet"},
+	{ChainID: "88880", Name: "chiliz_scoville_testnet", DisplayName: "Chiliz Scoville Testnet", Testnet: true},
+	{ChainID: "88882", Name: "chiliz_spicy_testnet", DisplayName: "Chiliz Spicy Testnet", Testnet: true},
+	{ChainID: "88888", Name: "chiliz", DisplayName: "Chiliz"},
+	{ChainID: "90001", Name: "fxcore_testnet", DisplayName: "Fxcore Testnet", Testnet: true},
+	{ChainID: "90210", Name: "beverly_hills", DisplayName: "Beverly Hills"},
+	{ChainID: "90354", Name: "camp_testnet", DisplayName: "Camp Testnet", Testnet: true},
+	{ChainID: "91002", Name: "nautilus_trition", DisplayName: "Nautilus Trition"},
+	{ChainID: "91120", Name: "metadap_enterprise", DisplayName: "Metadap Enterprise"},
+	{ChainID: "91715", Name: "combo_testnet", DisplayName: "Combo Testnet", Testnet: true},
+	{ChainID: "92001", Name: "lambda_testnet", DisplayName: "Lambda Testnet", Testnet: true},
+	{ChainID: "93572", Name: "liquidlayer_testnet", DisplayName: "Liquidlayer Testnet", Testnet: true},
+	{ChainID: "93747", Name: "stratovm_testnet", DisplayName: "Stratovm Testnet", Testnet: true},
+	{ChainID: "96970", Name: "mantis_testnet_hexapod", DisplayName: "Mantis Testnet Hexapod", Testnet: true},
+	{ChainID: "97053", Name: "tetron_testnet", DisplayName: "Tetron Testnet", Testnet: true},
+	{ChainID: "97055", Name: "tetron", DisplayName: "Tetron"},
+	{ChainID: "97288", Name: "boba_bnb_old", DisplayName: "Boba Bnb Old"},
+	{ChainID: "97435", Name: "slingshot_testnet", DisplayName: "Slingshot Testnet", Testnet: true},
+	{ChainID: "97531", Name: "green_testnet", DisplayName: "Green Testnet", Testnet: true},
+	{ChainID: "97970", Name: "optimusz7_testnet", DisplayName: "Optimusz7 Testnet", Testnet: true},
+	{ChainID: "98881", Name: "ebi", DisplayName: "Ebi"},
+	{ChainID: "99099", Name: "eliberty_testnet", DisplayName: "Eliberty Testnet", Testnet: true},
+	{ChainID: "99876", Name: "edge_matrix_testnet", DisplayName: "Edge Matrix Testnet", Testnet: true},
+	{ChainID: "99998", Name: "ubtestnet", DisplayName: "Ubtestnet", Testnet: true},
+	{ChainID: "99999", Name: "ub", DisplayName: "Ub"},
+	{ChainID: "100000", Name: "quark_root", DisplayName: "Quark Root"},
+	{ChainID: "100001", Name: "quark_shard_0", DisplayName: "Quark Shard 0"},
+	{ChainID: "100002", Name: "quark_shard_1", DisplayName: "Quark Shard 1"},
+	{ChainID: "100003", Name: "quark_shard_2", DisplayName: "Quark Shard 2"},
+	{ChainID: "100004", Name: "quark_shard_3", DisplayName: "Quark Shard 3"},
+	{ChainID: "100005", Name: "quark_shard_4", DisplayName: "Quark Shard 4"},
+	{ChainID: "100006", Name: "quark_shard_5", DisplayName: "Quark Shard 5"},
+	{ChainID: "100007", Name: "quark_shard_6", DisplayName: "Quark Shard 6"},
+	{ChainID: "100008", Name: "quark_shard_7", DisplayName: "Quark Shard 7"},
+	{ChainID: "100009", Name: "ve", DisplayName: "Ve"},
+	{ChainID: "100010", Name: "ve_testnet", DisplayName: "Ve Testnet", Testnet: true},
+	{ChainID: "100011", Name: "quark_l2", DisplayName: "Quark L2"},
+	{ChainID: "101010", Name: "global_trust", DisplayName: "Global Trust"},
+	{ChainID: "102030", Name: "creditcoin", DisplayName: "Creditcoin"},
+	{ChainID: "102031", Name: "creditcoin_testnet", DisplayName: "Creditcoin Testnet", Testnet: true},
+	{ChainID: "102032", Name: "creditcoin_devnet", DisplayName: "Creditcoin Devnet", Testnet: true},
+	{ChainID: "103090", Name: "crystaleum", DisplayName: "Crystaleum"},
+	{ChainID: "103454", Name: "masa_testnet", DisplayName: "Masa Testnet", Testnet: true},
+	{ChainID: "104566", Name: "kaspaclassic", DisplayName: "Kaspaclassic"},
+	{ChainID: "105105", Name: "stratis", DisplayName: "Stratis"},
+	{ChainID: "108801", Name: "bro", DisplayName: "Bro"},
+	{ChainID: "110000", Name: "quark_devnet_root", DisplayName: "Quark Devnet Root", Testnet: true},
+	{ChainID: "110001", Name: "quark_devnet_shard_0", DisplayName: "Quark Devnet Shard 0", Testnet: true},
+	{ChainID: "110002", Name: "quark_devnet_shard_1", DisplayName: "Quark Devnet Shard 1", Testnet: true},
+	{ChainID: "110003", Name: "quark_devnet_shard_2", DisplayName: "Quark Devnet Shard 2", Testnet: true},
+	{ChainID: "110004", Name: "quark_devnet_shard_3", DisplayName: "Quark Devnet Shard 3", Testnet: true},
+	{ChainID: "110005", Name: "quark_devnet_shard_4", DisplayName: "Quark Devnet Shard 4", Testnet: true},
+	{ChainID: "110006", Name: "quark_devnet_shard_5", DisplayName: "Quark Devnet Shard 5", Testnet: true},
+	{ChainID: "110007", Name: "quark_devnet_shard_6", DisplayName: "Quark Devnet Shard 6", Testnet: true},
+	{ChainID: "110008", Name: "quark_devnet_shard_7", DisplayName: "Quark Devnet Shard 7", Testnet: true},
+	{ChainID: "110011", Name: "quark_l2_testnet", DisplayName: "Quark L2 Testnet", Testnet: true},
+	{ChainID: "110110", Name: "mars_credit", DisplayName: "Mars Credit"},
+	{ChainID: "111000", Name: "siberium_test", DisplayName: "Siberium Test", Testnet: true},
+	{ChainID: "111111", Name: "siberium", DisplayName: "Siberium"},
+	{ChainID: "111188", Name: "re.al", DisplayName: "Re.al"},
+	{ChainID: "112358", Name: "meta_one", DisplayName: "Meta One"},
+	{ChainID: "119139", Name: "metadap_enterprise_testnet", DisplayName: "Metadap Enterprise Testnet", Testnet: true},
+	{ChainID: "123321", Name: "gem", DisplayName: "Gem"},
+	{ChainID: "123456", Name: "adil_devnet", DisplayName: "Adil Devnet", Testnet: true},
+	{ChainID: "128123", Name: "etherlink_testnet", DisplayName: "Etherlink Testnet", Testnet: true},
+	{ChainID: "131313", Name: "odyssey_testnet", DisplayName: "Odyssey Testnet", Testnet: true},
+	{ChainID: "131419", Name: "etnds", DisplayName: "Etnds"},
+	{ChainID: "132902", Name: "form_testnet", DisplayName: "Form Testnet", Testnet: true},
+	{ChainID: "141319", Name: "magape_testnet", DisplayName: "Magape Testnet", Testnet: true},
+	{ChainID: "142857", Name: "icplaza", DisplayName: "Icplaza"},
+	{ChainID: "161212", Name: "playfi", DisplayName: "Playfi"},
+	{ChainID: "165279", Name: "eclat", DisplayName: "Eclat"},
+	{ChainID: "167000", Name: "taiko", DisplayName: "Taiko"},
+	{ChainID: "167004", Name: "taiko_alpha_2_testnet", DisplayName: "Taiko Alpha 2 Testnet", Testnet: true},
+	{ChainID: "167005", Name: "taiko_grimsvotn_l2", DisplayName: "Taiko Grimsvotn L2"},
+	{ChainID: "167006", Name: "taiko_eldfell_l3", DisplayName: "Taiko Eldfell L3"},
+	{ChainID: "167007", Name: "taiko_jolnir_l2", DisplayName: "Taiko Jolnir L2"},
+	{ChainID: "167008", Name: "taiko_katla_l2", DisplayName: "Taiko Katla L2"},
+	{ChainID: "167009", Name: "taiko_hekla_l2", DisplayName: "Taiko Hekla L2"},
+	{ChainID: "168168", Name: "zs", DisplayName: "Zs"},
+	{ChainID: "171000", Name: "fair_testnet", DisplayName: "Fair Testnet", Testnet: true},
+	{ChainID: "175177", Name: "chronicle_lit_protocol_testnet", DisplayName: "Chronicle Lit Protocol Testnet", Testnet: true},
+	{ChainID: "175188", Name: "chronicle_yellowstone_lit_protocol_testnet", DisplayName: "Chronicle Yellowstone Lit Protocol Testnet", Testnet: true},
+	{ChainID: "188710", Name: "bitica", DisplayName: "Bitica"},
+	{ChainID: "188881", Name: "condor_test", DisplayName: "Condor Test", Testnet: true},
+	{ChainID: "199991", Name: "mazze_testnet", DisplayName: "Mazze Testnet", Testnet: true},
+	{ChainID: "200000", Name: "xfair.ai_testnet", DisplayName: "Xfair.ai Testnet", Testnet: true},
+	{ChainID: "200101", Name: "milkomeda_c1_testnet", DisplayName: "Milkomeda C1 Testnet", Testnet: true},
+	{ChainID: "200202", Name: "milkomeda_a1_testnet", DisplayName: "Milkomeda A1 Testnet", Testnet: true},
+	{ChainID: "200625", Name: "akroma", DisplayName: "Akroma"},
+	{ChainID: "200810", Name: "bitlayer_testnet", DisplayName: "Bitlayer Testnet", Testnet: true},
+	{ChainID: "200901", Name: "bitlayer", DisplayName: "Bitlayer"},
+	{ChainID: "201018", Name: "alaya", DisplayName: "Alaya"},
+	{ChainID: "201030", Name: "alaya_dev_testnet", DisplayName: "Alaya Dev Testnet", Testnet: true},
+	{ChainID: "201804", Name: "mythical", DisplayName: "Mythical"},
+	{ChainID: "202020", Name: "decimal_testnet", DisplayName: "Decimal Testnet", Testnet: true},
+	{ChainID: "202105", Name: "duck_testnet", DisplayName: "Duck Testnet", Testnet: true},
+	{ChainID: "202202", Name: "bethel_sydney", DisplayName: "Bethel Sydney"},
+	{ChainID: "202212", Name: "x1_devnet", DisplayName: "X1 Devnet", Testnet: true},
+	{ChainID: "202401", Name: "ymtech_besu_testnet", DisplayName: "Ymtech Besu Testnet", Testnet: true},
+	{ChainID: "202624", Name: "jellie", DisplayName: "Jellie"},
+	{ChainID: "204005", Name: "x1_network", DisplayName: "X1 Network"},
+	{ChainID: "205205", Name: "auroria_testnet", DisplayName: "Auroria Testnet", Testnet: true},
+	{ChainID: "210049", Name: "gitagi_atlas_testnet", DisplayName: "Gitagi Atlas Testnet", Testnet: true},
+	{ChainID: "210425", Name: "platon", DisplayName: "Platon"},
+	{ChainID: "212013", Name: "litentry", DisplayName: "Litentry"},
+	{ChainID: "220315", Name: "mas", DisplayName: "Mas"},
+	{ChainID: "221230", Name: "reap", DisplayName: "Reap"},
+	{ChainID: "221231", Name: "reap_testnet", DisplayName: "Reap Testnet", Testnet: true},
+	{ChainID: "222222", Name: "hydradx", DisplayName: "Hydradx"},
+	{ChainID: "222555", Name: "deepl", DisplayName: "Deepl"},
+	{ChainID: "222666", Name: "deepl_testnet", DisplayName: "Deepl Testnet", Testnet: true},
+	{ChainID: "224168", Name: "taf_eco", DisplayName: "Taf Eco"},
+	{ChainID: "224422", Name: "conet_sebolia_testnet", DisplayName: "Conet Sebolia Testnet", Testnet: true},
+	{ChainID: "224433", Name: "conet_holesky", DisplayName: "Conet Holesky", Testnet: true},
+	{ChainID: "229772", Name: "abyss_protocol", DisplayName: "Abyss Protocol"},
+	{ChainID: "230315", Name: "hashkey_testnetdiscard", DisplayName: "Hashkey Testnetdiscard", Testnet: true},
+	{ChainID: "234666", Name: "haymo_testnet", DisplayName: "Haymo Testnet", Testnet: true},
+	{ChainID: "240515", Name: "orange_testnet", DisplayName: "Orange Testnet", Testnet: true},
+	{ChainID: "246529", Name: "artis_sigma1", DisplayName: "Artis Sigma1"},
+	{ChainID: "246785", Name: "artis_testnet_tau1", DisplayName: "Artis Testnet Tau1", Testnet: true},
+	{ChainID: "247253", Name: "saakuru_testnet", DisplayName: "Saakuru Testnet", Testnet: true},
+	{ChainID: "256256", Name: "cmp", DisplayName: "Cmp"},
+	{ChainID: "262371", Name: "eclat_testnet", DisplayName: "Eclat Testnet", Testnet: true},
+	{ChainID: "266256", Name: "gear_zero_testnet", DisplayName: "Gear Zero Testnet", Testnet: true},
+	{ChainID: "271271", Name: "egoncoin_testnet", DisplayName: "Egoncoin Testnet", Testnet: true},
+	{ChainID: "281121", Name: "social", DisplayName: "Social"},
+	{ChainID: "282828", Name: "zillion_sepolia_testnet", DisplayName: "Zillion Sepolia Testnet", Testnet: true},
+	{ChainID: "292003", Name: "cipherem_testnet", DisplayName: "Cipherem Testnet", Testnet: true},
+	{ChainID: "309075", Name: "one_world", DisplayName: "One World"},
+	{ChainID: "313313", Name: "saharaai_testnet", DisplayName: "Saharaai Testnet", Testnet: true},
+	{ChainID: "314159", Name: "filecoin_calibration_testnet", DisplayName: "Filecoin Calibration Testnet", Testnet: true},
+	{ChainID: "322202", Name: "parex", DisplayName: "Parex"},
+	{ChainID: "323213", Name: "bloom_genesis_testnet", DisplayName: "Bloom Genesis Testnet", Testnet: true},
+	{ChainID: "327126", Name: "waba_testnet", DisplayName: "Waba Testnet", Testnet: true},
+	{ChainID: "328527", Name: "nal", DisplayName: "Nal"},
+	{ChainID: "330844", Name: "ttcoin", DisplayName: "Ttcoin"},
+	{ChainID: "333313", Name: "bloom_genesis", DisplayName: "Bloom Genesis"},
+	{ChainID: "333331", Name: "aves_testnet", DisplayName: "Aves Testnet", Testnet: true},
+	{ChainID: "333333", Name: "nativ3_testnet", DisplayName: "Nativ3 Testnet", Testnet: true},
+	{ChainID: "333666", Name: "oone_testnet", DisplayName: "Oone Testnet", Testnet: true},
+	{ChainID: "333777", Name: "oone_devnet", DisplayName: "Oone Devnet", Testnet: true},
+	{ChainID: "333888", Name: "polis_testnet", DisplayName: "Polis Testnet", Testnet: true},
+	{ChainID: "333999", Name: "polis", DisplayName: "Polis"},
+	{ChainID: "336655", Name: "up_testnet", DisplayName: "Up Testnet", Testnet: true},
+	{ChainID: "336666", Name: "up", DisplayName: "Up"},
+	{ChainID: "355110", Name: "bitfinity", DisplayName: "Bitfinity"},
+	{ChainID: "355113", Name: "bitfinity_testnet", DisplayName: "Bitfinity Testnet", Testnet: true},
+	{ChainID: "360890", Name: "lavita", DisplayName: "Lavita"},
+	{ChainID: "363636", Name: "digit_soul_2", DisplayName: "Digit Soul 2"},
+	{ChainID: "373737", Name: "hap_testnet", DisplayName: "Hap Testnet", Testnet: true},
+	{ChainID: "381931", Name: "metal_c", DisplayName: "Metal C"},
+	{ChainID: "381932", Name: "metal_tahoe_c", DisplayName: "Metal Tahoe C"},
+	{ChainID: "404040", Name: "tipboxcoin", DisplayName: "Tipboxcoin"},
+	{ChainID: "413413", Name: "aie_testnet", DisplayName: "Aie Testnet", Testnet: true},
+	{ChainID: "420420", Name: "kek", DisplayName: "Kek"},
+	{ChainID: "420666", Name: "kek_kektest", DisplayName: "Kek Kektest", Testnet: true},
+	{ChainID: "420692", Name: "alterium_l2_testnet", DisplayName: "Alterium L2 Testnet", Testnet: true},
+	{ChainID: "421611", Name: "arbitrum_rinkeby", DisplayName: "Arbitrum Rinkeby", Testnet: true},
+	{ChainID: "421613", Name: "arbitrum_goerli", DisplayName: "Arbitrum Goerli", Testnet: true},
+	{ChainID: "421614", Name: "arbitrum_sepolia", DisplayName: "Arbitrum Sepolia", Aliases: []string{"arb_sepolia"}, Testnet: true, Parent: "11155111", Currency: "ETH", Explorer: "https://sepolia.arbiscan.io"},
+	{ChainID: "424242", Name: "fastex_testnet", DisplayName: "Fastex Testnet", Testnet: true},
+	{ChainID: "431140", Name: "markr_go", DisplayName: "Markr Go"},
+	{ChainID: "432201", Name: "dexalot_subnet_testnet", DisplayName: "Dexalot Subnet Testnet", Testnet: true},
+	{ChainID: "432204", Name: "dexalot_subnet", DisplayName: "Dexalot Subnet"},
+	{ChainID: "444444", Name: "syndr_l3_sepolia", DisplayName: "Syndr L3 Sepolia", Testnet: true},
+	{ChainID: "444900", Name: "weelink_testnet", DisplayName: "Weelink Testnet", Testnet: true},
+	{ChainID: "471100", Name: "patex_sepolia_testnet", DisplayName: "Patex Sepolia Testnet", Testnet: true},
+	{ChainID: "473861", Name: "ultra_pro", DisplayName: "Ultra Pro"},
+	{ChainID: "474142", Name: "open", DisplayName: "Open"},
+	{ChainID: "486487", Name: "gobbl_testnet", DisplayName: "Gobbl Testnet", Testnet: true},
+	{ChainID: "490000", Name: "autonomys_testnet_nova_domain", DisplayName: "Autonomys Testnet Nova Domain", Testnet: true},
+	{ChainID: "504441", Name: "playdapp", DisplayName: "Playdapp"},
+	{ChainID: "512512", Name: "cmp_testnet", DisplayName: "Cmp Testnet", Testnet: true},
+	{ChainID: "513100", Name: "dis", DisplayName: "Dis"},
+	{ChainID: "526916", Name: "docoin_community", DisplayName: "Docoin Community"},
+	{ChainID: "534351", Name: "scroll_sepolia_testnet", DisplayName: "Scroll Sepolia Testnet", Aliases: []string{"scroll_sepolia"}, Testnet: true, Parent: "11155111", Currency: "ETH", Explorer: "https://sepolia.scrollscan.com"},
+	{ChainID: "534352", Name: "scroll", DisplayName: "Scroll", Aliases: []string{"scroll_mainnet"}, Parent: "1", Currency: "ETH", Explorer: "https://scrollscan.com"},
+	{ChainID: "534353", Name: "scroll_alpha_testnet", DisplayName: "Scroll Alpha Testnet", Testnet: true},
+	{ChainID: "534354", Name: "scroll_pre_alpha_testnet", DisplayName: "Scroll Pre Alpha Testnet", Testnet: true},
+	{ChainID: "534849", Name: "shinarium_beta", DisplayName: "Shinarium Beta"},
+	{ChainID: "535037", Name: "beaneco_smart", DisplayName: "Beaneco Smart"},
+	{ChainID: "541764", Name: "overprotocol_testnet", DisplayName: "Overprotocol Testnet", Testnet: true},
+	{ChainID: "552981", Name: "one_world_testnet", DisplayName: "One World Testnet", Testnet: true},
+	{ChainID: "555555", Name: "pentagon_testnet", DisplayName: "Pentagon Testnet", Testnet: true},
+	{ChainID: "555666", Name: "eclipse_testnet", DisplayName: "Eclipse Testnet", Testnet: true},
+	{ChainID: "555888", Name: "dustboy_iot", DisplayName: "Dustboy Iot"},
+	{ChainID: "622277", Name: "hypra", DisplayName: "Hypra"},
+	{ChainID: "622463", Name: "atlas", DisplayName: "Atlas"},
+	{ChainID: "641230", Name: "bear", DisplayName: "Bear"},
+	{ChainID: "651940", Name: "all", DisplayName: "All"},
+	{ChainID: "656476", Name: "open_campus_codex", DisplayName: "Open Campus Codex"},
+	{ChainID: "660279", Name: "xai", DisplayName: "Xai"},
+	{ChainID: "666666", Name: "vision_vpioneer_test", DisplayName: "Vision Vpioneer Test", Testnet: true},
+	{ChainID: "666888", Name: "hela_official_runtime_testnet", DisplayName: "Hela Official Runtime Testnet", Testnet: true},
+	{ChainID: "686868", Name: "won", DisplayName: "Won"},
+	{ChainID: "696969", Name: "galadriel_devnet", DisplayName: "Galadriel Devnet", Testnet: true},
+	{ChainID: "710420", Name: "tiltyard_subnet", DisplayName: "Tiltyard Subnet"},
+	{ChainID: "713715", Name: "sei_devnet", DisplayName: "Sei Devnet", Testnet: true},
+	{ChainID: "721529", Name: "eram", DisplayName: "Eram"},
+	{ChainID: "723107", Name: "tix_testnet", DisplayName: "Tix Testnet", Testnet: true},
+	{ChainID: "743111", Name: "hemi_sepolia", DisplayName: "Hemi Sepolia", Testnet: true},
+	{ChainID: "751230", Name: "bear_testnet", DisplayName: "Bear Testnet", Testnet: true},
+	{ChainID: "752024", Name: "ternoa_testnet", DisplayName: "Ternoa Testnet", Testnet: true},
+	{ChainID: "761412", Name: "miexs_smart", DisplayName: "Miexs Smart"},
+	{ChainID: "764984", Name: "lamina1_testnet", DisplayName: "Lamina1 Testnet", Testnet: true},
+	{ChainID: "767368", Name: "lamina1_identity_testnet", DisplayName: "Lamina1 Identity Testnet", Testnet: true},
+	{ChainID: "776877", Name: "modularium", DisplayName: "Modularium"},
+	{ChainID: "800001", Name: "octaspace", DisplayName: "Octaspace"},
+	{ChainID: "808080", Name: "biz_testnet", DisplayName: "Biz Testnet", Testnet: true},
+	{ChainID: "808813", Name: "bob_sepolia", DisplayName: "Bob Sepolia", Testnet: true},
+	{ChainID: "810180", Name: "zklink_nova", DisplayName: "Zklink Nova"},
+	{ChainID: "810181", Name: "zklink_nova_sepolia_testnet", DisplayName: "Zklink Nova Sepolia Testnet", Testnet: true},
+	{ChainID: "810182", Name: "zklink_nova_goerli_testnet", DisplayName: "Zklink Nova Goerli Testnet", Testnet: true},
+	{ChainID: "812397", Name: "sg_verse", DisplayName: "Sg Verse"},
+	{ChainID: "820522", Name: "tsc_testnet", DisplayName: "Tsc Testnet", Testnet: true},
+	{ChainID: "827431", Name: "curve", DisplayName: "Curve"},
+	{ChainID: "839320", Name: "prm_testnet", DisplayName: "Prm Testnet", Testnet: true},
+	{ChainID: "840000", Name: "runevm_testnet", DisplayName: "Runevm Testnet", Testnet: true},
+	{ChainID: "846000", Name: "4goodnetwork", DisplayName: "4goodnetwork"},
+	{ChainID: "855456", Name: "dodao", DisplayName: "Dodao"},
+	{ChainID: "879151", Name: "blocx", DisplayName: "Blocx"},
+	{ChainID: "888882", Name: "rexx", DisplayName: "Rexx"},
+	{ChainID: "888888", Name: "vision", DisplayName: "Vision"},
+	{ChainID: "900000", Name: "posi_shard_0", DisplayName: "Posi Shard 0"},
+	{ChainID: "910000", Name: "posi_testnet_shard_0", DisplayName: "Posi Testnet Shard 0", Testnet: true},
+	{ChainID: "912559", Name: "astria_evm_dusknet", DisplayName: "Astria Evm Dusknet"},
+	{ChainID: "920000", Name: "posi_devnet_shard_0", DisplayName: "Posi Devnet Shard 0", Testnet: true},
+	{ChainID: "920001", Name: "posi_devnet_shard_1", DisplayName: "Posi Devnet Shard 1", Testnet: true},
+	{ChainID: "923018", Name: "fncy_testnet", DisplayName: "Fncy Testnet", Testnet: true},
+	{ChainID: "955081", Name: "jono12_subnet", DisplayName: "Jono12 Subnet"},
+	{ChainID: "955305", Name: "eluvio_content_fabric", DisplayName: "Eluvio Content Fabric"},
+	{ChainID: "978657", Name: "treasure_ruby", DisplayName: "Treasure Ruby"},
+	{ChainID: "984122", Name: "forma", DisplayName: "Forma"},
+	{ChainID: "984123", Name: "forma_sketchpad", DisplayName: "Forma Sketchpad"},
+	{ChainID: "988207", Name: "ecrox", DisplayName: "Ecrox"},
+	{ChainID: "998899", Name: "supernet_testnet", DisplayName: "Supernet Testnet", Testnet: true},
+	{ChainID: "999999", Name: "am", DisplayName: "Am"},
+	{ChainID: "1100789", Name: "netmind_testnet", DisplayName: "Netmind Testnet", Testnet: true},
+	{ChainID: "1234567", Name: "sharecle", DisplayName: "Sharecle"},
+	{ChainID: "1261120", Name: "zkatana", DisplayName: "Zkatana"},
+	{ChainID: "1313114", Name: "etho_protocol", DisplayName: "Etho Protocol"},
+	{ChainID: "1313500", Name: "xerom", DisplayName: "Xerom"},
+	{ChainID: "1337702", Name: "kintsugi", DisplayName: "Kintsugi"},
+	{ChainID: "1337802", Name: "kiln", DisplayName: "Kiln"},
+	{ChainID: "1337803", Name: "zhejiang", DisplayName: "Zhejiang"},
+	{ChainID: "1398243", Name: "automata_testnet", DisplayName: "Automata Testnet", Testnet: true},
+	{ChainID: "1398244", Name: "automata_orbit_testnet", DisplayName: "Automata Orbit Testnet", Testnet: true},
+	{ChainID: "1612127", Name: "playfi_albireo_testnet", DisplayName: "Playfi Albireo Testnet", Testnet: true},
+	{ChainID: "1637450", Name: "xterio_testnet", DisplayName: "Xterio Testnet", Testnet: true},
+	{ChainID: "1731313", Name: "turkey_demo_dev", DisplayName: "Turkey Demo Dev"},
+	{ChainID: "2021398", Name: "debank_testnet", DisplayName: "Debank Testnet", Testnet: true},
+	{ChainID: "2099156", Name: "plian_main", DisplayName: "Plian Main"},
+	{ChainID: "2206132", Name: "platon_dev_testnet2", DisplayName: "Platon Dev Testnet2", Testnet: true},
+	{ChainID: "2611555", Name: "dpu", DisplayName: "Dpu"},
+	{ChainID: "2702128", Name: "xterio", DisplayName: "Xterio"},
+	{ChainID: "3132023", Name: "saharaai", DisplayName: "Saharaai"},
+	{ChainID: "3141592", Name: "filecoin_butterfly_testnet", DisplayName: "Filecoin Butterfly Testnet", Testnet: true},
+	{ChainID: "3397901", Name: "funki_sepolia_sandbox", DisplayName: "Funki Sepolia Sandbox", Testnet: true},
+	{ChainID: "3441005", Name: "manta_pacific_testnet", DisplayName: "Manta Pacific Testnet", Testnet: true},
+	{ChainID: "3441006", Name: "manta_pacific_sepolia_testnet", DisplayName: "Manta Pacific Sepolia Testnet", Testnet: true},
+	{ChainID: "4000003", Name: "altlayer_zero_gas", DisplayName: "Altlayer Zero Gas"},
+	{ChainID: "4281033", Name: "worlds_caldera", DisplayName: "Worlds Caldera"},
+	{ChainID: "4444444", Name: "altar_testnet", DisplayName: "Altar Testnet", Testnet: true},
+	{ChainID: "4457845", Name: "zero_testnet_sepolia", DisplayName: "Zero Testnet Sepolia", Testnet: true},
+	{ChainID: "5112023", Name: "numblock", DisplayName: "Numblock"},
+	{ChainID: "5167003", Name: "mxc_wannsee_zkevm_testnet", DisplayName: "Mxc Wannsee Zkevm Testnet", Testnet: true},
+	{ChainID: "5167004", Name: "moon_geneva_testnet", DisplayName: "Moon Geneva Testnet", Testnet: true},
+	{ChainID: "5201420", Name: "electroneum_testnet", DisplayName: "Electroneum Testnet", Testnet: true},
+	{ChainID: "5318008", Name: "reactive_kopli", DisplayName: "Reactive Kopli"},
+	{ChainID: "5555555", Name: "imversed", DisplayName: "Imversed"},
+	{ChainID: "5555558", Name: "imversed_testnet", DisplayName: "Imversed Testnet", Testnet: true},
+	{ChainID: "6038361", Name: "astar_zkyoto", DisplayName: "Astar Zkyoto"},
+	{ChainID: "6666665", Name: "safeanwang", DisplayName: "Safeanwang"},
+	{ChainID: "6666666", Name: "safeanwang_testnet", DisplayName: "Safeanwang Testnet", Testnet: true},
+	{ChainID: "7082400", Name: "coti_testnet", DisplayName: "Coti Testnet", Testnet: true},
+	{ChainID: "7225878", Name: "saakuru", DisplayName: "Saakuru"},
+	{ChainID: "7355310", Name: "openvessel", DisplayName: "Openvessel"},
+	{ChainID: "7668378", Name: "ql1_testnet", DisplayName: "Ql1 Testnet", Testnet: true},
+	{ChainID: "7762959", Name: "musicoin", DisplayName: "Musicoin"},
+	{ChainID: "7777777", Name: "zora", DisplayName: "Zora", Parent: "1", Currency: "ETH", Explorer: "https://explorer.zora.energy"},
+	{ChainID: "8007736", Name: "plian_sub_1", DisplayName: "Plian Sub 1"},
+	{ChainID: "8008135", Name: "fhenix_helium", DisplayName: "Fhenix Helium"},
+	{ChainID: "8080808", Name: "hokum", DisplayName: "Hokum"},
+	{ChainID: "8601152", Name: "waterfall_8_test", DisplayName: "Waterfall 8 Test", Testnet: true},
+	{ChainID: "8794598", Name: "hap", DisplayName: "Hap"},
+	{ChainID: "8888881", Name: "quarix_testnet", DisplayName: "Quarix Testnet", Testnet: true},
+	{ChainID: "8888888", Name: "quarix", DisplayName: "Quarix"},
+	{ChainID: "9322252", Name: "xcap", DisplayName: "Xcap"},
+	{ChainID: "9322253", Name: "milvine", DisplayName: "Milvine"},
+	{ChainID: "9999999", Name: "fluence", DisplayName: "Fluence"},
+	{ChainID: "10067275", Name: "plian_testnet_sub_1", DisplayName: "Plian Testnet Sub 1", Testnet: true},
+	{ChainID: "10101010", Name: "soverun", DisplayName: "Soverun"},
+	{ChainID: "10241024", Name: "alienx", DisplayName: "Alienx"},
+	{ChainID: "10241025", Name: "alienx_hal_testnet", DisplayName: "Alienx Hal Testnet", Testnet: true},
+	{ChainID: "11145513", Name: "blessnet_sepolia", DisplayName: "Blessnet Sepolia", Testnet: true},
+	{ChainID: "11155111", Name: "sepolia", DisplayName: "Sepolia", Aliases: []string{"eth_sepolia", "ethereum_sepolia"}, Testnet: true, Currency: "ETH", Explorer: "https://sepolia.etherscan.io"},
+	{ChainID: "11155420", Name: "op_sepolia_testnet", DisplayName: "OP Sepolia Testnet", Aliases: []string{"op_sepolia", "optimism_sepolia"}, Testnet: true, Parent: "11155111", Currency: "ETH", Explorer: "https://sepolia-optimism.etherscan.io"},
+	{ChainID: "12052024", Name: "memento_testnet", DisplayName: "Memento Testnet", Testnet: true},
+	{ChainID: "12227331", Name: "neox_testnet_t3", DisplayName: "Neox Testnet T3", Testnet: true},
+	{ChainID: "12227332", Name: "neo_x_testnet_t4", DisplayName: "Neo X Testnet T4", Testnet: true},
+	{ChainID: "13068200", Name: "coti_devnet", DisplayName: "Coti Devnet", Testnet: true},
+	{ChainID: "13371337", Name: "pep_churchill", DisplayName: "Pep Churchill"},
+	{ChainID: "14288640", Name: "andus", DisplayName: "Andus"},
+	{ChainID: "16658437", Name: "plian_testnet_main", DisplayName: "Plian Testnet Main", Testnet: true},
+	{ChainID: "18071918", Name: "mande", DisplayName: "Mande"},
+	{ChainID: "18289463", Name: "iolite", DisplayName: "Iolite"},
+	{ChainID: "19850818", Name: "deepbrain_testnet", DisplayName: "Deepbrain Testnet", Testnet: true},
+	{ChainID: "19880818", Name: "deepbrain", DisplayName: "Deepbrain"},
+	{ChainID: "20180427", Name: "stability_testnet", DisplayName: "Stability Testnet", Testnet: true},
+	{ChainID: "20180430", Name: "smartmesh", DisplayName: "Smartmesh"},
+	{ChainID: "20181205", Name: "quarkblock", DisplayName: "Quarkblock"},
+	{ChainID: "20201022", Name: "pego", DisplayName: "Pego"},
+	{ChainID: "20230825", Name: "vcity_testnet", DisplayName: "Vcity Testnet", Testnet: true},
+	{ChainID: "20240324", Name: "debank_sepolia_testnet", DisplayName: "Debank Sepolia Testnet", Testnet: true},
+	{ChainID: "20240603", Name: "dbk", DisplayName: "Dbk"},
+	{ChainID: "20241133", Name: "swan_proxima_testnet", DisplayName: "Swan Proxima Testnet", Testnet: true},
+	{ChainID: "20482050", Name: "hokum_testnet", DisplayName: "Hokum Testnet", Testnet: true},
+	{ChainID: "22052002", Name: "excelon", DisplayName: "Excelon"},
+	{ChainID: "24772477", Name: "6degree_of_outreach_testnet", DisplayName: "6degree Of Outreach Testnet", Testnet: true},
+	{ChainID: "27082017", Name: "excoincial_volta_testnet", DisplayName: "Excoincial Volta Testnet", Testnet: true},
+	{ChainID: "27082022", Name: "excoincial", DisplayName: "Excoincial"},
+	{ChainID: "28122024", Name: "ancient8_testnet", DisplayName: "Ancient8 Testnet", Testnet: true},
+	{ChainID: "28945486", Name: "auxilium", DisplayName: "Auxilium"},
+	{ChainID: "29032022", Name: "fla", DisplayName: "Fla"},
+	{ChainID: "31415926", Name: "filecoin_local_testnet", DisplayName: "Filecoin Local Testnet", Testnet: true},
+	{ChainID: "33626250", Name: "toliman_suave_testnet", DisplayName: "Toliman Suave Testnet", Testnet: true},
+	{ChainID: "35855456", Name: "joys_digital", DisplayName: "Joys Digital"},
+	{ChainID: "37084624", Name: "skale_nebula_hub_testnet", DisplayName: "Skale Nebula Hub Testnet", Testnet: true},
+	{ChainID: "39916801", Name: "kingdom", DisplayName: "Kingdom"},
+	{ChainID: "43214913", Name: "maistestsubnet", DisplayName: "Maistestsubnet", Testnet: true},
+	{ChainID: "52164803", Name: "fluence_testnet", DisplayName: "Fluence Testnet", Testnet: true},
+	{ChainID: "61717561", Name: "aqua", DisplayName: "Aqua"},
+	{ChainID: "65010000", Name: "autonity_bakerloo_thames_testnet", DisplayName: "Autonity Bakerloo Thames Testnet", Testnet: true},
+	{ChainID: "65010001", Name: "autonity_bakerloo_barada_testnet", DisplayName: "Autonity Bakerloo Barada Testnet", Testnet: true},
+	{ChainID: "65010002", Name: "autonity_bakerloo_sumida_testnet", DisplayName: "Autonity Bakerloo Sumida Testnet", Testnet: true},
+	{ChainID: "65010003", Name: "autonity_bakerloo_yamuna_testnet", DisplayName: "Autonity Bakerloo Yamuna Testnet", Testnet: true},
+	{ChainID: "65100000", Name: "autonity_piccadilly_thames_testnet", DisplayName: "Autonity Piccadilly Thames Testnet", Testnet: true},
+	{ChainID: "65100001", Name: "autonity_piccadilly_barada_testnet", DisplayName: "Autonity Piccadilly Barada Testnet", Testnet: true},
+	{ChainID: "65100002", Name: "autonity_piccadilly_sumida_testnet", DisplayName: "Autonity Piccadilly Sumida Testnet", Testnet: true},
+	{ChainID: "65100003", Name: "autonity_piccadilly_yamuna_testnet", DisplayName: "Autonity Piccadilly Yamuna Testnet", Testnet: true},
+	{ChainID: "68840142", Name: "frame_testnet", DisplayName: "Frame Testnet", Testnet: true},
+	{ChainID: "77787778", Name: "0xhash_testnet", DisplayName: "0xhash Testnet", Testnet: true},
+	{ChainID: "79479957", Name: "sx_toronto_rollup", DisplayName: "Sx Toronto Rollup"},
+	{ChainID: "88558801", Name: "backstop_testnet", DisplayName: "Backstop Testnet", Testnet: true},
+	{ChainID: "88888888", Name: "t.e.a.m_block", DisplayName: "T.e.a.m Block"},
+	{ChainID: "94204209", Name: "polygon_blackberry", DisplayName: "Polygon Blackberry"},
+	{ChainID: "99415706", Name: "joys_digital_testnet", DisplayName: "Joys Digital Testnet", Testnet: true},
+	{ChainID: "100000000", Name: "ethos", DisplayName: "Ethos"},
+	{ChainID: "108160679", Name: "orai", DisplayName: "Orai"},
+	{ChainID: "111557560", Name: "cyber_testnet", DisplayName: "Cyber Testnet", Testnet: true},
+	{ChainID: "123420111", Name: "op_celestia_raspberry", DisplayName: "Op Celestia Raspberry"},
+	{ChainID: "161221135", Name: "plume_testnet", DisplayName: "Plume Testnet", Testnet: true},
+	{ChainID: "168587773", Name: "blast_sepolia_testnet", DisplayName: "Blast Sepolia Testnet", Testnet: true},
+	{ChainID: "192837465", Name: "gather", DisplayName: "Gather"},
+	{ChainID: "222000222", Name: "kanazawa", DisplayName: "Kanazawa"},
+	{ChainID: "245022926", Name: "neon_evm_devnet", DisplayName: "Neon Evm Devnet", Testnet: true},
+	{ChainID: "245022929", Name: "neon_evm_devnet_rollup", DisplayName: "Neon Evm Devnet Rollup", Testnet: true},
+	{ChainID: "245022934", Name: "neon_evm", DisplayName: "Neon Evm"},
+	{ChainID: "245022940", Name: "neon_evm_testnet", DisplayName: "Neon Evm Testnet", Testnet: true},
+	{ChainID: "278611351", Name: "razor_skale", DisplayName: "Razor Skale"},
+	{ChainID: "311752642", Name: "oneledger", DisplayName: "Oneledger"},
+	{ChainID: "328527624", Name: "nal_sepolia_testnet", DisplayName: "Nal Sepolia Testnet", Testnet: true},
+	{ChainID: "333000333", Name: "meld", DisplayName: "Meld"},
+	{ChainID: "356256156", Name: "gather_testnet", DisplayName: "Gather Testnet", Testnet: true},
+	{ChainID: "476462898", Name: "skopje_testnet", DisplayName: "Skopje Testnet", Testnet: true},
+	{ChainID: "486217935", Name: "gather_devnet", DisplayName: "Gather Devnet", Testnet: true},
+	{ChainID: "531050104", Name: "sophon_testnet", DisplayName: "Sophon Testnet", Testnet: true},
+	{ChainID: "666666666", Name: "degen", DisplayName: "Degen"},
+	{ChainID: "728126428", Name: "tron", DisplayName: "Tron"},
+	{ChainID: "888888888", Name: "ancient8", DisplayName: "Ancient8"},
+	{ChainID: "889910245", Name: "ptcescan_testnet", DisplayName: "Ptcescan Testnet", Testnet: true},
+	{ChainID: "889910246", Name: "ptcescan", DisplayName: "Ptcescan"},
+	{ChainID: "974399131", Name: "skale_calypso_hub_testnet", DisplayName: "Skale Calypso Hub Testnet", Testnet: true},
+	{ChainID: "999999999", Name: "zora_sepolia_testnet", DisplayName: "Zora Sepolia Testnet", Testnet: true},
+	{ChainID: "1020352220", Name: "skale_titan_hub_testnet", DisplayName: "Skale Titan Hub Testnet", Testnet: true},
+	{ChainID: "1122334455", Name: "ipos", DisplayName: "Ipos"},
+	{ChainID: "1146703430", Name: "cyberdecknet", DisplayName: "Cyberdecknet"},
+	{ChainID: "1273227453", Name: "human_protocol", DisplayName: "Human Protocol"},
+	{ChainID: "1313161554", Name: "aurora", DisplayName: "Aurora"},
+	{ChainID: "1313161555", Name: "aurora_testnet", DisplayName: "Aurora Testnet", Testnet: true},
+	{ChainID: "1313161556", Name: "aurora_betanet", DisplayName: "Aurora Betanet"},
+	{ChainID: "1313161560", Name: "powergold", DisplayName: "Powergold"},
+	{ChainID: "1350216234", Name: "skale_titan_hub", DisplayName: "Skale Titan Hub"},
+	{ChainID: "1351057110", Name: "chaos_skale_testnet", DisplayName: "Chaos Skale Testnet", Testnet: true},
+	{ChainID: "1380012617", Name: "rari", DisplayName: "Rari"},
+	{ChainID: "1380996178", Name: "raptor", DisplayName: "Raptor"},
+	{ChainID: "1444673419", Name: "skale_europa_hub_testnet", DisplayName: "Skale Europa Hub Testnet", Testnet: true},
+	{ChainID: "1482601649", Name: "skale_nebula_hub", DisplayName: "Skale Nebula Hub"},
+	{ChainID: "1511670449", Name: "gpt", DisplayName: "Gpt"},
+	{ChainID: "1564830818", Name: "skale_calypso_hub", DisplayName: "Skale Calypso Hub"},
+	{ChainID: "1666600000", Name: "harmony_shard_0", DisplayName: "Harmony Shard 0"},
+	{ChainID: "1666600001", Name: "harmony_shard_1", DisplayName: "Harmony Shard 1"},
+	{ChainID: "1666600002", Name: "harmony_shard_2", DisplayName: "Harmony Shard 2"},
+	{ChainID: "1666600003", Name: "harmony_shard_3", DisplayName: "Harmony Shard 3"},
+	{ChainID: "1666700000", Name: "harmony_testnet_shard_0", DisplayName: "Harmony Testnet Shard 0", Testnet: true},
+	{ChainID: "1666700001", Name: "harmony_testnet_shard_1", DisplayName: "Harmony Testnet Shard 1", Testnet: true},
+	{ChainID: "1666900000", Name: "harmony_devnet_shard_0", DisplayName: "Harmony Devnet Shard 0", Testnet: true},
+	{ChainID: "1666900001", Name: "harmony_devnet_shard_1", DisplayName: "Harmony Devnet Shard 1", Testnet: true},
+	{ChainID: "1722641160", Name: "silicon_zkevm_sepolia_testnet", DisplayName: "Silicon Zkevm Sepolia Testnet", Testnet: true},
+	{ChainID: "1802203764", Name: "kakarot_sepolia", DisplayName: "Kakarot Sepolia", Testnet: true},
+	{ChainID: "1903648807", Name: "gemu_testnet", DisplayName: "Gemu Testnet", Testnet: true},
+	{ChainID: "1918988905", Name: "rari_testnet", DisplayName: "Rari Testnet", Testnet: true},
+	{ChainID: "2021121117", Name: "datahopper", DisplayName: "Datahopper"},
+	{ChainID: "2046399126", Name: "skale_europa_hub", DisplayName: "Skale Europa Hub"},
+	{ChainID: "3125659152", Name: "pirl", DisplayName: "Pirl"},
+	{ChainID: "4216137055", Name: "oneledger_testnet_frankenstein", DisplayName: "Oneledger Testnet Frankenstein", Testnet: true},
+	{ChainID: "11297108099", Name: "palm_testnet", DisplayName: "Palm Testnet", Testnet: true},
+	{ChainID: "28872323069", Name: "gitswarm_test", DisplayName: "Gitswarm Test", Testnet: true},
+	{ChainID: "37714555429", Name: "xai_testnet_v2", DisplayName: "Xai Testnet V2", Testnet: true},
+	{ChainID: "88153591557", Name: "arbitrum_blueberry", DisplayName: "Arbitrum Blueberry"},
+	{ChainID: "111222333444", Name: "alphabet", DisplayName: "Alphabet"},
+	{ChainID: "111551119090", Name: "thanos_sepolia", DisplayName: "Thanos Sepolia", Testnet: true},
+	{ChainID: "123420000220", Name: "fluence_stage", DisplayName: "Fluence Stage"},
+	{ChainID: "197710212030", Name: "ntity", DisplayName: "Ntity"},
+	{ChainID: "197710212031", Name: "haradev_testnet", DisplayName: "Haradev Testnet", Testnet: true},
+	{ChainID: "202402181627", Name: "gm_testnet", DisplayName: "Gm Testnet", Testnet: true},
+	{ChainID: "383414847825", Name: "zeniq", DisplayName: "Zeniq"},
+	{ChainID: "666301171999", Name: "pdc", DisplayName: "Pdc"},
+	{ChainID: "6022140761023", Name: "molereum", DisplayName: "Molereum"},
+	{ChainID: "2713017997578000", Name: "d_testnet", DisplayName: "D Testnet", Testnet: true},
+}
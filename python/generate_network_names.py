@@ -7,12 +7,15 @@ Name data is sourced from https://chainid.network
 This script creates the following files in the MESC repository:
 - ./python/mesc/network_names.py
 - ./rust/crates/mesc/src/network_names.rs
+- ./typescript/src/directory.ts
+- ./go/pkg/mesc/model/network_directory.go
 """
 
 from __future__ import annotations
 
+import json
 import os
-from typing import Mapping, MutableMapping
+from typing import Any, Mapping, MutableMapping, Sequence, TypedDict
 
 import requests
 
@@ -30,12 +33,224 @@ special_cases: Mapping[str, str] = {
 }
 
 
-def get_network_names() -> Mapping[str, str]:
-    # fetch raw network data
+# curated details of well-known networks, taking precedence over chainid.network data
+network_details: Mapping[str, Mapping[str, Any]] = {
+    '1': {
+        'display_name': 'Ethereum Mainnet',
+        'aliases': ['mainnet', 'eth', 'ethereum_mainnet'],
+        'currency': 'ETH',
+        'explorer': 'https://etherscan.io',
+    },
+    '5': {
+        'display_name': 'Goerli',
+        'aliases': ['eth_goerli'],
+        'currency': 'ETH',
+        'explorer': 'https://goerli.etherscan.io',
+    },
+    '10': {
+        'display_name': 'OP Mainnet',
+        'aliases': ['op', 'op_mainnet', 'optimism_mainnet'],
+        'parent': '1',
+        'currency': 'ETH',
+        'explorer': 'https://optimistic.etherscan.io',
+    },
+    '56': {
+        'display_name': 'BNB Smart Chain Mainnet',
+        'aliases': ['bnb', 'bnb_smart_chain'],
+        'currency': 'BNB',
+        'explorer': 'https://bscscan.com',
+    },
+    '97': {
+        'display_name': 'BNB Smart Chain Testnet',
+        'aliases': ['bsc_testnet'],
+        'currency': 'tBNB',
+        'explorer': 'https://testnet.bscscan.com',
+    },
+    '100': {
+        'display_name': 'Gnosis',
+        'aliases': ['xdai'],
+        'currency': 'XDAI',
+        'explorer': 'https://gnosisscan.io',
+    },
+    '137': {
+        'display_name': 'Polygon Mainnet',
+        'aliases': ['matic', 'polygon_pos'],
+        'currency': 'POL',
+        'explorer': 'https://polygonscan.com',
+    },
+    '250': {
+        'display_name': 'Fantom Opera',
+        'aliases': ['fantom', 'ftm'],
+        'currency': 'FTM',
+        'explorer': 'https://ftmscan.com',
+    },
+    '300': {
+        'display_name': 'zkSync Sepolia Testnet',
+        'aliases': ['zksync_sepolia'],
+        'parent': '11155111',
+        'currency': 'ETH',
+        'explorer': 'https://sepolia.explorer.zksync.io',
+    },
+    '324': {
+        'display_name': 'zkSync Mainnet',
+        'aliases': ['zksync_era', 'era'],
+        'parent': '1',
+        'currency': 'ETH',
+        'explorer': 'https://explorer.zksync.io',
+    },
+    '1101': {
+        'display_name': 'Polygon zkEVM',
+        'parent': '1',
+        'currency': 'ETH',
+        'explorer': 'https://zkevm.polygonscan.com',
+    },
+    '5000': {
+        'display_name': 'Mantle',
+        'parent': '1',
+        'currency': 'MNT',
+        'explorer': 'https://explorer.mantle.xyz',
+    },
+    '8453': {
+        'display_name': 'Base',
+        'aliases': ['base_mainnet'],
+        'parent': '1',
+        'currency': 'ETH',
+        'explorer': 'https://basescan.org',
+    },
+    '17000': {
+        'display_name': 'Holesky',
+        'aliases': ['eth_holesky'],
+        'currency': 'ETH',
+        'explorer': 'https://holesky.etherscan.io',
+    },
+    '42161': {
+        'display_name': 'Arbitrum One',
+        'aliases': ['arb', 'arb1', 'arbitrum_one'],
+        'parent': '1',
+        'currency': 'ETH',
+        'explorer': 'https://arbiscan.io',
+    },
+    '42170': {
+        'display_name': 'Arbitrum Nova',
+        'parent': '1',
+        'currency': 'ETH',
+        'explorer': 'https://nova.arbiscan.io',
+    },
+    '43113': {
+        'display_name': 'Avalanche Fuji Testnet',
+        'aliases': ['fuji'],
+        'currency': 'AVAX',
+        'explorer': 'https://testnet.snowtrace.io',
+    },
+    '43114': {
+        'display_name': 'Avalanche C-Chain',
+        'aliases': ['avax', 'avalanche_c'],
+        'currency': 'AVAX',
+        'explorer': 'https://snowtrace.io',
+    },
+    '59141': {
+        'display_name': 'Linea Sepolia',
+        'parent': '11155111',
+        'currency': 'ETH',
+        'explorer': 'https://sepolia.lineascan.build',
+    },
+    '59144': {
+        'display_name': 'Linea',
+        'aliases': ['linea_mainnet'],
+        'parent': '1',
+        'currency': 'ETH',
+        'explorer': 'https://lineascan.build',
+    },
+    '80002': {
+        'display_name': 'Amoy',
+        'aliases': ['polygon_amoy'],
+        'currency': 'POL',
+        'explorer': 'https://amoy.polygonscan.com',
+    },
+    '84532': {
+        'display_name': 'Base Sepolia Testnet',
+        'aliases': ['base_sepolia'],
+        'parent': '11155111',
+        'currency': 'ETH',
+        'explorer': 'https://sepolia.basescan.org',
+    },
+    '421614': {
+        'display_name': 'Arbitrum Sepolia',
+        'aliases': ['arb_sepolia'],
+        'parent': '11155111',
+        'currency': 'ETH',
+        'explorer': 'https://sepolia.arbiscan.io',
+    },
+    '534351': {
+        'display_name': 'Scroll Sepolia Testnet',
+        'aliases': ['scroll_sepolia'],
+        'parent': '11155111',
+        'currency': 'ETH',
+        'explorer': 'https://sepolia.scrollscan.com',
+    },
+    '534352': {
+        'display_name': 'Scroll',
+        'aliases': ['scroll_mainnet'],
+        'parent': '1',
+        'currency': 'ETH',
+        'explorer': 'https://scrollscan.com',
+    },
+    '7777777': {
+        'display_name': 'Zora',
+        'parent': '1',
+        'currency': 'ETH',
+        'explorer': 'https://explorer.zora.energy',
+    },
+    '11155111': {
+        'display_name': 'Sepolia',
+        'aliases': ['eth_sepolia', 'ethereum_sepolia'],
+        'currency': 'ETH',
+        'explorer': 'https://sepolia.etherscan.io',
+    },
+    '11155420': {
+        'display_name': 'OP Sepolia Testnet',
+        'aliases': ['op_sepolia', 'optimism_sepolia'],
+        'parent': '11155111',
+        'currency': 'ETH',
+        'explorer': 'https://sepolia-optimism.etherscan.io',
+    },
+}
+
+# words in network names that indicate a testnet
+testnet_keywords: Sequence[str] = [
+    'test',
+    'devnet',
+    'sepolia',
+    'goerli',
+    'holesky',
+    'ropsten',
+    'rinkeby',
+    'kovan',
+    'fuji',
+    'amoy',
+    'mumbai',
+    'chiado',
+]
+
+
+class NetworkInfo(TypedDict):
+    chain_id: str
+    name: str
+    display_name: str
+    aliases: Sequence[str]
+    testnet: bool
+    parent: str | None
+    currency: str | None
+    explorer: str | None
+
+
+def get_network_data() -> Sequence[Mapping[str, Any]]:
     url = 'https://chainid.network/chains.json'
     result = requests.get(url)
-    data = result.json()
+    return result.json()  # type: ignore
 
+
+def get_network_names(data: Sequence[Mapping[str, Any]]) -> Mapping[str, str]:
     network_names: MutableMapping[str, str] = {}
     for datum in data:
         # standardize name
@@ -52,6 +267,40 @@ def get_network_names() -> Mapping[str, str]:
     return network_names
 
 
+def get_networks(
+    network_names: Mapping[str, str],
+    data: Sequence[Mapping[str, Any]],
+) -> Sequence[NetworkInfo]:
+    data_by_chain_id = {str(datum['chainId']): datum for datum in data}
+    networks = []
+    for chain_id, name in network_names.items():
+        datum = data_by_chain_id.get(chain_id, {})
+        explorers = datum.get('explorers') or [{}]
+        parent = datum.get('parent', {}).get('chain')
+        network: NetworkInfo = {
+            'chain_id': chain_id,
+            'name': name,
+            'display_name': datum.get('name') or default_display_name(name),
+            'aliases': [],
+            'testnet': is_testnet(datum.get('name', name)),
+            'parent': parent.split('-')[-1] if parent is not None else None,
+            'currency': datum.get('nativeCurrency', {}).get('symbol'),
+            'explorer': explorers[0].get('url'),
+        }
+        network.update(network_details.get(chain_id, {}))  # type: ignore
+        networks.append(network)
+    return networks
+
+
+def default_display_name(name: str) -> str:
+    return ' '.join(word.capitalize() for word in name.split('_'))
+
+
+def is_testnet(name: str) -> bool:
+    name = name.lower()
+    return any(keyword in name for keyword in testnet_keywords)
+
+
 def standardize_name(name: str) -> str:
     """put name into standard format"""
 
@@ -109,58 +358,210 @@ Do not edit this file manually."""
 python_path = './mesc/network_names.py'
 python_template = '''"""{preamble}"""
 
+from __future__ import annotations
+
+import typing
+
+if typing.TYPE_CHECKING:
+    from .types import NetworkInfo
+
 network_names = {name_data}
+
+network_directory: list[NetworkInfo] = {network_data}
 '''
 
 
-def generate_python_content(network_names: Mapping[str, str]) -> str:
+def generate_python_content(
+    network_names: Mapping[str, str], networks: Sequence[NetworkInfo]
+) -> str:
     name_data = '{'
     for chain_id, network_name in network_names.items():
         name_data += "\n    '" + chain_id + "': '" + network_name + "',"
     name_data += '\n}'
-    return python_template.format(preamble=preamble, name_data=name_data)
+    network_data = '['
+    for network in networks:
+        network_data += '\n    ' + repr(dict(network)) + ','
+    network_data += '\n]'
+    return python_template.format(
+        preamble=preamble, name_data=name_data, network_data=network_data
+    )
 
 
 # rust data
 rust_path = '../rust/crates/mesc/src/network_names.rs'
 rust_template = """{preamble}
 
-use crate::ChainId;
+use crate::{{directory::NetworkInfo, ChainId}};
 use std::{{collections::HashMap, sync::OnceLock}};
 
 /// get default mapping between chain_id's and network names
 pub fn get_network_names() -> &'static HashMap<ChainId, &'static str> {{
     static CACHE: OnceLock<HashMap<ChainId, &'static str>> = OnceLock::new();
-    CACHE.get_or_init(|| NETWORKS.iter().map(|&(chain_id, name)| (chain_id.into(), name)).collect())
+    CACHE.get_or_init(|| {{
+        NETWORKS.iter().map(|network| (network.chain_id.into(), network.name)).collect()
+    }})
 }}
 
-pub(crate) const NETWORKS: &[(u64, &str)] = &{name_data};
+#[rustfmt::skip]
+pub(crate) const NETWORKS: &[NetworkInfo] = &{network_data};
 """
 
 
-def generate_rust_content(network_names: Mapping[str, str]) -> str:
+def generate_rust_content(networks: Sequence[NetworkInfo]) -> str:
     rust_preamble = '\n'.join(('//! ' + line).strip() for line in preamble.split('\n'))
-    name_data = '['
-    for chain_id, network_name in network_names.items():
-        name_data += '\n    (' + chain_id + ', "' + network_name + '"),'
-    name_data += '\n]'
-    return rust_template.format(preamble=rust_preamble, name_data=name_data)
+    network_data = '['
+    for network in networks:
+        network_data += '\n    NetworkInfo::new({}, {}, {})'.format(
+            network['chain_id'],
+            rust_string(network['name']),
+            rust_string(network['display_name']),
+        )
+        if network['aliases']:
+            aliases = ', '.join(rust_string(alias) for alias in network['aliases'])
+            network_data += '.aliases(&[' + aliases + '])'
+        if network['testnet']:
+            network_data += '.testnet()'
+        if network['parent'] is not None:
+            network_data += '.parent(' + network['parent'] + ')'
+        if network['currency'] is not None:
+            network_data += '.currency(' + rust_string(network['currency']) + ')'
+        if network['explorer'] is not None:
+            network_data += '.explorer(' + rust_string(network['explorer']) + ')'
+        network_data += ','
+    network_data += '\n]'
+    return rust_template.format(preamble=rust_preamble, network_data=network_data)
+
+
+def rust_string(value: str) -> str:
+    return '"' + value.replace('\\', '\\\\').replace('"', '\\"') + '"'
+
+
+# typescript data
+typescript_path = '../typescript/src/directory.ts'
+typescript_template = """{preamble}
+
+import type {{ ChainId }} from '#/schemas/rpc-config.ts'
+
+export interface NetworkInfo {{
+  chain_id: ChainId
+  name: string
+  display_name: string
+  aliases: string[]
+  testnet: boolean
+  parent?: ChainId
+  currency?: string
+  explorer?: string
+}}
+
+export const networkDirectory: NetworkInfo[] = {network_data}
+"""
+
+
+def generate_typescript_content(networks: Sequence[NetworkInfo]) -> str:
+    typescript_preamble = '\n'.join(('// ' + line).strip() for line in preamble.split('\n'))
+    network_data = '['
+    for network in networks:
+        fields = [
+            'chain_id: ' + typescript_string(network['chain_id']),
+            'name: ' + typescript_string(network['name']),
+            'display_name: ' + typescript_string(network['display_name']),
+            'aliases: [' + ', '.join(typescript_string(alias) for alias in network['aliases']) + ']',
+            'testnet: ' + ('true' if network['testnet'] else 'false'),
+        ]
+        for key in ['parent', 'currency', 'explorer']:
+            if network[key] is not None:  # type: ignore
+                fields.append(key + ': ' + typescript_string(network[key]))  # type: ignore
+        network_data += '\n  { ' + ', '.join(fields) + ' },'
+    network_data = network_data.rstrip(',') + '\n]'
+    return typescript_template.format(preamble=typescript_preamble, network_data=network_data)
+
+
+def typescript_string(value: str) -> str:
+    return "'" + value.replace('\\', '\\\\').replace("'", "\\'") + "'"
+
+
+# go data
+go_path = '../go/pkg/mesc/model/network_directory.go'
+go_template = """// Code generated by python/generate_network_names.py. DO NOT EDIT.
+
+{preamble}
+
+package model
+
+// NetworkInfo describes a network in the network directory.
+type NetworkInfo struct {{
+	ChainID     ChainID
+	Name        string
+	DisplayName string
+	Aliases     []string
+	Testnet     bool
+	Parent      ChainID
+	Currency    string
+	Explorer    string
+}}
+
+// NetworkDirectory lists the networks known to every MESC implementation.
+var NetworkDirectory = []NetworkInfo{network_data}
+"""
+
+
+def generate_go_content(networks: Sequence[NetworkInfo]) -> str:
+    go_preamble = '\n'.join(('// ' + line).strip() for line in preamble.split('\n'))
+    network_data = '{'
+    for network in networks:
+        fields = [
+            'ChainID: ' + go_string(network['chain_id']),
+            'Name: ' + go_string(network['name']),
+            'DisplayName: ' + go_string(network['display_name']),
+        ]
+        if network['aliases']:
+            aliases = ', '.join(go_string(alias) for alias in network['aliases'])
+            fields.append('Aliases: []string{' + aliases + '}')
+        if network['testnet']:
+            fields.append('Testnet: true')
+        if network['parent'] is not None:
+            fields.append('Parent: ' + go_string(network['parent']))
+        if network['currency'] is not None:
+            fields.append('Currency: ' + go_string(network['currency']))
+        if network['explorer'] is not None:
+            fields.append('Explorer: ' + go_string(network['explorer']))
+        network_data += '\n\t{' + ', '.join(fields) + '},'
+    network_data += '\n}'
+    return go_template.format(preamble=go_preamble, network_data=network_data)
+
+
+def go_string(value: str) -> str:
+    return json.dumps(value, ensure_ascii=False)
 
 
 if __name__ == '__main__':
-    network_names = get_network_names()
+    data = get_network_data()
+    network_names = get_network_names(data)
+    networks = get_networks(network_names, data)
 
     # change working directory to parent of this script
     os.chdir(os.path.dirname(os.path.realpath(__file__)))
 
     # create python file
-    python_content = generate_python_content(network_names)
+    python_content = generate_python_content(network_names, networks)
     with open(python_path, 'w') as f:
         f.write(python_content)
     print('wrote', python_path)
 
     # create rust file
-    rust_content = generate_rust_content(network_names)
+    rust_content = generate_rust_content(networks)
     with open(rust_path, 'w') as f:
         f.write(rust_content)
     print('wrote', rust_path)
+
+    # create typescript file
+    typescript_content = generate_typescript_content(networks)
+    with open(typescript_path, 'w') as f:
+        f.write(typescript_content)
+    print('wrote', typescript_path)
+
+    # create go file
+    go_content = generate_go_content(networks)
+    with open(go_path, 'w') as f:
+        f.write(go_content)
+    print('wrote', go_path)
@@ -6,6 +6,13 @@ The same set of network names is used for each MESC implementation.
 
 Do not edit this file manually."""
 
+from __future__ import annotations
+
+import typing
+
+if typing.TYPE_CHECKING:
+    from .types import NetworkInfo
+
 network_names = {
     '1': 'ethereum',
     '2': 'expanse',
@@ -1850,3 +1857,1848 @@ network_names = {
     '6022140761023': 'molereum',
     '2713017997578000': 'd_testnet',
 }
+
+network_directory: list[NetworkInfo] = [
+    {'chain_id': '1', 'name': 'ethereum', 'display_name': 'Ethereum Mainnet', 'aliases': ['mainnet', 'eth', 'ethereum_mainnet'], 'testnet': False, 'parent': None, 'currency': 'ETH', 'explorer': 'https://etherscan.io'},
+    {'chain_id': '2', 'name': 'expanse', 'display_name': 'Expanse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3', 'name': 'ropsten', 'display_name': 'Ropsten', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4', 'name': 'rinkeby', 'display_name': 'Rinkeby', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5', 'name': 'goerli', 'display_name': 'Goerli', 'aliases': ['eth_goerli'], 'testnet': True, 'parent': None, 'currency': 'ETH', 'explorer': 'https://goerli.etherscan.io'},
+    {'chain_id': '6', 'name': 'kotti_testnet', 'display_name': 'Kotti Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7', 'name': 'thai', 'display_name': 'Thai', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8', 'name': 'ubiq', 'display_name': 'Ubiq', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9', 'name': 'ubiq_testnet', 'display_name': 'Ubiq Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10', 'name': 'optimism', 'display_name': 'OP Mainnet', 'aliases': ['op', 'op_mainnet', 'optimism_mainnet'], 'testnet': False, 'parent': '1', 'currency': 'ETH', 'explorer': 'https://optimistic.etherscan.io'},
+    {'chain_id': '11', 'name': 'metadium', 'display_name': 'Metadium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12', 'name': 'metadium_testnet', 'display_name': 'Metadium Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '13', 'name': 'diode_testnet_staging', 'display_name': 'Diode Testnet Staging', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '14', 'name': 'flare', 'display_name': 'Flare', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '15', 'name': 'diode_prenet', 'display_name': 'Diode Prenet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '16', 'name': 'songbird_testnet_coston', 'display_name': 'Songbird Testnet Coston', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '17', 'name': 'thaifi', 'display_name': 'Thaifi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '18', 'name': 'thundercore_testnet', 'display_name': 'Thundercore Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '19', 'name': 'songbird_canary', 'display_name': 'Songbird Canary', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20', 'name': 'elastos', 'display_name': 'Elastos', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '21', 'name': 'elastos_testnet', 'display_name': 'Elastos Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '22', 'name': 'ela_did', 'display_name': 'Ela Did', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '23', 'name': 'ela_did_testnet', 'display_name': 'Ela Did Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '24', 'name': 'kardia', 'display_name': 'Kardia', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '25', 'name': 'cronos', 'display_name': 'Cronos', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '26', 'name': 'genesis_testnet', 'display_name': 'Genesis Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '27', 'name': 'shiba', 'display_name': 'Shiba', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '28', 'name': 'boba_rinkeby_testnet', 'display_name': 'Boba Rinkeby Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '29', 'name': 'genesis', 'display_name': 'Genesis', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '30', 'name': 'rootstock', 'display_name': 'Rootstock', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '31', 'name': 'rootstock_testnet', 'display_name': 'Rootstock Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '32', 'name': 'gooddata_testnet', 'display_name': 'Gooddata Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '33', 'name': 'gooddata', 'display_name': 'Gooddata', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '34', 'name': 'secure', 'display_name': 'Secure', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '35', 'name': 'tbwg', 'display_name': 'Tbwg', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '36', 'name': 'dx', 'display_name': 'Dx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '37', 'name': 'xpla', 'display_name': 'Xpla', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '38', 'name': 'valorbit', 'display_name': 'Valorbit', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '39', 'name': 'u2u_solaris', 'display_name': 'U2u Solaris', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '40', 'name': 'telos_evm', 'display_name': 'Telos Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '41', 'name': 'telos_evm_testnet', 'display_name': 'Telos Evm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '42', 'name': 'lukso', 'display_name': 'Lukso', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '43', 'name': 'darwinia_pangolin_testnet', 'display_name': 'Darwinia Pangolin Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '44', 'name': 'crab', 'display_name': 'Crab', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '45', 'name': 'darwinia_pangoro_testnet', 'display_name': 'Darwinia Pangoro Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '46', 'name': 'darwinia', 'display_name': 'Darwinia', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '47', 'name': 'acria_intelli', 'display_name': 'Acria Intelli', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '48', 'name': 'ennothem_proterozoic', 'display_name': 'Ennothem Proterozoic', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '49', 'name': 'ennothem_testnet_pioneer', 'display_name': 'Ennothem Testnet Pioneer', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '50', 'name': 'xdc', 'display_name': 'Xdc', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '51', 'name': 'xdc_apothem', 'display_name': 'Xdc Apothem', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '52', 'name': 'coinex', 'display_name': 'Coinex', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '53', 'name': 'coinex_testnet', 'display_name': 'Coinex Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '54', 'name': 'openpiece', 'display_name': 'Openpiece', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '55', 'name': 'zyx', 'display_name': 'Zyx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '56', 'name': 'bsc', 'display_name': 'BNB Smart Chain Mainnet', 'aliases': ['bnb', 'bnb_smart_chain'], 'testnet': False, 'parent': None, 'currency': 'BNB', 'explorer': 'https://bscscan.com'},
+    {'chain_id': '57', 'name': 'syscoin', 'display_name': 'Syscoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '58', 'name': 'ontology', 'display_name': 'Ontology', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '59', 'name': 'eos_evm_legacy', 'display_name': 'Eos Evm Legacy', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '60', 'name': 'go', 'display_name': 'Go', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '61', 'name': 'ethereum_classic', 'display_name': 'Ethereum Classic', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '62', 'name': 'morden_testnet', 'display_name': 'Morden Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '63', 'name': 'mordor_testnet', 'display_name': 'Mordor Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '64', 'name': 'ellaism', 'display_name': 'Ellaism', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '65', 'name': 'okex_testnet', 'display_name': 'Okex Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '66', 'name': 'okx', 'display_name': 'Okx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '67', 'name': 'db_testnet', 'display_name': 'Db Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '68', 'name': 'soterone', 'display_name': 'Soterone', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '69', 'name': 'optimism_kovan', 'display_name': 'Optimism Kovan', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '70', 'name': 'hoo', 'display_name': 'Hoo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '71', 'name': 'conflux_espace_testnet', 'display_name': 'Conflux Espace Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '72', 'name': 'dx_testnet', 'display_name': 'Dx Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '73', 'name': 'fncy', 'display_name': 'Fncy', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '74', 'name': 'id', 'display_name': 'Id', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '75', 'name': 'decimal', 'display_name': 'Decimal', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '76', 'name': 'mix', 'display_name': 'Mix', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '77', 'name': 'poa_sokol', 'display_name': 'Poa Sokol', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '78', 'name': 'primus', 'display_name': 'Primus', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '79', 'name': 'zenith', 'display_name': 'Zenith', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '80', 'name': 'gene', 'display_name': 'Gene', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '81', 'name': 'japan_open', 'display_name': 'Japan Open', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '82', 'name': 'meter', 'display_name': 'Meter', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '83', 'name': 'meter_testnet', 'display_name': 'Meter Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '84', 'name': 'linqto_devnet', 'display_name': 'Linqto Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '85', 'name': 'gate_testnet', 'display_name': 'Gate Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '86', 'name': 'gate', 'display_name': 'Gate', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '87', 'name': 'nova', 'display_name': 'Nova', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '88', 'name': 'viction', 'display_name': 'Viction', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '89', 'name': 'viction_testnet', 'display_name': 'Viction Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '90', 'name': 'garizon_stage0', 'display_name': 'Garizon Stage0', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '91', 'name': 'garizon_stage1', 'display_name': 'Garizon Stage1', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '92', 'name': 'garizon_stage2', 'display_name': 'Garizon Stage2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '93', 'name': 'garizon_stage3', 'display_name': 'Garizon Stage3', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '94', 'name': 'swissdlt', 'display_name': 'Swissdlt', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '95', 'name': 'camdl', 'display_name': 'Camdl', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '96', 'name': 'bitkub', 'display_name': 'Bitkub', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '97', 'name': 'bnb_testnet', 'display_name': 'BNB Smart Chain Testnet', 'aliases': ['bsc_testnet'], 'testnet': True, 'parent': None, 'currency': 'tBNB', 'explorer': 'https://testnet.bscscan.com'},
+    {'chain_id': '98', 'name': 'six_protocol', 'display_name': 'Six Protocol', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '99', 'name': 'poa_core', 'display_name': 'Poa Core', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '100', 'name': 'gnosis', 'display_name': 'Gnosis', 'aliases': ['xdai'], 'testnet': False, 'parent': None, 'currency': 'XDAI', 'explorer': 'https://gnosisscan.io'},
+    {'chain_id': '101', 'name': 'etherinc', 'display_name': 'Etherinc', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '102', 'name': 'web3games_testnet', 'display_name': 'Web3games Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '103', 'name': 'worldland', 'display_name': 'Worldland', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '104', 'name': 'kaiba_lightning_testnet', 'display_name': 'Kaiba Lightning Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '105', 'name': 'web3games_devnet', 'display_name': 'Web3games Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '106', 'name': 'velas_evm', 'display_name': 'Velas Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '107', 'name': 'nebula_testnet', 'display_name': 'Nebula Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '108', 'name': 'thundercore', 'display_name': 'Thundercore', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '109', 'name': 'shibarium', 'display_name': 'Shibarium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '110', 'name': 'proton_testnet', 'display_name': 'Proton Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '111', 'name': 'etherlite', 'display_name': 'Etherlite', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '112', 'name': 'coinbit', 'display_name': 'Coinbit', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '113', 'name': 'dehvo', 'display_name': 'Dehvo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '114', 'name': 'flare_testnet_coston2', 'display_name': 'Flare Testnet Coston2', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '116', 'name': 'debank', 'display_name': 'Debank', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '117', 'name': 'uptick', 'display_name': 'Uptick', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '118', 'name': 'arcology_testnet', 'display_name': 'Arcology Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '119', 'name': 'enuls', 'display_name': 'Enuls', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '120', 'name': 'enuls_testnet', 'display_name': 'Enuls Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '121', 'name': 'real', 'display_name': 'Real', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '122', 'name': 'fuse', 'display_name': 'Fuse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '123', 'name': 'fuse_sparknet', 'display_name': 'Fuse Sparknet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '124', 'name': 'decentralized_web', 'display_name': 'Decentralized Web', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '125', 'name': 'oy_testnet', 'display_name': 'Oy Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '126', 'name': 'oy', 'display_name': 'Oy', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '127', 'name': 'factory_127', 'display_name': 'Factory 127', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '128', 'name': 'huobi_eco', 'display_name': 'Huobi Eco', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '129', 'name': 'innovator', 'display_name': 'Innovator', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '131', 'name': 'engram_testnet', 'display_name': 'Engram Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '132', 'name': 'namefi', 'display_name': 'Namefi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '133', 'name': 'hashkey_testnet', 'display_name': 'Hashkey Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '134', 'name': 'iexec', 'display_name': 'Iexec', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '135', 'name': 'alyx_testnet', 'display_name': 'Alyx Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '136', 'name': 'deam', 'display_name': 'Deam', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '137', 'name': 'polygon', 'display_name': 'Polygon Mainnet', 'aliases': ['matic', 'polygon_pos'], 'testnet': False, 'parent': None, 'currency': 'POL', 'explorer': 'https://polygonscan.com'},
+    {'chain_id': '138', 'name': 'defi_oracle_meta', 'display_name': 'Defi Oracle Meta', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '139', 'name': 'woop', 'display_name': 'Woop', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '140', 'name': 'eternal', 'display_name': 'Eternal', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '141', 'name': 'openpiece_testnet', 'display_name': 'Openpiece Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '142', 'name': 'dax', 'display_name': 'Dax', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '144', 'name': 'phi_v2', 'display_name': 'Phi V2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '145', 'name': 'soraai_testnet', 'display_name': 'Soraai Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '147', 'name': 'flag', 'display_name': 'Flag', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '148', 'name': 'shimmerevm', 'display_name': 'Shimmerevm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '150', 'name': 'six_protocol_testnet', 'display_name': 'Six Protocol Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '151', 'name': 'redbelly', 'display_name': 'Redbelly', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '152', 'name': 'redbelly_devnet', 'display_name': 'Redbelly Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '153', 'name': 'redbelly_testnet', 'display_name': 'Redbelly Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '154', 'name': 'redbelly_tge', 'display_name': 'Redbelly Tge', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '155', 'name': 'tenet_testnet', 'display_name': 'Tenet Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '156', 'name': 'oeblock_testnet', 'display_name': 'Oeblock Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '157', 'name': 'puppynet_shibarium', 'display_name': 'Puppynet Shibarium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '158', 'name': 'roburna', 'display_name': 'Roburna', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '159', 'name': 'roburna_testnet', 'display_name': 'Roburna Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '160', 'name': 'armonia_eva', 'display_name': 'Armonia Eva', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '161', 'name': 'armonia_eva_testnet', 'display_name': 'Armonia Eva Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '162', 'name': 'lightstreams_testnet', 'display_name': 'Lightstreams Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '163', 'name': 'lightstreams', 'display_name': 'Lightstreams', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '164', 'name': 'omni_omega_testnet', 'display_name': 'Omni Omega Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '166', 'name': 'omni', 'display_name': 'Omni', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '167', 'name': 'atoshi_testnet', 'display_name': 'Atoshi Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '168', 'name': 'aioz', 'display_name': 'Aioz', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '169', 'name': 'manta_pacific', 'display_name': 'Manta Pacific', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '170', 'name': 'hoo_testnet', 'display_name': 'Hoo Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '171', 'name': 'co2e_ledger', 'display_name': 'Co2e Ledger', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '172', 'name': 'latam_block_resil_testnet', 'display_name': 'Latam Block Resil Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '176', 'name': 'dc', 'display_name': 'Dc', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '178', 'name': 'abey_testnet', 'display_name': 'Abey Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '179', 'name': 'abey', 'display_name': 'Abey', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '180', 'name': 'ame', 'display_name': 'Ame', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '181', 'name': 'waterfall', 'display_name': 'Waterfall', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '183', 'name': 'ethernity', 'display_name': 'Ethernity', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '184', 'name': 'dojima_testnet', 'display_name': 'Dojima Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '185', 'name': 'mint', 'display_name': 'Mint', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '186', 'name': 'seele', 'display_name': 'Seele', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '187', 'name': 'dojima', 'display_name': 'Dojima', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '188', 'name': 'bmc', 'display_name': 'Bmc', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '189', 'name': 'bmc_testnet', 'display_name': 'Bmc Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '190', 'name': 'cmdao_bbq', 'display_name': 'Cmdao Bbq', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '191', 'name': 'filefilego', 'display_name': 'Filefilego', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '193', 'name': 'crypto_emergency', 'display_name': 'Crypto Emergency', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '195', 'name': 'x_layer_testnet', 'display_name': 'X Layer Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '196', 'name': 'x_layer', 'display_name': 'X Layer', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '197', 'name': 'neutrinos_testnet', 'display_name': 'Neutrinos Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '198', 'name': 'bit', 'display_name': 'Bit', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '199', 'name': 'bittorrent', 'display_name': 'Bittorrent', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '200', 'name': 'arbitrum_on_xdai', 'display_name': 'Arbitrum On Xdai', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '201', 'name': 'moac_testnet', 'display_name': 'Moac Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '202', 'name': 'edgeless_testnet', 'display_name': 'Edgeless Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '204', 'name': 'opbnb', 'display_name': 'Opbnb', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '205', 'name': 'ekaash', 'display_name': 'Ekaash', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '206', 'name': 'vinu_testnet', 'display_name': 'Vinu Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '207', 'name': 'vinu', 'display_name': 'Vinu', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '208', 'name': 'structx', 'display_name': 'Structx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '210', 'name': 'bitnet', 'display_name': 'Bitnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '211', 'name': 'freight_trust', 'display_name': 'Freight Trust', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '212', 'name': 'mapo_makalu', 'display_name': 'Mapo Makalu', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '213', 'name': 'b2_hub', 'display_name': 'B2 Hub', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '214', 'name': 'shinarium', 'display_name': 'Shinarium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '217', 'name': 'siriusnet_v2', 'display_name': 'Siriusnet V2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '218', 'name': 'soterone_old', 'display_name': 'Soterone Old', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '220', 'name': 'scalind_testnet', 'display_name': 'Scalind Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '221', 'name': 'blockex', 'display_name': 'Blockex', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '222', 'name': 'permission', 'display_name': 'Permission', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '223', 'name': 'b2', 'display_name': 'B2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '224', 'name': 'viridis_testnet', 'display_name': 'Viridis Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '225', 'name': 'la', 'display_name': 'La', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '226', 'name': 'la_testnet', 'display_name': 'La Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '228', 'name': 'mind', 'display_name': 'Mind', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '230', 'name': 'swapdex', 'display_name': 'Swapdex', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '233', 'name': 'ethernity_testnet', 'display_name': 'Ethernity Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '234', 'name': 'protojumbo_testnet', 'display_name': 'Protojumbo Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '236', 'name': 'deam_testnet', 'display_name': 'Deam Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '238', 'name': 'blast', 'display_name': 'Blast', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '242', 'name': 'plinga', 'display_name': 'Plinga', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '246', 'name': 'energy_web', 'display_name': 'Energy Web', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '248', 'name': 'oasys', 'display_name': 'Oasys', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '250', 'name': 'fantom_opera', 'display_name': 'Fantom Opera', 'aliases': ['fantom', 'ftm'], 'testnet': False, 'parent': None, 'currency': 'FTM', 'explorer': 'https://ftmscan.com'},
+    {'chain_id': '252', 'name': 'fraxtal', 'display_name': 'Fraxtal', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '254', 'name': 'swan', 'display_name': 'Swan', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '255', 'name': 'kroma', 'display_name': 'Kroma', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '256', 'name': 'huobi_eco_testnet', 'display_name': 'Huobi Eco Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '258', 'name': 'setheum', 'display_name': 'Setheum', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '259', 'name': 'neonlink', 'display_name': 'Neonlink', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '261', 'name': 'guru_testnet', 'display_name': 'Guru Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '262', 'name': 'sur_block', 'display_name': 'Sur Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '266', 'name': 'neura', 'display_name': 'Neura', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '267', 'name': 'neura_testnet', 'display_name': 'Neura Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '268', 'name': 'neura_devnet', 'display_name': 'Neura Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '269', 'name': 'high_performance_block', 'display_name': 'High Performance Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '271', 'name': 'egoncoin', 'display_name': 'Egoncoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '278', 'name': 'xfair.ai', 'display_name': 'Xfair.ai', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '279', 'name': 'bpx_block', 'display_name': 'Bpx Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '282', 'name': 'cronos_zkevm_testnet', 'display_name': 'Cronos Zkevm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '288', 'name': 'boba', 'display_name': 'Boba', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '291', 'name': 'orderly', 'display_name': 'Orderly', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '295', 'name': 'hedera', 'display_name': 'Hedera', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '296', 'name': 'hedera_testnet', 'display_name': 'Hedera Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '297', 'name': 'hedera_previewnet', 'display_name': 'Hedera Previewnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '298', 'name': 'hedera_localnet', 'display_name': 'Hedera Localnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '300', 'name': 'zksync_sepolia_testnet', 'display_name': 'zkSync Sepolia Testnet', 'aliases': ['zksync_sepolia'], 'testnet': True, 'parent': '11155111', 'currency': 'ETH', 'explorer': 'https://sepolia.explorer.zksync.io'},
+    {'chain_id': '301', 'name': 'bobaopera', 'display_name': 'Bobaopera', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '302', 'name': 'zkcandy_sepolia_testnet', 'display_name': 'Zkcandy Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '303', 'name': 'neuro_testnet', 'display_name': 'Neuro Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '305', 'name': 'zksats', 'display_name': 'Zksats', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '307', 'name': 'lovely_testnet', 'display_name': 'Lovely Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '308', 'name': 'furtheon', 'display_name': 'Furtheon', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '309', 'name': 'wyzth_testnet', 'display_name': 'Wyzth Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '311', 'name': 'omax', 'display_name': 'Omax', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '313', 'name': 'neuro', 'display_name': 'Neuro', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '314', 'name': 'filecoin', 'display_name': 'Filecoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '321', 'name': 'kcc', 'display_name': 'Kcc', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '322', 'name': 'kcc_testnet', 'display_name': 'Kcc Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '323', 'name': 'cosvm', 'display_name': 'Cosvm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '324', 'name': 'zksync', 'display_name': 'zkSync Mainnet', 'aliases': ['zksync_era', 'era'], 'testnet': False, 'parent': '1', 'currency': 'ETH', 'explorer': 'https://explorer.zksync.io'},
+    {'chain_id': '325', 'name': 'grvt', 'display_name': 'Grvt', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '326', 'name': 'grvt_sepolia_testnet', 'display_name': 'Grvt Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '333', 'name': 'web3q', 'display_name': 'Web3q', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '335', 'name': 'dfk_test', 'display_name': 'Dfk Test', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '336', 'name': 'shiden', 'display_name': 'Shiden', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '338', 'name': 'cronos_testnet', 'display_name': 'Cronos Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '345', 'name': 'tsc', 'display_name': 'Tsc', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '360', 'name': 'shape', 'display_name': 'Shape', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '361', 'name': 'theta', 'display_name': 'Theta', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '363', 'name': 'theta_sapphire_testnet', 'display_name': 'Theta Sapphire Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '364', 'name': 'theta_amber_testnet', 'display_name': 'Theta Amber Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '365', 'name': 'theta_testnet', 'display_name': 'Theta Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '369', 'name': 'pulse', 'display_name': 'Pulse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '371', 'name': 'consta_testnet', 'display_name': 'Consta Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '380', 'name': 'zkamoeba_testnet', 'display_name': 'Zkamoeba Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '381', 'name': 'zkamoeba', 'display_name': 'Zkamoeba', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '385', 'name': 'lisinski', 'display_name': 'Lisinski', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '388', 'name': 'cronos_zkevm', 'display_name': 'Cronos Zkevm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '395', 'name': 'camdl_testnet', 'display_name': 'Camdl Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '397', 'name': 'near', 'display_name': 'Near', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '398', 'name': 'near_testnet', 'display_name': 'Near Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '399', 'name': 'nativ3', 'display_name': 'Nativ3', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '400', 'name': 'hyperon_testnet', 'display_name': 'Hyperon Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '401', 'name': 'ozone_testnet', 'display_name': 'Ozone Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '404', 'name': 'syndr_l3', 'display_name': 'Syndr L3', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '411', 'name': 'pepe', 'display_name': 'Pepe', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '416', 'name': 'sx', 'display_name': 'Sx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '418', 'name': 'latestnet', 'display_name': 'Latestnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '420', 'name': 'optimism_goerli_testnet', 'display_name': 'Optimism Goerli Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '422', 'name': 'viridis', 'display_name': 'Viridis', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '424', 'name': 'pgn_public_goods', 'display_name': 'Pgn Public Goods', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '427', 'name': 'zeeth', 'display_name': 'Zeeth', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '428', 'name': 'geso_verse', 'display_name': 'Geso Verse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '434', 'name': 'boyaa', 'display_name': 'Boyaa', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '443', 'name': 'ten_testnet', 'display_name': 'Ten Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '444', 'name': 'synapse_testnet', 'display_name': 'Synapse Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '456', 'name': 'arzio', 'display_name': 'Arzio', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '462', 'name': 'areon_testnet', 'display_name': 'Areon Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '463', 'name': 'areon', 'display_name': 'Areon', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '480', 'name': 'world', 'display_name': 'World', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '499', 'name': 'rupaya', 'display_name': 'Rupaya', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '500', 'name': 'camino_c', 'display_name': 'Camino C', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '501', 'name': 'columbus_test', 'display_name': 'Columbus Test', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '510', 'name': 'syndicate', 'display_name': 'Syndicate', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '512', 'name': 'double_a', 'display_name': 'Double A', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '513', 'name': 'double_a_testnet', 'display_name': 'Double A Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '516', 'name': 'gear_zero', 'display_name': 'Gear Zero', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '520', 'name': 'xt', 'display_name': 'Xt', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '529', 'name': 'fire', 'display_name': 'Fire', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '530', 'name': 'fxcore', 'display_name': 'Fxcore', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '534', 'name': 'candle', 'display_name': 'Candle', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '537', 'name': 'optrust', 'display_name': 'Optrust', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '542', 'name': 'paw_testnet', 'display_name': 'Paw Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '545', 'name': 'evm_on_flow_testnet', 'display_name': 'Evm On Flow Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '555', 'name': 'vela1', 'display_name': 'Vela1', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '558', 'name': 'tao', 'display_name': 'Tao', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '568', 'name': 'doge_testnet', 'display_name': 'Doge Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '570', 'name': 'rollux', 'display_name': 'Rollux', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '571', 'name': 'meta', 'display_name': 'Meta', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '579', 'name': 'filenova', 'display_name': 'Filenova', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '588', 'name': 'metis_stardust_testnet', 'display_name': 'Metis Stardust Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '592', 'name': 'astar', 'display_name': 'Astar', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '595', 'name': 'acala_mandala_testnet_tc9', 'display_name': 'Acala Mandala Testnet Tc9', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '596', 'name': 'karura_testnet', 'display_name': 'Karura Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '597', 'name': 'acala_testnet', 'display_name': 'Acala Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '599', 'name': 'metis_goerli_testnet', 'display_name': 'Metis Goerli Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '600', 'name': 'meshnyan_testnet', 'display_name': 'Meshnyan Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '601', 'name': 'vine_testnet', 'display_name': 'Vine Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '610', 'name': 'darwin_devnet', 'display_name': 'Darwin Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '612', 'name': 'eiob', 'display_name': 'Eiob', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '614', 'name': 'graphlinq_block', 'display_name': 'Graphlinq Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '624', 'name': 'binary', 'display_name': 'Binary', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '625', 'name': 'binary_sepolia', 'display_name': 'Binary Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '634', 'name': 'avocado', 'display_name': 'Avocado', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '646', 'name': 'previewnet', 'display_name': 'Previewnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '647', 'name': 'sx_testnet', 'display_name': 'Sx Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '648', 'name': 'endurance', 'display_name': 'Endurance', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '653', 'name': 'kali_testnet', 'display_name': 'Kali Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '654', 'name': 'kali', 'display_name': 'Kali', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '662', 'name': 'ultronsmart', 'display_name': 'Ultronsmart', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '666', 'name': 'pixie_testnet', 'display_name': 'Pixie Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '667', 'name': 'laos_arrakis', 'display_name': 'Laos Arrakis', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '668', 'name': 'junca', 'display_name': 'Junca', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '669', 'name': 'junca_testnet', 'display_name': 'Junca Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '686', 'name': 'karura', 'display_name': 'Karura', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '690', 'name': 'redstone', 'display_name': 'Redstone', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '700', 'name': 'star_social_testnet', 'display_name': 'Star Social Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '701', 'name': 'darwinia_koi_testnet', 'display_name': 'Darwinia Koi Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '707', 'name': 'block_station', 'display_name': 'Block Station', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '708', 'name': 'block_station_testnet', 'display_name': 'Block Station Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '710', 'name': 'highbury', 'display_name': 'Highbury', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '711', 'name': 'tucana', 'display_name': 'Tucana', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '712', 'name': 'birdee_2', 'display_name': 'Birdee 2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '713', 'name': 'vrcscan', 'display_name': 'Vrcscan', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '719', 'name': 'shibarium_beta', 'display_name': 'Shibarium Beta', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '721', 'name': 'lycan', 'display_name': 'Lycan', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '727', 'name': 'blucrates', 'display_name': 'Blucrates', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '730', 'name': 'lovely', 'display_name': 'Lovely', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '740', 'name': 'canto_testnet', 'display_name': 'Canto Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '741', 'name': 'vention_testnet', 'display_name': 'Vention Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '742', 'name': 'script_testnet', 'display_name': 'Script Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '747', 'name': 'evm_on_flow', 'display_name': 'Evm On Flow', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '766', 'name': 'ql1', 'display_name': 'Ql1', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '776', 'name': 'open_testnet', 'display_name': 'Open Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '777', 'name': 'cheapeth', 'display_name': 'Cheapeth', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '786', 'name': 'maal', 'display_name': 'Maal', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '787', 'name': 'acala', 'display_name': 'Acala', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '788', 'name': 'aero_testnet', 'display_name': 'Aero Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '789', 'name': 'patex', 'display_name': 'Patex', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '799', 'name': 'rupaya_testnet', 'display_name': 'Rupaya Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '800', 'name': 'lucid_block', 'display_name': 'Lucid Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '803', 'name': 'haic', 'display_name': 'Haic', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '808', 'name': 'portal_fantasy_test', 'display_name': 'Portal Fantasy Test', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '810', 'name': 'haven1_testnet', 'display_name': 'Haven1 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '813', 'name': 'qitmeer', 'display_name': 'Qitmeer', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '814', 'name': 'fire_zkevm', 'display_name': 'Fire Zkevm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '818', 'name': 'beone', 'display_name': 'Beone', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '820', 'name': 'callisto', 'display_name': 'Callisto', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '822', 'name': 'runic_testnet', 'display_name': 'Runic Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '831', 'name': 'checkdot_block_devnet', 'display_name': 'Checkdot Block Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '841', 'name': 'taraxa', 'display_name': 'Taraxa', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '842', 'name': 'taraxa_testnet', 'display_name': 'Taraxa Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '852', 'name': 'hongkong', 'display_name': 'Hongkong', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '859', 'name': 'zeeth_dev', 'display_name': 'Zeeth Dev', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '868', 'name': 'fantasia', 'display_name': 'Fantasia', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '876', 'name': 'bandai_namco_research_verse', 'display_name': 'Bandai Namco Research Verse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '877', 'name': 'dexit', 'display_name': 'Dexit', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '880', 'name': 'ambros', 'display_name': 'Ambros', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '888', 'name': 'wan', 'display_name': 'Wan', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '898', 'name': 'maxi_testnet', 'display_name': 'Maxi Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '899', 'name': 'maxi', 'display_name': 'Maxi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '900', 'name': 'garizon_testnet_stage0', 'display_name': 'Garizon Testnet Stage0', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '901', 'name': 'garizon_testnet_stage1', 'display_name': 'Garizon Testnet Stage1', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '902', 'name': 'garizon_testnet_stage2', 'display_name': 'Garizon Testnet Stage2', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '903', 'name': 'garizon_testnet_stage3', 'display_name': 'Garizon Testnet Stage3', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '909', 'name': 'portal_fantasy', 'display_name': 'Portal Fantasy', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '910', 'name': 'decentrabone_layer1_testnet', 'display_name': 'Decentrabone Layer1 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '911', 'name': 'taproot', 'display_name': 'Taproot', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '917', 'name': 'rinia_testnet', 'display_name': 'Rinia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '919', 'name': 'mode_testnet', 'display_name': 'Mode Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '927', 'name': 'yidark', 'display_name': 'Yidark', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '940', 'name': 'pulse_testnet', 'display_name': 'Pulse Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '941', 'name': 'pulse_testnet_v2b', 'display_name': 'Pulse Testnet V2b', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '942', 'name': 'pulse_testnet_v3', 'display_name': 'Pulse Testnet V3', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '943', 'name': 'pulse_testnet_v4', 'display_name': 'Pulse Testnet V4', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '945', 'name': 'subtensor_evm_testnet', 'display_name': 'Subtensor Evm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '956', 'name': 'munode_testnet', 'display_name': 'Munode Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '957', 'name': 'lyra', 'display_name': 'Lyra', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '963', 'name': 'btc20', 'display_name': 'Btc20', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '969', 'name': 'ethxy', 'display_name': 'Ethxy', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '970', 'name': 'oort', 'display_name': 'Oort', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '971', 'name': 'oort_huygens', 'display_name': 'Oort Huygens', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '972', 'name': 'oort_ascraeus', 'display_name': 'Oort Ascraeus', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '973', 'name': 'palm', 'display_name': 'Palm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '977', 'name': 'nepal_block', 'display_name': 'Nepal Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '979', 'name': 'ethxy_testnet', 'display_name': 'Ethxy Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '980', 'name': 'top_evm', 'display_name': 'Top Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '985', 'name': 'memo', 'display_name': 'Memo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '989', 'name': 'top', 'display_name': 'Top', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '990', 'name': 'eliberty', 'display_name': 'Eliberty', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '995', 'name': '5ire', 'display_name': '5ire', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '996', 'name': 'bifrost_polkadot', 'display_name': 'Bifrost Polkadot', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '997', 'name': '5ire_thunder_testnet', 'display_name': '5ire Thunder Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '998', 'name': 'lucky', 'display_name': 'Lucky', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '999', 'name': 'wan_testnet', 'display_name': 'Wan Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1000', 'name': 'gton', 'display_name': 'Gton', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1001', 'name': 'kaia_testnet_kairos', 'display_name': 'Kaia Testnet Kairos', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1003', 'name': 'tectum_emission_token', 'display_name': 'Tectum Emission Token', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1004', 'name': 't_ekta', 'display_name': 'T Ekta', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1005', 'name': 'lemontestnet', 'display_name': 'Lemontestnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1006', 'name': 'lemon', 'display_name': 'Lemon', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1007', 'name': 'newton_testnet', 'display_name': 'Newton Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1008', 'name': 'eurus', 'display_name': 'Eurus', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1009', 'name': 'jumbo', 'display_name': 'Jumbo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1010', 'name': 'evrice', 'display_name': 'Evrice', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1011', 'name': 'rebus', 'display_name': 'Rebus', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1012', 'name': 'newton', 'display_name': 'Newton', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1022', 'name': 'sakura', 'display_name': 'Sakura', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1023', 'name': 'clover_testnet', 'display_name': 'Clover Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1024', 'name': 'clv_para', 'display_name': 'Clv Para', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1028', 'name': 'bittorrent_testnet', 'display_name': 'Bittorrent Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1030', 'name': 'conflux_espace', 'display_name': 'Conflux Espace', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1031', 'name': 'proxy_testnet', 'display_name': 'Proxy Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1038', 'name': 'bronos_testnet', 'display_name': 'Bronos Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1039', 'name': 'bronos', 'display_name': 'Bronos', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1073', 'name': 'shimmerevm_testnet', 'display_name': 'Shimmerevm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1075', 'name': 'iota_evm_testnet', 'display_name': 'Iota Evm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1079', 'name': 'mintara_testnet', 'display_name': 'Mintara Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1080', 'name': 'mintara', 'display_name': 'Mintara', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1088', 'name': 'metis_andromeda', 'display_name': 'Metis Andromeda', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1089', 'name': 'humans.ai', 'display_name': 'Humans.ai', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1099', 'name': 'moac', 'display_name': 'Moac', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1100', 'name': 'dymension', 'display_name': 'Dymension', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1101', 'name': 'polygon_zkevm', 'display_name': 'Polygon zkEVM', 'aliases': [], 'testnet': False, 'parent': '1', 'currency': 'ETH', 'explorer': 'https://zkevm.polygonscan.com'},
+    {'chain_id': '1107', 'name': 'blxq_testnet', 'display_name': 'Blxq Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1108', 'name': 'blxq', 'display_name': 'Blxq', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1111', 'name': 'wemix', 'display_name': 'Wemix', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1112', 'name': 'wemix_testnet', 'display_name': 'Wemix Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1113', 'name': 'b2_hub_testnet', 'display_name': 'B2 Hub Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1115', 'name': 'core_block_testnet', 'display_name': 'Core Block Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1116', 'name': 'core_block', 'display_name': 'Core Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1117', 'name': 'dogcoin', 'display_name': 'Dogcoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1123', 'name': 'b2_testnet', 'display_name': 'B2 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1130', 'name': 'defi_evm', 'display_name': 'Defi Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1131', 'name': 'defi_evm_testnet', 'display_name': 'Defi Evm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1133', 'name': 'defimeta_changi_testnet', 'display_name': 'Defimeta Changi Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1135', 'name': 'lisk', 'display_name': 'Lisk', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1138', 'name': 'amstar_testnet', 'display_name': 'Amstar Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1139', 'name': 'math', 'display_name': 'Math', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1140', 'name': 'math_testnet', 'display_name': 'Math Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1147', 'name': 'flag_testnet', 'display_name': 'Flag Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1149', 'name': 'symplexia', 'display_name': 'Symplexia', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1170', 'name': 'origin_testnet', 'display_name': 'Origin Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1177', 'name': 'smart_host_teknoloji_testnet', 'display_name': 'Smart Host Teknoloji Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1188', 'name': 'clubmos', 'display_name': 'Clubmos', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1197', 'name': 'iora', 'display_name': 'Iora', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1200', 'name': 'cuckoo', 'display_name': 'Cuckoo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1201', 'name': 'evanesco_testnet', 'display_name': 'Evanesco Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1202', 'name': 'world_trade_technical', 'display_name': 'World Trade Technical', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1209', 'name': 'saitablocksbc', 'display_name': 'Saitablocksbc', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1210', 'name': 'cuckoo_sepolia', 'display_name': 'Cuckoo Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1213', 'name': 'popcateum', 'display_name': 'Popcateum', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1214', 'name': 'enter', 'display_name': 'Enter', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1221', 'name': 'cycle_testnet', 'display_name': 'Cycle Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1223', 'name': 'cycle_testnet_jellyfish', 'display_name': 'Cycle Testnet Jellyfish', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1225', 'name': 'hybrid_testnet', 'display_name': 'Hybrid Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1227', 'name': 'bitcoin_protocol_testnet', 'display_name': 'Bitcoin Protocol Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1228', 'name': 'cycle_testnet_cuttlefish', 'display_name': 'Cycle Testnet Cuttlefish', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1229', 'name': 'exzo', 'display_name': 'Exzo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1230', 'name': 'ultron_testnet', 'display_name': 'Ultron Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1231', 'name': 'ultron', 'display_name': 'Ultron', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1234', 'name': 'step', 'display_name': 'Step', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1235', 'name': 'itx', 'display_name': 'Itx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1243', 'name': 'arc', 'display_name': 'Arc', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1244', 'name': 'arc_testnet', 'display_name': 'Arc Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1246', 'name': 'om_platform', 'display_name': 'Om Platform', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1248', 'name': 'dogether', 'display_name': 'Dogether', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1252', 'name': 'cic_testnet', 'display_name': 'Cic Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1260', 'name': 'metacces_testnet', 'display_name': 'Metacces Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1280', 'name': 'halo', 'display_name': 'Halo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1284', 'name': 'moonbeam', 'display_name': 'Moonbeam', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1285', 'name': 'moonriver', 'display_name': 'Moonriver', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1286', 'name': 'moonrock_old', 'display_name': 'Moonrock Old', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1287', 'name': 'moonbase_alpha', 'display_name': 'Moonbase Alpha', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1288', 'name': 'moonrock', 'display_name': 'Moonrock', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1291', 'name': 'swisstronik_testnet', 'display_name': 'Swisstronik Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1294', 'name': 'bobabeam', 'display_name': 'Bobabeam', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1297', 'name': 'bobabase_testnet', 'display_name': 'Bobabase Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1311', 'name': 'dos_fuji_subnet', 'display_name': 'Dos Fuji Subnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1313', 'name': 'jaiho', 'display_name': 'Jaiho', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1314', 'name': 'alyx', 'display_name': 'Alyx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1319', 'name': 'aia', 'display_name': 'Aia', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1320', 'name': 'aia_testnet', 'display_name': 'Aia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1328', 'name': 'sei_testnet', 'display_name': 'Sei Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1329', 'name': 'sei', 'display_name': 'Sei', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1337', 'name': 'geth_testnet', 'display_name': 'Geth Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1338', 'name': 'elysium_testnet', 'display_name': 'Elysium Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1339', 'name': 'elysium', 'display_name': 'Elysium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1343', 'name': 'blitz_subnet', 'display_name': 'Blitz Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1353', 'name': 'cic', 'display_name': 'Cic', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1369', 'name': 'zafirium', 'display_name': 'Zafirium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1370', 'name': 'ramestta', 'display_name': 'Ramestta', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1377', 'name': 'pingaksha_testnet', 'display_name': 'Pingaksha Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1379', 'name': 'kalar', 'display_name': 'Kalar', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1388', 'name': 'amstar', 'display_name': 'Amstar', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1392', 'name': 'joseon', 'display_name': 'Joseon', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1402', 'name': 'polygon_zkevm_testnet_old', 'display_name': 'Polygon Zkevm Testnet Old', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1422', 'name': 'polygon_zkevm_testnet_pre_audit_upgraded', 'display_name': 'Polygon Zkevm Testnet Pre Audit Upgraded', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1433', 'name': 'rikeza', 'display_name': 'Rikeza', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1440', 'name': 'living_assets', 'display_name': 'Living Assets', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1442', 'name': 'polygon_zkevm_testnet', 'display_name': 'Polygon Zkevm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1452', 'name': 'gil_testnet', 'display_name': 'Gil Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1453', 'name': 'meta_istanbul', 'display_name': 'Meta Istanbul', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1455', 'name': 'ctex_scan_block', 'display_name': 'Ctex Scan Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1456', 'name': 'zkbase', 'display_name': 'Zkbase', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1490', 'name': 'vitruveo', 'display_name': 'Vitruveo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1499', 'name': 'idos_games_testnet', 'display_name': 'Idos Games Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1501', 'name': 'bevm_canary', 'display_name': 'Bevm Canary', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1506', 'name': 'sherpax', 'display_name': 'Sherpax', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1507', 'name': 'sherpax_testnet', 'display_name': 'Sherpax Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1513', 'name': 'story_testnet', 'display_name': 'Story Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1515', 'name': 'beagle_messaging', 'display_name': 'Beagle Messaging', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1559', 'name': 'tenet', 'display_name': 'Tenet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1570', 'name': 'star_testnet', 'display_name': 'Star Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1578', 'name': 'star', 'display_name': 'Star', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1617', 'name': 'ethereum_inscription', 'display_name': 'Ethereum Inscription', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1618', 'name': 'catecoin', 'display_name': 'Catecoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1620', 'name': 'atheios', 'display_name': 'Atheios', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1625', 'name': 'gravity_alpha', 'display_name': 'Gravity Alpha', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1648', 'name': 'pivotal', 'display_name': 'Pivotal', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1657', 'name': 'bta', 'display_name': 'Bta', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1662', 'name': 'liqui', 'display_name': 'Liqui', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1663', 'name': 'horizen_gobi_testnet', 'display_name': 'Horizen Gobi Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1686', 'name': 'mint_testnet', 'display_name': 'Mint Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1687', 'name': 'mint_sepolia_testnet', 'display_name': 'Mint Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1688', 'name': 'ludan', 'display_name': 'Ludan', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1701', 'name': 'anytype_evm', 'display_name': 'Anytype Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1707', 'name': 'tbsi', 'display_name': 'Tbsi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1708', 'name': 'tbsi_testnet', 'display_name': 'Tbsi Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1717', 'name': 'doric', 'display_name': 'Doric', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1718', 'name': 'palette', 'display_name': 'Palette', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1729', 'name': 'reya', 'display_name': 'Reya', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1740', 'name': 'metal_l2_testnet', 'display_name': 'Metal L2 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1750', 'name': 'metal_l2', 'display_name': 'Metal L2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1773', 'name': 'party', 'display_name': 'Party', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1777', 'name': 'gauss', 'display_name': 'Gauss', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1789', 'name': 'zkbase_sepolia_testnet', 'display_name': 'Zkbase Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1804', 'name': 'kerleano', 'display_name': 'Kerleano', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1807', 'name': 'rabbit_analog_testnet', 'display_name': 'Rabbit Analog Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1811', 'name': 'lif3_testnet', 'display_name': 'Lif3 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1818', 'name': 'cube', 'display_name': 'Cube', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1819', 'name': 'cube_testnet', 'display_name': 'Cube Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1821', 'name': 'ruby', 'display_name': 'Ruby', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1853', 'name': 'highoctane_subnet', 'display_name': 'Highoctane Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1856', 'name': 'teslafunds', 'display_name': 'Teslafunds', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1875', 'name': 'white', 'display_name': 'White', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1881', 'name': 'gitshock_cartenz_testnet', 'display_name': 'Gitshock Cartenz Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1890', 'name': 'lightlink_phoenix', 'display_name': 'Lightlink Phoenix', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1891', 'name': 'lightlink_pegasus_testnet', 'display_name': 'Lightlink Pegasus Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1898', 'name': 'bon', 'display_name': 'Bon', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1899', 'name': 'redefi_layer_2', 'display_name': 'Redefi Layer 2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1904', 'name': 'sports', 'display_name': 'Sports', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1907', 'name': 'bitci', 'display_name': 'Bitci', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1908', 'name': 'bitci_testnet', 'display_name': 'Bitci Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1909', 'name': 'merkle_scan', 'display_name': 'Merkle Scan', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1911', 'name': 'scalind', 'display_name': 'Scalind', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1912', 'name': 'ruby_testnet', 'display_name': 'Ruby Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1918', 'name': 'upb_crescdi_testnet', 'display_name': 'Upb Crescdi Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1945', 'name': 'onus_testnet', 'display_name': 'Onus Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1946', 'name': 'minato', 'display_name': 'Minato', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1951', 'name': 'd', 'display_name': 'D', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1953', 'name': 'selendra_testnet', 'display_name': 'Selendra Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1954', 'name': 'dexilla_testnet', 'display_name': 'Dexilla Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1956', 'name': 'aiw3_testnet', 'display_name': 'Aiw3 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1961', 'name': 'selendra', 'display_name': 'Selendra', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1967', 'name': 'eleanor', 'display_name': 'Eleanor', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1969', 'name': 'super_testnet', 'display_name': 'Super Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1970', 'name': 'super', 'display_name': 'Super', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1971', 'name': 'atelier', 'display_name': 'Atelier', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1972', 'name': 'redecoin', 'display_name': 'Redecoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1975', 'name': 'onus', 'display_name': 'Onus', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1984', 'name': 'eurus_testnet', 'display_name': 'Eurus Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1985', 'name': 'satoshie', 'display_name': 'Satoshie', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1986', 'name': 'satoshie_testnet', 'display_name': 'Satoshie Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1987', 'name': 'ethergem', 'display_name': 'Ethergem', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1992', 'name': 'hubble_exchange', 'display_name': 'Hubble Exchange', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1993', 'name': 'b3_sepolia_testnet', 'display_name': 'B3 Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1994', 'name': 'ekta', 'display_name': 'Ekta', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1995', 'name': 'edexa_testnet', 'display_name': 'Edexa Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1996', 'name': 'sanko', 'display_name': 'Sanko', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1997', 'name': 'kyoto', 'display_name': 'Kyoto', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1998', 'name': 'kyoto_testnet', 'display_name': 'Kyoto Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2000', 'name': 'doge', 'display_name': 'Doge', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2001', 'name': 'milkomeda_c1', 'display_name': 'Milkomeda C1', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2002', 'name': 'milkomeda_a1', 'display_name': 'Milkomeda A1', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2004', 'name': 'metalink', 'display_name': 'Metalink', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2008', 'name': 'cloudwalk_testnet', 'display_name': 'Cloudwalk Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2009', 'name': 'cloudwalk', 'display_name': 'Cloudwalk', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2013', 'name': 'panarchy', 'display_name': 'Panarchy', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2014', 'name': 'now_testnet', 'display_name': 'Now Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2016', 'name': 'mainnetz', 'display_name': 'Mainnetz', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2017', 'name': 'adiri', 'display_name': 'Adiri', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2018', 'name': 'publicmint_devnet', 'display_name': 'Publicmint Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2019', 'name': 'publicmint_testnet', 'display_name': 'Publicmint Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2020', 'name': 'publicmint', 'display_name': 'Publicmint', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2021', 'name': 'edgeware_edgeevm', 'display_name': 'Edgeware Edgeevm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2022', 'name': 'beresheet_bereevm_testnet', 'display_name': 'Beresheet Bereevm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2023', 'name': 'taycan_testnet', 'display_name': 'Taycan Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2024', 'name': 'swan_saturn_testnet', 'display_name': 'Swan Saturn Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2025', 'name': 'rangers_protocol', 'display_name': 'Rangers Protocol', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2026', 'name': 'edgeless', 'display_name': 'Edgeless', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2031', 'name': 'centrifuge', 'display_name': 'Centrifuge', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2032', 'name': 'catalyst', 'display_name': 'Catalyst', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2035', 'name': 'phala', 'display_name': 'Phala', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2037', 'name': 'kiwi_subnet', 'display_name': 'Kiwi Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2038', 'name': 'shrapnel_testnet', 'display_name': 'Shrapnel Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2039', 'name': 'aleph_zero', 'display_name': 'Aleph Zero', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2040', 'name': 'vanar', 'display_name': 'Vanar', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2043', 'name': 'neuroweb', 'display_name': 'Neuroweb', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2044', 'name': 'shrapnel_subnet', 'display_name': 'Shrapnel Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2045', 'name': 'aiw3', 'display_name': 'Aiw3', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2047', 'name': 'stratos_testnet', 'display_name': 'Stratos Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2048', 'name': 'stratos', 'display_name': 'Stratos', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2049', 'name': 'movo', 'display_name': 'Movo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2071', 'name': 'metacces', 'display_name': 'Metacces', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2077', 'name': 'quokkacoin', 'display_name': 'Quokkacoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2088', 'name': 'altair', 'display_name': 'Altair', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2089', 'name': 'algol', 'display_name': 'Algol', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2100', 'name': 'ecoball', 'display_name': 'Ecoball', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2101', 'name': 'ecoball_testnet_espuma', 'display_name': 'Ecoball Testnet Espuma', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2109', 'name': 'exosama', 'display_name': 'Exosama', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2112', 'name': 'u', 'display_name': 'U', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2121', 'name': 'catena', 'display_name': 'Catena', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2122', 'name': 'metaplayerone', 'display_name': 'Metaplayerone', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2124', 'name': 'metaplayerone_dubai_testnet', 'display_name': 'Metaplayerone Dubai Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2136', 'name': 'bigshortbets_testnet', 'display_name': 'Bigshortbets Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2137', 'name': 'bigshortbets', 'display_name': 'Bigshortbets', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2138', 'name': 'defi_oracle_meta_testnet', 'display_name': 'Defi Oracle Meta Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2140', 'name': 'oneness', 'display_name': 'Oneness', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2141', 'name': 'oneness_testnet', 'display_name': 'Oneness Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2151', 'name': 'bosagora', 'display_name': 'Bosagora', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2152', 'name': 'findora', 'display_name': 'Findora', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2153', 'name': 'findora_testnet', 'display_name': 'Findora Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2154', 'name': 'findora_forge', 'display_name': 'Findora Forge', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2162', 'name': 'anime_testnet', 'display_name': 'Anime Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2192', 'name': 'snax', 'display_name': 'Snax', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2199', 'name': 'moonsama', 'display_name': 'Moonsama', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2202', 'name': 'antofy', 'display_name': 'Antofy', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2203', 'name': 'bitcoin_evm', 'display_name': 'Bitcoin Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2213', 'name': 'evanesco', 'display_name': 'Evanesco', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2221', 'name': 'kava_testnet', 'display_name': 'Kava Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2222', 'name': 'kava', 'display_name': 'Kava', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2223', 'name': 'v', 'display_name': 'V', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2241', 'name': 'krest', 'display_name': 'Krest', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2300', 'name': 'bomb', 'display_name': 'Bomb', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2306', 'name': 'ebro', 'display_name': 'Ebro', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2309', 'name': 'arevia', 'display_name': 'Arevia', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2311', 'name': 'chronicle_vesuvius_lit_protocol_testnet', 'display_name': 'Chronicle Vesuvius Lit Protocol Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2323', 'name': 'soma_testnet', 'display_name': 'Soma Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2330', 'name': 'altcoin', 'display_name': 'Altcoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2331', 'name': 'rss3_vsl_sepolia_testnet', 'display_name': 'Rss3 Vsl Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2332', 'name': 'soma', 'display_name': 'Soma', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2340', 'name': 'atleta_olympia', 'display_name': 'Atleta Olympia', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2342', 'name': 'omnia', 'display_name': 'Omnia', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2355', 'name': 'silicon_zkevm', 'display_name': 'Silicon Zkevm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2358', 'name': 'kroma_sepolia', 'display_name': 'Kroma Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2370', 'name': 'nexis_testnet', 'display_name': 'Nexis Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2399', 'name': 'bomb_testnet', 'display_name': 'Bomb Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2400', 'name': 'tcg_verse', 'display_name': 'Tcg Verse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2410', 'name': 'karak', 'display_name': 'Karak', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2415', 'name': 'xodex', 'display_name': 'Xodex', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2425', 'name': 'king_of_legends', 'display_name': 'King Of Legends', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2442', 'name': 'polygon_zkevm_cardona_testnet', 'display_name': 'Polygon Zkevm Cardona Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2468', 'name': 'hybrid', 'display_name': 'Hybrid', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2477', 'name': '6degree_of_outreach', 'display_name': '6degree Of Outreach', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2484', 'name': 'unicorn_ultra_nebulas_testnet', 'display_name': 'Unicorn Ultra Nebulas Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2511', 'name': 'karak_goerli', 'display_name': 'Karak Goerli', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2522', 'name': 'fraxtal_testnet', 'display_name': 'Fraxtal Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2525', 'name': 'inevm', 'display_name': 'Inevm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2559', 'name': 'kortho', 'display_name': 'Kortho', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2569', 'name': 'techpay', 'display_name': 'Techpay', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2606', 'name': 'pocrnet', 'display_name': 'Pocrnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2611', 'name': 'redlight', 'display_name': 'Redlight', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2612', 'name': 'ez_c', 'display_name': 'Ez C', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2613', 'name': 'ez_c_testnet', 'display_name': 'Ez C Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2625', 'name': 'white_testnet', 'display_name': 'White Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2648', 'name': 'ailayer_testnet', 'display_name': 'Ailayer Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2649', 'name': 'ailayer', 'display_name': 'Ailayer', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2662', 'name': 'apex', 'display_name': 'Apex', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2710', 'name': 'morph_testnet', 'display_name': 'Morph Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2718', 'name': 'k_laos', 'display_name': 'K Laos', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2730', 'name': 'xr_sepolia', 'display_name': 'Xr Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2731', 'name': 'elizabeth_testnet', 'display_name': 'Elizabeth Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2748', 'name': 'nanon', 'display_name': 'Nanon', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2777', 'name': 'gm', 'display_name': 'Gm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2810', 'name': 'morph_holesky', 'display_name': 'Morph Holesky', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2868', 'name': 'hyperagi', 'display_name': 'Hyperagi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2882', 'name': 'chips', 'display_name': 'Chips', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2888', 'name': 'boba_goerli_testnet', 'display_name': 'Boba Goerli Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2889', 'name': 'aarma', 'display_name': 'Aarma', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2907', 'name': 'elux', 'display_name': 'Elux', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2911', 'name': 'hy', 'display_name': 'Hy', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2941', 'name': 'xenon_testnet', 'display_name': 'Xenon Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2999', 'name': 'bityuan', 'display_name': 'Bityuan', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3000', 'name': 'cennznet_rata', 'display_name': 'Cennznet Rata', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3001', 'name': 'cennznet_nikau', 'display_name': 'Cennznet Nikau', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3003', 'name': 'canxium', 'display_name': 'Canxium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3011', 'name': 'playa3ull_games', 'display_name': 'Playa3ull Games', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3031', 'name': 'orlando', 'display_name': 'Orlando', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3033', 'name': 'rebus_testnet', 'display_name': 'Rebus Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3068', 'name': 'bifrost', 'display_name': 'Bifrost', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3073', 'name': 'movement_evm', 'display_name': 'Movement Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3084', 'name': 'xl_testnet', 'display_name': 'Xl Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3100', 'name': 'immu3_evm', 'display_name': 'Immu3 Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3102', 'name': 'vulture_evm_beta', 'display_name': 'Vulture Evm Beta', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3109', 'name': 'satoshivm_alpha', 'display_name': 'Satoshivm Alpha', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3110', 'name': 'satoshivm_testnet', 'display_name': 'Satoshivm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3141', 'name': 'filecoin_hyperspace_testnet', 'display_name': 'Filecoin Hyperspace Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3269', 'name': 'dubxcoin', 'display_name': 'Dubxcoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3270', 'name': 'dubxcoin_testnet', 'display_name': 'Dubxcoin Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3306', 'name': 'debounce_subnet_testnet', 'display_name': 'Debounce Subnet Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3331', 'name': 'zcore_testnet', 'display_name': 'Zcore Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3333', 'name': 'ethstorage_testnet', 'display_name': 'Ethstorage Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3334', 'name': 'web3q_galileo', 'display_name': 'Web3q Galileo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3335', 'name': 'ethstorage', 'display_name': 'Ethstorage', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3338', 'name': 'peaq', 'display_name': 'Peaq', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3400', 'name': 'paribu_net', 'display_name': 'Paribu Net', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3424', 'name': 'evolve', 'display_name': 'Evolve', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3434', 'name': 'secure_testnet', 'display_name': 'Secure Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3456', 'name': 'layeredge_testnet', 'display_name': 'Layeredge Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3490', 'name': 'gtcscan', 'display_name': 'Gtcscan', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3500', 'name': 'paribu_net_testnet', 'display_name': 'Paribu Net Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3501', 'name': 'jfin', 'display_name': 'Jfin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3601', 'name': 'pandoproject', 'display_name': 'Pandoproject', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3602', 'name': 'pandoproject_testnet', 'display_name': 'Pandoproject Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3630', 'name': 'tycooncoin', 'display_name': 'Tycooncoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3636', 'name': 'botanix_testnet', 'display_name': 'Botanix Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3637', 'name': 'botanix', 'display_name': 'Botanix', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3639', 'name': 'i', 'display_name': 'I', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3645', 'name': 'i_testnet', 'display_name': 'I Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3666', 'name': 'jouleverse', 'display_name': 'Jouleverse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3690', 'name': 'bittex', 'display_name': 'Bittex', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3693', 'name': 'empire', 'display_name': 'Empire', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3698', 'name': 'senjepowers_testnet', 'display_name': 'Senjepowers Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3699', 'name': 'senjepowers', 'display_name': 'Senjepowers', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3701', 'name': 'xpla_testnet', 'display_name': 'Xpla Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3737', 'name': 'crossbell', 'display_name': 'Crossbell', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3776', 'name': 'astar_zkevm', 'display_name': 'Astar Zkevm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3797', 'name': 'alvey', 'display_name': 'Alvey', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3799', 'name': 'tangle_testnet', 'display_name': 'Tangle Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3885', 'name': 'fire_zkevm_ghostrider', 'display_name': 'Fire Zkevm Ghostrider', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3888', 'name': 'kaly', 'display_name': 'Kaly', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3889', 'name': 'kaly_testnet', 'display_name': 'Kaly Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3912', 'name': 'drac', 'display_name': 'Drac', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3939', 'name': 'dos_tesnet', 'display_name': 'Dos Tesnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3966', 'name': 'dyno', 'display_name': 'Dyno', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3967', 'name': 'dyno_testnet', 'display_name': 'Dyno Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3993', 'name': 'apex_testnet', 'display_name': 'Apex Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3999', 'name': 'yuan', 'display_name': 'Yuan', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4000', 'name': 'ozone', 'display_name': 'Ozone', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4001', 'name': 'peperium_testnet', 'display_name': 'Peperium Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4002', 'name': 'fantom_testnet', 'display_name': 'Fantom Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4003', 'name': 'x1_fastnet', 'display_name': 'X1 Fastnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4040', 'name': 'carbonium_testnet', 'display_name': 'Carbonium Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4048', 'name': 'gan_testnet', 'display_name': 'Gan Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4051', 'name': 'bobaopera_testnet', 'display_name': 'Bobaopera Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4058', 'name': 'bahamut_ocean', 'display_name': 'Bahamut Ocean', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4061', 'name': 'nahmii_3', 'display_name': 'Nahmii 3', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4062', 'name': 'nahmii_3_testnet', 'display_name': 'Nahmii 3 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4078', 'name': 'muster', 'display_name': 'Muster', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4080', 'name': 'tobe_testnet', 'display_name': 'Tobe Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4088', 'name': 'zeroth', 'display_name': 'Zeroth', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4090', 'name': 'fastex_bahamut_oasis_testnet', 'display_name': 'Fastex Bahamut Oasis Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4096', 'name': 'bitindi_testnet', 'display_name': 'Bitindi Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4099', 'name': 'bitindi', 'display_name': 'Bitindi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4102', 'name': 'aioz_testnet', 'display_name': 'Aioz Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4139', 'name': 'humans.ai_testnet', 'display_name': 'Humans.ai Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4141', 'name': 'tipboxcoin_testnet', 'display_name': 'Tipboxcoin Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4157', 'name': 'crossfi_testnet', 'display_name': 'Crossfi Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4162', 'name': 'sx_rollup', 'display_name': 'Sx Rollup', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4181', 'name': 'phi_v1', 'display_name': 'Phi V1', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4200', 'name': 'merlin', 'display_name': 'Merlin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4201', 'name': 'lukso_testnet', 'display_name': 'Lukso Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4202', 'name': 'lisk_sepolia_testnet', 'display_name': 'Lisk Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4242', 'name': 'nexi', 'display_name': 'Nexi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4243', 'name': 'nexi_v2', 'display_name': 'Nexi V2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4269', 'name': 'laika_testnet', 'display_name': 'Laika Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4328', 'name': 'bobafuji_testnet', 'display_name': 'Bobafuji Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4337', 'name': 'beam', 'display_name': 'Beam', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4400', 'name': 'credit', 'display_name': 'Credit', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4444', 'name': 'htmlcoin', 'display_name': 'Htmlcoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4460', 'name': 'orderly_sepolia_testnet', 'display_name': 'Orderly Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4488', 'name': 'hydra', 'display_name': 'Hydra', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4544', 'name': 'emoney_testnet', 'display_name': 'Emoney Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4613', 'name': 'very', 'display_name': 'Very', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4653', 'name': 'gold', 'display_name': 'Gold', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4689', 'name': 'iotex', 'display_name': 'Iotex', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4690', 'name': 'iotex_testnet', 'display_name': 'Iotex Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4759', 'name': 'meverse_testnet', 'display_name': 'Meverse Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4777', 'name': 'blackfort_exchange_testnet', 'display_name': 'Blackfort Exchange Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4801', 'name': 'world_sepolia_testnet', 'display_name': 'World Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4893', 'name': 'globel', 'display_name': 'Globel', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4918', 'name': 'venidium_testnet', 'display_name': 'Venidium Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4919', 'name': 'venidium', 'display_name': 'Venidium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4999', 'name': 'blackfort_exchange', 'display_name': 'Blackfort Exchange', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5000', 'name': 'mantle', 'display_name': 'Mantle', 'aliases': [], 'testnet': False, 'parent': '1', 'currency': 'MNT', 'explorer': 'https://explorer.mantle.xyz'},
+    {'chain_id': '5001', 'name': 'mantle_testnet', 'display_name': 'Mantle Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5002', 'name': 'treasurenet_alpha', 'display_name': 'Treasurenet Alpha', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5003', 'name': 'mantle_sepolia_testnet', 'display_name': 'Mantle Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5005', 'name': 'treasurenet_testnet', 'display_name': 'Treasurenet Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5039', 'name': 'onigiri_test_subnet', 'display_name': 'Onigiri Test Subnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5040', 'name': 'onigiri_subnet', 'display_name': 'Onigiri Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5051', 'name': 'nollie_skate_testnet', 'display_name': 'Nollie Skate Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5100', 'name': 'syndicate_testnet', 'display_name': 'Syndicate Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5101', 'name': 'syndicate_frame', 'display_name': 'Syndicate Frame', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5102', 'name': 'sic_testnet', 'display_name': 'Sic Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5103', 'name': 'coordinape_testnet', 'display_name': 'Coordinape Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5104', 'name': 'charmverse_testnet', 'display_name': 'Charmverse Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5105', 'name': 'superloyalty_testnet', 'display_name': 'Superloyalty Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5106', 'name': 'azra_testnet', 'display_name': 'Azra Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5112', 'name': 'ham', 'display_name': 'Ham', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5151', 'name': 'me_testnet', 'display_name': 'Me Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5165', 'name': 'bahamut', 'display_name': 'Bahamut', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5169', 'name': 'smart_layer', 'display_name': 'Smart Layer', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5177', 'name': 'tl', 'display_name': 'Tl', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5197', 'name': 'eraswap', 'display_name': 'Eraswap', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5234', 'name': 'humanode', 'display_name': 'Humanode', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5290', 'name': 'fire_old', 'display_name': 'Fire Old', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5315', 'name': 'uzmi', 'display_name': 'Uzmi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5317', 'name': 'optrust_testnet', 'display_name': 'Optrust Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5321', 'name': 'itx_testnet', 'display_name': 'Itx Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5333', 'name': 'netsbo', 'display_name': 'Netsbo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5353', 'name': 'tritanium_testnet', 'display_name': 'Tritanium Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5372', 'name': 'settlus_testnet', 'display_name': 'Settlus Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5424', 'name': 'edexa', 'display_name': 'Edexa', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5439', 'name': 'ego', 'display_name': 'Ego', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5522', 'name': 'vex_evm_testnet', 'display_name': 'Vex Evm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5545', 'name': 'duck', 'display_name': 'Duck', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5551', 'name': 'nahmii_2', 'display_name': 'Nahmii 2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5553', 'name': 'nahmii_2_testnet', 'display_name': 'Nahmii 2 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5555', 'name': 'verse', 'display_name': 'Verse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5611', 'name': 'opbnb_testnet', 'display_name': 'Opbnb Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5615', 'name': 'arcturus_testneet', 'display_name': 'Arcturus Testneet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5616', 'name': 'arcturus_testnet', 'display_name': 'Arcturus Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5656', 'name': 'qie_block', 'display_name': 'Qie Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5675', 'name': 'filenova_testnet', 'display_name': 'Filenova Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5678', 'name': 'tanssi_demo', 'display_name': 'Tanssi Demo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5700', 'name': 'syscoin_tanenbaum_testnet', 'display_name': 'Syscoin Tanenbaum Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5729', 'name': 'hika_testnet', 'display_name': 'Hika Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5758', 'name': 'satoshi_testnet', 'display_name': 'Satoshi Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5777', 'name': 'ganache', 'display_name': 'Ganache', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5845', 'name': 'tangle', 'display_name': 'Tangle', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5851', 'name': 'ontology_testnet', 'display_name': 'Ontology Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5858', 'name': 'chang_foundation', 'display_name': 'Chang Foundation', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5869', 'name': 'wego_rubidium', 'display_name': 'Wego Rubidium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6000', 'name': 'bouncebit_testnet', 'display_name': 'Bouncebit Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6001', 'name': 'bouncebit', 'display_name': 'Bouncebit', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6065', 'name': 'tres_testnet', 'display_name': 'Tres Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6066', 'name': 'tres', 'display_name': 'Tres', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6102', 'name': 'cascadia_testnet', 'display_name': 'Cascadia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6118', 'name': 'uptn_testnet', 'display_name': 'Uptn Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6119', 'name': 'uptn', 'display_name': 'Uptn', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6283', 'name': 'laos', 'display_name': 'Laos', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6321', 'name': 'aura_euphoria_testnet', 'display_name': 'Aura Euphoria Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6322', 'name': 'aura', 'display_name': 'Aura', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6363', 'name': 'digit_soul', 'display_name': 'Digit Soul', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6398', 'name': 'connext_sepolia', 'display_name': 'Connext Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6502', 'name': 'peerpay', 'display_name': 'Peerpay', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6550', 'name': 'flamma_testnet', 'display_name': 'Flamma Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6552', 'name': 'scolcoin_wei_testnet', 'display_name': 'Scolcoin Wei Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6565', 'name': 'fox_testnet', 'display_name': 'Fox Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6626', 'name': 'pixie', 'display_name': 'Pixie', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6660', 'name': 'latest_testnet', 'display_name': 'Latest Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6661', 'name': 'cybria', 'display_name': 'Cybria', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6666', 'name': 'cybria_testnet', 'display_name': 'Cybria Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6667', 'name': 'stor', 'display_name': 'Stor', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6678', 'name': 'edge_matrix', 'display_name': 'Edge Matrix', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6688', 'name': 'irishub', 'display_name': 'Irishub', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6699', 'name': 'ox', 'display_name': 'Ox', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6701', 'name': 'paxb', 'display_name': 'Paxb', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6779', 'name': 'compverse', 'display_name': 'Compverse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6805', 'name': 'race', 'display_name': 'Race', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6806', 'name': 'race_testnet', 'display_name': 'Race Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6868', 'name': 'pools', 'display_name': 'Pools', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6880', 'name': 'mtt', 'display_name': 'Mtt', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6942', 'name': 'laika', 'display_name': 'Laika', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6969', 'name': 'tomb', 'display_name': 'Tomb', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6999', 'name': 'polysmart', 'display_name': 'Polysmart', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7000', 'name': 'zeta', 'display_name': 'Zeta', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7001', 'name': 'zeta_testnet', 'display_name': 'Zeta Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7007', 'name': 'bst', 'display_name': 'Bst', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7027', 'name': 'ella_the_heart', 'display_name': 'Ella The Heart', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7070', 'name': 'planq', 'display_name': 'Planq', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7077', 'name': 'planq_atlas_testnet', 'display_name': 'Planq Atlas Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7100', 'name': 'nume', 'display_name': 'Nume', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7118', 'name': 'help_the_homeless', 'display_name': 'Help The Homeless', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7171', 'name': 'bitrock', 'display_name': 'Bitrock', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7210', 'name': 'nibiru_testnet_1', 'display_name': 'Nibiru Testnet 1', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7222', 'name': 'nibiru_devnet_3', 'display_name': 'Nibiru Devnet 3', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7244', 'name': 'zeus_testnet', 'display_name': 'Zeus Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7300', 'name': 'xpla_verse', 'display_name': 'Xpla Verse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7331', 'name': 'klyntar', 'display_name': 'Klyntar', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7332', 'name': 'horizen_eon', 'display_name': 'Horizen Eon', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7341', 'name': 'shyft', 'display_name': 'Shyft', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7484', 'name': 'raba', 'display_name': 'Raba', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7518', 'name': 'meverse', 'display_name': 'Meverse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7560', 'name': 'cyber', 'display_name': 'Cyber', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7575', 'name': 'adil_testnet', 'display_name': 'Adil Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7576', 'name': 'adil_v2', 'display_name': 'Adil V2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7668', 'name': 'the_root', 'display_name': 'The Root', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7672', 'name': 'the_root_porcini_testnet', 'display_name': 'The Root Porcini Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7700', 'name': 'canto', 'display_name': 'Canto', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7701', 'name': 'canto_tesnet', 'display_name': 'Canto Tesnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7771', 'name': 'bitrock_testnet', 'display_name': 'Bitrock Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7774', 'name': 'gdcc', 'display_name': 'Gdcc', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7775', 'name': 'gdcc_testnet', 'display_name': 'Gdcc Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7776', 'name': 'pandasea', 'display_name': 'Pandasea', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7777', 'name': 'rise_of_the_warbots_testnet', 'display_name': 'Rise Of The Warbots Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7778', 'name': 'orenium_protocol', 'display_name': 'Orenium Protocol', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7798', 'name': 'openex_long_testnet', 'display_name': 'Openex Long Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7860', 'name': 'maal_testnet', 'display_name': 'Maal Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7862', 'name': 'maal_v2', 'display_name': 'Maal V2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7863', 'name': 'maal_testnet_v2', 'display_name': 'Maal Testnet V2', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7878', 'name': 'hazlor_testnet', 'display_name': 'Hazlor Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7887', 'name': 'kinto', 'display_name': 'Kinto', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7895', 'name': 'ardenium_athena', 'display_name': 'Ardenium Athena', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7923', 'name': 'dot_blox', 'display_name': 'Dot Blox', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7924', 'name': 'mo', 'display_name': 'Mo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7979', 'name': 'dos', 'display_name': 'Dos', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8000', 'name': 'teleport', 'display_name': 'Teleport', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8001', 'name': 'teleport_testnet', 'display_name': 'Teleport Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8008', 'name': 'polynomial', 'display_name': 'Polynomial', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8017', 'name': 'isuncoin', 'display_name': 'Isuncoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8029', 'name': 'mdgl_testnet', 'display_name': 'Mdgl Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8047', 'name': 'boat', 'display_name': 'Boat', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8054', 'name': 'karak_sepolia', 'display_name': 'Karak Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8080', 'name': 'shardeum_liberty_1.x', 'display_name': 'Shardeum Liberty 1.x', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8081', 'name': 'shardeum_liberty_2.x', 'display_name': 'Shardeum Liberty 2.x', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8082', 'name': 'shardeum_sphinx_1.x', 'display_name': 'Shardeum Sphinx 1.x', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8086', 'name': 'bitcoin', 'display_name': 'Bitcoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8087', 'name': 'e_dollar', 'display_name': 'E Dollar', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8098', 'name': 'streamux_block', 'display_name': 'Streamux Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8131', 'name': 'qitmeer_testnet', 'display_name': 'Qitmeer Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8132', 'name': 'qitmeer_mixnet', 'display_name': 'Qitmeer Mixnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8133', 'name': 'qitmeer_privnet', 'display_name': 'Qitmeer Privnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8134', 'name': 'amana', 'display_name': 'Amana', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8135', 'name': 'flana', 'display_name': 'Flana', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8136', 'name': 'mizana', 'display_name': 'Mizana', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8181', 'name': 'testnet_beone', 'display_name': 'Testnet Beone', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8192', 'name': 'torus', 'display_name': 'Torus', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8194', 'name': 'torus_testnet', 'display_name': 'Torus Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8217', 'name': 'kaia', 'display_name': 'Kaia', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8227', 'name': 'space_subnet', 'display_name': 'Space Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8272', 'name': 'blockton_block', 'display_name': 'Blockton Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8285', 'name': 'korthotest', 'display_name': 'Korthotest', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8329', 'name': 'lorenzo', 'display_name': 'Lorenzo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8333', 'name': 'b3', 'display_name': 'B3', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8387', 'name': 'dracones_financial_services', 'display_name': 'Dracones Financial Services', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8428', 'name': 'that', 'display_name': 'That', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8453', 'name': 'base', 'display_name': 'Base', 'aliases': ['base_mainnet'], 'testnet': False, 'parent': '1', 'currency': 'ETH', 'explorer': 'https://basescan.org'},
+    {'chain_id': '8545', 'name': 'chakra_testnet', 'display_name': 'Chakra Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8654', 'name': 'toki', 'display_name': 'Toki', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8655', 'name': 'toki_testnet', 'display_name': 'Toki Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8668', 'name': 'hela_official_runtime', 'display_name': 'Hela Official Runtime', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8723', 'name': 'tool_global', 'display_name': 'Tool Global', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8724', 'name': 'tool_global_testnet', 'display_name': 'Tool Global Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8726', 'name': 'storage', 'display_name': 'Storage', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8727', 'name': 'storage_testnet', 'display_name': 'Storage Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8732', 'name': 'bullions', 'display_name': 'Bullions', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8738', 'name': 'alph', 'display_name': 'Alph', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8768', 'name': 'tmy', 'display_name': 'Tmy', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8822', 'name': 'iota_evm', 'display_name': 'Iota Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8844', 'name': 'hydra_testnet', 'display_name': 'Hydra Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8848', 'name': 'maro_block', 'display_name': 'Maro Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8866', 'name': 'superlumio', 'display_name': 'Superlumio', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8869', 'name': 'lif3', 'display_name': 'Lif3', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8880', 'name': 'unique', 'display_name': 'Unique', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8881', 'name': 'quartz_by_unique', 'display_name': 'Quartz By Unique', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8882', 'name': 'opal_testnet_by_unique', 'display_name': 'Opal Testnet By Unique', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8883', 'name': 'sapphire_by_unique', 'display_name': 'Sapphire By Unique', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8886', 'name': 'avenium_testnet', 'display_name': 'Avenium Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8888', 'name': 'xana', 'display_name': 'Xana', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8889', 'name': 'vyvo', 'display_name': 'Vyvo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8890', 'name': 'orenium_testnet_protocol', 'display_name': 'Orenium Testnet Protocol', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8898', 'name': 'mammoth', 'display_name': 'Mammoth', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8899', 'name': 'jib', 'display_name': 'Jib', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8911', 'name': 'algen', 'display_name': 'Algen', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8912', 'name': 'algen_testnet', 'display_name': 'Algen Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8921', 'name': 'algen_layer2', 'display_name': 'Algen Layer2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8922', 'name': 'algen_layer2_testnet', 'display_name': 'Algen Layer2 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8989', 'name': 'giant_mammoth', 'display_name': 'Giant Mammoth', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8995', 'name': 'bloxberg', 'display_name': 'Bloxberg', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9000', 'name': 'evmos_testnet', 'display_name': 'Evmos Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9001', 'name': 'evmos', 'display_name': 'Evmos', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9007', 'name': 'shido_testnet_block', 'display_name': 'Shido Testnet Block', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9008', 'name': 'shido_block', 'display_name': 'Shido Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9012', 'name': 'berylbit', 'display_name': 'Berylbit', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9024', 'name': 'nexa_testnet_block', 'display_name': 'Nexa Testnet Block', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9025', 'name': 'nexa_block', 'display_name': 'Nexa Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9069', 'name': 'apex_fusion_nexus', 'display_name': 'Apex Fusion Nexus', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9070', 'name': 'apex_fusion_nexus_testnet', 'display_name': 'Apex Fusion Nexus Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9100', 'name': 'genesis_coin', 'display_name': 'Genesis Coin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9170', 'name': 'rinia_testnet_old', 'display_name': 'Rinia Testnet Old', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9223', 'name': 'codefin', 'display_name': 'Codefin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9339', 'name': 'dogcoin_testnet', 'display_name': 'Dogcoin Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9372', 'name': 'oasys_testnet', 'display_name': 'Oasys Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9393', 'name': 'dela_sepolia_testnet', 'display_name': 'Dela Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9395', 'name': 'evoke', 'display_name': 'Evoke', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9496', 'name': 'weavevm_testnet', 'display_name': 'Weavevm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9527', 'name': 'rangers_protocol_testnet_robin', 'display_name': 'Rangers Protocol Testnet Robin', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9528', 'name': 'qeasyweb3_testnet', 'display_name': 'Qeasyweb3 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9559', 'name': 'neonlink_testnet', 'display_name': 'Neonlink Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9700', 'name': 'oortdev', 'display_name': 'Oortdev', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9728', 'name': 'boba_bnb_testnet', 'display_name': 'Boba Bnb Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9768', 'name': 'mainnetz_testnet', 'display_name': 'Mainnetz Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9779', 'name': 'pepenetwork', 'display_name': 'Pepenetwork', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9789', 'name': 'tabi_testnet', 'display_name': 'Tabi Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9790', 'name': 'carbon_evm', 'display_name': 'Carbon Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9792', 'name': 'carbon_evm_testnet', 'display_name': 'Carbon Evm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9797', 'name': 'optimusz7', 'display_name': 'Optimusz7', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9818', 'name': 'imperium_testnet', 'display_name': 'Imperium Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9819', 'name': 'imperium', 'display_name': 'Imperium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9876', 'name': 'binary_testnet', 'display_name': 'Binary Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9888', 'name': 'dogelayer', 'display_name': 'Dogelayer', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9897', 'name': 'arena_z_testnet', 'display_name': 'Arena Z Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9898', 'name': 'larissa', 'display_name': 'Larissa', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9911', 'name': 'espento', 'display_name': 'Espento', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9966', 'name': 'uxer_testnet', 'display_name': 'Uxer Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9977', 'name': 'mind_testnet', 'display_name': 'Mind Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9980', 'name': 'combo', 'display_name': 'Combo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9981', 'name': 'volley', 'display_name': 'Volley', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9990', 'name': 'agung', 'display_name': 'Agung', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9997', 'name': 'altlayer_testnet', 'display_name': 'Altlayer Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9998', 'name': 'ztc', 'display_name': 'Ztc', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9999', 'name': 'myown_testnet', 'display_name': 'Myown Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10000', 'name': 'smart_bitcoin_cash', 'display_name': 'Smart Bitcoin Cash', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10001', 'name': 'smart_bitcoin_cash_testnet', 'display_name': 'Smart Bitcoin Cash Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10024', 'name': 'gon', 'display_name': 'Gon', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10081', 'name': 'japan_open_testnet', 'display_name': 'Japan Open Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10086', 'name': 'sjatsh', 'display_name': 'Sjatsh', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10096', 'name': 'metanova_verse', 'display_name': 'Metanova Verse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10101', 'name': 'block_genesis', 'display_name': 'Block Genesis', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10200', 'name': 'gnosis_chiado_testnet', 'display_name': 'Gnosis Chiado Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10201', 'name': 'maxx', 'display_name': 'Maxx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10222', 'name': 'glscan', 'display_name': 'Glscan', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10242', 'name': 'arthera', 'display_name': 'Arthera', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10243', 'name': 'arthera_testnet', 'display_name': 'Arthera Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10248', 'name': '0xtade', 'display_name': '0xtade', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10321', 'name': 'tao_evm', 'display_name': 'Tao Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10324', 'name': 'tao_evm_testnet', 'display_name': 'Tao Evm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10395', 'name': 'worldland_testnet', 'display_name': 'Worldland Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10507', 'name': 'numbers', 'display_name': 'Numbers', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10508', 'name': 'numbers_testnet', 'display_name': 'Numbers Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10823', 'name': 'cryptocoinpay', 'display_name': 'Cryptocoinpay', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10849', 'name': 'lamina1', 'display_name': 'Lamina1', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10850', 'name': 'lamina1_identity', 'display_name': 'Lamina1 Identity', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10946', 'name': 'quadrans_block', 'display_name': 'Quadrans Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10947', 'name': 'quadrans_block_testnet', 'display_name': 'Quadrans Block Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11000', 'name': 'kb', 'display_name': 'Kb', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11011', 'name': 'shape_sepolia_testnet', 'display_name': 'Shape Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11110', 'name': 'astra', 'display_name': 'Astra', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11111', 'name': 'wagmi', 'display_name': 'Wagmi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11115', 'name': 'astra_testnet', 'display_name': 'Astra Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11119', 'name': 'hashbit', 'display_name': 'Hashbit', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11124', 'name': 'abstract_testnet', 'display_name': 'Abstract Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11221', 'name': 'shine', 'display_name': 'Shine', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11227', 'name': 'jiritsu_testnet_subnet', 'display_name': 'Jiritsu Testnet Subnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11235', 'name': 'haqq', 'display_name': 'Haqq', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11437', 'name': 'shyft_testnet', 'display_name': 'Shyft Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11501', 'name': 'bevm', 'display_name': 'Bevm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11503', 'name': 'bevm_testnet', 'display_name': 'Bevm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11521', 'name': 'sats', 'display_name': 'Sats', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11612', 'name': 'sardis_testnet', 'display_name': 'Sardis Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11822', 'name': 'artela_testnet', 'display_name': 'Artela Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11891', 'name': 'polygon_supernet_arianee', 'display_name': 'Polygon Supernet Arianee', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12001', 'name': 'fuse_testnet', 'display_name': 'Fuse Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12009', 'name': 'satoshi', 'display_name': 'Satoshi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12020', 'name': 'aternos', 'display_name': 'Aternos', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12051', 'name': 'singularity_zero_testnet', 'display_name': 'Singularity Zero Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12052', 'name': 'singularity_zero', 'display_name': 'Singularity Zero', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12123', 'name': 'brc', 'display_name': 'Brc', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12306', 'name': 'fibonacci', 'display_name': 'Fibonacci', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12321', 'name': 'blg_testnet', 'display_name': 'Blg Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12324', 'name': 'l3x_protocol', 'display_name': 'L3x Protocol', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12325', 'name': 'l3x_protocol_testnet', 'display_name': 'L3x Protocol Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12345', 'name': 'step_testnet', 'display_name': 'Step Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12553', 'name': 'rss3_vsl', 'display_name': 'Rss3 Vsl', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12715', 'name': 'rikeza_testnet', 'display_name': 'Rikeza Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12781', 'name': 'playdapp_testnet', 'display_name': 'Playdapp Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12890', 'name': 'quantum_testnet', 'display_name': 'Quantum Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12898', 'name': 'playfair_testnet_subnet', 'display_name': 'Playfair Testnet Subnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '13000', 'name': 'sps', 'display_name': 'Sps', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '13337', 'name': 'beam_testnet', 'display_name': 'Beam Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '13370', 'name': 'cannon_testnet', 'display_name': 'Cannon Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '13371', 'name': 'immutable_zkevm', 'display_name': 'Immutable Zkevm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '13381', 'name': 'phoenix', 'display_name': 'Phoenix', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '13396', 'name': 'masa', 'display_name': 'Masa', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '13473', 'name': 'immutable_zkevm_testnet', 'display_name': 'Immutable Zkevm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '13505', 'name': 'gravity_alpha_testnet_sepolia', 'display_name': 'Gravity Alpha Testnet Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '13600', 'name': 'kronobit', 'display_name': 'Kronobit', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '13812', 'name': 'susono', 'display_name': 'Susono', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '14000', 'name': 'sps_testnet', 'display_name': 'Sps Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '14088', 'name': 'zeroth_testnet', 'display_name': 'Zeroth Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '14324', 'name': 'evolve_testnet', 'display_name': 'Evolve Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '14333', 'name': 'vitruveo_testnet', 'display_name': 'Vitruveo Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '14800', 'name': 'vana_moksha_testnet', 'display_name': 'Vana Moksha Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '14801', 'name': 'vana_satori_testnet', 'display_name': 'Vana Satori Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '14853', 'name': 'humanode_testnet_5_israfel', 'display_name': 'Humanode Testnet 5 Israfel', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '15003', 'name': 'immutable_zkevm_devnet', 'display_name': 'Immutable Zkevm Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '15257', 'name': 'poodl_testnet', 'display_name': 'Poodl Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '15259', 'name': 'poodl', 'display_name': 'Poodl', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '15430', 'name': 'kymtc', 'display_name': 'Kymtc', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '15551', 'name': 'loopnetwork', 'display_name': 'Loopnetwork', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '15555', 'name': 'trust_evm_testnet', 'display_name': 'Trust Evm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '15557', 'name': 'eos_evm_testnet', 'display_name': 'Eos Evm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '16000', 'name': 'metadot', 'display_name': 'Metadot', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '16001', 'name': 'metadot_testnet', 'display_name': 'Metadot Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '16116', 'name': 'defiverse', 'display_name': 'Defiverse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '16166', 'name': 'cypherium', 'display_name': 'Cypherium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '16180', 'name': 'plyr_phi', 'display_name': 'Plyr Phi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '16350', 'name': 'incentiv_devnet', 'display_name': 'Incentiv Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '16481', 'name': 'pivotal_sepolia', 'display_name': 'Pivotal Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '16507', 'name': 'genesys', 'display_name': 'Genesys', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '16600', 'name': '0g_newton_testnet', 'display_name': '0g Newton Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '16688', 'name': 'irishub_testnet', 'display_name': 'Irishub Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '16718', 'name': 'airdao', 'display_name': 'Airdao', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '16888', 'name': 'ivar_testnet', 'display_name': 'Ivar Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '17000', 'name': 'holesky', 'display_name': 'Holesky', 'aliases': ['eth_holesky'], 'testnet': True, 'parent': None, 'currency': 'ETH', 'explorer': 'https://holesky.etherscan.io'},
+    {'chain_id': '17001', 'name': 'redstone_holesky_testnet', 'display_name': 'Redstone Holesky Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '17069', 'name': 'garnet_holesky', 'display_name': 'Garnet Holesky', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '17071', 'name': 'on_points', 'display_name': 'On Points', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '17117', 'name': 'defiverse_testnet', 'display_name': 'Defiverse Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '17171', 'name': 'g8', 'display_name': 'G8', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '17172', 'name': 'eclipse_subnet', 'display_name': 'Eclipse Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '17180', 'name': 'palette_testnet', 'display_name': 'Palette Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '17217', 'name': 'konet', 'display_name': 'Konet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '17777', 'name': 'eos_evm', 'display_name': 'Eos Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '18000', 'name': 'frontier_of_dreams_testnet', 'display_name': 'Frontier Of Dreams Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '18122', 'name': 'smart_trades', 'display_name': 'Smart Trades', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '18159', 'name': 'proof_of_memes', 'display_name': 'Proof Of Memes', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '18181', 'name': 'g8_testnet', 'display_name': 'G8 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '18231', 'name': 'unreal_old', 'display_name': 'Unreal Old', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '18233', 'name': 'unreal', 'display_name': 'Unreal', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '18686', 'name': 'mxc_zkevm_moon', 'display_name': 'Mxc Zkevm Moon', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '18888', 'name': 'titan_tkx', 'display_name': 'Titan Tkx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '18889', 'name': 'titan_tkx_testnet', 'display_name': 'Titan Tkx Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '19011', 'name': 'home_verse', 'display_name': 'Home Verse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '19077', 'name': 'blockx_atlantis_testnet', 'display_name': 'Blockx Atlantis Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '19191', 'name': 'blockx', 'display_name': 'Blockx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '19224', 'name': 'decentraconnect_social', 'display_name': 'Decentraconnect Social', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '19527', 'name': 'magnet', 'display_name': 'Magnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '19600', 'name': 'lbry', 'display_name': 'Lbry', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '19845', 'name': 'btcix', 'display_name': 'Btcix', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20001', 'name': 'camelark', 'display_name': 'Camelark', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20041', 'name': 'niza', 'display_name': 'Niza', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20073', 'name': 'niza_testnet', 'display_name': 'Niza Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20729', 'name': 'callisto_testnet', 'display_name': 'Callisto Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20736', 'name': 'p12', 'display_name': 'P12', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20765', 'name': 'jono11_subnet', 'display_name': 'Jono11 Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '21004', 'name': 'c4ei', 'display_name': 'C4ei', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '21133', 'name': 'all_about_healthy', 'display_name': 'All About Healthy', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '21223', 'name': 'dcpay', 'display_name': 'Dcpay', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '21224', 'name': 'dcpay_testnet', 'display_name': 'Dcpay Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '21337', 'name': 'cennznet_azalea', 'display_name': 'Cennznet Azalea', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '21363', 'name': 'lestnet', 'display_name': 'Lestnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '21816', 'name': 'om', 'display_name': 'Om', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '21912', 'name': 'bsl', 'display_name': 'Bsl', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '22023', 'name': 'taycan', 'display_name': 'Taycan', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '22040', 'name': 'airdao_testnet', 'display_name': 'Airdao Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '22222', 'name': 'nautilus', 'display_name': 'Nautilus', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '22324', 'name': 'goldx_testnet', 'display_name': 'Goldx Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '22776', 'name': 'map_protocol', 'display_name': 'Map Protocol', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '23006', 'name': 'antofy_testnet', 'display_name': 'Antofy Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '23118', 'name': 'opside_testnet', 'display_name': 'Opside Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '23294', 'name': 'oasis_sapphire', 'display_name': 'Oasis Sapphire', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '23295', 'name': 'oasis_sapphire_testnet', 'display_name': 'Oasis Sapphire Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '23451', 'name': 'dreyerx', 'display_name': 'Dreyerx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '23452', 'name': 'dreyerx_testnet', 'display_name': 'Dreyerx Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '23888', 'name': 'blast_testnet', 'display_name': 'Blast Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '24076', 'name': 'kymtc_testnet', 'display_name': 'Kymtc Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '24484', 'name': 'web', 'display_name': 'Web', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '24734', 'name': 'mintme.com', 'display_name': 'Mintme.com', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '25186', 'name': 'liquidlayer', 'display_name': 'Liquidlayer', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '25327', 'name': 'everclear', 'display_name': 'Everclear', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '25839', 'name': 'alvey_testnet', 'display_name': 'Alvey Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '25888', 'name': 'hammer', 'display_name': 'Hammer', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '25925', 'name': 'bitkub_testnet', 'display_name': 'Bitkub Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '26026', 'name': 'ferrum_testnet', 'display_name': 'Ferrum Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '26482', 'name': 'ducatusx_testnet', 'display_name': 'Ducatusx Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '26483', 'name': 'ducatusx', 'display_name': 'Ducatusx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '26600', 'name': 'hertz', 'display_name': 'Hertz', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '26863', 'name': 'oasis', 'display_name': 'Oasis', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '27181', 'name': 'klaos_nova', 'display_name': 'Klaos Nova', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '27483', 'name': 'nanon_sepolia', 'display_name': 'Nanon Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '27827', 'name': 'zeroone_subnet', 'display_name': 'Zeroone Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '28516', 'name': 'vizing_testnet', 'display_name': 'Vizing Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '28518', 'name': 'vizing', 'display_name': 'Vizing', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '28528', 'name': 'optimism_bedrock_goerli_alpha_testnet', 'display_name': 'Optimism Bedrock Goerli Alpha Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '28882', 'name': 'boba_sepolia', 'display_name': 'Boba Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '29112', 'name': 'hy_testnet', 'display_name': 'Hy Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '29223', 'name': 'nexa_metanet', 'display_name': 'Nexa Metanet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '29536', 'name': 'kai_testnet', 'display_name': 'Kai Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '29548', 'name': 'mch_verse', 'display_name': 'Mch Verse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '30000', 'name': 'q', 'display_name': 'Q', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '30067', 'name': 'piece_testnet', 'display_name': 'Piece Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '30088', 'name': 'miyou', 'display_name': 'Miyou', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '30103', 'name': 'cerium_testnet', 'display_name': 'Cerium Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '30730', 'name': 'movement_evm_legacy', 'display_name': 'Movement Evm Legacy', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '30731', 'name': 'movement_evm_devnet', 'display_name': 'Movement Evm Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '30732', 'name': 'movement_evm_testnet', 'display_name': 'Movement Evm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '31102', 'name': 'ethersocial', 'display_name': 'Ethersocial', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '31223', 'name': 'cloudtx', 'display_name': 'Cloudtx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '31224', 'name': 'cloudtx_testnet', 'display_name': 'Cloudtx Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '31337', 'name': 'go_testnet', 'display_name': 'Go Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '31414', 'name': 'evoke_testnet', 'display_name': 'Evoke Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '31415', 'name': 'filecoin_wallaby_testnet', 'display_name': 'Filecoin Wallaby Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '31753', 'name': 'x', 'display_name': 'X', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '31754', 'name': 'x_testnet', 'display_name': 'X Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '32001', 'name': 'w3gamez_holesky_testnet', 'display_name': 'W3gamez Holesky Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '32382', 'name': 'santiment_intelligence', 'display_name': 'Santiment Intelligence', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '32520', 'name': 'bitgert', 'display_name': 'Bitgert', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '32659', 'name': 'fusion', 'display_name': 'Fusion', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '32769', 'name': 'zilliqa_evm', 'display_name': 'Zilliqa Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '32990', 'name': 'zilliqa_evm_isolated_server', 'display_name': 'Zilliqa Evm Isolated Server', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '33033', 'name': 'entangle', 'display_name': 'Entangle', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '33101', 'name': 'zilliqa_evm_testnet', 'display_name': 'Zilliqa Evm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '33103', 'name': 'zilliqa_2_evm_proto_testnet', 'display_name': 'Zilliqa 2 Evm Proto Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '33111', 'name': 'curtis', 'display_name': 'Curtis', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '33133', 'name': 'entangle_testnet', 'display_name': 'Entangle Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '33210', 'name': 'cloudverse_subnet', 'display_name': 'Cloudverse Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '33333', 'name': 'aves', 'display_name': 'Aves', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '33385', 'name': 'zilliqa_evm_devnet', 'display_name': 'Zilliqa Evm Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '33401', 'name': 'slingshot', 'display_name': 'Slingshot', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '33469', 'name': 'zilliqa_2_evm_devnet', 'display_name': 'Zilliqa 2 Evm Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '33979', 'name': 'funki', 'display_name': 'Funki', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '34443', 'name': 'mode', 'display_name': 'Mode', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '34504', 'name': 'zeus', 'display_name': 'Zeus', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '35011', 'name': 'j2o_taro', 'display_name': 'J2o Taro', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '35443', 'name': 'q_testnet', 'display_name': 'Q Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '38400', 'name': 'connectormanager', 'display_name': 'Connectormanager', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '38401', 'name': 'connectormanager_robin', 'display_name': 'Connectormanager Robin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '39656', 'name': 'prm', 'display_name': 'Prm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '39797', 'name': 'energi', 'display_name': 'Energi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '39815', 'name': 'oho', 'display_name': 'Oho', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '40000', 'name': 'div', 'display_name': 'Div', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '41455', 'name': 'aleph_zero_evm', 'display_name': 'Aleph Zero Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '41500', 'name': 'opulent_x_beta', 'display_name': 'Opulent X Beta', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '42069', 'name': 'pegglecoin', 'display_name': 'Pegglecoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '42072', 'name': 'agentlayer_testnet', 'display_name': 'Agentlayer Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '42161', 'name': 'arbitrum', 'display_name': 'Arbitrum One', 'aliases': ['arb', 'arb1', 'arbitrum_one'], 'testnet': False, 'parent': '1', 'currency': 'ETH', 'explorer': 'https://arbiscan.io'},
+    {'chain_id': '42170', 'name': 'arbitrum_nova', 'display_name': 'Arbitrum Nova', 'aliases': [], 'testnet': False, 'parent': '1', 'currency': 'ETH', 'explorer': 'https://nova.arbiscan.io'},
+    {'chain_id': '42220', 'name': 'celo', 'display_name': 'Celo', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '42261', 'name': 'oasis_emerald_testnet', 'display_name': 'Oasis Emerald Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '42262', 'name': 'oasis_emerald', 'display_name': 'Oasis Emerald', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '42355', 'name': 'goldx', 'display_name': 'Goldx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '42421', 'name': 'asset_testnet', 'display_name': 'Asset Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '42766', 'name': 'zkfair', 'display_name': 'Zkfair', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '42793', 'name': 'etherlink', 'display_name': 'Etherlink', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '42801', 'name': 'gesoten_verse_testnet', 'display_name': 'Gesoten Verse Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '42888', 'name': 'kinto_testnet', 'display_name': 'Kinto Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '43110', 'name': 'athereum', 'display_name': 'Athereum', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '43111', 'name': 'hemi', 'display_name': 'Hemi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '43113', 'name': 'avalanche_fuji_testnet', 'display_name': 'Avalanche Fuji Testnet', 'aliases': ['fuji'], 'testnet': True, 'parent': None, 'currency': 'AVAX', 'explorer': 'https://testnet.snowtrace.io'},
+    {'chain_id': '43114', 'name': 'avalanche', 'display_name': 'Avalanche C-Chain', 'aliases': ['avax', 'avalanche_c'], 'testnet': False, 'parent': None, 'currency': 'AVAX', 'explorer': 'https://snowtrace.io'},
+    {'chain_id': '43288', 'name': 'boba_avax', 'display_name': 'Boba Avax', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '43851', 'name': 'zkfair_testnet', 'display_name': 'Zkfair Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '44444', 'name': 'fren', 'display_name': 'Fren', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '44445', 'name': 'quantum', 'display_name': 'Quantum', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '44787', 'name': 'celo_alfajores_testnet', 'display_name': 'Celo Alfajores Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '45000', 'name': 'autobahn', 'display_name': 'Autobahn', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '45454', 'name': 'swamps_l2', 'display_name': 'Swamps L2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '45510', 'name': 'deelance', 'display_name': 'Deelance', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '45513', 'name': 'blessnet', 'display_name': 'Blessnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '46688', 'name': 'fusion_testnet', 'display_name': 'Fusion Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '47763', 'name': 'neo_x', 'display_name': 'Neo X', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '47803', 'name': 'redefi_layer_1', 'display_name': 'Redefi Layer 1', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '47805', 'name': 'rei', 'display_name': 'Rei', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '48795', 'name': 'space_subnet_testnet', 'display_name': 'Space Subnet Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '48899', 'name': 'zircuit_testnet', 'display_name': 'Zircuit Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '48900', 'name': 'zircuit', 'display_name': 'Zircuit', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '49049', 'name': 'wireshape_floripa_testnet', 'display_name': 'Wireshape Floripa Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '49088', 'name': 'bifrost_testnet', 'display_name': 'Bifrost Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '49321', 'name': 'gunz_testnet', 'display_name': 'Gunz Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '49797', 'name': 'energi_testnet', 'display_name': 'Energi Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '50001', 'name': 'liveplex_oracleevm', 'display_name': 'Liveplex Oracleevm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '50005', 'name': 'yooldo_verse', 'display_name': 'Yooldo Verse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '50006', 'name': 'yooldo_verse_testnet', 'display_name': 'Yooldo Verse Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '50021', 'name': 'gton_testnet', 'display_name': 'Gton Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '50888', 'name': 'erbie', 'display_name': 'Erbie', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '51178', 'name': 'lumoz_testnet_alpha', 'display_name': 'Lumoz Testnet Alpha', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '51712', 'name': 'sardis', 'display_name': 'Sardis', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '52014', 'name': 'electroneum', 'display_name': 'Electroneum', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '53277', 'name': 'doid', 'display_name': 'Doid', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '53302', 'name': 'superseed_sepolia_testnet', 'display_name': 'Superseed Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '53457', 'name': 'dodo_testnet', 'display_name': 'Dodo Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '53935', 'name': 'dfk', 'display_name': 'Dfk', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '54176', 'name': 'overprotocol', 'display_name': 'Overprotocol', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '54211', 'name': 'haqq_testnet', 'display_name': 'Haqq Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '54321', 'name': 'toronet_testnet', 'display_name': 'Toronet Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '55004', 'name': 'titan', 'display_name': 'Titan', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '55551', 'name': 'photon_aurora_testnet', 'display_name': 'Photon Aurora Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '55556', 'name': 'rei_testnet', 'display_name': 'Rei Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '55614', 'name': 'flamma', 'display_name': 'Flamma', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '56026', 'name': 'lambda', 'display_name': 'Lambda', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '56288', 'name': 'boba_bnb', 'display_name': 'Boba Bnb', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '56400', 'name': 'testnet_zeroone_subnet', 'display_name': 'Testnet Zeroone Subnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '56789', 'name': 'velo_labs', 'display_name': 'Velo Labs', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '56797', 'name': 'doid_testnet', 'display_name': 'Doid Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '57000', 'name': 'rollux_testnet', 'display_name': 'Rollux Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '57451', 'name': 'coinsec', 'display_name': 'Coinsec', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '58008', 'name': 'sepolia_pgn_public_goods', 'display_name': 'Sepolia Pgn Public Goods', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '58680', 'name': 'lumoz_quidditch_testnet', 'display_name': 'Lumoz Quidditch Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '59140', 'name': 'linea_goerli', 'display_name': 'Linea Goerli', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '59141', 'name': 'linea_sepolia', 'display_name': 'Linea Sepolia', 'aliases': [], 'testnet': True, 'parent': '11155111', 'currency': 'ETH', 'explorer': 'https://sepolia.lineascan.build'},
+    {'chain_id': '59144', 'name': 'linea', 'display_name': 'Linea', 'aliases': ['linea_mainnet'], 'testnet': False, 'parent': '1', 'currency': 'ETH', 'explorer': 'https://lineascan.build'},
+    {'chain_id': '59902', 'name': 'metis_sepolia_testnet', 'display_name': 'Metis Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '59971', 'name': 'genesys_code', 'display_name': 'Genesys Code', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '60000', 'name': 'thinkium_testnet_0', 'display_name': 'Thinkium Testnet 0', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '60001', 'name': 'thinkium_testnet_1', 'display_name': 'Thinkium Testnet 1', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '60002', 'name': 'thinkium_testnet_2', 'display_name': 'Thinkium Testnet 2', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '60103', 'name': 'thinkium_testnet_103', 'display_name': 'Thinkium Testnet 103', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '60808', 'name': 'bob', 'display_name': 'Bob', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '61022', 'name': 'orange', 'display_name': 'Orange', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '61406', 'name': 'kai', 'display_name': 'Kai', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '61800', 'name': 'axel_dev_net', 'display_name': 'Axel Dev Net', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '61803', 'name': 'etica', 'display_name': 'Etica', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '61916', 'name': 'doken_super', 'display_name': 'Doken Super', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '62049', 'name': 'optopia_testnet', 'display_name': 'Optopia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '62050', 'name': 'optopia', 'display_name': 'Optopia', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '62298', 'name': 'citrea_devnet', 'display_name': 'Citrea Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '62320', 'name': 'celo_baklava_testnet', 'display_name': 'Celo Baklava Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '62621', 'name': 'multivac', 'display_name': 'Multivac', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '62831', 'name': 'plyr_tau_testnet', 'display_name': 'Plyr Tau Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '62850', 'name': 'laos_sigma_testnet', 'display_name': 'Laos Sigma Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '63000', 'name': 'ecredits', 'display_name': 'Ecredits', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '63001', 'name': 'ecredits_testnet', 'display_name': 'Ecredits Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '63002', 'name': 'esync_testnet', 'display_name': 'Esync Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '65349', 'name': 'cratd2c_testnet', 'display_name': 'Cratd2c Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '65357', 'name': 'vecno', 'display_name': 'Vecno', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '65450', 'name': 'scolcoin', 'display_name': 'Scolcoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '65536', 'name': 'automata', 'display_name': 'Automata', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '66665', 'name': 'creator_testnet', 'display_name': 'Creator Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '66988', 'name': 'janus_testnet', 'display_name': 'Janus Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '67390', 'name': 'siriusnet', 'display_name': 'Siriusnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '67588', 'name': 'cosmic', 'display_name': 'Cosmic', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '68770', 'name': 'dm2_verse', 'display_name': 'Dm2 Verse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '68775', 'name': 'dm2_verse_testnet', 'display_name': 'Dm2 Verse Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '69420', 'name': 'condrieu', 'display_name': 'Condrieu', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '70000', 'name': 'thinkium_0', 'display_name': 'Thinkium 0', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '70001', 'name': 'thinkium_1', 'display_name': 'Thinkium 1', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '70002', 'name': 'thinkium_2', 'display_name': 'Thinkium 2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '70103', 'name': 'thinkium_103', 'display_name': 'Thinkium 103', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '70700', 'name': 'proof_of_play_apex', 'display_name': 'Proof Of Play Apex', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '71111', 'name': 'guapcoinx', 'display_name': 'Guapcoinx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '71393', 'name': 'polyjuice_testnet', 'display_name': 'Polyjuice Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '71401', 'name': 'godwoken_testnet_v1', 'display_name': 'Godwoken Testnet V1', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '71402', 'name': 'godwoken', 'display_name': 'Godwoken', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '72778', 'name': 'caga_crypto_ankara_testnet', 'display_name': 'Caga Crypto Ankara Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '72992', 'name': 'grok', 'display_name': 'Grok', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '73114', 'name': 'icb_testnet', 'display_name': 'Icb Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '73115', 'name': 'icb', 'display_name': 'Icb', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '73799', 'name': 'energy_web_volta_testnet', 'display_name': 'Energy Web Volta Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '73927', 'name': 'mixin_virtual_machine', 'display_name': 'Mixin Virtual Machine', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '75000', 'name': 'resincoin', 'display_name': 'Resincoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '75512', 'name': 'geek_verse', 'display_name': 'Geek Verse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '75513', 'name': 'geek_verse_testnet', 'display_name': 'Geek Verse Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '77001', 'name': 'bora', 'display_name': 'Bora', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '77238', 'name': 'foundry_testnet', 'display_name': 'Foundry Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '77612', 'name': 'vention', 'display_name': 'Vention', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '77677', 'name': 'cycle_sailboat', 'display_name': 'Cycle Sailboat', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '77777', 'name': 'toronet', 'display_name': 'Toronet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '78110', 'name': 'firenze_test', 'display_name': 'Firenze Test', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '78281', 'name': 'dragonfly_hexapod', 'display_name': 'Dragonfly Hexapod', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '78430', 'name': 'amplify_subnet', 'display_name': 'Amplify Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '78431', 'name': 'bulletin_subnet', 'display_name': 'Bulletin Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '78432', 'name': 'conduit_subnet', 'display_name': 'Conduit Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '78600', 'name': 'vanguard', 'display_name': 'Vanguard', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '79879', 'name': 'gold_testnet', 'display_name': 'Gold Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '80001', 'name': 'mumbai', 'display_name': 'Mumbai', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '80002', 'name': 'amoy', 'display_name': 'Amoy', 'aliases': ['polygon_amoy'], 'testnet': True, 'parent': None, 'currency': 'POL', 'explorer': 'https://amoy.polygonscan.com'},
+    {'chain_id': '80008', 'name': 'polynomia_sepolia', 'display_name': 'Polynomia Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '80084', 'name': 'bera_bartio', 'display_name': 'Bera Bartio', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '80085', 'name': 'bera_artio', 'display_name': 'Bera Artio', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '80096', 'name': 'hizoco', 'display_name': 'Hizoco', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '81041', 'name': 'nordek', 'display_name': 'Nordek', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '81341', 'name': 'amana_testnet', 'display_name': 'Amana Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '81342', 'name': 'amana_mixnet', 'display_name': 'Amana Mixnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '81343', 'name': 'amana_privnet', 'display_name': 'Amana Privnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '81351', 'name': 'flana_testnet', 'display_name': 'Flana Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '81352', 'name': 'flana_mixnet', 'display_name': 'Flana Mixnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '81353', 'name': 'flana_privnet', 'display_name': 'Flana Privnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '81361', 'name': 'mizana_testnet', 'display_name': 'Mizana Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '81362', 'name': 'mizana_mixnet', 'display_name': 'Mizana Mixnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '81363', 'name': 'mizana_privnet', 'display_name': 'Mizana Privnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '82459', 'name': 'smart_layer_testnet', 'display_name': 'Smart Layer Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '83144', 'name': 'xprotocol_testnet', 'display_name': 'Xprotocol Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '83278', 'name': 'esa', 'display_name': 'Esa', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '83872', 'name': 'zedxion', 'display_name': 'Zedxion', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '84531', 'name': 'base_goerli_testnet', 'display_name': 'Base Goerli Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '84532', 'name': 'base_sepolia_testnet', 'display_name': 'Base Sepolia Testnet', 'aliases': ['base_sepolia'], 'testnet': True, 'parent': '11155111', 'currency': 'ETH', 'explorer': 'https://sepolia.basescan.org'},
+    {'chain_id': '84886', 'name': 'aerie', 'display_name': 'Aerie', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '85449', 'name': 'cybertrust', 'display_name': 'Cybertrust', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '88002', 'name': 'nautilus_proteus_testnet', 'display_name': 'Nautilus Proteus Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '88559', 'name': 'inoai', 'display_name': 'Inoai', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '88800', 'name': 'zkasino', 'display_name': 'Zkasino', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '88817', 'name': 'unit_zero_testnet', 'display_name': 'Unit Zero Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '88819', 'name': 'unit_zero_stagenet', 'display_name': 'Unit Zero Stagenet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '88880', 'name': 'chiliz_scoville_testnet', 'display_name': 'Chiliz Scoville Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '88882', 'name': 'chiliz_spicy_testnet', 'display_name': 'Chiliz Spicy Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '88888', 'name': 'chiliz', 'display_name': 'Chiliz', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '90001', 'name': 'fxcore_testnet', 'display_name': 'Fxcore Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '90210', 'name': 'beverly_hills', 'display_name': 'Beverly Hills', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '90354', 'name': 'camp_testnet', 'display_name': 'Camp Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '91002', 'name': 'nautilus_trition', 'display_name': 'Nautilus Trition', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '91120', 'name': 'metadap_enterprise', 'display_name': 'Metadap Enterprise', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '91715', 'name': 'combo_testnet', 'display_name': 'Combo Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '92001', 'name': 'lambda_testnet', 'display_name': 'Lambda Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '93572', 'name': 'liquidlayer_testnet', 'display_name': 'Liquidlayer Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '93747', 'name': 'stratovm_testnet', 'display_name': 'Stratovm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '96970', 'name': 'mantis_testnet_hexapod', 'display_name': 'Mantis Testnet Hexapod', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '97053', 'name': 'tetron_testnet', 'display_name': 'Tetron Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '97055', 'name': 'tetron', 'display_name': 'Tetron', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '97288', 'name': 'boba_bnb_old', 'display_name': 'Boba Bnb Old', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '97435', 'name': 'slingshot_testnet', 'display_name': 'Slingshot Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '97531', 'name': 'green_testnet', 'display_name': 'Green Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '97970', 'name': 'optimusz7_testnet', 'display_name': 'Optimusz7 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '98881', 'name': 'ebi', 'display_name': 'Ebi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '99099', 'name': 'eliberty_testnet', 'display_name': 'Eliberty Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '99876', 'name': 'edge_matrix_testnet', 'display_name': 'Edge Matrix Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '99998', 'name': 'ubtestnet', 'display_name': 'Ubtestnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '99999', 'name': 'ub', 'display_name': 'Ub', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '100000', 'name': 'quark_root', 'display_name': 'Quark Root', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '100001', 'name': 'quark_shard_0', 'display_name': 'Quark Shard 0', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '100002', 'name': 'quark_shard_1', 'display_name': 'Quark Shard 1', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '100003', 'name': 'quark_shard_2', 'display_name': 'Quark Shard 2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '100004', 'name': 'quark_shard_3', 'display_name': 'Quark Shard 3', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '100005', 'name': 'quark_shard_4', 'display_name': 'Quark Shard 4', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '100006', 'name': 'quark_shard_5', 'display_name': 'Quark Shard 5', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '100007', 'name': 'quark_shard_6', 'display_name': 'Quark Shard 6', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '100008', 'name': 'quark_shard_7', 'display_name': 'Quark Shard 7', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '100009', 'name': 've', 'display_name': 'Ve', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '100010', 'name': 've_testnet', 'display_name': 'Ve Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '100011', 'name': 'quark_l2', 'display_name': 'Quark L2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '101010', 'name': 'global_trust', 'display_name': 'Global Trust', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '102030', 'name': 'creditcoin', 'display_name': 'Creditcoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '102031', 'name': 'creditcoin_testnet', 'display_name': 'Creditcoin Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '102032', 'name': 'creditcoin_devnet', 'display_name': 'Creditcoin Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '103090', 'name': 'crystaleum', 'display_name': 'Crystaleum', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '103454', 'name': 'masa_testnet', 'display_name': 'Masa Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '104566', 'name': 'kaspaclassic', 'display_name': 'Kaspaclassic', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '105105', 'name': 'stratis', 'display_name': 'Stratis', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '108801', 'name': 'bro', 'display_name': 'Bro', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '110000', 'name': 'quark_devnet_root', 'display_name': 'Quark Devnet Root', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '110001', 'name': 'quark_devnet_shard_0', 'display_name': 'Quark Devnet Shard 0', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '110002', 'name': 'quark_devnet_shard_1', 'display_name': 'Quark Devnet Shard 1', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '110003', 'name': 'quark_devnet_shard_2', 'display_name': 'Quark Devnet Shard 2', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '110004', 'name': 'quark_devnet_shard_3', 'display_name': 'Quark Devnet Shard 3', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '110005', 'name': 'quark_devnet_shard_4', 'display_name': 'Quark Devnet Shard 4', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '110006', 'name': 'quark_devnet_shard_5', 'display_name': 'Quark Devnet Shard 5', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '110007', 'name': 'quark_devnet_shard_6', 'display_name': 'Quark Devnet Shard 6', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '110008', 'name': 'quark_devnet_shard_7', 'display_name': 'Quark Devnet Shard 7', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '110011', 'name': 'quark_l2_testnet', 'display_name': 'Quark L2 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '110110', 'name': 'mars_credit', 'display_name': 'Mars Credit', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '111000', 'name': 'siberium_test', 'display_name': 'Siberium Test', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '111111', 'name': 'siberium', 'display_name': 'Siberium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '111188', 'name': 're.al', 'display_name': 'Re.al', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '112358', 'name': 'meta_one', 'display_name': 'Meta One', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '119139', 'name': 'metadap_enterprise_testnet', 'display_name': 'Metadap Enterprise Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '123321', 'name': 'gem', 'display_name': 'Gem', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '123456', 'name': 'adil_devnet', 'display_name': 'Adil Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '128123', 'name': 'etherlink_testnet', 'display_name': 'Etherlink Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '131313', 'name': 'odyssey_testnet', 'display_name': 'Odyssey Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '131419', 'name': 'etnds', 'display_name': 'Etnds', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '132902', 'name': 'form_testnet', 'display_name': 'Form Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '141319', 'name': 'magape_testnet', 'display_name': 'Magape Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '142857', 'name': 'icplaza', 'display_name': 'Icplaza', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '161212', 'name': 'playfi', 'display_name': 'Playfi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '165279', 'name': 'eclat', 'display_name': 'Eclat', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '167000', 'name': 'taiko', 'display_name': 'Taiko', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '167004', 'name': 'taiko_alpha_2_testnet', 'display_name': 'Taiko Alpha 2 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '167005', 'name': 'taiko_grimsvotn_l2', 'display_name': 'Taiko Grimsvotn L2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '167006', 'name': 'taiko_eldfell_l3', 'display_name': 'Taiko Eldfell L3', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '167007', 'name': 'taiko_jolnir_l2', 'display_name': 'Taiko Jolnir L2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '167008', 'name': 'taiko_katla_l2', 'display_name': 'Taiko Katla L2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '167009', 'name': 'taiko_hekla_l2', 'display_name': 'Taiko Hekla L2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '168168', 'name': 'zs', 'display_name': 'Zs', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '171000', 'name': 'fair_testnet', 'display_name': 'Fair Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '175177', 'name': 'chronicle_lit_protocol_testnet', 'display_name': 'Chronicle Lit Protocol Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '175188', 'name': 'chronicle_yellowstone_lit_protocol_testnet', 'display_name': 'Chronicle Yellowstone Lit Protocol Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '188710', 'name': 'bitica', 'display_name': 'Bitica', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '188881', 'name': 'condor_test', 'display_name': 'Condor Test', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '199991', 'name': 'mazze_testnet', 'display_name': 'Mazze Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '200000', 'name': 'xfair.ai_testnet', 'display_name': 'Xfair.ai Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '200101', 'name': 'milkomeda_c1_testnet', 'display_name': 'Milkomeda C1 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '200202', 'name': 'milkomeda_a1_testnet', 'display_name': 'Milkomeda A1 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '200625', 'name': 'akroma', 'display_name': 'Akroma', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '200810', 'name': 'bitlayer_testnet', 'display_name': 'Bitlayer Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '200901', 'name': 'bitlayer', 'display_name': 'Bitlayer', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '201018', 'name': 'alaya', 'display_name': 'Alaya', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '201030', 'name': 'alaya_dev_testnet', 'display_name': 'Alaya Dev Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '201804', 'name': 'mythical', 'display_name': 'Mythical', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '202020', 'name': 'decimal_testnet', 'display_name': 'Decimal Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '202105', 'name': 'duck_testnet', 'display_name': 'Duck Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '202202', 'name': 'bethel_sydney', 'display_name': 'Bethel Sydney', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '202212', 'name': 'x1_devnet', 'display_name': 'X1 Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '202401', 'name': 'ymtech_besu_testnet', 'display_name': 'Ymtech Besu Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '202624', 'name': 'jellie', 'display_name': 'Jellie', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '204005', 'name': 'x1_network', 'display_name': 'X1 Network', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '205205', 'name': 'auroria_testnet', 'display_name': 'Auroria Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '210049', 'name': 'gitagi_atlas_testnet', 'display_name': 'Gitagi Atlas Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '210425', 'name': 'platon', 'display_name': 'Platon', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '212013', 'name': 'litentry', 'display_name': 'Litentry', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '220315', 'name': 'mas', 'display_name': 'Mas', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '221230', 'name': 'reap', 'display_name': 'Reap', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '221231', 'name': 'reap_testnet', 'display_name': 'Reap Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '222222', 'name': 'hydradx', 'display_name': 'Hydradx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '222555', 'name': 'deepl', 'display_name': 'Deepl', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '222666', 'name': 'deepl_testnet', 'display_name': 'Deepl Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '224168', 'name': 'taf_eco', 'display_name': 'Taf Eco', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '224422', 'name': 'conet_sebolia_testnet', 'display_name': 'Conet Sebolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '224433', 'name': 'conet_holesky', 'display_name': 'Conet Holesky', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '229772', 'name': 'abyss_protocol', 'display_name': 'Abyss Protocol', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '230315', 'name': 'hashkey_testnetdiscard', 'display_name': 'Hashkey Testnetdiscard', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '234666', 'name': 'haymo_testnet', 'display_name': 'Haymo Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '240515', 'name': 'orange_testnet', 'display_name': 'Orange Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '246529', 'name': 'artis_sigma1', 'display_name': 'Artis Sigma1', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '246785', 'name': 'artis_testnet_tau1', 'display_name': 'Artis Testnet Tau1', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '247253', 'name': 'saakuru_testnet', 'display_name': 'Saakuru Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '256256', 'name': 'cmp', 'display_name': 'Cmp', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '262371', 'name': 'eclat_testnet', 'display_name': 'Eclat Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '266256', 'name': 'gear_zero_testnet', 'display_name': 'Gear Zero Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '271271', 'name': 'egoncoin_testnet', 'display_name': 'Egoncoin Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '281121', 'name': 'social', 'display_name': 'Social', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '282828', 'name': 'zillion_sepolia_testnet', 'display_name': 'Zillion Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '292003', 'name': 'cipherem_testnet', 'display_name': 'Cipherem Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '309075', 'name': 'one_world', 'display_name': 'One World', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '313313', 'name': 'saharaai_testnet', 'display_name': 'Saharaai Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '314159', 'name': 'filecoin_calibration_testnet', 'display_name': 'Filecoin Calibration Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '322202', 'name': 'parex', 'display_name': 'Parex', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '323213', 'name': 'bloom_genesis_testnet', 'display_name': 'Bloom Genesis Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '327126', 'name': 'waba_testnet', 'display_name': 'Waba Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '328527', 'name': 'nal', 'display_name': 'Nal', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '330844', 'name': 'ttcoin', 'display_name': 'Ttcoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '333313', 'name': 'bloom_genesis', 'display_name': 'Bloom Genesis', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '333331', 'name': 'aves_testnet', 'display_name': 'Aves Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '333333', 'name': 'nativ3_testnet', 'display_name': 'Nativ3 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '333666', 'name': 'oone_testnet', 'display_name': 'Oone Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '333777', 'name': 'oone_devnet', 'display_name': 'Oone Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '333888', 'name': 'polis_testnet', 'display_name': 'Polis Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '333999', 'name': 'polis', 'display_name': 'Polis', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '336655', 'name': 'up_testnet', 'display_name': 'Up Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '336666', 'name': 'up', 'display_name': 'Up', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '355110', 'name': 'bitfinity', 'display_name': 'Bitfinity', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '355113', 'name': 'bitfinity_testnet', 'display_name': 'Bitfinity Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '360890', 'name': 'lavita', 'display_name': 'Lavita', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '363636', 'name': 'digit_soul_2', 'display_name': 'Digit Soul 2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '373737', 'name': 'hap_testnet', 'display_name': 'Hap Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '381931', 'name': 'metal_c', 'display_name': 'Metal C', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '381932', 'name': 'metal_tahoe_c', 'display_name': 'Metal Tahoe C', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '404040', 'name': 'tipboxcoin', 'display_name': 'Tipboxcoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '413413', 'name': 'aie_testnet', 'display_name': 'Aie Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '420420', 'name': 'kek', 'display_name': 'Kek', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '420666', 'name': 'kek_kektest', 'display_name': 'Kek Kektest', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '420692', 'name': 'alterium_l2_testnet', 'display_name': 'Alterium L2 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '421611', 'name': 'arbitrum_rinkeby', 'display_name': 'Arbitrum Rinkeby', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '421613', 'name': 'arbitrum_goerli', 'display_name': 'Arbitrum Goerli', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '421614', 'name': 'arbitrum_sepolia', 'display_name': 'Arbitrum Sepolia', 'aliases': ['arb_sepolia'], 'testnet': True, 'parent': '11155111', 'currency': 'ETH', 'explorer': 'https://sepolia.arbiscan.io'},
+    {'chain_id': '424242', 'name': 'fastex_testnet', 'display_name': 'Fastex Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '431140', 'name': 'markr_go', 'display_name': 'Markr Go', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '432201', 'name': 'dexalot_subnet_testnet', 'display_name': 'Dexalot Subnet Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '432204', 'name': 'dexalot_subnet', 'display_name': 'Dexalot Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '444444', 'name': 'syndr_l3_sepolia', 'display_name': 'Syndr L3 Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '444900', 'name': 'weelink_testnet', 'display_name': 'Weelink Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '471100', 'name': 'patex_sepolia_testnet', 'display_name': 'Patex Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '473861', 'name': 'ultra_pro', 'display_name': 'Ultra Pro', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '474142', 'name': 'open', 'display_name': 'Open', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '486487', 'name': 'gobbl_testnet', 'display_name': 'Gobbl Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '490000', 'name': 'autonomys_testnet_nova_domain', 'display_name': 'Autonomys Testnet Nova Domain', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '504441', 'name': 'playdapp', 'display_name': 'Playdapp', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '512512', 'name': 'cmp_testnet', 'display_name': 'Cmp Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '513100', 'name': 'dis', 'display_name': 'Dis', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '526916', 'name': 'docoin_community', 'display_name': 'Docoin Community', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '534351', 'name': 'scroll_sepolia_testnet', 'display_name': 'Scroll Sepolia Testnet', 'aliases': ['scroll_sepolia'], 'testnet': True, 'parent': '11155111', 'currency': 'ETH', 'explorer': 'https://sepolia.scrollscan.com'},
+    {'chain_id': '534352', 'name': 'scroll', 'display_name': 'Scroll', 'aliases': ['scroll_mainnet'], 'testnet': False, 'parent': '1', 'currency': 'ETH', 'explorer': 'https://scrollscan.com'},
+    {'chain_id': '534353', 'name': 'scroll_alpha_testnet', 'display_name': 'Scroll Alpha Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '534354', 'name': 'scroll_pre_alpha_testnet', 'display_name': 'Scroll Pre Alpha Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '534849', 'name': 'shinarium_beta', 'display_name': 'Shinarium Beta', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '535037', 'name': 'beaneco_smart', 'display_name': 'Beaneco Smart', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '541764', 'name': 'overprotocol_testnet', 'display_name': 'Overprotocol Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '552981', 'name': 'one_world_testnet', 'display_name': 'One World Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '555555', 'name': 'pentagon_testnet', 'display_name': 'Pentagon Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '555666', 'name': 'eclipse_testnet', 'display_name': 'Eclipse Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '555888', 'name': 'dustboy_iot', 'display_name': 'Dustboy Iot', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '622277', 'name': 'hypra', 'display_name': 'Hypra', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '622463', 'name': 'atlas', 'display_name': 'Atlas', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '641230', 'name': 'bear', 'display_name': 'Bear', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '651940', 'name': 'all', 'display_name': 'All', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '656476', 'name': 'open_campus_codex', 'display_name': 'Open Campus Codex', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '660279', 'name': 'xai', 'display_name': 'Xai', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '666666', 'name': 'vision_vpioneer_test', 'display_name': 'Vision Vpioneer Test', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '666888', 'name': 'hela_official_runtime_testnet', 'display_name': 'Hela Official Runtime Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '686868', 'name': 'won', 'display_name': 'Won', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '696969', 'name': 'galadriel_devnet', 'display_name': 'Galadriel Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '710420', 'name': 'tiltyard_subnet', 'display_name': 'Tiltyard Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '713715', 'name': 'sei_devnet', 'display_name': 'Sei Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '721529', 'name': 'eram', 'display_name': 'Eram', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '723107', 'name': 'tix_testnet', 'display_name': 'Tix Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '743111', 'name': 'hemi_sepolia', 'display_name': 'Hemi Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '751230', 'name': 'bear_testnet', 'display_name': 'Bear Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '752024', 'name': 'ternoa_testnet', 'display_name': 'Ternoa Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '761412', 'name': 'miexs_smart', 'display_name': 'Miexs Smart', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '764984', 'name': 'lamina1_testnet', 'display_name': 'Lamina1 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '767368', 'name': 'lamina1_identity_testnet', 'display_name': 'Lamina1 Identity Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '776877', 'name': 'modularium', 'display_name': 'Modularium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '800001', 'name': 'octaspace', 'display_name': 'Octaspace', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '808080', 'name': 'biz_testnet', 'display_name': 'Biz Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '808813', 'name': 'bob_sepolia', 'display_name': 'Bob Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '810180', 'name': 'zklink_nova', 'display_name': 'Zklink Nova', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '810181', 'name': 'zklink_nova_sepolia_testnet', 'display_name': 'Zklink Nova Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '810182', 'name': 'zklink_nova_goerli_testnet', 'display_name': 'Zklink Nova Goerli Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '812397', 'name': 'sg_verse', 'display_name': 'Sg Verse', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '820522', 'name': 'tsc_testnet', 'display_name': 'Tsc Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '827431', 'name': 'curve', 'display_name': 'Curve', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '839320', 'name': 'prm_testnet', 'display_name': 'Prm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '840000', 'name': 'runevm_testnet', 'display_name': 'Runevm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '846000', 'name': '4goodnetwork', 'display_name': '4goodnetwork', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '855456', 'name': 'dodao', 'display_name': 'Dodao', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '879151', 'name': 'blocx', 'display_name': 'Blocx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '888882', 'name': 'rexx', 'display_name': 'Rexx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '888888', 'name': 'vision', 'display_name': 'Vision', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '900000', 'name': 'posi_shard_0', 'display_name': 'Posi Shard 0', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '910000', 'name': 'posi_testnet_shard_0', 'display_name': 'Posi Testnet Shard 0', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '912559', 'name': 'astria_evm_dusknet', 'display_name': 'Astria Evm Dusknet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '920000', 'name': 'posi_devnet_shard_0', 'display_name': 'Posi Devnet Shard 0', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '920001', 'name': 'posi_devnet_shard_1', 'display_name': 'Posi Devnet Shard 1', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '923018', 'name': 'fncy_testnet', 'display_name': 'Fncy Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '955081', 'name': 'jono12_subnet', 'display_name': 'Jono12 Subnet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '955305', 'name': 'eluvio_content_fabric', 'display_name': 'Eluvio Content Fabric', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '978657', 'name': 'treasure_ruby', 'display_name': 'Treasure Ruby', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '984122', 'name': 'forma', 'display_name': 'Forma', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '984123', 'name': 'forma_sketchpad', 'display_name': 'Forma Sketchpad', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '988207', 'name': 'ecrox', 'display_name': 'Ecrox', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '998899', 'name': 'supernet_testnet', 'display_name': 'Supernet Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '999999', 'name': 'am', 'display_name': 'Am', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1100789', 'name': 'netmind_testnet', 'display_name': 'Netmind Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1234567', 'name': 'sharecle', 'display_name': 'Sharecle', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1261120', 'name': 'zkatana', 'display_name': 'Zkatana', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1313114', 'name': 'etho_protocol', 'display_name': 'Etho Protocol', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1313500', 'name': 'xerom', 'display_name': 'Xerom', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1337702', 'name': 'kintsugi', 'display_name': 'Kintsugi', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1337802', 'name': 'kiln', 'display_name': 'Kiln', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1337803', 'name': 'zhejiang', 'display_name': 'Zhejiang', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1398243', 'name': 'automata_testnet', 'display_name': 'Automata Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1398244', 'name': 'automata_orbit_testnet', 'display_name': 'Automata Orbit Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1612127', 'name': 'playfi_albireo_testnet', 'display_name': 'Playfi Albireo Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1637450', 'name': 'xterio_testnet', 'display_name': 'Xterio Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1731313', 'name': 'turkey_demo_dev', 'display_name': 'Turkey Demo Dev', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2021398', 'name': 'debank_testnet', 'display_name': 'Debank Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2099156', 'name': 'plian_main', 'display_name': 'Plian Main', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2206132', 'name': 'platon_dev_testnet2', 'display_name': 'Platon Dev Testnet2', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2611555', 'name': 'dpu', 'display_name': 'Dpu', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2702128', 'name': 'xterio', 'display_name': 'Xterio', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3132023', 'name': 'saharaai', 'display_name': 'Saharaai', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3141592', 'name': 'filecoin_butterfly_testnet', 'display_name': 'Filecoin Butterfly Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3397901', 'name': 'funki_sepolia_sandbox', 'display_name': 'Funki Sepolia Sandbox', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3441005', 'name': 'manta_pacific_testnet', 'display_name': 'Manta Pacific Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3441006', 'name': 'manta_pacific_sepolia_testnet', 'display_name': 'Manta Pacific Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4000003', 'name': 'altlayer_zero_gas', 'display_name': 'Altlayer Zero Gas', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4281033', 'name': 'worlds_caldera', 'display_name': 'Worlds Caldera', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4444444', 'name': 'altar_testnet', 'display_name': 'Altar Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4457845', 'name': 'zero_testnet_sepolia', 'display_name': 'Zero Testnet Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5112023', 'name': 'numblock', 'display_name': 'Numblock', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5167003', 'name': 'mxc_wannsee_zkevm_testnet', 'display_name': 'Mxc Wannsee Zkevm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5167004', 'name': 'moon_geneva_testnet', 'display_name': 'Moon Geneva Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5201420', 'name': 'electroneum_testnet', 'display_name': 'Electroneum Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5318008', 'name': 'reactive_kopli', 'display_name': 'Reactive Kopli', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5555555', 'name': 'imversed', 'display_name': 'Imversed', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '5555558', 'name': 'imversed_testnet', 'display_name': 'Imversed Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6038361', 'name': 'astar_zkyoto', 'display_name': 'Astar Zkyoto', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6666665', 'name': 'safeanwang', 'display_name': 'Safeanwang', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6666666', 'name': 'safeanwang_testnet', 'display_name': 'Safeanwang Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7082400', 'name': 'coti_testnet', 'display_name': 'Coti Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7225878', 'name': 'saakuru', 'display_name': 'Saakuru', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7355310', 'name': 'openvessel', 'display_name': 'Openvessel', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7668378', 'name': 'ql1_testnet', 'display_name': 'Ql1 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7762959', 'name': 'musicoin', 'display_name': 'Musicoin', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '7777777', 'name': 'zora', 'display_name': 'Zora', 'aliases': [], 'testnet': False, 'parent': '1', 'currency': 'ETH', 'explorer': 'https://explorer.zora.energy'},
+    {'chain_id': '8007736', 'name': 'plian_sub_1', 'display_name': 'Plian Sub 1', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8008135', 'name': 'fhenix_helium', 'display_name': 'Fhenix Helium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8080808', 'name': 'hokum', 'display_name': 'Hokum', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8601152', 'name': 'waterfall_8_test', 'display_name': 'Waterfall 8 Test', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8794598', 'name': 'hap', 'display_name': 'Hap', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8888881', 'name': 'quarix_testnet', 'display_name': 'Quarix Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '8888888', 'name': 'quarix', 'display_name': 'Quarix', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9322252', 'name': 'xcap', 'display_name': 'Xcap', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9322253', 'name': 'milvine', 'display_name': 'Milvine', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '9999999', 'name': 'fluence', 'display_name': 'Fluence', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10067275', 'name': 'plian_testnet_sub_1', 'display_name': 'Plian Testnet Sub 1', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10101010', 'name': 'soverun', 'display_name': 'Soverun', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10241024', 'name': 'alienx', 'display_name': 'Alienx', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '10241025', 'name': 'alienx_hal_testnet', 'display_name': 'Alienx Hal Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11145513', 'name': 'blessnet_sepolia', 'display_name': 'Blessnet Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11155111', 'name': 'sepolia', 'display_name': 'Sepolia', 'aliases': ['eth_sepolia', 'ethereum_sepolia'], 'testnet': True, 'parent': None, 'currency': 'ETH', 'explorer': 'https://sepolia.etherscan.io'},
+    {'chain_id': '11155420', 'name': 'op_sepolia_testnet', 'display_name': 'OP Sepolia Testnet', 'aliases': ['op_sepolia', 'optimism_sepolia'], 'testnet': True, 'parent': '11155111', 'currency': 'ETH', 'explorer': 'https://sepolia-optimism.etherscan.io'},
+    {'chain_id': '12052024', 'name': 'memento_testnet', 'display_name': 'Memento Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12227331', 'name': 'neox_testnet_t3', 'display_name': 'Neox Testnet T3', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '12227332', 'name': 'neo_x_testnet_t4', 'display_name': 'Neo X Testnet T4', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '13068200', 'name': 'coti_devnet', 'display_name': 'Coti Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '13371337', 'name': 'pep_churchill', 'display_name': 'Pep Churchill', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '14288640', 'name': 'andus', 'display_name': 'Andus', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '16658437', 'name': 'plian_testnet_main', 'display_name': 'Plian Testnet Main', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '18071918', 'name': 'mande', 'display_name': 'Mande', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '18289463', 'name': 'iolite', 'display_name': 'Iolite', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '19850818', 'name': 'deepbrain_testnet', 'display_name': 'Deepbrain Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '19880818', 'name': 'deepbrain', 'display_name': 'Deepbrain', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20180427', 'name': 'stability_testnet', 'display_name': 'Stability Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20180430', 'name': 'smartmesh', 'display_name': 'Smartmesh', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20181205', 'name': 'quarkblock', 'display_name': 'Quarkblock', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20201022', 'name': 'pego', 'display_name': 'Pego', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20230825', 'name': 'vcity_testnet', 'display_name': 'Vcity Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20240324', 'name': 'debank_sepolia_testnet', 'display_name': 'Debank Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20240603', 'name': 'dbk', 'display_name': 'Dbk', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20241133', 'name': 'swan_proxima_testnet', 'display_name': 'Swan Proxima Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '20482050', 'name': 'hokum_testnet', 'display_name': 'Hokum Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '22052002', 'name': 'excelon', 'display_name': 'Excelon', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '24772477', 'name': '6degree_of_outreach_testnet', 'display_name': '6degree Of Outreach Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '27082017', 'name': 'excoincial_volta_testnet', 'display_name': 'Excoincial Volta Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '27082022', 'name': 'excoincial', 'display_name': 'Excoincial', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '28122024', 'name': 'ancient8_testnet', 'display_name': 'Ancient8 Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '28945486', 'name': 'auxilium', 'display_name': 'Auxilium', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '29032022', 'name': 'fla', 'display_name': 'Fla', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '31415926', 'name': 'filecoin_local_testnet', 'display_name': 'Filecoin Local Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '33626250', 'name': 'toliman_suave_testnet', 'display_name': 'Toliman Suave Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '35855456', 'name': 'joys_digital', 'display_name': 'Joys Digital', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '37084624', 'name': 'skale_nebula_hub_testnet', 'display_name': 'Skale Nebula Hub Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '39916801', 'name': 'kingdom', 'display_name': 'Kingdom', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '43214913', 'name': 'maistestsubnet', 'display_name': 'Maistestsubnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '52164803', 'name': 'fluence_testnet', 'display_name': 'Fluence Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '61717561', 'name': 'aqua', 'display_name': 'Aqua', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '65010000', 'name': 'autonity_bakerloo_thames_testnet', 'display_name': 'Autonity Bakerloo Thames Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '65010001', 'name': 'autonity_bakerloo_barada_testnet', 'display_name': 'Autonity Bakerloo Barada Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '65010002', 'name': 'autonity_bakerloo_sumida_testnet', 'display_name': 'Autonity Bakerloo Sumida Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '65010003', 'name': 'autonity_bakerloo_yamuna_testnet', 'display_name': 'Autonity Bakerloo Yamuna Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '65100000', 'name': 'autonity_piccadilly_thames_testnet', 'display_name': 'Autonity Piccadilly Thames Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '65100001', 'name': 'autonity_piccadilly_barada_testnet', 'display_name': 'Autonity Piccadilly Barada Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '65100002', 'name': 'autonity_piccadilly_sumida_testnet', 'display_name': 'Autonity Piccadilly Sumida Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '65100003', 'name': 'autonity_piccadilly_yamuna_testnet', 'display_name': 'Autonity Piccadilly Yamuna Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '68840142', 'name': 'frame_testnet', 'display_name': 'Frame Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '77787778', 'name': '0xhash_testnet', 'display_name': '0xhash Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '79479957', 'name': 'sx_toronto_rollup', 'display_name': 'Sx Toronto Rollup', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '88558801', 'name': 'backstop_testnet', 'display_name': 'Backstop Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '88888888', 'name': 't.e.a.m_block', 'display_name': 'T.e.a.m Block', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '94204209', 'name': 'polygon_blackberry', 'display_name': 'Polygon Blackberry', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '99415706', 'name': 'joys_digital_testnet', 'display_name': 'Joys Digital Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '100000000', 'name': 'ethos', 'display_name': 'Ethos', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '108160679', 'name': 'orai', 'display_name': 'Orai', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '111557560', 'name': 'cyber_testnet', 'display_name': 'Cyber Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '123420111', 'name': 'op_celestia_raspberry', 'display_name': 'Op Celestia Raspberry', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '161221135', 'name': 'plume_testnet', 'display_name': 'Plume Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '168587773', 'name': 'blast_sepolia_testnet', 'display_name': 'Blast Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '192837465', 'name': 'gather', 'display_name': 'Gather', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '222000222', 'name': 'kanazawa', 'display_name': 'Kanazawa', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '245022926', 'name': 'neon_evm_devnet', 'display_name': 'Neon Evm Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '245022929', 'name': 'neon_evm_devnet_rollup', 'display_name': 'Neon Evm Devnet Rollup', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '245022934', 'name': 'neon_evm', 'display_name': 'Neon Evm', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '245022940', 'name': 'neon_evm_testnet', 'display_name': 'Neon Evm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '278611351', 'name': 'razor_skale', 'display_name': 'Razor Skale', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '311752642', 'name': 'oneledger', 'display_name': 'Oneledger', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '328527624', 'name': 'nal_sepolia_testnet', 'display_name': 'Nal Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '333000333', 'name': 'meld', 'display_name': 'Meld', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '356256156', 'name': 'gather_testnet', 'display_name': 'Gather Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '476462898', 'name': 'skopje_testnet', 'display_name': 'Skopje Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '486217935', 'name': 'gather_devnet', 'display_name': 'Gather Devnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '531050104', 'name': 'sophon_testnet', 'display_name': 'Sophon Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '666666666', 'name': 'degen', 'display_name': 'Degen', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '728126428', 'name': 'tron', 'display_name': 'Tron', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '888888888', 'name': 'ancient8', 'display_name': 'Ancient8', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '889910245', 'name': 'ptcescan_testnet', 'display_name': 'Ptcescan Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '889910246', 'name': 'ptcescan', 'display_name': 'Ptcescan', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '974399131', 'name': 'skale_calypso_hub_testnet', 'display_name': 'Skale Calypso Hub Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '999999999', 'name': 'zora_sepolia_testnet', 'display_name': 'Zora Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1020352220', 'name': 'skale_titan_hub_testnet', 'display_name': 'Skale Titan Hub Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1122334455', 'name': 'ipos', 'display_name': 'Ipos', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1146703430', 'name': 'cyberdecknet', 'display_name': 'Cyberdecknet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1273227453', 'name': 'human_protocol', 'display_name': 'Human Protocol', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1313161554', 'name': 'aurora', 'display_name': 'Aurora', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1313161555', 'name': 'aurora_testnet', 'display_name': 'Aurora Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1313161556', 'name': 'aurora_betanet', 'display_name': 'Aurora Betanet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1313161560', 'name': 'powergold', 'display_name': 'Powergold', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1350216234', 'name': 'skale_titan_hub', 'display_name': 'Skale Titan Hub', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1351057110', 'name': 'chaos_skale_testnet', 'display_name': 'Chaos Skale Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1380012617', 'name': 'rari', 'display_name': 'Rari', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1380996178', 'name': 'raptor', 'display_name': 'Raptor', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1444673419', 'name': 'skale_europa_hub_testnet', 'display_name': 'Skale Europa Hub Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1482601649', 'name': 'skale_nebula_hub', 'display_name': 'Skale Nebula Hub', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1511670449', 'name': 'gpt', 'display_name': 'Gpt', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1564830818', 'name': 'skale_calypso_hub', 'display_name': 'Skale Calypso Hub', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1666600000', 'name': 'harmony_shard_0', 'display_name': 'Harmony Shard 0', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1666600001', 'name': 'harmony_shard_1', 'display_name': 'Harmony Shard 1', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1666600002', 'name': 'harmony_shard_2', 'display_name': 'Harmony Shard 2', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1666600003', 'name': 'harmony_shard_3', 'display_name': 'Harmony Shard 3', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1666700000', 'name': 'harmony_testnet_shard_0', 'display_name': 'Harmony Testnet Shard 0', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1666700001', 'name': 'harmony_testnet_shard_1', 'display_name': 'Harmony Testnet Shard 1', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1666900000', 'name': 'harmony_devnet_shard_0', 'display_name': 'Harmony Devnet Shard 0', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1666900001', 'name': 'harmony_devnet_shard_1', 'display_name': 'Harmony Devnet Shard 1', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1722641160', 'name': 'silicon_zkevm_sepolia_testnet', 'display_name': 'Silicon Zkevm Sepolia Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1802203764', 'name': 'kakarot_sepolia', 'display_name': 'Kakarot Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1903648807', 'name': 'gemu_testnet', 'display_name': 'Gemu Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '1918988905', 'name': 'rari_testnet', 'display_name': 'Rari Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2021121117', 'name': 'datahopper', 'display_name': 'Datahopper', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2046399126', 'name': 'skale_europa_hub', 'display_name': 'Skale Europa Hub', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '3125659152', 'name': 'pirl', 'display_name': 'Pirl', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '4216137055', 'name': 'oneledger_testnet_frankenstein', 'display_name': 'Oneledger Testnet Frankenstein', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '11297108099', 'name': 'palm_testnet', 'display_name': 'Palm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '28872323069', 'name': 'gitswarm_test', 'display_name': 'Gitswarm Test', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '37714555429', 'name': 'xai_testnet_v2', 'display_name': 'Xai Testnet V2', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '88153591557', 'name': 'arbitrum_blueberry', 'display_name': 'Arbitrum Blueberry', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '111222333444', 'name': 'alphabet', 'display_name': 'Alphabet', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '111551119090', 'name': 'thanos_sepolia', 'display_name': 'Thanos Sepolia', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '123420000220', 'name': 'fluence_stage', 'display_name': 'Fluence Stage', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '197710212030', 'name': 'ntity', 'display_name': 'Ntity', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '197710212031', 'name': 'haradev_testnet', 'display_name': 'Haradev Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '202402181627', 'name': 'gm_testnet', 'display_name': 'Gm Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '383414847825', 'name': 'zeniq', 'display_name': 'Zeniq', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '666301171999', 'name': 'pdc', 'display_name': 'Pdc', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '6022140761023', 'name': 'molereum', 'display_name': 'Molereum', 'aliases': [], 'testnet': False, 'parent': None, 'currency': None, 'explorer': None},
+    {'chain_id': '2713017997578000', 'name': 'd_testnet', 'display_name': 'D Testnet', 'aliases': [], 'testnet': True, 'parent': None, 'currency': None, 'explorer': None},
+]
@@ -44,6 +44,16 @@ if typing.TYPE_CHECKING:
         profiles: MutableMapping[str, Profile]
         global_metadata: MutableMapping[str, Any]
 
+    class NetworkInfo(TypedDict):
+        chain_id: str
+        name: str
+        display_name: str
+        aliases: list[str]
+        testnet: bool
+        parent: str | None
+        currency: str | None
+        explorer: str | None
+
 
 endpoint_types: dict[str, type | tuple[type, ...]] = {
     'name': str,
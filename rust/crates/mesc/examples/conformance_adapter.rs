@@ -0,0 +1,23 @@
+//! adapter for the cross-implementation test suite in `tests/`
+//!
+//! takes a json `MescQuery` as its single argument, loads the config from the environment,
+//! and prints the json result, or `FAIL` if loading or querying fails
+//!
+//! usage: `cargo run --example conformance_adapter -- '<QUERY_JSON>'`
+
+fn main() {
+    match run() {
+        Ok(output) => println!("{}", output),
+        Err(e) => {
+            println!("FAIL");
+            println!("{}", e);
+        }
+    }
+}
+
+fn run() -> Result<String, Box<dyn std::error::Error>> {
+    let query = std::env::args().nth(1).ok_or("missing query argument")?;
+    let query: mesc::MescQuery = serde_json::from_str(&query)?;
+    let result = mesc::execute(query)?;
+    Ok(serde_json::to_string(&result)?)
+}
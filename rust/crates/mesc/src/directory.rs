@@ -1,14 +1,212 @@
-use crate::ChainId;
+use crate::{network_names::NETWORKS, ChainId};
+use serde::Serialize;
+use std::{collections::HashMap, sync::OnceLock};
+
+/// NetworkInfo, entry of the network directory
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetworkInfo {
+    /// chain id
+    pub chain_id: u64,
+    /// standard network name, e.g. `optimism`
+    pub name: &'static str,
+    /// human readable name, e.g. `OP Mainnet`
+    pub display_name: &'static str,
+    /// other names of network, e.g. `op` or `op_mainnet`
+    pub aliases: &'static [&'static str],
+    /// whether network is a testnet
+    pub testnet: bool,
+    /// chain id of parent network of rollups
+    pub parent: Option<u64>,
+    /// symbol of native currency
+    pub currency: Option<&'static str>,
+    /// block explorer url
+    pub explorer: Option<&'static str>,
+}
+
+impl NetworkInfo {
+    pub(crate) const fn new(
+        chain_id: u64,
+        name: &'static str,
+        display_name: &'static str,
+    ) -> NetworkInfo {
+        NetworkInfo {
+            chain_id,
+            name,
+            display_name,
+            aliases: &[],
+            testnet: false,
+            parent: None,
+            currency: None,
+            explorer: None,
+        }
+    }
+
+    pub(crate) const fn aliases(mut self, aliases: &'static [&'static str]) -> NetworkInfo {
+        self.aliases = aliases;
+        self
+    }
+
+    pub(crate) const fn testnet(mut self) -> NetworkInfo {
+        self.testnet = true;
+        self
+    }
+
+    pub(crate) const fn parent(mut self, parent: u64) -> NetworkInfo {
+        self.parent = Some(parent);
+        self
+    }
+
+    pub(crate) const fn currency(mut self, currency: &'static str) -> NetworkInfo {
+        self.currency = Some(currency);
+        self
+    }
+
+    pub(crate) const fn explorer(mut self, explorer: &'static str) -> NetworkInfo {
+        self.explorer = Some(explorer);
+        self
+    }
+
+    /// chain id as ChainId
+    pub fn chain_id(&self) -> ChainId {
+        ChainId::new(self.chain_id)
+    }
+}
+
+/// get every network in the network directory
+pub fn get_networks() -> &'static [NetworkInfo] {
+    NETWORKS
+}
+
+/// get network by name, alias, or display name
+///
+/// exact names are matched first, then names are compared ignoring case and the separators
+/// ` `, `-`, `_`, and `.`, so `op-mainnet` and `OP Mainnet` both find `optimism`
+pub fn get_network(query: &str) -> Option<&'static NetworkInfo> {
+    find_network(NETWORKS, get_network_index(), query)
+}
+
+/// get network of given chain id
+pub fn get_network_by_chain_id(chain_id: &ChainId) -> Option<&'static NetworkInfo> {
+    static CACHE: OnceLock<HashMap<ChainId, usize>> = OnceLock::new();
+    let index = CACHE.get_or_init(|| {
+        NETWORKS.iter().enumerate().map(|(i, network)| (network.chain_id(), i)).collect()
+    });
+    index.get(chain_id).map(|index| &NETWORKS[*index])
+}
 
 /// get chain id of given network name
 pub(crate) fn get_network_chain_id(network_name: &str) -> Option<ChainId> {
-    crate::network_names::NETWORKS
-        .iter()
-        .find(|(_, name)| *name == network_name)
-        .map(|(chain_id, _)| (*chain_id).into())
+    get_network(network_name).map(NetworkInfo::chain_id)
 }
 
 /// get network name of given chain id
 pub fn get_network_name(chain_id: &ChainId) -> Option<&'static str> {
     crate::network_names::get_network_names().get(chain_id).copied()
 }
+
+/// lowercase name without separators
+pub fn normalize_network_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_' | '.'))
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+type NetworkIndex = (HashMap<&'static str, usize>, HashMap<String, usize>);
+
+fn get_network_index() -> &'static NetworkIndex {
+    static CACHE: OnceLock<NetworkIndex> = OnceLock::new();
+    CACHE.get_or_init(|| build_network_index(NETWORKS))
+}
+
+fn build_network_index(networks: &[NetworkInfo]) -> NetworkIndex {
+    let mut exact = HashMap::new();
+    let mut normalized = HashMap::new();
+    // names take precedence over aliases, which take precedence over display names
+    let names = networks.iter().enumerate().map(|(i, network)| (i, network.name));
+    let aliases = networks
+        .iter()
+        .enumerate()
+        .flat_map(|(i, network)| network.aliases.iter().map(move |alias| (i, *alias)));
+    let display_names = networks.iter().enumerate().map(|(i, network)| (i, network.display_name));
+    for (i, name) in names.chain(aliases).chain(display_names) {
+        exact.entry(name).or_insert(i);
+        normalized.entry(normalize_network_name(name)).or_insert(i);
+    }
+    (exact, normalized)
+}
+
+fn find_network<'a>(
+    networks: &'a [NetworkInfo],
+    index: &NetworkIndex,
+    query: &str,
+) -> Option<&'a NetworkInfo> {
+    let (exact, normalized) = index;
+    exact
+        .get(query)
+        .or_else(|| normalized.get(&normalize_network_name(query)))
+        .map(|index| &networks[*index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn network_lookup_ignores_case_and_separators() {
+        for query in ["ethereum", "mainnet", "ETH", "Ethereum Mainnet"] {
+            assert_eq!(get_network_chain_id(query), Some(ChainId::new(1)), "{}", query);
+        }
+        for query in ["optimism", "op-mainnet", "OP Mainnet", "op_mainnet"] {
+            assert_eq!(get_network_chain_id(query), Some(ChainId::new(10)), "{}", query);
+        }
+        assert_eq!(get_network_chain_id("Arbitrum One"), Some(ChainId::new(42161)));
+        assert_eq!(get_network_chain_id("re.al"), Some(ChainId::new(111188)));
+        assert_eq!(get_network_chain_id("not a network"), None);
+
+        let base_sepolia = get_network_by_chain_id(&ChainId::new(84532)).unwrap();
+        assert_eq!(base_sepolia.display_name, "Base Sepolia Testnet");
+        assert_eq!(get_network("base_sepolia"), Some(base_sepolia));
+        assert!(base_sepolia.testnet);
+        assert_eq!(base_sepolia.parent, Some(11155111));
+        assert_eq!(get_network("base").unwrap().currency, Some("ETH"));
+    }
+
+    fn lookup(networks: &[NetworkInfo], query: &str) -> Option<u64> {
+        let index = build_network_index(networks);
+        find_network(networks, &index, query).map(|network| network.chain_id)
+    }
+
+    #[test]
+    fn names_take_precedence_over_aliases_and_display_names() {
+        let networks = [
+            NetworkInfo::new(1, "alpha", "Gamma").aliases(&["beta"]),
+            NetworkInfo::new(2, "beta", "Beta Chain").aliases(&["gamma"]),
+            NetworkInfo::new(3, "gamma", "Gamma Chain"),
+            NetworkInfo::new(4, "delta", "Epsilon"),
+            NetworkInfo::new(5, "epsilon_chain", "Epsilon Chain").aliases(&["epsilon"]),
+        ];
+        assert_eq!(lookup(&networks, "beta"), Some(2));
+        assert_eq!(lookup(&networks, "gamma"), Some(3));
+        assert_eq!(lookup(&networks, "Gamma"), Some(1));
+        assert_eq!(lookup(&networks, "epsilon"), Some(5));
+        assert_eq!(lookup(&networks, "Epsilon"), Some(4));
+        assert_eq!(lookup(&networks, "EPSILON"), Some(5));
+    }
+
+    #[test]
+    fn normalized_collisions_resolve_to_first_network() {
+        let networks = [
+            NetworkInfo::new(1, "op_main", "Op Main"),
+            NetworkInfo::new(2, "opmain", "OpMain"),
+            NetworkInfo::new(3, "zeta", "Op-Main Testnet").aliases(&["op.main"]),
+        ];
+        assert_eq!(lookup(&networks, "op_main"), Some(1));
+        assert_eq!(lookup(&networks, "opmain"), Some(2));
+        assert_eq!(lookup(&networks, "op.main"), Some(3));
+        for query in ["OP-MAIN", "Op Main.", "o.p.m.a.i.n"] {
+            assert_eq!(lookup(&networks, query), Some(1), "{}", query);
+        }
+        assert_eq!(lookup(&networks, "op main testnet"), Some(3));
+    }
+}
@@ -0,0 +1,641 @@
+//! filter expressions over endpoints
+//!
+//! examples:
+//! - `chain_id in (1, 10)`
+//! - `"archive" in labels and host != "quicknode"`
+//! - `not (name == "local" or "localhost" in url)`
+//!
+//! fields are `name`, `url`, `chain_id`, or a dot-separated path into endpoint metadata
+//! (prefix with `metadata.` to reach metadata keys named `name`, `url`, or `chain_id`)
+//!
+//! a field on its own, e.g. `archive`, matches if the value is present and is not `null` or
+//! `false`, so `archive` and `archive == true` are equivalent for boolean flags
+//!
+//! `and`, `or`, `not`, `in`, `true`, `false`, and `null` are keywords rather than fields,
+//! metadata keys with these names can be reached as `metadata.true`
+
+use crate::{directory, ChainId, Endpoint, MescError, TryIntoChainId};
+use serde::{Deserialize, Serialize};
+
+/// compiled filter expression over endpoints, serialized as its source text
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(into = "String", try_from = "String")]
+pub struct EndpointFilter {
+    source: String,
+    expr: Expr,
+}
+
+impl EndpointFilter {
+    /// parse filter expression
+    pub fn parse(source: &str) -> Result<Self, MescError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser { tokens, position: 0, end: source.chars().count() + 1 };
+        let expr = parser.parse_or()?;
+        if let Some(token) = parser.peek() {
+            return Err(filter_error(token.column, "expected 'and', 'or', or end of filter"));
+        }
+        Ok(Self { source: source.to_string(), expr })
+    }
+
+    /// whether endpoint matches filter
+    pub fn matches(&self, endpoint: &Endpoint) -> bool {
+        self.expr.matches(endpoint)
+    }
+}
+
+impl std::str::FromStr for EndpointFilter {
+    type Err = MescError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source)
+    }
+}
+
+impl TryFrom<String> for EndpointFilter {
+    type Error = MescError;
+
+    fn try_from(source: String) -> Result<Self, Self::Error> {
+        Self::parse(&source)
+    }
+}
+
+impl From<EndpointFilter> for String {
+    fn from(filter: EndpointFilter) -> String {
+        filter.source
+    }
+}
+
+impl std::fmt::Display for EndpointFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+fn filter_error(column: usize, message: &str) -> MescError {
+    MescError::InvalidFilter { column, message: message.to_string() }
+}
+
+//
+// // evaluation
+//
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Compare(Field, CompareOp, serde_json::Value),
+    CompareChainId(CompareOp, ChainId),
+    In(Field, Vec<serde_json::Value>),
+    InChainIds(Vec<ChainId>),
+    Contains(Field, serde_json::Value),
+    Exists(Field),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Field {
+    Name,
+    Url,
+    ChainId,
+    Metadata(Vec<String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Expr {
+    fn matches(&self, endpoint: &Endpoint) -> bool {
+        match self {
+            Expr::And(lhs, rhs) => lhs.matches(endpoint) && rhs.matches(endpoint),
+            Expr::Or(lhs, rhs) => lhs.matches(endpoint) || rhs.matches(endpoint),
+            Expr::Not(expr) => !expr.matches(endpoint),
+            Expr::Compare(field, op, value) => compare(field.get(endpoint).as_ref(), *op, value),
+            Expr::CompareChainId(op, chain_id) => match endpoint.chain_id.as_ref() {
+                Some(endpoint_chain_id) => op.test(endpoint_chain_id.cmp(chain_id)),
+                None => *op == CompareOp::Ne,
+            },
+            Expr::In(field, values) => match field.get(endpoint) {
+                Some(value) => values.iter().any(|x| values_equal(&value, x)),
+                None => false,
+            },
+            Expr::InChainIds(chain_ids) => match endpoint.chain_id.as_ref() {
+                Some(chain_id) => chain_ids.contains(chain_id),
+                None => false,
+            },
+            Expr::Contains(field, value) => match (field.get(endpoint), value) {
+                (Some(serde_json::Value::Array(items)), value) => {
+                    items.iter().any(|item| values_equal(item, value))
+                }
+                (Some(serde_json::Value::String(s)), serde_json::Value::String(substring)) => {
+                    s.contains(substring.as_str())
+                }
+                _ => false,
+            },
+            Expr::Exists(field) => !matches!(
+                field.get(endpoint),
+                None | Some(serde_json::Value::Null) | Some(serde_json::Value::Bool(false))
+            ),
+        }
+    }
+}
+
+impl Field {
+    fn get(&self, endpoint: &Endpoint) -> Option<serde_json::Value> {
+        match self {
+            Field::Name => Some(endpoint.name.clone().into()),
+            Field::Url => Some(endpoint.url.clone().into()),
+            Field::ChainId => endpoint.chain_id.as_ref().map(|x| x.to_string().into()),
+            Field::Metadata(path) => {
+                let (first, rest) = path.split_first()?;
+                let mut value = endpoint.endpoint_metadata.get(first)?;
+                for key in rest.iter() {
+                    value = value.get(key)?;
+                }
+                Some(value.clone())
+            }
+        }
+    }
+}
+
+impl CompareOp {
+    fn test(&self, ordering: std::cmp::Ordering) -> bool {
+        use std::cmp::Ordering::*;
+        match self {
+            CompareOp::Eq => ordering == Equal,
+            CompareOp::Ne => ordering != Equal,
+            CompareOp::Lt => ordering == Less,
+            CompareOp::Le => ordering != Greater,
+            CompareOp::Gt => ordering == Greater,
+            CompareOp::Ge => ordering != Less,
+        }
+    }
+}
+
+fn compare(lhs: Option<&serde_json::Value>, op: CompareOp, rhs: &serde_json::Value) -> bool {
+    let lhs = match lhs {
+        Some(lhs) => lhs,
+        None => return op == CompareOp::Ne,
+    };
+    match op {
+        CompareOp::Eq => values_equal(lhs, rhs),
+        CompareOp::Ne => !values_equal(lhs, rhs),
+        _ => {
+            let ordering = match (lhs, rhs) {
+                (serde_json::Value::Number(lhs), serde_json::Value::Number(rhs)) => {
+                    lhs.as_f64().partial_cmp(&rhs.as_f64())
+                }
+                (serde_json::Value::String(lhs), serde_json::Value::String(rhs)) => {
+                    Some(lhs.cmp(rhs))
+                }
+                _ => None,
+            };
+            ordering.is_some_and(|ordering| op.test(ordering))
+        }
+    }
+}
+
+fn values_equal(lhs: &serde_json::Value, rhs: &serde_json::Value) -> bool {
+    match (lhs, rhs) {
+        (serde_json::Value::Number(lhs), serde_json::Value::Number(rhs)) => {
+            lhs.as_f64() == rhs.as_f64()
+        }
+        _ => lhs == rhs,
+    }
+}
+
+//
+// // parsing
+//
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Ident(String),
+    Str(String),
+    Num(String),
+    Op(CompareOp),
+    LParen,
+    RParen,
+    Comma,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    column: usize,
+}
+
+impl Token {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(&self.kind, TokenKind::Ident(ident) if ident.eq_ignore_ascii_case(keyword))
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, MescError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let column = i + 1;
+        let kind = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => TokenKind::LParen,
+            ')' => TokenKind::RParen,
+            ',' => TokenKind::Comma,
+            '=' | '!' | '<' | '>' => {
+                let next_is_eq = chars.get(i + 1) == Some(&'=');
+                let op = match (c, next_is_eq) {
+                    ('=', _) => CompareOp::Eq,
+                    ('!', true) => CompareOp::Ne,
+                    ('<', false) => CompareOp::Lt,
+                    ('<', true) => CompareOp::Le,
+                    ('>', false) => CompareOp::Gt,
+                    ('>', true) => CompareOp::Ge,
+                    _ => return Err(filter_error(column, "expected '!='")),
+                };
+                if next_is_eq {
+                    i += 1;
+                }
+                TokenKind::Op(op)
+            }
+            '"' | '\'' => {
+                let mut value = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err(filter_error(column, "unterminated string")),
+                        Some('\\') => match chars.get(i + 1) {
+                            Some(escaped) => {
+                                value.push(*escaped);
+                                i += 2;
+                            }
+                            None => return Err(filter_error(i + 1, "trailing escape")),
+                        },
+                        Some(quote) if *quote == c => break,
+                        Some(other) => {
+                            value.push(*other);
+                            i += 1;
+                        }
+                    }
+                }
+                TokenKind::Str(value)
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '.' => {
+                let start = i;
+                while i + 1 < chars.len()
+                    && (chars[i + 1].is_ascii_alphanumeric() || chars[i + 1] == '.')
+                {
+                    i += 1;
+                }
+                TokenKind::Num(chars[start..=i].iter().collect())
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i + 1 < chars.len()
+                    && (chars[i + 1].is_alphanumeric() || matches!(chars[i + 1], '_' | '-' | '.'))
+                {
+                    i += 1;
+                }
+                TokenKind::Ident(chars[start..=i].iter().collect())
+            }
+            _ => return Err(filter_error(column, &format!("unexpected character '{}'", c))),
+        };
+        tokens.push(Token { kind, column });
+        i += 1;
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self, expected: &str) -> Result<Token, MescError> {
+        match self.tokens.get(self.position) {
+            Some(token) => {
+                self.position += 1;
+                Ok(token.clone())
+            }
+            None => Err(filter_error(self.end, &format!("expected {}", expected))),
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(token) if token.is_keyword(keyword) => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, MescError> {
+        let mut expr = self.parse_and()?;
+        while self.eat_keyword("or") {
+            expr = Expr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, MescError> {
+        let mut expr = self.parse_not()?;
+        while self.eat_keyword("and") {
+            expr = Expr::And(Box::new(expr), Box::new(self.parse_not()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_not(&mut self) -> Result<Expr, MescError> {
+        if self.eat_keyword("not") {
+            Ok(Expr::Not(Box::new(self.parse_not()?)))
+        } else {
+            self.parse_primary()
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, MescError> {
+        let token = self.next("field, value, or '('")?;
+        match &token.kind {
+            TokenKind::LParen => {
+                let expr = self.parse_or()?;
+                let close = self.next("')'")?;
+                if close.kind != TokenKind::RParen {
+                    return Err(filter_error(close.column, "expected ')'"));
+                }
+                Ok(expr)
+            }
+            TokenKind::Ident(ident) if is_reserved(ident) => {
+                Err(filter_error(token.column, &format!("unexpected keyword '{}'", ident)))
+            }
+            TokenKind::Ident(ident) => {
+                let field = parse_field(ident);
+                self.parse_field_predicate(field)
+            }
+            TokenKind::Str(_) | TokenKind::Num(_) => {
+                let value = parse_value(&token)?;
+                let negate = self.eat_keyword("not");
+                let keyword = self.next("'in'")?;
+                if !keyword.is_keyword("in") {
+                    return Err(filter_error(keyword.column, "expected 'in'"));
+                }
+                let field_token = self.next("field")?;
+                let field = match &field_token.kind {
+                    TokenKind::Ident(ident) if !is_reserved(ident) => parse_field(ident),
+                    _ => return Err(filter_error(field_token.column, "expected field")),
+                };
+                Ok(negated(Expr::Contains(field, value), negate))
+            }
+            _ => Err(filter_error(token.column, "expected field, value, or '('")),
+        }
+    }
+
+    fn parse_field_predicate(&mut self, field: Field) -> Result<Expr, MescError> {
+        let negate = match self.tokens.get(self.position..self.position + 2) {
+            Some([not, in_]) if not.is_keyword("not") && in_.is_keyword("in") => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        };
+        match self.peek().map(|token| token.kind.clone()) {
+            Some(TokenKind::Op(op)) => {
+                self.position += 1;
+                let token = self.next("value")?;
+                let expr = if field == Field::ChainId {
+                    Expr::CompareChainId(op, parse_chain_id(&token)?)
+                } else {
+                    Expr::Compare(field, op, parse_value(&token)?)
+                };
+                Ok(expr)
+            }
+            Some(TokenKind::Ident(ident)) if ident.eq_ignore_ascii_case("in") => {
+                self.position += 1;
+                let open = self.next("'('")?;
+                if open.kind != TokenKind::LParen {
+                    return Err(filter_error(open.column, "expected '('"));
+                }
+                let mut tokens = Vec::new();
+                loop {
+                    tokens.push(self.next("value")?);
+                    let separator = self.next("',' or ')'")?;
+                    match separator.kind {
+                        TokenKind::Comma => continue,
+                        TokenKind::RParen => break,
+                        _ => return Err(filter_error(separator.column, "expected ',' or ')'")),
+                    }
+                }
+                let expr = if field == Field::ChainId {
+                    Expr::InChainIds(tokens.iter().map(parse_chain_id).collect::<Result<_, _>>()?)
+                } else {
+                    Expr::In(field, tokens.iter().map(parse_value).collect::<Result<_, _>>()?)
+                };
+                Ok(negated(expr, negate))
+            }
+            _ => Ok(Expr::Exists(field)),
+        }
+    }
+}
+
+fn negated(expr: Expr, negate: bool) -> Expr {
+    if negate {
+        Expr::Not(Box::new(expr))
+    } else {
+        expr
+    }
+}
+
+fn is_reserved(ident: &str) -> bool {
+    ["and", "or", "not", "in", "true", "false", "null"]
+        .iter()
+        .any(|keyword| ident.eq_ignore_ascii_case(keyword))
+}
+
+fn parse_field(ident: &str) -> Field {
+    match ident {
+        "name" => Field::Name,
+        "url" => Field::Url,
+        "chain_id" => Field::ChainId,
+        _ => {
+            let path = ident
+                .strip_prefix("metadata.")
+                .or_else(|| ident.strip_prefix("endpoint_metadata."))
+                .unwrap_or(ident);
+            Field::Metadata(path.split('.').map(|key| key.to_string()).collect())
+        }
+    }
+}
+
+fn parse_value(token: &Token) -> Result<serde_json::Value, MescError> {
+    match &token.kind {
+        TokenKind::Str(value) => Ok(serde_json::Value::String(value.clone())),
+        TokenKind::Num(text) => serde_json::from_str::<serde_json::Number>(text)
+            .map(serde_json::Value::Number)
+            .map_err(|_| filter_error(token.column, &format!("invalid number: {}", text))),
+        TokenKind::Ident(ident) if ident.eq_ignore_ascii_case("true") => Ok(true.into()),
+        TokenKind::Ident(ident) if ident.eq_ignore_ascii_case("false") => Ok(false.into()),
+        TokenKind::Ident(ident) if ident.eq_ignore_ascii_case("null") => {
+            Ok(serde_json::Value::Null)
+        }
+        _ => Err(filter_error(token.column, "expected string, number, true, false, or null")),
+    }
+}
+
+fn parse_chain_id(token: &Token) -> Result<ChainId, MescError> {
+    let text = match &token.kind {
+        TokenKind::Ident(text) if is_reserved(text) => {
+            return Err(filter_error(token.column, "expected chain id"))
+        }
+        TokenKind::Str(text) | TokenKind::Num(text) | TokenKind::Ident(text) => text,
+        _ => return Err(filter_error(token.column, "expected chain id")),
+    };
+    text.as_str()
+        .try_into_chain_id()
+        .ok()
+        .or_else(|| directory::get_network_chain_id(text))
+        .ok_or_else(|| filter_error(token.column, &format!("invalid chain_id: {}", text)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn endpoint(name: &str, chain_id: u64, metadata: serde_json::Value) -> Endpoint {
+        Endpoint {
+            name: name.to_string(),
+            url: format!("https://{}.example.com", name),
+            chain_id: Some(ChainId::new(chain_id)),
+            endpoint_metadata: serde_json::from_value::<HashMap<_, _>>(metadata).unwrap(),
+        }
+    }
+
+    fn endpoints() -> Vec<Endpoint> {
+        vec![
+            endpoint(
+                "alchemy_eth",
+                1,
+                serde_json::json!({"host": "alchemy", "labels": ["archive"], "rate_limit_rps": 250}),
+            ),
+            endpoint(
+                "quicknode_op",
+                10,
+                serde_json::json!({"host": "quicknode", "labels": ["archive"]}),
+            ),
+            endpoint("local_op", 10, serde_json::json!({"labels": [], "limits": {"rps": 10}})),
+            endpoint("base", 8453, serde_json::json!({})),
+        ]
+    }
+
+    fn matching(filter: &str) -> Vec<String> {
+        let filter = EndpointFilter::parse(filter).unwrap();
+        endpoints().into_iter().filter(|e| filter.matches(e)).map(|e| e.name).collect()
+    }
+
+    fn error_column(filter: &str) -> usize {
+        match EndpointFilter::parse(filter) {
+            Err(MescError::InvalidFilter { column, .. }) => column,
+            other => panic!("expected filter error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn combined_expression() {
+        let filter = r#"chain_id in (1,10) and "archive" in labels and host != "quicknode""#;
+        assert_eq!(matching(filter), vec!["alchemy_eth"]);
+    }
+
+    #[test]
+    fn precedence_and_negation() {
+        assert_eq!(
+            matching("chain_id == 8453 or chain_id == 1 and host"),
+            vec!["alchemy_eth", "base"]
+        );
+        assert_eq!(matching("not (chain_id = 10) and name != 'base'"), vec!["alchemy_eth"]);
+        assert_eq!(matching("'archive' not in labels"), vec!["local_op", "base"]);
+        assert_eq!(matching("chain_id not in (optimism, 0x1)"), vec!["base"]);
+    }
+
+    #[test]
+    fn metadata_values() {
+        assert_eq!(matching("rate_limit_rps >= 250.0"), vec!["alchemy_eth"]);
+        assert_eq!(matching("limits.rps < 100"), vec!["local_op"]);
+        assert_eq!(matching("\"quicknode\" in url"), vec!["quicknode_op"]);
+        assert_eq!(
+            matching("metadata.host in ('alchemy', 'quicknode')"),
+            vec!["alchemy_eth", "quicknode_op"]
+        );
+    }
+
+    #[test]
+    fn parse_errors_point_at_column() {
+        assert_eq!(error_column("chain_id in (1, 10"), 19);
+        assert_eq!(error_column("host == \"alchemy"), 9);
+        assert_eq!(error_column("chain_id == abc"), 13);
+        assert_eq!(error_column("host == 'x' labels"), 13);
+        assert_eq!(error_column("host ! 'x'"), 6);
+    }
+
+    #[test]
+    fn bare_fields_ignore_null_and_false() {
+        let endpoints = [
+            endpoint("yes", 1, serde_json::json!({"archive": true})),
+            endpoint("no", 1, serde_json::json!({"archive": false})),
+            endpoint("null", 1, serde_json::json!({"archive": null})),
+            endpoint("zero", 1, serde_json::json!({"archive": 0})),
+            endpoint("empty", 1, serde_json::json!({"archive": ""})),
+            endpoint("missing", 1, serde_json::json!({})),
+        ];
+        let matching = |filter: &str| -> Vec<&str> {
+            let filter = EndpointFilter::parse(filter).unwrap();
+            endpoints.iter().filter(|e| filter.matches(e)).map(|e| e.name.as_str()).collect()
+        };
+        assert_eq!(matching("archive"), ["yes", "zero", "empty"]);
+        assert_eq!(matching("not archive"), ["no", "null", "missing"]);
+        assert_eq!(matching("archive == true"), ["yes"]);
+        assert_eq!(matching("archive == false"), ["no"]);
+        assert_eq!(matching("archive == null"), ["null"]);
+        assert_eq!(matching("archive != false"), ["yes", "null", "zero", "empty", "missing"]);
+    }
+
+    #[test]
+    fn keywords_are_not_fields() {
+        for filter in ["true", "null == 1", "false and name", "name == 'x' or in"] {
+            assert!(
+                matches!(EndpointFilter::parse(filter), Err(MescError::InvalidFilter { .. })),
+                "{}",
+                filter
+            );
+        }
+        assert_eq!(error_column("true"), 1);
+        assert_eq!(error_column("name == 'x' or not"), 19);
+
+        let endpoints = [
+            endpoint("a", 1, serde_json::json!({"true": "yes", "in": ["x"]})),
+            endpoint("b", 1, serde_json::json!({})),
+        ];
+        for filter in ["metadata.true == 'yes'", "metadata.true", "'x' in metadata.in"] {
+            let filter = EndpointFilter::parse(filter).unwrap();
+            let names: Vec<_> =
+                endpoints.iter().filter(|e| filter.matches(e)).map(|e| e.name.as_str()).collect();
+            assert_eq!(names, ["a"], "{}", filter);
+        }
+    }
+}
@@ -3,7 +3,8 @@ use crate::{
     load::load_config_data,
     query,
     types::{Endpoint, MescError},
-    MultiEndpointQuery, TryIntoChainId,
+    MescQuery, MescQueryResult, MultiEndpointQuery, ResolutionTrace, SelectionStrategy,
+    TryIntoChainId,
 };
 use std::collections::HashMap;
 
@@ -25,12 +26,20 @@ pub fn get_endpoint_by_network<T: TryIntoChainId + std::fmt::Debug + std::clone:
     query::get_endpoint_by_network(&load_config_data()?, chain_id, profile)
 }
 
+/// get endpoints of network in order of preference
+pub fn get_endpoints_by_network_ranked<T: TryIntoChainId + std::fmt::Debug + std::clone::Clone>(
+    chain_id: T,
+    profile: Option<&str>,
+) -> Result<Vec<Endpoint>, MescError> {
+    query::get_endpoints_by_network_ranked(&load_config_data()?, chain_id, profile)
+}
+
 /// get endpoint by name
 pub fn get_endpoint_by_name(name: &str) -> Result<Option<Endpoint>, MescError> {
     query::get_endpoint_by_name(&load_config_data()?, name)
 }
 
-/// parse user query
+/// parse user query, which can be an endpoint name, url, group, chain_id, or network name
 pub fn get_endpoint_by_query(
     query: &str,
     profile: Option<&str>,
@@ -38,11 +47,51 @@ pub fn get_endpoint_by_query(
     query::get_endpoint_by_query(&load_config_data()?, query, profile)
 }
 
+/// parse user query, along with each step taken to resolve it
+pub fn trace_endpoint_by_query(
+    query: &str,
+    profile: Option<&str>,
+) -> Result<ResolutionTrace, MescError> {
+    query::trace_endpoint_by_query(&load_config_data()?, query, profile)
+}
+
 /// find endpoints
 pub fn find_endpoints(query: MultiEndpointQuery) -> Result<Vec<Endpoint>, MescError> {
     query::find_endpoints(&load_config_data()?, query)
 }
 
+/// get endpoints of group
+pub fn get_group(name: &str) -> Result<Option<Vec<Endpoint>>, MescError> {
+    query::get_group(&load_config_data()?, name)
+}
+
+/// list names of endpoint groups
+pub fn list_groups() -> Result<Vec<String>, MescError> {
+    query::list_groups(&load_config_data()?)
+}
+
+/// select member of endpoint group using strategy
+pub fn select_endpoint(
+    group: &str,
+    strategy: SelectionStrategy,
+) -> Result<Option<Endpoint>, MescError> {
+    query::select_endpoint(&load_config_data()?, group, strategy)
+}
+
+/// execute query
+pub fn execute(query: MescQuery) -> Result<MescQueryResult, MescError> {
+    query::execute(&load_config_data()?, query)
+}
+
+/// get effective metadata value, looking in endpoint, then profile, then global metadata
+pub fn get_metadata_value<T: serde::de::DeserializeOwned>(
+    path: &str,
+    endpoint: Option<&Endpoint>,
+    profile: Option<&str>,
+) -> Result<Option<T>, MescError> {
+    query::get_metadata_value(&load_config_data()?, path, endpoint, profile)
+}
+
 /// get global metadata
 pub fn get_global_metadata(
     profile: Option<&str>,
@@ -9,6 +9,8 @@
 
 /// directory matching chain_id's to network names
 pub mod directory;
+/// filter expression module
+pub mod filter;
 mod types;
 mod validate;
 pub use types::*;
@@ -21,7 +23,31 @@ pub mod network_names;
 pub use interface::*;
 /// overrides module
 pub mod overrides;
+/// provenance module
+pub mod provenance;
 /// queries module
 pub mod query;
 /// write module
 pub mod write;
+
+/// run function with only the given MESC_* variables set
+///
+/// config is read from the process environment, so tests that set variables must not run
+/// concurrently
+#[cfg(test)]
+pub(crate) fn with_env<T>(vars: &[(&str, &str)], f: impl FnOnce() -> T) -> T {
+    static ENV_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mesc_vars = ["MESC_MODE", "MESC_PATH", "MESC_ENV", "MESC_PROFILE"];
+    for var in mesc_vars.iter().chain(overrides::OVERRIDE_VARS.iter()) {
+        std::env::remove_var(var);
+    }
+    for (var, value) in vars.iter() {
+        std::env::set_var(var, value);
+    }
+    let result = f();
+    for (var, _) in vars.iter() {
+        std::env::remove_var(var);
+    }
+    result
+}
@@ -1,4 +1,8 @@
-use crate::{overrides::apply_overrides, ConfigMode, MescError, RpcConfig};
+use crate::{
+    overrides::{apply_overrides, get_active_overrides},
+    ConfigMode, MescError, RpcConfig,
+};
+use serde_json::Value;
 use std::{env, fs};
 
 /// check whether mesc is enabled
@@ -49,28 +53,64 @@ pub fn get_config_mode() -> Result<ConfigMode, MescError> {
             return Ok(ConfigMode::Env);
         }
     }
+    if !get_active_overrides().is_empty() {
+        return Ok(ConfigMode::Overrides);
+    }
 
     Ok(ConfigMode::Disabled)
 }
 
 /// load config data
 pub fn load_config_data() -> Result<RpcConfig, MescError> {
-    let config = match get_config_mode() {
-        Ok(ConfigMode::Path) => load_file_config(None),
-        Ok(ConfigMode::Env) => load_env_config(),
-        Ok(ConfigMode::Disabled) => Err(MescError::MescNotEnabled),
-        Err(e) => Err(e),
+    let (mut config, mode) = load_base_config()?;
+    apply_overrides(&mut config)?;
+    if mode == ConfigMode::Overrides {
+        config.validate()?;
+    }
+    Ok(config)
+}
+
+/// load config data for the current mode, before override variables are applied
+pub(crate) fn load_base_config() -> Result<(RpcConfig, ConfigMode), MescError> {
+    let mode = get_config_mode()?;
+    let config = match mode {
+        ConfigMode::Path => load_file_config(None)?,
+        ConfigMode::Env => load_env_config()?,
+        ConfigMode::Overrides => RpcConfig::default(),
+        ConfigMode::Disabled => return Err(MescError::MescNotEnabled),
     };
+    Ok((config, mode))
+}
 
-    let mut config = config?;
+/// load config built only from override variables
+pub fn load_overrides_config() -> Result<RpcConfig, MescError> {
+    let mut config = RpcConfig::default();
     apply_overrides(&mut config)?;
+    config.validate()?;
     Ok(config)
 }
 
 /// load env config
 pub fn load_env_config() -> Result<RpcConfig, MescError> {
     let config_json = env::var("MESC_ENV")?;
-    serde_json::from_str(&config_json).map_err(|_| MescError::InvalidJson)
+    parse_config_json(&config_json, "MESC_ENV")
+}
+
+/// load raw json of underlying config with its origin (file path or MESC_ENV), or None if
+/// config does not come from MESC_PATH or MESC_ENV
+pub fn load_config_json() -> Result<Option<(String, String)>, MescError> {
+    match get_config_mode()? {
+        ConfigMode::Path => {
+            let path = get_config_path()?;
+            if !std::path::Path::new(path.as_str()).exists() {
+                return Err(MescError::MissingConfigFile(path));
+            };
+            let config_json = fs::read_to_string(&path)?;
+            Ok(Some((path, config_json)))
+        }
+        ConfigMode::Env => Ok(Some(("MESC_ENV".to_string(), env::var("MESC_ENV")?))),
+        ConfigMode::Overrides | ConfigMode::Disabled => Ok(None),
+    }
 }
 
 /// load file config
@@ -82,8 +122,72 @@ pub fn load_file_config(path: Option<String>) -> Result<RpcConfig, MescError> {
     if !std::path::Path::new(path.as_str()).exists() {
         return Err(MescError::MissingConfigFile(path));
     };
-    let config_str = fs::read_to_string(path).map_err(MescError::IOError)?;
-    serde_json::from_str(&config_str).map_err(|_| MescError::InvalidJson)
+    let config_str = fs::read_to_string(&path).map_err(MescError::IOError)?;
+    parse_config_json(&config_str, &path)
+}
+
+/// parse config json, reporting location of syntax errors and json path of schema errors
+///
+/// `origin` names where the data came from (a file path or MESC_ENV) and is included in errors
+pub fn parse_config_json(config_json: &str, origin: &str) -> Result<RpcConfig, MescError> {
+    let mut deserializer = serde_json::Deserializer::from_str(config_json);
+    match serde_path_to_error::deserialize::<_, RpcConfig>(&mut deserializer) {
+        Ok(config) => {
+            deserializer.end().map_err(|error| json_syntax_error(config_json, origin, error))?;
+            Ok(config)
+        }
+        Err(error) if error.inner().is_data() => {
+            // syntax errors later in the data take precedence over schema errors
+            serde_json::from_str::<Value>(config_json)
+                .map_err(|error| json_syntax_error(config_json, origin, error))?;
+            let path = match error.path().to_string() {
+                path if path == "." => String::new(),
+                path => path,
+            };
+            let error = error.into_inner();
+            let message = error_message(&error);
+            Err(MescError::InvalidConfigSchema { origin: origin.to_string(), path, message, error })
+        }
+        Err(error) => Err(json_syntax_error(config_json, origin, error.into_inner())),
+    }
+}
+
+/// convert json syntax error into error with location and snippet of offending line
+pub(crate) fn json_syntax_error(
+    config_json: &str,
+    origin: &str,
+    error: serde_json::Error,
+) -> MescError {
+    const WIDTH: usize = 80;
+    let (line, column) = (error.line(), error.column());
+    let line_text = config_json.lines().nth(line.saturating_sub(1)).unwrap_or_default();
+    let text: Vec<char> = line_text.chars().collect();
+
+    // serde_json columns count bytes, the caret is placed by char
+    let byte = column.saturating_sub(1);
+    let caret = line_text.char_indices().take_while(|(i, _)| *i < byte).count();
+
+    // keep a window of long lines around the problem
+    let start = caret.saturating_sub(WIDTH / 2).min(text.len().saturating_sub(WIDTH));
+    let end = (start + WIDTH).min(text.len());
+    let snippet: String = text[start..end].iter().collect();
+    let snippet = format!("    {}\n    {}^", snippet, " ".repeat(caret - start));
+
+    MescError::InvalidConfigJson {
+        origin: origin.to_string(),
+        line,
+        column,
+        message: error_message(&error),
+        snippet,
+        error,
+    }
+}
+
+/// serde_json error message without its location suffix
+fn error_message(error: &serde_json::Error) -> String {
+    let message = error.to_string();
+    let suffix = format!(" at line {} column {}", error.line(), error.column());
+    message.strip_suffix(suffix.as_str()).unwrap_or(message.as_str()).to_string()
 }
 
 /// get config path
@@ -104,3 +208,122 @@ pub fn expand_path<P: AsRef<std::path::Path>>(path: P) -> Result<String, MescErr
         Ok(path_str.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ChainId;
+
+    #[test]
+    fn parse_errors_report_location_and_path() {
+        let config_json = "{\n    \"mesc_version\": \"MESC 1.0\",\n    \"endpoints\": {},\n}";
+        match parse_config_json(config_json, "mesc.json") {
+            Err(MescError::InvalidConfigJson { origin, line, column, snippet, .. }) => {
+                assert_eq!((origin.as_str(), line, column), ("mesc.json", 4, 1));
+                assert_eq!(snippet, "    }\n    ^");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let mut config = serde_json::to_value(RpcConfig::default()).unwrap();
+        config["endpoints"]["foo"] = serde_json::json!({
+            "name": "foo",
+            "url": "http://localhost:8545",
+            "chain_id": "mainnet",
+            "endpoint_metadata": {}
+        });
+        match parse_config_json(&config.to_string(), "MESC_ENV") {
+            Err(MescError::InvalidConfigSchema { path, message, .. }) => {
+                assert_eq!(path, "endpoints.foo.chain_id");
+                assert_eq!(message, "Invalid chain ID: mainnet");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn syntax_error_caret_counts_chars_not_bytes() {
+        let config_json = "{\"mesc_version\": \"ünïcødé ✓\", }";
+        match parse_config_json(config_json, "mesc.json") {
+            Err(MescError::InvalidConfigJson { snippet, .. }) => {
+                let caret = snippet.lines().nth(1).unwrap().chars().count() - 1;
+                assert_eq!(snippet.lines().next().unwrap().chars().nth(caret), Some('}'));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let long = format!("{{\"mesc_version\": \"{}\", }}", "é".repeat(100));
+        match parse_config_json(&long, "mesc.json") {
+            Err(MescError::InvalidConfigJson { snippet, .. }) => {
+                let caret = snippet.lines().nth(1).unwrap().chars().count() - 1;
+                assert_eq!(snippet.lines().next().unwrap().chars().nth(caret), Some('}'));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn schema_errors_report_path_of_first_invalid_value() {
+        let endpoint = serde_json::json!({"name": "foo", "chain_id": "1", "endpoint_metadata": {}});
+        let profile = serde_json::json!({
+            "name": "p",
+            "default_endpoint": null,
+            "network_defaults": {},
+            "profile_metadata": {},
+            "use_mesc": "yes"
+        });
+        let cases = [
+            ("mesc_version", serde_json::json!(1), "mesc_version"),
+            ("endpoints", serde_json::json!({"foo": endpoint}), "endpoints.foo"),
+            (
+                "network_defaults",
+                serde_json::json!({"mainnet!": "foo"}),
+                "network_defaults.mainnet!",
+            ),
+            ("network_defaults", serde_json::json!({"1": 5}), "network_defaults.1"),
+            ("network_names", serde_json::json!({"x": "abc"}), "network_names.x"),
+            ("profiles", serde_json::json!({"p": profile}), "profiles.p.use_mesc"),
+            ("global_metadata", serde_json::json!([]), "global_metadata"),
+        ];
+        for (key, value, expected) in cases {
+            let mut config = serde_json::to_value(RpcConfig::default()).unwrap();
+            config[key] = value;
+            match parse_config_json(&config.to_string(), "mesc.json") {
+                Err(MescError::InvalidConfigSchema { origin, path, .. }) => {
+                    assert_eq!((origin.as_str(), path.as_str()), ("mesc.json", expected))
+                }
+                other => panic!("unexpected result for {}: {:?}", expected, other),
+            }
+        }
+
+        // syntax errors are reported even after a schema error
+        let config_json = "{\"mesc_version\": 1, }";
+        let result = parse_config_json(config_json, "mesc.json");
+        assert!(matches!(result, Err(MescError::InvalidConfigJson { column: 21, .. })));
+        let config_json = serde_json::to_string(&RpcConfig::default()).unwrap() + " x";
+        let result = parse_config_json(&config_json, "mesc.json");
+        assert!(matches!(result, Err(MescError::InvalidConfigJson { .. })));
+    }
+
+    #[test]
+    fn overrides_build_config_without_file() {
+        let vars = [
+            ("MESC_ENDPOINTS", "local:1=localhost:8545"),
+            ("MESC_NETWORK_DEFAULTS", "1=local"),
+            ("MESC_DEFAULT_ENDPOINT", "local"),
+        ];
+        let config = crate::with_env(&vars, || {
+            assert_eq!(get_config_mode().unwrap(), ConfigMode::Overrides);
+            load_config_data()
+        })
+        .unwrap();
+        assert_eq!(config.default_endpoint.as_deref(), Some("local"));
+        assert_eq!(config.endpoints["local"].url, "localhost:8545");
+        assert_eq!(config.network_defaults[&ChainId::new(1)], "local");
+        assert!(config.profiles.is_empty());
+
+        let vars = [("MESC_DEFAULT_ENDPOINT", "missing")];
+        let result = crate::with_env(&vars, load_config_data);
+        assert!(matches!(result, Err(MescError::OverrideError(_))), "{:?}", result);
+    }
+}
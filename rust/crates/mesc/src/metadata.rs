@@ -1,48 +1,156 @@
-use crate::MescError;
-use serde::de::DeserializeOwned;
+use crate::{ChainId, MescError, RpcConfig, TryIntoChainId};
 use std::collections::HashMap;
 
-/// get api key
+/// metadata key of ordered fallback endpoints for each network
+pub const NETWORK_FALLBACKS_KEY: &str = "network_fallbacks";
+
+/// global metadata key of endpoint groups
+pub const GROUPS_KEY: &str = "groups";
+
+/// endpoint metadata key of relative weight used for weighted selection
+pub const WEIGHT_KEY: &str = "weight";
+
+/// endpoint metadata key of observed latency in milliseconds
+pub const LATENCY_KEY: &str = "latency_ms";
+
+/// endpoint metadata key of observed health
+pub const HEALTHY_KEY: &str = "healthy";
+
+/// get api key from profile or global `api_keys` metadata
+///
+/// profiles with `use_mesc` disabled still provide their api keys
 pub fn get_api_key<T: AsRef<str>>(
     key_name: T,
     profile_name: Option<T>,
 ) -> Result<Option<String>, MescError> {
-    let key_name = key_name.as_ref();
     let config = crate::load::load_config_data()?;
+    get_config_api_key(&config, key_name.as_ref(), profile_name.as_ref().map(|x| x.as_ref()))
+}
 
-    if let Some(profile_name) = profile_name {
-        if let Some(profile) = config.profiles.get(profile_name.as_ref()) {
-            if let Some(api_keys) = profile.profile_metadata.get("api_keys") {
-                let api_keys: HashMap<String, String> = get_value_at(api_keys, &[])?;
-                if let Some(key) = api_keys.get(key_name) {
-                    return Ok(Some(key.clone()));
+fn get_config_api_key(
+    config: &RpcConfig,
+    key_name: &str,
+    profile: Option<&str>,
+) -> Result<Option<String>, MescError> {
+    let path = ["api_keys", key_name];
+    let profile_metadata =
+        profile.and_then(|profile| config.profiles.get(profile)).map(|p| &p.profile_metadata);
+    for metadata in profile_metadata.into_iter().chain([&config.global_metadata]) {
+        if let Some(value) = get_metadata_path(metadata, &path) {
+            return serde_json::from_value(value.clone()).map(Some).map_err(|_| {
+                MescError::InvalidMetadataType {
+                    key: path.join("."),
+                    expected: short_type_name::<String>(),
+                    found: json_type_name(value).to_string(),
                 }
-            }
+            });
         }
+    }
+    Ok(None)
+}
+
+/// get ordered fallback endpoint names of each network from global or profile metadata
+///
+/// fallbacks are stored as `{"network_fallbacks": {"<chain_id>": ["<endpoint>", ...]}}`
+pub fn get_network_fallbacks(
+    metadata: &HashMap<String, serde_json::Value>,
+) -> Result<HashMap<ChainId, Vec<String>>, MescError> {
+    let raw: HashMap<String, Vec<String>> = match metadata.get(NETWORK_FALLBACKS_KEY) {
+        Some(value) => serde_json::from_value(value.clone()).map_err(|_| {
+            MescError::IntegrityError(format!(
+                "{} must map chain_id's to lists of endpoint names",
+                NETWORK_FALLBACKS_KEY
+            ))
+        })?,
+        None => return Ok(HashMap::new()),
     };
+    raw.into_iter().map(|(chain_id, names)| Ok((chain_id.try_into_chain_id()?, names))).collect()
+}
 
-    if let Some(api_keys) = config.global_metadata.get("api_keys") {
-        let api_keys: HashMap<String, String> = get_value_at(api_keys, &[])?;
-        if let Some(key) = api_keys.get(key_name) {
-            return Ok(Some(key.clone()));
-        }
+/// get endpoint groups from global metadata, mapping group name to endpoint names
+pub fn get_groups(
+    metadata: &HashMap<String, serde_json::Value>,
+) -> Result<HashMap<String, Vec<String>>, MescError> {
+    match metadata.get(GROUPS_KEY) {
+        Some(value) => serde_json::from_value(value.clone()).map_err(|_| {
+            MescError::IntegrityError(format!(
+                "{} must map group names to lists of endpoint names",
+                GROUPS_KEY
+            ))
+        }),
+        None => Ok(HashMap::new()),
     }
+}
 
-    Ok(None)
+/// get value at path of keys within metadata
+pub fn get_metadata_path<'a>(
+    metadata: &'a HashMap<String, serde_json::Value>,
+    path: &[&str],
+) -> Option<&'a serde_json::Value> {
+    let (first, rest) = path.split_first()?;
+    let mut current = metadata.get(*first)?;
+    for key in rest {
+        current = current.get(key)?;
+    }
+    Some(current)
 }
 
-fn get_value_at<T>(root: &serde_json::Value, path: &[&str]) -> Result<T, MescError>
-where
-    T: DeserializeOwned,
-{
-    let mut current = root;
+/// name of json value type, as used in error messages
+pub(crate) fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "bool",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "list",
+        serde_json::Value::Object(_) => "map",
+    }
+}
 
-    for key in path {
-        current = match current.get(key) {
-            Some(value) => value,
-            None => return Err(MescError::IntegrityError("missing path".to_string())),
-        };
+/// name of rust type without module paths, as used in error messages
+pub(crate) fn short_type_name<T>() -> String {
+    let mut name = String::new();
+    for part in std::any::type_name::<T>().split("::") {
+        let n_keep = name.trim_end_matches(|c: char| c.is_alphanumeric() || c == '_').len();
+        name.truncate(n_keep);
+        name.push_str(part);
     }
+    name
+}
 
-    Ok(serde_json::from_value(current.clone())?)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Profile;
+
+    #[test]
+    fn api_keys_come_from_profile_then_global() {
+        let mut config = RpcConfig::default();
+        let api_keys = serde_json::json!({"etherscan": "global", "alchemy": "global"});
+        config.global_metadata.insert("api_keys".to_string(), api_keys);
+        let mut profile = Profile::new("xyz");
+        let api_keys = serde_json::json!({"etherscan": "profile"});
+        profile.profile_metadata.insert("api_keys".to_string(), api_keys);
+        config.profiles.insert("xyz".to_string(), profile);
+
+        let key = |config: &RpcConfig, key_name: &str, profile: Option<&str>| {
+            get_config_api_key(config, key_name, profile).unwrap()
+        };
+        assert_eq!(key(&config, "etherscan", Some("xyz")).as_deref(), Some("profile"));
+        assert_eq!(key(&config, "alchemy", Some("xyz")).as_deref(), Some("global"));
+        assert_eq!(key(&config, "etherscan", None).as_deref(), Some("global"));
+        assert_eq!(key(&config, "etherscan", Some("unknown")).as_deref(), Some("global"));
+        assert_eq!(key(&config, "infura", Some("xyz")), None);
+
+        // api keys do not depend on whether profile uses mesc for endpoints
+        config.profiles.get_mut("xyz").unwrap().use_mesc = false;
+        assert_eq!(key(&config, "etherscan", Some("xyz")).as_deref(), Some("profile"));
+        assert_eq!(key(&config, "alchemy", Some("xyz")).as_deref(), Some("global"));
+
+        config.global_metadata.insert("api_keys".to_string(), serde_json::json!({"alchemy": 1}));
+        assert!(matches!(
+            get_config_api_key(&config, "alchemy", None),
+            Err(MescError::InvalidMetadataType { key, .. }) if key == "api_keys.alchemy"
+        ));
+    }
 }
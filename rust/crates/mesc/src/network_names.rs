@@ -6,1856 +6,1859 @@
 //!
 //! Do not edit this file manually.
 
-use crate::ChainId;
+use crate::{directory::NetworkInfo, ChainId};
 use std::{collections::HashMap, sync::OnceLock};
 
 /// get default mapping between chain_id's and network names
 pub fn get_network_names() -> &'static HashMap<ChainId, &'static str> {
     static CACHE: OnceLock<HashMap<ChainId, &'static str>> = OnceLock::new();
-    CACHE.get_or_init(|| NETWORKS.iter().map(|&(chain_id, name)| (chain_id.into(), name)).collect())
+    CACHE.get_or_init(|| {
+        NETWORKS.iter().map(|network| (network.chain_id.into(), network.name)).collect()
+    })
 }
 
-pub(crate) const NETWORKS: &[(u64, &str)] = &[
-    (1, "ethereum"),
-    (2, "expanse"),
-    (3, "ropsten"),
-    (4, "rinkeby"),
-    (5, "goerli"),
-    (6, "kotti_testnet"),
-    (7, "thai"),
-    (8, "ubiq"),
-    (9, "ubiq_testnet"),
-    (10, "optimism"),
-    (11, "metadium"),
-    (12, "metadium_testnet"),
-    (13, "diode_testnet_staging"),
-    (14, "flare"),
-    (15, "diode_prenet"),
-    (16, "songbird_testnet_coston"),
-    (17, "thaifi"),
-    (18, "thundercore_testnet"),
-    (19, "songbird_canary"),
-    (20, "elastos"),
-    (21, "elastos_testnet"),
-    (22, "ela_did"),
-    (23, "ela_did_testnet"),
-    (24, "kardia"),
-    (25, "cronos"),
-    (26, "genesis_testnet"),
-    (27, "shiba"),
-    (28, "boba_rinkeby_testnet"),
-    (29, "genesis"),
-    (30, "rootstock"),
-    (31, "rootstock_testnet"),
-    (32, "gooddata_testnet"),
-    (33, "gooddata"),
-    (34, "secure"),
-    (35, "tbwg"),
-    (36, "dx"),
-    (37, "xpla"),
-    (38, "valorbit"),
-    (39, "u2u_solaris"),
-    (40, "telos_evm"),
-    (41, "telos_evm_testnet"),
-    (42, "lukso"),
-    (43, "darwinia_pangolin_testnet"),
-    (44, "crab"),
-    (45, "darwinia_pangoro_testnet"),
-    (46, "darwinia"),
-    (47, "acria_intelli"),
-    (48, "ennothem_proterozoic"),
-    (49, "ennothem_testnet_pioneer"),
-    (50, "xdc"),
-    (51, "xdc_apothem"),
-    (52, "coinex"),
-    (53, "coinex_testnet"),
-    (54, "openpiece"),
-    (55, "zyx"),
-    (56, "bsc"),
-    (57, "syscoin"),
-    (58, "ontology"),
-    (59, "eos_evm_legacy"),
-    (60, "go"),
-    (61, "ethereum_classic"),
-    (62, "morden_testnet"),
-    (63, "mordor_testnet"),
-    (64, "ellaism"),
-    (65, "okex_testnet"),
-    (66, "okx"),
-    (67, "db_testnet"),
-    (68, "soterone"),
-    (69, "optimism_kovan"),
-    (70, "hoo"),
-    (71, "conflux_espace_testnet"),
-    (72, "dx_testnet"),
-    (73, "fncy"),
-    (74, "id"),
-    (75, "decimal"),
-    (76, "mix"),
-    (77, "poa_sokol"),
-    (78, "primus"),
-    (79, "zenith"),
-    (80, "gene"),
-    (81, "japan_open"),
-    (82, "meter"),
-    (83, "meter_testnet"),
-    (84, "linqto_devnet"),
-    (85, "gate_testnet"),
-    (86, "gate"),
-    (87, "nova"),
-    (88, "viction"),
-    (89, "viction_testnet"),
-    (90, "garizon_stage0"),
-    (91, "garizon_stage1"),
-    (92, "garizon_stage2"),
-    (93, "garizon_stage3"),
-    (94, "swissdlt"),
-    (95, "camdl"),
-    (96, "bitkub"),
-    (97, "bnb_testnet"),
-    (98, "six_protocol"),
-    (99, "poa_core"),
-    (100, "gnosis"),
-    (101, "etherinc"),
-    (102, "web3games_testnet"),
-    (103, "worldland"),
-    (104, "kaiba_lightning_testnet"),
-    (105, "web3games_devnet"),
-    (106, "velas_evm"),
-    (107, "nebula_testnet"),
-    (108, "thundercore"),
-    (109, "shibarium"),
-    (110, "proton_testnet"),
-    (111, "etherlite"),
-    (112, "coinbit"),
-    (113, "dehvo"),
-    (114, "flare_testnet_coston2"),
-    (116, "debank"),
-    (117, "uptick"),
-    (118, "arcology_testnet"),
-    (119, "enuls"),
-    (120, "enuls_testnet"),
-    (121, "real"),
-    (122, "fuse"),
-    (123, "fuse_sparknet"),
-    (124, "decentralized_web"),
-    (125, "oy_testnet"),
-    (126, "oy"),
-    (127, "factory_127"),
-    (128, "huobi_eco"),
-    (129, "innovator"),
-    (131, "engram_testnet"),
-    (132, "namefi"),
-    (133, "hashkey_testnet"),
-    (134, "iexec"),
-    (135, "alyx_testnet"),
-    (136, "deam"),
-    (137, "polygon"),
-    (138, "defi_oracle_meta"),
-    (139, "woop"),
-    (140, "eternal"),
-    (141, "openpiece_testnet"),
-    (142, "dax"),
-    (144, "phi_v2"),
-    (145, "soraai_testnet"),
-    (147, "flag"),
-    (148, "shimmerevm"),
-    (150, "six_protocol_testnet"),
-    (151, "redbelly"),
-    (152, "redbelly_devnet"),
-    (153, "redbelly_testnet"),
-    (154, "redbelly_tge"),
-    (155, "tenet_testnet"),
-    (156, "oeblock_testnet"),
-    (157, "puppynet_shibarium"),
-    (158, "roburna"),
-    (159, "roburna_testnet"),
-    (160, "armonia_eva"),
-    (161, "armonia_eva_testnet"),
-    (162, "lightstreams_testnet"),
-    (163, "lightstreams"),
-    (164, "omni_omega_testnet"),
-    (166, "omni"),
-    (167, "atoshi_testnet"),
-    (168, "aioz"),
-    (169, "manta_pacific"),
-    (170, "hoo_testnet"),
-    (171, "co2e_ledger"),
-    (172, "latam_block_resil_testnet"),
-    (176, "dc"),
-    (178, "abey_testnet"),
-    (179, "abey"),
-    (180, "ame"),
-    (181, "waterfall"),
-    (183, "ethernity"),
-    (184, "dojima_testnet"),
-    (185, "mint"),
-    (186, "seele"),
-    (187, "dojima"),
-    (188, "bmc"),
-    (189, "bmc_testnet"),
-    (190, "cmdao_bbq"),
-    (191, "filefilego"),
-    (193, "crypto_emergency"),
-    (195, "x_layer_testnet"),
-    (196, "x_layer"),
-    (197, "neutrinos_testnet"),
-    (198, "bit"),
-    (199, "bittorrent"),
-    (200, "arbitrum_on_xdai"),
-    (201, "moac_testnet"),
-    (202, "edgeless_testnet"),
-    (204, "opbnb"),
-    (205, "ekaash"),
-    (206, "vinu_testnet"),
-    (207, "vinu"),
-    (208, "structx"),
-    (210, "bitnet"),
-    (211, "freight_trust"),
-    (212, "mapo_makalu"),
-    (213, "b2_hub"),
-    (214, "shinarium"),
-    (217, "siriusnet_v2"),
-    (218, "soterone_old"),
-    (220, "scalind_testnet"),
-    (221, "blockex"),
-    (222, "permission"),
-    (223, "b2"),
-    (224, "viridis_testnet"),
-    (225, "la"),
-    (226, "la_testnet"),
-    (228, "mind"),
-    (230, "swapdex"),
-    (233, "ethernity_testnet"),
-    (234, "protojumbo_testnet"),
-    (236, "deam_testnet"),
-    (238, "blast"),
-    (242, "plinga"),
-    (246, "energy_web"),
-    (248, "oasys"),
-    (250, "fantom_opera"),
-    (252, "fraxtal"),
-    (254, "swan"),
-    (255, "kroma"),
-    (256, "huobi_eco_testnet"),
-    (258, "setheum"),
-    (259, "neonlink"),
-    (261, "guru_testnet"),
-    (262, "sur_block"),
-    (266, "neura"),
-    (267, "neura_testnet"),
-    (268, "neura_devnet"),
-    (269, "high_performance_block"),
-    (271, "egoncoin"),
-    (278, "xfair.ai"),
-    (279, "bpx_block"),
-    (282, "cronos_zkevm_testnet"),
-    (288, "boba"),
-    (291, "orderly"),
-    (295, "hedera"),
-    (296, "hedera_testnet"),
-    (297, "hedera_previewnet"),
-    (298, "hedera_localnet"),
-    (300, "zksync_sepolia_testnet"),
-    (301, "bobaopera"),
-    (302, "zkcandy_sepolia_testnet"),
-    (303, "neuro_testnet"),
-    (305, "zksats"),
-    (307, "lovely_testnet"),
-    (308, "furtheon"),
-    (309, "wyzth_testnet"),
-    (311, "omax"),
-    (313, "neuro"),
-    (314, "filecoin"),
-    (321, "kcc"),
-    (322, "kcc_testnet"),
-    (323, "cosvm"),
-    (324, "zksync"),
-    (325, "grvt"),
-    (326, "grvt_sepolia_testnet"),
-    (333, "web3q"),
-    (335, "dfk_test"),
-    (336, "shiden"),
-    (338, "cronos_testnet"),
-    (345, "tsc"),
-    (360, "shape"),
-    (361, "theta"),
-    (363, "theta_sapphire_testnet"),
-    (364, "theta_amber_testnet"),
-    (365, "theta_testnet"),
-    (369, "pulse"),
-    (371, "consta_testnet"),
-    (380, "zkamoeba_testnet"),
-    (381, "zkamoeba"),
-    (385, "lisinski"),
-    (388, "cronos_zkevm"),
-    (395, "camdl_testnet"),
-    (397, "near"),
-    (398, "near_testnet"),
-    (399, "nativ3"),
-    (400, "hyperon_testnet"),
-    (401, "ozone_testnet"),
-    (404, "syndr_l3"),
-    (411, "pepe"),
-    (416, "sx"),
-    (418, "latestnet"),
-    (420, "optimism_goerli_testnet"),
-    (422, "viridis"),
-    (424, "pgn_public_goods"),
-    (427, "zeeth"),
-    (428, "geso_verse"),
-    (434, "boyaa"),
-    (443, "ten_testnet"),
-    (444, "synapse_testnet"),
-    (456, "arzio"),
-    (462, "areon_testnet"),
-    (463, "areon"),
-    (480, "world"),
-    (499, "rupaya"),
-    (500, "camino_c"),
-    (501, "columbus_test"),
-    (510, "syndicate"),
-    (512, "double_a"),
-    (513, "double_a_testnet"),
-    (516, "gear_zero"),
-    (520, "xt"),
-    (529, "fire"),
-    (530, "fxcore"),
-    (534, "candle"),
-    (537, "optrust"),
-    (542, "paw_testnet"),
-    (545, "evm_on_flow_testnet"),
-    (555, "vela1"),
-    (558, "tao"),
-    (568, "doge_testnet"),
-    (570, "rollux"),
-    (571, "meta"),
-    (579, "filenova"),
-    (588, "metis_stardust_testnet"),
-    (592, "astar"),
-    (595, "acala_mandala_testnet_tc9"),
-    (596, "karura_testnet"),
-    (597, "acala_testnet"),
-    (599, "metis_goerli_testnet"),
-    (600, "meshnyan_testnet"),
-    (601, "vine_testnet"),
-    (610, "darwin_devnet"),
-    (612, "eiob"),
-    (614, "graphlinq_block"),
-    (624, "binary"),
-    (625, "binary_sepolia"),
-    (634, "avocado"),
-    (646, "previewnet"),
-    (647, "sx_testnet"),
-    (648, "endurance"),
-    (653, "kali_testnet"),
-    (654, "kali"),
-    (662, "ultronsmart"),
-    (666, "pixie_testnet"),
-    (667, "laos_arrakis"),
-    (668, "junca"),
-    (669, "junca_testnet"),
-    (686, "karura"),
-    (690, "redstone"),
-    (700, "star_social_testnet"),
-    (701, "darwinia_koi_testnet"),
-    (707, "block_station"),
-    (708, "block_station_testnet"),
-    (710, "highbury"),
-    (711, "tucana"),
-    (712, "birdee_2"),
-    (713, "vrcscan"),
-    (719, "shibarium_beta"),
-    (721, "lycan"),
-    (727, "blucrates"),
-    (730, "lovely"),
-    (740, "canto_testnet"),
-    (741, "vention_testnet"),
-    (742, "script_testnet"),
-    (747, "evm_on_flow"),
-    (766, "ql1"),
-    (776, "open_testnet"),
-    (777, "cheapeth"),
-    (786, "maal"),
-    (787, "acala"),
-    (788, "aero_testnet"),
-    (789, "patex"),
-    (799, "rupaya_testnet"),
-    (800, "lucid_block"),
-    (803, "haic"),
-    (808, "portal_fantasy_test"),
-    (810, "haven1_testnet"),
-    (813, "qitmeer"),
-    (814, "fire_zkevm"),
-    (818, "beone"),
-    (820, "callisto"),
-    (822, "runic_testnet"),
-    (831, "checkdot_block_devnet"),
-    (841, "taraxa"),
-    (842, "taraxa_testnet"),
-    (852, "hongkong"),
-    (859, "zeeth_dev"),
-    (868, "fantasia"),
-    (876, "bandai_namco_research_verse"),
-    (877, "dexit"),
-    (880, "ambros"),
-    (888, "wan"),
-    (898, "maxi_testnet"),
-    (899, "maxi"),
-    (900, "garizon_testnet_stage0"),
-    (901, "garizon_testnet_stage1"),
-    (902, "garizon_testnet_stage2"),
-    (903, "garizon_testnet_stage3"),
-    (909, "portal_fantasy"),
-    (910, "decentrabone_layer1_testnet"),
-    (911, "taproot"),
-    (917, "rinia_testnet"),
-    (919, "mode_testnet"),
-    (927, "yidark"),
-    (940, "pulse_testnet"),
-    (941, "pulse_testnet_v2b"),
-    (942, "pulse_testnet_v3"),
-    (943, "pulse_testnet_v4"),
-    (945, "subtensor_evm_testnet"),
-    (956, "munode_testnet"),
-    (957, "lyra"),
-    (963, "btc20"),
-    (969, "ethxy"),
-    (970, "oort"),
-    (971, "oort_huygens"),
-    (972, "oort_ascraeus"),
-    (973, "palm"),
-    (977, "nepal_block"),
-    (979, "ethxy_testnet"),
-    (980, "top_evm"),
-    (985, "memo"),
-    (989, "top"),
-    (990, "eliberty"),
-    (995, "5ire"),
-    (996, "bifrost_polkadot"),
-    (997, "5ire_thunder_testnet"),
-    (998, "lucky"),
-    (999, "wan_testnet"),
-    (1000, "gton"),
-    (1001, "kaia_testnet_kairos"),
-    (1003, "tectum_emission_token"),
-    (1004, "t_ekta"),
-    (1005, "lemontestnet"),
-    (1006, "lemon"),
-    (1007, "newton_testnet"),
-    (1008, "eurus"),
-    (1009, "jumbo"),
-    (1010, "evrice"),
-    (1011, "rebus"),
-    (1012, "newton"),
-    (1022, "sakura"),
-    (1023, "clover_testnet"),
-    (1024, "clv_para"),
-    (1028, "bittorrent_testnet"),
-    (1030, "conflux_espace"),
-    (1031, "proxy_testnet"),
-    (1038, "bronos_testnet"),
-    (1039, "bronos"),
-    (1073, "shimmerevm_testnet"),
-    (1075, "iota_evm_testnet"),
-    (1079, "mintara_testnet"),
-    (1080, "mintara"),
-    (1088, "metis_andromeda"),
-    (1089, "humans.ai"),
-    (1099, "moac"),
-    (1100, "dymension"),
-    (1101, "polygon_zkevm"),
-    (1107, "blxq_testnet"),
-    (1108, "blxq"),
-    (1111, "wemix"),
-    (1112, "wemix_testnet"),
-    (1113, "b2_hub_testnet"),
-    (1115, "core_block_testnet"),
-    (1116, "core_block"),
-    (1117, "dogcoin"),
-    (1123, "b2_testnet"),
-    (1130, "defi_evm"),
-    (1131, "defi_evm_testnet"),
-    (1133, "defimeta_changi_testnet"),
-    (1135, "lisk"),
-    (1138, "amstar_testnet"),
-    (1139, "math"),
-    (1140, "math_testnet"),
-    (1147, "flag_testnet"),
-    (1149, "symplexia"),
-    (1170, "origin_testnet"),
-    (1177, "smart_host_teknoloji_testnet"),
-    (1188, "clubmos"),
-    (1197, "iora"),
-    (1200, "cuckoo"),
-    (1201, "evanesco_testnet"),
-    (1202, "world_trade_technical"),
-    (1209, "saitablocksbc"),
-    (1210, "cuckoo_sepolia"),
-    (1213, "popcateum"),
-    (1214, "enter"),
-    (1221, "cycle_testnet"),
-    (1223, "cycle_testnet_jellyfish"),
-    (1225, "hybrid_testnet"),
-    (1227, "bitcoin_protocol_testnet"),
-    (1228, "cycle_testnet_cuttlefish"),
-    (1229, "exzo"),
-    (1230, "ultron_testnet"),
-    (1231, "ultron"),
-    (1234, "step"),
-    (1235, "itx"),
-    (1243, "arc"),
-    (1244, "arc_testnet"),
-    (1246, "om_platform"),
-    (1248, "dogether"),
-    (1252, "cic_testnet"),
-    (1260, "metacces_testnet"),
-    (1280, "halo"),
-    (1284, "moonbeam"),
-    (1285, "moonriver"),
-    (1286, "moonrock_old"),
-    (1287, "moonbase_alpha"),
-    (1288, "moonrock"),
-    (1291, "swisstronik_testnet"),
-    (1294, "bobabeam"),
-    (1297, "bobabase_testnet"),
-    (1311, "dos_fuji_subnet"),
-    (1313, "jaiho"),
-    (1314, "alyx"),
-    (1319, "aia"),
-    (1320, "aia_testnet"),
-    (1328, "sei_testnet"),
-    (1329, "sei"),
-    (1337, "geth_testnet"),
-    (1338, "elysium_testnet"),
-    (1339, "elysium"),
-    (1343, "blitz_subnet"),
-    (1353, "cic"),
-    (1369, "zafirium"),
-    (1370, "ramestta"),
-    (1377, "pingaksha_testnet"),
-    (1379, "kalar"),
-    (1388, "amstar"),
-    (1392, "joseon"),
-    (1402, "polygon_zkevm_testnet_old"),
-    (1422, "polygon_zkevm_testnet_pre_audit_upgraded"),
-    (1433, "rikeza"),
-    (1440, "living_assets"),
-    (1442, "polygon_zkevm_testnet"),
-    (1452, "gil_testnet"),
-    (1453, "meta_istanbul"),
-    (1455, "ctex_scan_block"),
-    (1456, "zkbase"),
-    (1490, "vitruveo"),
-    (1499, "idos_games_testnet"),
-    (1501, "bevm_canary"),
-    (1506, "sherpax"),
-    (1507, "sherpax_testnet"),
-    (1513, "story_testnet"),
-    (1515, "beagle_messaging"),
-    (1559, "tenet"),
-    (1570, "star_testnet"),
-    (1578, "star"),
-    (1617, "ethereum_inscription"),
-    (1618, "catecoin"),
-    (1620, "atheios"),
-    (1625, "gravity_alpha"),
-    (1648, "pivotal"),
-    (1657, "bta"),
-    (1662, "liqui"),
-    (1663, "horizen_gobi_testnet"),
-    (1686, "mint_testnet"),
-    (1687, "mint_sepolia_testnet"),
-    (1688, "ludan"),
-    (1701, "anytype_evm"),
-    (1707, "tbsi"),
-    (1708, "tbsi_testnet"),
-    (1717, "doric"),
-    (1718, "palette"),
-    (1729, "reya"),
-    (1740, "metal_l2_testnet"),
-    (1750, "metal_l2"),
-    (1773, "party"),
-    (1777, "gauss"),
-    (1789, "zkbase_sepolia_testnet"),
-    (1804, "kerleano"),
-    (1807, "rabbit_analog_testnet"),
-    (1811, "lif3_testnet"),
-    (1818, "cube"),
-    (1819, "cube_testnet"),
-    (1821, "ruby"),
-    (1853, "highoctane_subnet"),
-    (1856, "teslafunds"),
-    (1875, "white"),
-    (1881, "gitshock_cartenz_testnet"),
-    (1890, "lightlink_phoenix"),
-    (1891, "lightlink_pegasus_testnet"),
-    (1898, "bon"),
-    (1899, "redefi_layer_2"),
-    (1904, "sports"),
-    (1907, "bitci"),
-    (1908, "bitci_testnet"),
-    (1909, "merkle_scan"),
-    (1911, "scalind"),
-    (1912, "ruby_testnet"),
-    (1918, "upb_crescdi_testnet"),
-    (1945, "onus_testnet"),
-    (1946, "minato"),
-    (1951, "d"),
-    (1953, "selendra_testnet"),
-    (1954, "dexilla_testnet"),
-    (1956, "aiw3_testnet"),
-    (1961, "selendra"),
-    (1967, "eleanor"),
-    (1969, "super_testnet"),
-    (1970, "super"),
-    (1971, "atelier"),
-    (1972, "redecoin"),
-    (1975, "onus"),
-    (1984, "eurus_testnet"),
-    (1985, "satoshie"),
-    (1986, "satoshie_testnet"),
-    (1987, "ethergem"),
-    (1992, "hubble_exchange"),
-    (1993, "b3_sepolia_testnet"),
-    (1994, "ekta"),
-    (1995, "edexa_testnet"),
-    (1996, "sanko"),
-    (1997, "kyoto"),
-    (1998, "kyoto_testnet"),
-    (2000, "doge"),
-    (2001, "milkomeda_c1"),
-    (2002, "milkomeda_a1"),
-    (2004, "metalink"),
-    (2008, "cloudwalk_testnet"),
-    (2009, "cloudwalk"),
-    (2013, "panarchy"),
-    (2014, "now_testnet"),
-    (2016, "mainnetz"),
-    (2017, "adiri"),
-    (2018, "publicmint_devnet"),
-    (2019, "publicmint_testnet"),
-    (2020, "publicmint"),
-    (2021, "edgeware_edgeevm"),
-    (2022, "beresheet_bereevm_testnet"),
-    (2023, "taycan_testnet"),
-    (2024, "swan_saturn_testnet"),
-    (2025, "rangers_protocol"),
-    (2026, "edgeless"),
-    (2031, "centrifuge"),
-    (2032, "catalyst"),
-    (2035, "phala"),
-    (2037, "kiwi_subnet"),
-    (2038, "shrapnel_testnet"),
-    (2039, "aleph_zero"),
-    (2040, "vanar"),
-    (2043, "neuroweb"),
-    (2044, "shrapnel_subnet"),
-    (2045, "aiw3"),
-    (2047, "stratos_testnet"),
-    (2048, "stratos"),
-    (2049, "movo"),
-    (2071, "metacces"),
-    (2077, "quokkacoin"),
-    (2088, "altair"),
-    (2089, "algol"),
-    (2100, "ecoball"),
-    (2101, "ecoball_testnet_espuma"),
-    (2109, "exosama"),
-    (2112, "u"),
-    (2121, "catena"),
-    (2122, "metaplayerone"),
-    (2124, "metaplayerone_dubai_testnet"),
-    (2136, "bigshortbets_testnet"),
-    (2137, "bigshortbets"),
-    (2138, "defi_oracle_meta_testnet"),
-    (2140, "oneness"),
-    (2141, "oneness_testnet"),
-    (2151, "bosagora"),
-    (2152, "findora"),
-    (2153, "findora_testnet"),
-    (2154, "findora_forge"),
-    (2162, "anime_testnet"),
-    (2192, "snax"),
-    (2199, "moonsama"),
-    (2202, "antofy"),
-    (2203, "bitcoin_evm"),
-    (2213, "evanesco"),
-    (2221, "kava_testnet"),
-    (2222, "kava"),
-    (2223, "v"),
-    (2241, "krest"),
-    (2300, "bomb"),
-    (2306, "ebro"),
-    (2309, "arevia"),
-    (2311, "chronicle_vesuvius_lit_protocol_testnet"),
-    (2323, "soma_testnet"),
-    (2330, "altcoin"),
-    (2331, "rss3_vsl_sepolia_testnet"),
-    (2332, "soma"),
-    (2340, "atleta_olympia"),
-    (2342, "omnia"),
-    (2355, "silicon_zkevm"),
-    (2358, "kroma_sepolia"),
-    (2370, "nexis_testnet"),
-    (2399, "bomb_testnet"),
-    (2400, "tcg_verse"),
-    (2410, "karak"),
-    (2415, "xodex"),
-    (2425, "king_of_legends"),
-    (2442, "polygon_zkevm_cardona_testnet"),
-    (2468, "hybrid"),
-    (2477, "6degree_of_outreach"),
-    (2484, "unicorn_ultra_nebulas_testnet"),
-    (2511, "karak_goerli"),
-    (2522, "fraxtal_testnet"),
-    (2525, "inevm"),
-    (2559, "kortho"),
-    (2569, "techpay"),
-    (2606, "pocrnet"),
-    (2611, "redlight"),
-    (2612, "ez_c"),
-    (2613, "ez_c_testnet"),
-    (2625, "white_testnet"),
-    (2648, "ailayer_testnet"),
-    (2649, "ailayer"),
-    (2662, "apex"),
-    (2710, "morph_testnet"),
-    (2718, "k_laos"),
-    (2730, "xr_sepolia"),
-    (2731, "elizabeth_testnet"),
-    (2748, "nanon"),
-    (2777, "gm"),
-    (2810, "morph_holesky"),
-    (2868, "hyperagi"),
-    (2882, "chips"),
-    (2888, "boba_goerli_testnet"),
-    (2889, "aarma"),
-    (2907, "elux"),
-    (2911, "hy"),
-    (2941, "xenon_testnet"),
-    (2999, "bityuan"),
-    (3000, "cennznet_rata"),
-    (3001, "cennznet_nikau"),
-    (3003, "canxium"),
-    (3011, "playa3ull_games"),
-    (3031, "orlando"),
-    (3033, "rebus_testnet"),
-    (3068, "bifrost"),
-    (3073, "movement_evm"),
-    (3084, "xl_testnet"),
-    (3100, "immu3_evm"),
-    (3102, "vulture_evm_beta"),
-    (3109, "satoshivm_alpha"),
-    (3110, "satoshivm_testnet"),
-    (3141, "filecoin_hyperspace_testnet"),
-    (3269, "dubxcoin"),
-    (3270, "dubxcoin_testnet"),
-    (3306, "debounce_subnet_testnet"),
-    (3331, "zcore_testnet"),
-    (3333, "ethstorage_testnet"),
-    (3334, "web3q_galileo"),
-    (3335, "ethstorage"),
-    (3338, "peaq"),
-    (3400, "paribu_net"),
-    (3424, "evolve"),
-    (3434, "secure_testnet"),
-    (3456, "layeredge_testnet"),
-    (3490, "gtcscan"),
-    (3500, "paribu_net_testnet"),
-    (3501, "jfin"),
-    (3601, "pandoproject"),
-    (3602, "pandoproject_testnet"),
-    (3630, "tycooncoin"),
-    (3636, "botanix_testnet"),
-    (3637, "botanix"),
-    (3639, "i"),
-    (3645, "i_testnet"),
-    (3666, "jouleverse"),
-    (3690, "bittex"),
-    (3693, "empire"),
-    (3698, "senjepowers_testnet"),
-    (3699, "senjepowers"),
-    (3701, "xpla_testnet"),
-    (3737, "crossbell"),
-    (3776, "astar_zkevm"),
-    (3797, "alvey"),
-    (3799, "tangle_testnet"),
-    (3885, "fire_zkevm_ghostrider"),
-    (3888, "kaly"),
-    (3889, "kaly_testnet"),
-    (3912, "drac"),
-    (3939, "dos_tesnet"),
-    (3966, "dyno"),
-    (3967, "dyno_testnet"),
-    (3993, "apex_testnet"),
-    (3999, "yuan"),
-    (4000, "ozone"),
-    (4001, "peperium_testnet"),
-    (4002, "fantom_testnet"),
-    (4003, "x1_fastnet"),
-    (4040, "carbonium_testnet"),
-    (4048, "gan_testnet"),
-    (4051, "bobaopera_testnet"),
-    (4058, "bahamut_ocean"),
-    (4061, "nahmii_3"),
-    (4062, "nahmii_3_testnet"),
-    (4078, "muster"),
-    (4080, "tobe_testnet"),
-    (4088, "zeroth"),
-    (4090, "fastex_bahamut_oasis_testnet"),
-    (4096, "bitindi_testnet"),
-    (4099, "bitindi"),
-    (4102, "aioz_testnet"),
-    (4139, "humans.ai_testnet"),
-    (4141, "tipboxcoin_testnet"),
-    (4157, "crossfi_testnet"),
-    (4162, "sx_rollup"),
-    (4181, "phi_v1"),
-    (4200, "merlin"),
-    (4201, "lukso_testnet"),
-    (4202, "lisk_sepolia_testnet"),
-    (4242, "nexi"),
-    (4243, "nexi_v2"),
-    (4269, "laika_testnet"),
-    (4328, "bobafuji_testnet"),
-    (4337, "beam"),
-    (4400, "credit"),
-    (4444, "htmlcoin"),
-    (4460, "orderly_sepolia_testnet"),
-    (4488, "hydra"),
-    (4544, "emoney_testnet"),
-    (4613, "very"),
-    (4653, "gold"),
-    (4689, "iotex"),
-    (4690, "iotex_testnet"),
-    (4759, "meverse_testnet"),
-    (4777, "blackfort_exchange_testnet"),
-    (4801, "world_sepolia_testnet"),
-    (4893, "globel"),
-    (4918, "venidium_testnet"),
-    (4919, "venidium"),
-    (4999, "blackfort_exchange"),
-    (5000, "mantle"),
-    (5001, "mantle_testnet"),
-    (5002, "treasurenet_alpha"),
-    (5003, "mantle_sepolia_testnet"),
-    (5005, "treasurenet_testnet"),
-    (5039, "onigiri_test_subnet"),
-    (5040, "onigiri_subnet"),
-    (5051, "nollie_skate_testnet"),
-    (5100, "syndicate_testnet"),
-    (5101, "syndicate_frame"),
-    (5102, "sic_testnet"),
-    (5103, "coordinape_testnet"),
-    (5104, "charmverse_testnet"),
-    (5105, "superloyalty_testnet"),
-    (5106, "azra_testnet"),
-    (5112, "ham"),
-    (5151, "me_testnet"),
-    (5165, "bahamut"),
-    (5169, "smart_layer"),
-    (5177, "tl"),
-    (5197, "eraswap"),
-    (5234, "humanode"),
-    (5290, "fire_old"),
-    (5315, "uzmi"),
-    (5317, "optrust_testnet"),
-    (5321, "itx_testnet"),
-    (5333, "netsbo"),
-    (5353, "tritanium_testnet"),
-    (5372, "settlus_testnet"),
-    (5424, "edexa"),
-    (5439, "ego"),
-    (5522, "vex_evm_testnet"),
-    (5545, "duck"),
-    (5551, "nahmii_2"),
-    (5553, "nahmii_2_testnet"),
-    (5555, "verse"),
-    (5611, "opbnb_testnet"),
-    (5615, "arcturus_testneet"),
-    (5616, "arcturus_testnet"),
-    (5656, "qie_block"),
-    (5675, "filenova_testnet"),
-    (5678, "tanssi_demo"),
-    (5700, "syscoin_tanenbaum_testnet"),
-    (5729, "hika_testnet"),
-    (5758, "satoshi_testnet"),
-    (5777, "ganache"),
-    (5845, "tangle"),
-    (5851, "ontology_testnet"),
-    (5858, "chang_foundation"),
-    (5869, "wego_rubidium"),
-    (6000, "bouncebit_testnet"),
-    (6001, "bouncebit"),
-    (6065, "tres_testnet"),
-    (6066, "tres"),
-    (6102, "cascadia_testnet"),
-    (6118, "uptn_testnet"),
-    (6119, "uptn"),
-    (6283, "laos"),
-    (6321, "aura_euphoria_testnet"),
-    (6322, "aura"),
-    (6363, "digit_soul"),
-    (6398, "connext_sepolia"),
-    (6502, "peerpay"),
-    (6550, "flamma_testnet"),
-    (6552, "scolcoin_wei_testnet"),
-    (6565, "fox_testnet"),
-    (6626, "pixie"),
-    (6660, "latest_testnet"),
-    (6661, "cybria"),
-    (6666, "cybria_testnet"),
-    (6667, "stor"),
-    (6678, "edge_matrix"),
-    (6688, "irishub"),
-    (6699, "ox"),
-    (6701, "paxb"),
-    (6779, "compverse"),
-    (6805, "race"),
-    (6806, "race_testnet"),
-    (6868, "pools"),
-    (6880, "mtt"),
-    (6942, "laika"),
-    (6969, "tomb"),
-    (6999, "polysmart"),
-    (7000, "zeta"),
-    (7001, "zeta_testnet"),
-    (7007, "bst"),
-    (7027, "ella_the_heart"),
-    (7070, "planq"),
-    (7077, "planq_atlas_testnet"),
-    (7100, "nume"),
-    (7118, "help_the_homeless"),
-    (7171, "bitrock"),
-    (7210, "nibiru_testnet_1"),
-    (7222, "nibiru_devnet_3"),
-    (7244, "zeus_testnet"),
-    (7300, "xpla_verse"),
-    (7331, "klyntar"),
-    (7332, "horizen_eon"),
-    (7341, "shyft"),
-    (7484, "raba"),
-    (7518, "meverse"),
-    (7560, "cyber"),
-    (7575, "adil_testnet"),
-    (7576, "adil_v2"),
-    (7668, "the_root"),
-    (7672, "the_root_porcini_testnet"),
-    (7700, "canto"),
-    (7701, "canto_tesnet"),
-    (7771, "bitrock_testnet"),
-    (7774, "gdcc"),
-    (7775, "gdcc_testnet"),
-    (7776, "pandasea"),
-    (7777, "rise_of_the_warbots_testnet"),
-    (7778, "orenium_protocol"),
-    (7798, "openex_long_testnet"),
-    (7860, "maal_testnet"),
-    (7862, "maal_v2"),
-    (7863, "maal_testnet_v2"),
-    (7878, "hazlor_testnet"),
-    (7887, "kinto"),
-    (7895, "ardenium_athena"),
-    (7923, "dot_blox"),
-    (7924, "mo"),
-    (7979, "dos"),
-    (8000, "teleport"),
-    (8001, "teleport_testnet"),
-    (8008, "polynomial"),
-    (8017, "isuncoin"),
-    (8029, "mdgl_testnet"),
-    (8047, "boat"),
-    (8054, "karak_sepolia"),
-    (8080, "shardeum_liberty_1.x"),
-    (8081, "shardeum_liberty_2.x"),
-    (8082, "shardeum_sphinx_1.x"),
-    (8086, "bitcoin"),
-    (8087, "e_dollar"),
-    (8098, "streamux_block"),
-    (8131, "qitmeer_testnet"),
-    (8132, "qitmeer_mixnet"),
-    (8133, "qitmeer_privnet"),
-    (8134, "amana"),
-    (8135, "flana"),
-    (8136, "mizana"),
-    (8181, "testnet_beone"),
-    (8192, "torus"),
-    (8194, "torus_testnet"),
-    (8217, "kaia"),
-    (8227, "space_subnet"),
-    (8272, "blockton_block"),
-    (8285, "korthotest"),
-    (8329, "lorenzo"),
-    (8333, "b3"),
-    (8387, "dracones_financial_services"),
-    (8428, "that"),
-    (8453, "base"),
-    (8545, "chakra_testnet"),
-    (8654, "toki"),
-    (8655, "toki_testnet"),
-    (8668, "hela_official_runtime"),
-    (8723, "tool_global"),
-    (8724, "tool_global_testnet"),
-    (8726, "storage"),
-    (8727, "storage_testnet"),
-    (8732, "bullions"),
-    (8738, "alph"),
-    (8768, "tmy"),
-    (8822, "iota_evm"),
-    (8844, "hydra_testnet"),
-    (8848, "maro_block"),
-    (8866, "superlumio"),
-    (8869, "lif3"),
-    (8880, "unique"),
-    (8881, "quartz_by_unique"),
-    (8882, "opal_testnet_by_unique"),
-    (8883, "sapphire_by_unique"),
-    (8886, "avenium_testnet"),
-    (8888, "xana"),
-    (8889, "vyvo"),
-    (8890, "orenium_testnet_protocol"),
-    (8898, "mammoth"),
-    (8899, "jib"),
-    (8911, "algen"),
-    (8912, "algen_testnet"),
-    (8921, "algen_layer2"),
-    (8922, "algen_layer2_testnet"),
-    (8989, "giant_mammoth"),
-    (8995, "bloxberg"),
-    (9000, "evmos_testnet"),
-    (9001, "evmos"),
-    (9007, "shido_testnet_block"),
-    (9008, "shido_block"),
-    (9012, "berylbit"),
-    (9024, "nexa_testnet_block"),
-    (9025, "nexa_block"),
-    (9069, "apex_fusion_nexus"),
-    (9070, "apex_fusion_nexus_testnet"),
-    (9100, "genesis_coin"),
-    (9170, "rinia_testnet_old"),
-    (9223, "codefin"),
-    (9339, "dogcoin_testnet"),
-    (9372, "oasys_testnet"),
-    (9393, "dela_sepolia_testnet"),
-    (9395, "evoke"),
-    (9496, "weavevm_testnet"),
-    (9527, "rangers_protocol_testnet_robin"),
-    (9528, "qeasyweb3_testnet"),
-    (9559, "neonlink_testnet"),
-    (9700, "oortdev"),
-    (9728, "boba_bnb_testnet"),
-    (9768, "mainnetz_testnet"),
-    (9779, "pepenetwork"),
-    (9789, "tabi_testnet"),
-    (9790, "carbon_evm"),
-    (9792, "carbon_evm_testnet"),
-    (9797, "optimusz7"),
-    (9818, "imperium_testnet"),
-    (9819, "imperium"),
-    (9876, "binary_testnet"),
-    (9888, "dogelayer"),
-    (9897, "arena_z_testnet"),
-    (9898, "larissa"),
-    (9911, "espento"),
-    (9966, "uxer_testnet"),
-    (9977, "mind_testnet"),
-    (9980, "combo"),
-    (9981, "volley"),
-    (9990, "agung"),
-    (9997, "altlayer_testnet"),
-    (9998, "ztc"),
-    (9999, "myown_testnet"),
-    (10000, "smart_bitcoin_cash"),
-    (10001, "smart_bitcoin_cash_testnet"),
-    (10024, "gon"),
-    (10081, "japan_open_testnet"),
-    (10086, "sjatsh"),
-    (10096, "metanova_verse"),
-    (10101, "block_genesis"),
-    (10200, "gnosis_chiado_testnet"),
-    (10201, "maxx"),
-    (10222, "glscan"),
-    (10242, "arthera"),
-    (10243, "arthera_testnet"),
-    (10248, "0xtade"),
-    (10321, "tao_evm"),
-    (10324, "tao_evm_testnet"),
-    (10395, "worldland_testnet"),
-    (10507, "numbers"),
-    (10508, "numbers_testnet"),
-    (10823, "cryptocoinpay"),
-    (10849, "lamina1"),
-    (10850, "lamina1_identity"),
-    (10946, "quadrans_block"),
-    (10947, "quadrans_block_testnet"),
-    (11000, "kb"),
-    (11011, "shape_sepolia_testnet"),
-    (11110, "astra"),
-    (11111, "wagmi"),
-    (11115, "astra_testnet"),
-    (11119, "hashbit"),
-    (11124, "abstract_testnet"),
-    (11221, "shine"),
-    (11227, "jiritsu_testnet_subnet"),
-    (11235, "haqq"),
-    (11437, "shyft_testnet"),
-    (11501, "bevm"),
-    (11503, "bevm_testnet"),
-    (11521, "sats"),
-    (11612, "sardis_testnet"),
-    (11822, "artela_testnet"),
-    (11891, "polygon_supernet_arianee"),
-    (12001, "fuse_testnet"),
-    (12009, "satoshi"),
-    (12020, "aternos"),
-    (12051, "singularity_zero_testnet"),
-    (12052, "singularity_zero"),
-    (12123, "brc"),
-    (12306, "fibonacci"),
-    (12321, "blg_testnet"),
-    (12324, "l3x_protocol"),
-    (12325, "l3x_protocol_testnet"),
-    (12345, "step_testnet"),
-    (12553, "rss3_vsl"),
-    (12715, "rikeza_testnet"),
-    (12781, "playdapp_testnet"),
-    (12890, "quantum_testnet"),
-    (12898, "playfair_testnet_subnet"),
-    (13000, "sps"),
-    (13337, "beam_testnet"),
-    (13370, "cannon_testnet"),
-    (13371, "immutable_zkevm"),
-    (13381, "phoenix"),
-    (13396, "masa"),
-    (13473, "immutable_zkevm_testnet"),
-    (13505, "gravity_alpha_testnet_sepolia"),
-    (13600, "kronobit"),
-    (13812, "susono"),
-    (14000, "sps_testnet"),
-    (14088, "zeroth_testnet"),
-    (14324, "evolve_testnet"),
-    (14333, "vitruveo_testnet"),
-    (14800, "vana_moksha_testnet"),
-    (14801, "vana_satori_testnet"),
-    (14853, "humanode_testnet_5_israfel"),
-    (15003, "immutable_zkevm_devnet"),
-    (15257, "poodl_testnet"),
-    (15259, "poodl"),
-    (15430, "kymtc"),
-    (15551, "loopnetwork"),
-    (15555, "trust_evm_testnet"),
-    (15557, "eos_evm_testnet"),
-    (16000, "metadot"),
-    (16001, "metadot_testnet"),
-    (16116, "defiverse"),
-    (16166, "cypherium"),
-    (16180, "plyr_phi"),
-    (16350, "incentiv_devnet"),
-    (16481, "pivotal_sepolia"),
-    (16507, "genesys"),
-    (16600, "0g_newton_testnet"),
-    (16688, "irishub_testnet"),
-    (16718, "airdao"),
-    (16888, "ivar_testnet"),
-    (17000, "holesky"),
-    (17001, "redstone_holesky_testnet"),
-    (17069, "garnet_holesky"),
-    (17071, "on_points"),
-    (17117, "defiverse_testnet"),
-    (17171, "g8"),
-    (17172, "eclipse_subnet"),
-    (17180, "palette_testnet"),
-    (17217, "konet"),
-    (17777, "eos_evm"),
-    (18000, "frontier_of_dreams_testnet"),
-    (18122, "smart_trades"),
-    (18159, "proof_of_memes"),
-    (18181, "g8_testnet"),
-    (18231, "unreal_old"),
-    (18233, "unreal"),
-    (18686, "mxc_zkevm_moon"),
-    (18888, "titan_tkx"),
-    (18889, "titan_tkx_testnet"),
-    (19011, "home_verse"),
-    (19077, "blockx_atlantis_testnet"),
-    (19191, "blockx"),
-    (19224, "decentraconnect_social"),
-    (19527, "magnet"),
-    (19600, "lbry"),
-    (19845, "btcix"),
-    (20001, "camelark"),
-    (20041, "niza"),
-    (20073, "niza_testnet"),
-    (20729, "callisto_testnet"),
-    (20736, "p12"),
-    (20765, "jono11_subnet"),
-    (21004, "c4ei"),
-    (21133, "all_about_healthy"),
-    (21223, "dcpay"),
-    (21224, "dcpay_testnet"),
-    (21337, "cennznet_azalea"),
-    (21363, "lestnet"),
-    (21816, "om"),
-    (21912, "bsl"),
-    (22023, "taycan"),
-    (22040, "airdao_testnet"),
-    (22222, "nautilus"),
-    (22324, "goldx_testnet"),
-    (22776, "map_protocol"),
-    (23006, "antofy_testnet"),
-    (23118, "opside_testnet"),
-    (23294, "oasis_sapphire"),
-    (23295, "oasis_sapphire_testnet"),
-    (23451, "dreyerx"),
-    (23452, "dreyerx_testnet"),
-    (23888, "blast_testnet"),
-    (24076, "kymtc_testnet"),
-    (24484, "web"),
-    (24734, "mintme.com"),
-    (25186, "liquidlayer"),
-    (25327, "everclear"),
-    (25839, "alvey_testnet"),
-    (25888, "hammer"),
-    (25925, "bitkub_testnet"),
-    (26026, "ferrum_testnet"),
-    (26482, "ducatusx_testnet"),
-    (26483, "ducatusx"),
-    (26600, "hertz"),
-    (26863, "oasis"),
-    (27181, "klaos_nova"),
-    (27483, "nanon_sepolia"),
-    (27827, "zeroone_subnet"),
-    (28516, "vizing_testnet"),
-    (28518, "vizing"),
-    (28528, "optimism_bedrock_goerli_alpha_testnet"),
-    (28882, "boba_sepolia"),
-    (29112, "hy_testnet"),
-    (29223, "nexa_metanet"),
-    (29536, "kai_testnet"),
-    (29548, "mch_verse"),
-    (30000, "q"),
-    (30067, "piece_testnet"),
-    (30088, "miyou"),
-    (30103, "cerium_testnet"),
-    (30730, "movement_evm_legacy"),
-    (30731, "movement_evm_devnet"),
-    (30732, "movement_evm_testnet"),
-    (31102, "ethersocial"),
-    (31223, "cloudtx"),
-    (31224, "cloudtx_testnet"),
-    (31337, "go_testnet"),
-    (31414, "evoke_testnet"),
-    (31415, "filecoin_wallaby_testnet"),
-    (31753, "x"),
-    (31754, "x_testnet"),
-    (32001, "w3gamez_holesky_testnet"),
-    (32382, "santiment_intelligence"),
-    (32520, "bitgert"),
-    (32659, "fusion"),
-    (32769, "zilliqa_evm"),
-    (32990, "zilliqa_evm_isolated_server"),
-    (33033, "entangle"),
-    (33101, "zilliqa_evm_testnet"),
-    (33103, "zilliqa_2_evm_proto_testnet"),
-    (33111, "curtis"),
-    (33133, "entangle_testnet"),
-    (33210, "cloudverse_subnet"),
-    (33333, "aves"),
-    (33385, "zilliqa_evm_devnet"),
-    (33401, "slingshot"),
-    (33469, "zilliqa_2_evm_devnet"),
-    (33979, "funki"),
-    (34443, "mode"),
-    (34504, "zeus"),
-    (35011, "j2o_taro"),
-    (35443, "q_testnet"),
-    (38400, "connectormanager"),
-    (38401, "connectormanager_robin"),
-    (39656, "prm"),
-    (39797, "energi"),
-    (39815, "oho"),
-    (40000, "div"),
-    (41455, "aleph_zero_evm"),
-    (41500, "opulent_x_beta"),
-    (42069, "pegglecoin"),
-    (42072, "agentlayer_testnet"),
-    (42161, "arbitrum"),
-    (42170, "arbitrum_nova"),
-    (42220, "celo"),
-    (42261, "oasis_emerald_testnet"),
-    (42262, "oasis_emerald"),
-    (42355, "goldx"),
-    (42421, "asset_testnet"),
-    (42766, "zkfair"),
-    (42793, "etherlink"),
-    (42801, "gesoten_verse_testnet"),
-    (42888, "kinto_testnet"),
-    (43110, "athereum"),
-    (43111, "hemi"),
-    (43113, "avalanche_fuji_testnet"),
-    (43114, "avalanche"),
-    (43288, "boba_avax"),
-    (43851, "zkfair_testnet"),
-    (44444, "fren"),
-    (44445, "quantum"),
-    (44787, "celo_alfajores_testnet"),
-    (45000, "autobahn"),
-    (45454, "swamps_l2"),
-    (45510, "deelance"),
-    (45513, "blessnet"),
-    (46688, "fusion_testnet"),
-    (47763, "neo_x"),
-    (47803, "redefi_layer_1"),
-    (47805, "rei"),
-    (48795, "space_subnet_testnet"),
-    (48899, "zircuit_testnet"),
-    (48900, "zircuit"),
-    (49049, "wireshape_floripa_testnet"),
-    (49088, "bifrost_testnet"),
-    (49321, "gunz_testnet"),
-    (49797, "energi_testnet"),
-    (50001, "liveplex_oracleevm"),
-    (50005, "yooldo_verse"),
-    (50006, "yooldo_verse_testnet"),
-    (50021, "gton_testnet"),
-    (50888, "erbie"),
-    (51178, "lumoz_testnet_alpha"),
-    (51712, "sardis"),
-    (52014, "electroneum"),
-    (53277, "doid"),
-    (53302, "superseed_sepolia_testnet"),
-    (53457, "dodo_testnet"),
-    (53935, "dfk"),
-    (54176, "overprotocol"),
-    (54211, "haqq_testnet"),
-    (54321, "toronet_testnet"),
-    (55004, "titan"),
-    (55551, "photon_aurora_testnet"),
-    (55556, "rei_testnet"),
-    (55614, "flamma"),
-    (56026, "lambda"),
-    (56288, "boba_bnb"),
-    (56400, "testnet_zeroone_subnet"),
-    (56789, "velo_labs"),
-    (56797, "doid_testnet"),
-    (57000, "rollux_testnet"),
-    (57451, "coinsec"),
-    (58008, "sepolia_pgn_public_goods"),
-    (58680, "lumoz_quidditch_testnet"),
-    (59140, "linea_goerli"),
-    (59141, "linea_sepolia"),
-    (59144, "linea"),
-    (59902, "metis_sepolia_testnet"),
-    (59971, "genesys_code"),
-    (60000, "thinkium_testnet_0"),
-    (60001, "thinkium_testnet_1"),
-    (60002, "thinkium_testnet_2"),
-    (60103, "thinkium_testnet_103"),
-    (60808, "bob"),
-    (61022, "orange"),
-    (61406, "kai"),
-    (61800, "axel_dev_net"),
-    (61803, "etica"),
-    (61916, "doken_super"),
-    (62049, "optopia_testnet"),
-    (62050, "optopia"),
-    (62298, "citrea_devnet"),
-    (62320, "celo_baklava_testnet"),
-    (62621, "multivac"),
-    (62831, "plyr_tau_testnet"),
-    (62850, "laos_sigma_testnet"),
-    (63000, "ecredits"),
-    (63001, "ecredits_testnet"),
-    (63002, "esync_testnet"),
-    (65349, "cratd2c_testnet"),
-    (65357, "vecno"),
-    (65450, "scolcoin"),
-    (65536, "automata"),
-    (66665, "creator_testnet"),
-    (66988, "janus_testnet"),
-    (67390, "siriusnet"),
-    (67588, "cosmic"),
-    (68770, "dm2_verse"),
-    (68775, "dm2_verse_testnet"),
-    (69420, "condrieu"),
-    (70000, "thinkium_0"),
-    (70001, "thinkium_1"),
-    (70002, "thinkium_2"),
-    (70103, "thinkium_103"),
-    (70700, "proof_of_play_apex"),
-    (71111, "guapcoinx"),
-    (71393, "polyjuice_testnet"),
-    (71401, "godwoken_testnet_v1"),
-    (71402, "godwoken"),
-    (72778, "caga_crypto_ankara_testnet"),
-    (72992, "grok"),
-    (73114, "icb_testnet"),
-    (73115, "icb"),
-    (73799, "energy_web_volta_testnet"),
-    (73927, "mixin_virtual_machine"),
-    (75000, "resincoin"),
-    (75512, "geek_verse"),
-    (75513, "geek_verse_testnet"),
-    (77001, "bora"),
-    (77238, "foundry_testnet"),
-    (77612, "vention"),
-    (77677, "cycle_sailboat"),
-    (77777, "toronet"),
-    (78110, "firenze_test"),
-    (78281, "dragonfly_hexapod"),
-    (78430, "amplify_subnet"),
-    (78431, "bulletin_subnet"),
-    (78432, "conduit_subnet"),
-    (78600, "vanguard"),
-    (79879, "gold_testnet"),
-    (80001, "mumbai"),
-    (80002, "amoy"),
-    (80008, "polynomia_sepolia"),
-    (80084, "bera_bartio"),
-    (80085, "bera_artio"),
-    (80096, "hizoco"),
-    (81041, "nordek"),
-    (81341, "amana_testnet"),
-    (81342, "amana_mixnet"),
-    (81343, "amana_privnet"),
-    (81351, "flana_testnet"),
-    (81352, "flana_mixnet"),
-    (81353, "flana_privnet"),
-    (81361, "mizana_testnet"),
-    (81362, "mizana_mixnet"),
-    (81363, "mizana_privnet"),
-    (82459, "smart_layer_testnet"),
-    (83144, "xprotocol_testnet"),
-    (83278, "esa"),
-    (83872, "zedxion"),
-    (84531, "base_goerli_testnet"),
-    (84532, "base_sepolia_testnet"),
-    (84886, "aerie"),
-    (85449, "cybertrust"),
-    (88002, "nautilus_proteus_testnet"),
-    (88559, "inoai"),
-    (88800, "zkasino"),
-    (88817, "unit_zero_testnet"),
-    (88819, "unit_zero_stagenet"),
-    (88880, "chiliz_scoville_testnet"),
-    (88882, "chiliz_spicy_testnet"),
-    (88888, "chiliz"),
-    (90001, "fxcore_testnet"),
-    (90210, "beverly_hills"),
-    (90354, "camp_testnet"),
-    (91002, "nautilus_trition"),
-    (91120, "metadap_enterprise"),
-    (91715, "combo_testnet"),
-    (92001, "lambda_testnet"),
-    (93572, "liquidlayer_testnet"),
-    (93747, "stratovm_testnet"),
-    (96970, "mantis_testnet_hexapod"),
-    (97053, "tetron_testnet"),
-    (97055, "tetron"),
-    (97288, "boba_bnb_old"),
-    (97435, "slingshot_testnet"),
-    (97531, "green_testnet"),
-    (97970, "optimusz7_testnet"),
-    (98881, "ebi"),
-    (99099, "eliberty_testnet"),
-    (99876, "edge_matrix_testnet"),
-    (99998, "ubtestnet"),
-    (99999, "ub"),
-    (100000, "quark_root"),
-    (100001, "quark_shard_0"),
-    (100002, "quark_shard_1"),
-    (100003, "quark_shard_2"),
-    (100004, "quark_shard_3"),
-    (100005, "quark_shard_4"),
-    (100006, "quark_shard_5"),
-    (100007, "quark_shard_6"),
-    (100008, "quark_shard_7"),
-    (100009, "ve"),
-    (100010, "ve_testnet"),
-    (100011, "quark_l2"),
-    (101010, "global_trust"),
-    (102030, "creditcoin"),
-    (102031, "creditcoin_testnet"),
-    (102032, "creditcoin_devnet"),
-    (103090, "crystaleum"),
-    (103454, "masa_testnet"),
-    (104566, "kaspaclassic"),
-    (105105, "stratis"),
-    (108801, "bro"),
-    (110000, "quark_devnet_root"),
-    (110001, "quark_devnet_shard_0"),
-    (110002, "quark_devnet_shard_1"),
-    (110003, "quark_devnet_shard_2"),
-    (110004, "quark_devnet_shard_3"),
-    (110005, "quark_devnet_shard_4"),
-    (110006, "quark_devnet_shard_5"),
-    (110007, "quark_devnet_shard_6"),
-    (110008, "quark_devnet_shard_7"),
-    (110011, "quark_l2_testnet"),
-    (110110, "mars_credit"),
-    (111000, "siberium_test"),
-    (111111, "siberium"),
-    (111188, "re.al"),
-    (112358, "meta_one"),
-    (119139, "metadap_enterprise_testnet"),
-    (123321, "gem"),
-    (123456, "adil_devnet"),
-    (128123, "etherlink_testnet"),
-    (131313, "odyssey_testnet"),
-    (131419, "etnds"),
-    (132902, "form_testnet"),
-    (141319, "magape_testnet"),
-    (142857, "icplaza"),
-    (161212, "playfi"),
-    (165279, "eclat"),
-    (167000, "taiko"),
-    (167004, "taiko_alpha_2_testnet"),
-    (167005, "taiko_grimsvotn_l2"),
-    (167006, "taiko_eldfell_l3"),
-    (167007, "taiko_jolnir_l2"),
-    (167008, "taiko_katla_l2"),
-    (167009, "taiko_hekla_l2"),
-    (168168, "zs"),
-    (171000, "fair_testnet"),
-    (175177, "chronicle_lit_protocol_testnet"),
-    (175188, "chronicle_yellowstone_lit_protocol_testnet"),
-    (188710, "bitica"),
-    (188881, "condor_test"),
-    (199991, "mazze_testnet"),
-    (200000, "xfair.ai_testnet"),
-    (200101, "milkomeda_c1_testnet"),
-    (200202, "milkomeda_a1_testnet"),
-    (200625, "akroma"),
-    (200810, "bitlayer_testnet"),
-    (200901, "bitlayer"),
-    (201018, "alaya"),
-    (201030, "alaya_dev_testnet"),
-    (201804, "mythical"),
-    (202020, "decimal_testnet"),
-    (202105, "duck_testnet"),
-    (202202, "bethel_sydney"),
-    (202212, "x1_devnet"),
-    (202401, "ymtech_besu_testnet"),
-    (202624, "jellie"),
-    (204005, "x1_network"),
-    (205205, "auroria_testnet"),
-    (210049, "gitagi_atlas_testnet"),
-    (210425, "platon"),
-    (212013, "litentry"),
-    (220315, "mas"),
-    (221230, "reap"),
-    (221231, "reap_testnet"),
-    (222222, "hydradx"),
-    (222555, "deepl"),
-    (222666, "deepl_testnet"),
-    (224168, "taf_eco"),
-    (224422, "conet_sebolia_testnet"),
-    (224433, "conet_holesky"),
-    (229772, "abyss_protocol"),
-    (230315, "hashkey_testnetdiscard"),
-    (234666, "haymo_testnet"),
-    (240515, "orange_testnet"),
-    (246529, "artis_sigma1"),
-    (246785, "artis_testnet_tau1"),
-    (247253, "saakuru_testnet"),
-    (256256, "cmp"),
-    (262371, "eclat_testnet"),
-    (266256, "gear_zero_testnet"),
-    (271271, "egoncoin_testnet"),
-    (281121, "social"),
-    (282828, "zillion_sepolia_testnet"),
-    (292003, "cipherem_testnet"),
-    (309075, "one_world"),
-    (313313, "saharaai_testnet"),
-    (314159, "filecoin_calibration_testnet"),
-    (322202, "parex"),
-    (323213, "bloom_genesis_testnet"),
-    (327126, "waba_testnet"),
-    (328527, "nal"),
-    (330844, "ttcoin"),
-    (333313, "bloom_genesis"),
-    (333331, "aves_testnet"),
-    (333333, "nativ3_testnet"),
-    (333666, "oone_testnet"),
-    (333777, "oone_devnet"),
-    (333888, "polis_testnet"),
-    (333999, "polis"),
-    (336655, "up_testnet"),
-    (336666, "up"),
-    (355110, "bitfinity"),
-    (355113, "bitfinity_testnet"),
-    (360890, "lavita"),
-    (363636, "digit_soul_2"),
-    (373737, "hap_testnet"),
-    (381931, "metal_c"),
-    (381932, "metal_tahoe_c"),
-    (404040, "tipboxcoin"),
-    (413413, "aie_testnet"),
-    (420420, "kek"),
-    (420666, "kek_kektest"),
-    (420692, "alterium_l2_testnet"),
-    (421611, "arbitrum_rinkeby"),
-    (421613, "arbitrum_goerli"),
-    (421614, "arbitrum_sepolia"),
-    (424242, "fastex_testnet"),
-    (431140, "markr_go"),
-    (432201, "dexalot_subnet_testnet"),
-    (432204, "dexalot_subnet"),
-    (444444, "syndr_l3_sepolia"),
-    (444900, "weelink_testnet"),
-    (471100, "patex_sepolia_testnet"),
-    (473861, "ultra_pro"),
-    (474142, "open"),
-    (486487, "gobbl_testnet"),
-    (490000, "autonomys_testnet_nova_domain"),
-    (504441, "playdapp"),
-    (512512, "cmp_testnet"),
-    (513100, "dis"),
-    (526916, "docoin_community"),
-    (534351, "scroll_sepolia_testnet"),
-    (534352, "scroll"),
-    (534353, "scroll_alpha_testnet"),
-    (534354, "scroll_pre_alpha_testnet"),
-    (534849, "shinarium_beta"),
-    (535037, "beaneco_smart"),
-    (541764, "overprotocol_testnet"),
-    (552981, "one_world_testnet"),
-    (555555, "pentagon_testnet"),
-    (555666, "eclipse_testnet"),
-    (555888, "dustboy_iot"),
-    (622277, "hypra"),
-    (622463, "atlas"),
-    (641230, "bear"),
-    (651940, "all"),
-    (656476, "open_campus_codex"),
-    (660279, "xai"),
-    (666666, "vision_vpioneer_test"),
-    (666888, "hela_official_runtime_testnet"),
-    (686868, "won"),
-    (696969, "galadriel_devnet"),
-    (710420, "tiltyard_subnet"),
-    (713715, "sei_devnet"),
-    (721529, "eram"),
-    (723107, "tix_testnet"),
-    (743111, "hemi_sepolia"),
-    (751230, "bear_testnet"),
-    (752024, "ternoa_testnet"),
-    (761412, "miexs_smart"),
-    (764984, "lamina1_testnet"),
-    (767368, "lamina1_identity_testnet"),
-    (776877, "modularium"),
-    (800001, "octaspace"),
-    (808080, "biz_testnet"),
-    (808813, "bob_sepolia"),
-    (810180, "zklink_nova"),
-    (810181, "zklink_nova_sepolia_testnet"),
-    (810182, "zklink_nova_goerli_testnet"),
-    (812397, "sg_verse"),
-    (820522, "tsc_testnet"),
-    (827431, "curve"),
-    (839320, "prm_testnet"),
-    (840000, "runevm_testnet"),
-    (846000, "4goodnetwork"),
-    (855456, "dodao"),
-    (879151, "blocx"),
-    (888882, "rexx"),
-    (888888, "vision"),
-    (900000, "posi_shard_0"),
-    (910000, "posi_testnet_shard_0"),
-    (912559, "astria_evm_dusknet"),
-    (920000, "posi_devnet_shard_0"),
-    (920001, "posi_devnet_shard_1"),
-    (923018, "fncy_testnet"),
-    (955081, "jono12_subnet"),
-    (955305, "eluvio_content_fabric"),
-    (978657, "treasure_ruby"),
-    (984122, "forma"),
-    (984123, "forma_sketchpad"),
-    (988207, "ecrox"),
-    (998899, "supernet_testnet"),
-    (999999, "am"),
-    (1100789, "netmind_testnet"),
-    (1234567, "sharecle"),
-    (1261120, "zkatana"),
-    (1313114, "etho_protocol"),
-    (1313500, "xerom"),
-    (1337702, "kintsugi"),
-    (1337802, "kiln"),
-    (1337803, "zhejiang"),
-    (1398243, "automata_testnet"),
-    (1398244, "automata_orbit_testnet"),
-    (1612127, "playfi_albireo_testnet"),
-    (1637450, "xterio_testnet"),
-    (1731313, "turkey_demo_dev"),
-    (2021398, "debank_testnet"),
-    (2099156, "plian_main"),
-    (2206132, "platon_dev_testnet2"),
-    (2611555, "dpu"),
-    (2702128, "xterio"),
-    (3132023, "saharaai"),
-    (3141592, "filecoin_butterfly_testnet"),
-    (3397901, "funki_sepolia_sandbox"),
-    (3441005, "manta_pacific_testnet"),
-    (3441006, "manta_pacific_sepolia_testnet"),
-    (4000003, "altlayer_zero_gas"),
-    (4281033, "worlds_caldera"),
-    (4444444, "altar_testnet"),
-    (4457845, "zero_testnet_sepolia"),
-    (5112023, "numblock"),
-    (5167003, "mxc_wannsee_zkevm_testnet"),
-    (5167004, "moon_geneva_testnet"),
-    (5201420, "electroneum_testnet"),
-    (5318008, "reactive_kopli"),
-    (5555555, "imversed"),
-    (5555558, "imversed_testnet"),
-    (6038361, "astar_zkyoto"),
-    (6666665, "safeanwang"),
-    (6666666, "safeanwang_testnet"),
-    (7082400, "coti_testnet"),
-    (7225878, "saakuru"),
-    (7355310, "openvessel"),
-    (7668378, "ql1_testnet"),
-    (7762959, "musicoin"),
-    (7777777, "zora"),
-    (8007736, "plian_sub_1"),
-    (8008135, "fhenix_helium"),
-    (8080808, "hokum"),
-    (8601152, "waterfall_8_test"),
-    (8794598, "hap"),
-    (8888881, "quarix_testnet"),
-    (8888888, "quarix"),
-    (9322252, "xcap"),
-    (9322253, "milvine"),
-    (9999999, "fluence"),
-    (10067275, "plian_testnet_sub_1"),
-    (10101010, "soverun"),
-    (10241024, "alienx"),
-    (10241025, "alienx_hal_testnet"),
-    (11145513, "blessnet_sepolia"),
-    (11155111, "sepolia"),
-    (11155420, "op_sepolia_testnet"),
-    (12052024, "memento_testnet"),
-    (12227331, "neox_testnet_t3"),
-    (12227332, "neo_x_testnet_t4"),
-    (13068200, "coti_devnet"),
-    (13371337, "pep_churchill"),
-    (14288640, "andus"),
-    (16658437, "plian_testnet_main"),
-    (18071918, "mande"),
-    (18289463, "iolite"),
-    (19850818, "deepbrain_testnet"),
-    (19880818, "deepbrain"),
-    (20180427, "stability_testnet"),
-    (20180430, "smartmesh"),
-    (20181205, "quarkblock"),
-    (20201022, "pego"),
-    (20230825, "vcity_testnet"),
-    (20240324, "debank_sepolia_testnet"),
-    (20240603, "dbk"),
-    (20241133, "swan_proxima_testnet"),
-    (20482050, "hokum_testnet"),
-    (22052002, "excelon"),
-    (24772477, "6degree_of_outreach_testnet"),
-    (27082017, "excoincial_volta_testnet"),
-    (27082022, "excoincial"),
-    (28122024, "ancient8_testnet"),
-    (28945486, "auxilium"),
-    (29032022, "fla"),
-    (31415926, "filecoin_local_testnet"),
-    (33626250, "toliman_suave_testnet"),
-    (35855456, "joys_digital"),
-    (37084624, "skale_nebula_hub_testnet"),
-    (39916801, "kingdom"),
-    (43214913, "maistestsubnet"),
-    (52164803, "fluence_testnet"),
-    (61717561, "aqua"),
-    (65010000, "autonity_bakerloo_thames_testnet"),
-    (65010001, "autonity_bakerloo_barada_testnet"),
-    (65010002, "autonity_bakerloo_sumida_testnet"),
-    (65010003, "autonity_bakerloo_yamuna_testnet"),
-    (65100000, "autonity_piccadilly_thames_testnet"),
-    (65100001, "autonity_piccadilly_barada_testnet"),
-    (65100002, "autonity_piccadilly_sumida_testnet"),
-    (65100003, "autonity_piccadilly_yamuna_testnet"),
-    (68840142, "frame_testnet"),
-    (77787778, "0xhash_testnet"),
-    (79479957, "sx_toronto_rollup"),
-    (88558801, "backstop_testnet"),
-    (88888888, "t.e.a.m_block"),
-    (94204209, "polygon_blackberry"),
-    (99415706, "joys_digital_testnet"),
-    (100000000, "ethos"),
-    (108160679, "orai"),
-    (111557560, "cyber_testnet"),
-    (123420111, "op_celestia_raspberry"),
-    (161221135, "plume_testnet"),
-    (168587773, "blast_sepolia_testnet"),
-    (192837465, "gather"),
-    (222000222, "kanazawa"),
-    (245022926, "neon_evm_devnet"),
-    (245022929, "neon_evm_devnet_rollup"),
-    (245022934, "neon_evm"),
-    (245022940, "neon_evm_testnet"),
-    (278611351, "razor_skale"),
-    (311752642, "oneledger"),
-    (328527624, "nal_sepolia_testnet"),
-    (333000333, "meld"),
-    (356256156, "gather_testnet"),
-    (476462898, "skopje_testnet"),
-    (486217935, "gather_devnet"),
-    (531050104, "sophon_testnet"),
-    (666666666, "degen"),
-    (728126428, "tron"),
-    (888888888, "ancient8"),
-    (889910245, "ptcescan_testnet"),
-    (889910246, "ptcescan"),
-    (974399131, "skale_calypso_hub_testnet"),
-    (999999999, "zora_sepolia_testnet"),
-    (1020352220, "skale_titan_hub_testnet"),
-    (1122334455, "ipos"),
-    (1146703430, "cyberdecknet"),
-    (1273227453, "human_protocol"),
-    (1313161554, "aurora"),
-    (1313161555, "aurora_testnet"),
-    (1313161556, "aurora_betanet"),
-    (1313161560, "powergold"),
-    (1350216234, "skale_titan_hub"),
-    (1351057110, "chaos_skale_testnet"),
-    (1380012617, "rari"),
-    (1380996178, "raptor"),
-    (1444673419, "skale_europa_hub_testnet"),
-    (1482601649, "skale_nebula_hub"),
-    (1511670449, "gpt"),
-    (1564830818, "skale_calypso_hub"),
-    (1666600000, "harmony_shard_0"),
-    (1666600001, "harmony_shard_1"),
-    (1666600002, "harmony_shard_2"),
-    (1666600003, "harmony_shard_3"),
-    (1666700000, "harmony_testnet_shard_0"),
-    (1666700001, "harmony_testnet_shard_1"),
-    (1666900000, "harmony_devnet_shard_0"),
-    (1666900001, "harmony_devnet_shard_1"),
-    (1722641160, "silicon_zkevm_sepolia_testnet"),
-    (1802203764, "kakarot_sepolia"),
-    (1903648807, "gemu_testnet"),
-    (1918988905, "rari_testnet"),
-    (2021121117, "datahopper"),
-    (2046399126, "skale_europa_hub"),
-    (3125659152, "pirl"),
-    (4216137055, "oneledger_testnet_frankenstein"),
-    (11297108099, "palm_testnet"),
-    (28872323069, "gitswarm_test"),
-    (37714555429, "xai_testnet_v2"),
-    (88153591557, "arbitrum_blueberry"),
-    (111222333444, "alphabet"),
-    (111551119090, "thanos_sepolia"),
-    (123420000220, "fluence_stage"),
-    (197710212030, "ntity"),
-    (197710212031, "haradev_testnet"),
-    (202402181627, "gm_testnet"),
-    (383414847825, "zeniq"),
-    (666301171999, "pdc"),
-    (6022140761023, "molereum"),
-    (2713017997578000, "d_testnet"),
+#[rustfmt::skip]
+pub(crate) const NETWORKS: &[NetworkInfo] = &[
+    NetworkInfo::new(1, "ethereum", "Ethereum Mainnet").aliases(&["mainnet", "eth", "ethereum_mainnet"]).currency("ETH").explorer("https://etherscan.io"),
+    NetworkInfo::new(2, "expanse", "Expanse"),
+    NetworkInfo::new(3, "ropsten", "Ropsten").testnet(),
+    NetworkInfo::new(4, "rinkeby", "Rinkeby").testnet(),
+    NetworkInfo::new(5, "goerli", "Goerli").aliases(&["eth_goerli"]).testnet().currency("ETH").explorer("https://goerli.etherscan.io"),
+    NetworkInfo::new(6, "kotti_testnet", "Kotti Testnet").testnet(),
+    NetworkInfo::new(7, "thai", "Thai"),
+    NetworkInfo::new(8, "ubiq", "Ubiq"),
+    NetworkInfo::new(9, "ubiq_testnet", "Ubiq Testnet").testnet(),
+    NetworkInfo::new(10, "optimism", "OP Mainnet").aliases(&["op", "op_mainnet", "optimism_mainnet"]).parent(1).currency("ETH").explorer("https://optimistic.etherscan.io"),
+    NetworkInfo::new(11, "metadium", "Metadium"),
+    NetworkInfo::new(12, "metadium_testnet", "Metadium Testnet").testnet(),
+    NetworkInfo::new(13, "diode_testnet_staging", "Diode Testnet Staging").testnet(),
+    NetworkInfo::new(14, "flare", "Flare"),
+    NetworkInfo::new(15, "diode_prenet", "Diode Prenet"),
+    NetworkInfo::new(16, "songbird_testnet_coston", "Songbird Testnet Coston").testnet(),
+    NetworkInfo::new(17, "thaifi", "Thaifi"),
+    NetworkInfo::new(18, "thundercore_testnet", "Thundercore Testnet").testnet(),
+    NetworkInfo::new(19, "songbird_canary", "Songbird Canary"),
+    NetworkInfo::new(20, "elastos", "Elastos"),
+    NetworkInfo::new(21, "elastos_testnet", "Elastos Testnet").testnet(),
+    NetworkInfo::new(22, "ela_did", "Ela Did"),
+    NetworkInfo::new(23, "ela_did_testnet", "Ela Did Testnet").testnet(),
+    NetworkInfo::new(24, "kardia", "Kardia"),
+    NetworkInfo::new(25, "cronos", "Cronos"),
+    NetworkInfo::new(26, "genesis_testnet", "Genesis Testnet").testnet(),
+    NetworkInfo::new(27, "shiba", "Shiba"),
+    NetworkInfo::new(28, "boba_rinkeby_testnet", "Boba Rinkeby Testnet").testnet(),
+    NetworkInfo::new(29, "genesis", "Genesis"),
+    NetworkInfo::new(30, "rootstock", "Rootstock"),
+    NetworkInfo::new(31, "rootstock_testnet", "Rootstock Testnet").testnet(),
+    NetworkInfo::new(32, "gooddata_testnet", "Gooddata Testnet").testnet(),
+    NetworkInfo::new(33, "gooddata", "Gooddata"),
+    NetworkInfo::new(34, "secure", "Secure"),
+    NetworkInfo::new(35, "tbwg", "Tbwg"),
+    NetworkInfo::new(36, "dx", "Dx"),
+    NetworkInfo::new(37, "xpla", "Xpla"),
+    NetworkInfo::new(38, "valorbit", "Valorbit"),
+    NetworkInfo::new(39, "u2u_solaris", "U2u Solaris"),
+    NetworkInfo::new(40, "telos_evm", "Telos Evm"),
+    NetworkInfo::new(41, "telos_evm_testnet", "Telos Evm Testnet").testnet(),
+    NetworkInfo::new(42, "lukso", "Lukso"),
+    NetworkInfo::new(43, "darwinia_pangolin_testnet", "Darwinia Pangolin Testnet").testnet(),
+    NetworkInfo::new(44, "crab", "Crab"),
+    NetworkInfo::new(45, "darwinia_pangoro_testnet", "Darwinia Pangoro Testnet").testnet(),
+    NetworkInfo::new(46, "darwinia", "Darwinia"),
+    NetworkInfo::new(47, "acria_intelli", "Acria Intelli"),
+    NetworkInfo::new(48, "ennothem_proterozoic", "Ennothem Proterozoic"),
+    NetworkInfo::new(49, "ennothem_testnet_pioneer", "Ennothem Testnet Pioneer").testnet(),
+    NetworkInfo::new(50, "xdc", "Xdc"),
+    NetworkInfo::new(51, "xdc_apothem", "Xdc Apothem"),
+    NetworkInfo::new(52, "coinex", "Coinex"),
+    NetworkInfo::new(53, "coinex_testnet", "Coinex Testnet").testnet(),
+    NetworkInfo::new(54, "openpiece", "Openpiece"),
+    NetworkInfo::new(55, "zyx", "Zyx"),
+    NetworkInfo::new(56, "bsc", "BNB Smart Chain Mainnet").aliases(&["bnb", "bnb_smart_chain"]).currency("BNB").explorer("https://bscscan.com"),
+    NetworkInfo::new(57, "syscoin", "Syscoin"),
+    NetworkInfo::new(58, "ontology", "Ontology"),
+    NetworkInfo::new(59, "eos_evm_legacy", "Eos Evm Legacy"),
+    NetworkInfo::new(60, "go", "Go"),
+    NetworkInfo::new(61, "ethereum_classic", "Ethereum Classic"),
+    NetworkInfo::new(62, "morden_testnet", "Morden Testnet").testnet(),
+    NetworkInfo::new(63, "mordor_testnet", "Mordor Testnet").testnet(),
+    NetworkInfo::new(64, "ellaism", "Ellaism"),
+    NetworkInfo::new(65, "okex_testnet", "Okex Testnet").testnet(),
+    NetworkInfo::new(66, "okx", "Okx"),
+    NetworkInfo::new(67, "db_testnet", "Db Testnet").testnet(),
+    NetworkInfo::new(68, "soterone", "Soterone"),
+    NetworkInfo::new(69, "optimism_kovan", "Optimism Kovan").testnet(),
+    NetworkInfo::new(70, "hoo", "Hoo"),
+    NetworkInfo::new(71, "conflux_espace_testnet", "Conflux Espace Testnet").testnet(),
+    NetworkInfo::new(72, "dx_testnet", "Dx Testnet").testnet(),
+    NetworkInfo::new(73, "fncy", "Fncy"),
+    NetworkInfo::new(74, "id", "Id"),
+    NetworkInfo::new(75, "decimal", "Decimal"),
+    NetworkInfo::new(76, "mix", "Mix"),
+    NetworkInfo::new(77, "poa_sokol", "Poa Sokol"),
+    NetworkInfo::new(78, "primus", "Primus"),
+    NetworkInfo::new(79, "zenith", "Zenith"),
+    NetworkInfo::new(80, "gene", "Gene"),
+    NetworkInfo::new(81, "japan_open", "Japan Open"),
+    NetworkInfo::new(82, "meter", "Meter"),
+    NetworkInfo::new(83, "meter_testnet", "Meter Testnet").testnet(),
+    NetworkInfo::new(84, "linqto_devnet", "Linqto Devnet").testnet(),
+    NetworkInfo::new(85, "gate_testnet", "Gate Testnet").testnet(),
+    NetworkInfo::new(86, "gate", "Gate"),
+    NetworkInfo::new(87, "nova", "Nova"),
+    NetworkInfo::new(88, "viction", "Viction"),
+    NetworkInfo::new(89, "viction_testnet", "Viction Testnet").testnet(),
+    NetworkInfo::new(90, "garizon_stage0", "Garizon Stage0"),
+    NetworkInfo::new(91, "garizon_stage1", "Garizon Stage1"),
+    NetworkInfo::new(92, "garizon_stage2", "Garizon Stage2"),
+    NetworkInfo::new(93, "garizon_stage3", "Garizon Stage3"),
+    NetworkInfo::new(94, "swissdlt", "Swissdlt"),
+    NetworkInfo::new(95, "camdl", "Camdl"),
+    NetworkInfo::new(96, "bitkub", "Bitkub"),
+    NetworkInfo::new(97, "bnb_testnet", "BNB Smart Chain Testnet").aliases(&["bsc_testnet"]).testnet().currency("tBNB").explorer("https://testnet.bscscan.com"),
+    NetworkInfo::new(98, "six_protocol", "Six Protocol"),
+    NetworkInfo::new(99, "poa_core", "Poa Core"),
+    NetworkInfo::new(100, "gnosis", "Gnosis").aliases(&["xdai"]).currency("XDAI").explorer("https://gnosisscan.io"),
+    NetworkInfo::new(101, "etherinc", "Etherinc"),
+    NetworkInfo::new(102, "web3games_testnet", "Web3games Testnet").testnet(),
+    NetworkInfo::new(103, "worldland", "Worldland"),
+    NetworkInfo::new(104, "kaiba_lightning_testnet", "Kaiba Lightning Testnet").testnet(),
+    NetworkInfo::new(105, "web3games_devnet", "Web3games Devnet").testnet(),
+    NetworkInfo::new(106, "velas_evm", "Velas Evm"),
+    NetworkInfo::new(107, "nebula_testnet", "Nebula Testnet").testnet(),
+    NetworkInfo::new(108, "thundercore", "Thundercore"),
+    NetworkInfo::new(109, "shibarium", "Shibarium"),
+    NetworkInfo::new(110, "proton_testnet", "Proton Testnet").testnet(),
+    NetworkInfo::new(111, "etherlite", "Etherlite"),
+    NetworkInfo::new(112, "coinbit", "Coinbit"),
+    NetworkInfo::new(113, "dehvo", "Dehvo"),
+    NetworkInfo::new(114, "flare_testnet_coston2", "Flare Testnet Coston2").testnet(),
+    NetworkInfo::new(116, "debank", "Debank"),
+    NetworkInfo::new(117, "uptick", "Uptick"),
+    NetworkInfo::new(118, "arcology_testnet", "Arcology Testnet").testnet(),
+    NetworkInfo::new(119, "enuls", "Enuls"),
+    NetworkInfo::new(120, "enuls_testnet", "Enuls Testnet").testnet(),
+    NetworkInfo::new(121, "real", "Real"),
+    NetworkInfo::new(122, "fuse", "Fuse"),
+    NetworkInfo::new(123, "fuse_sparknet", "Fuse Sparknet"),
+    NetworkInfo::new(124, "decentralized_web", "Decentralized Web"),
+    NetworkInfo::new(125, "oy_testnet", "Oy Testnet").testnet(),
+    NetworkInfo::new(126, "oy", "Oy"),
+    NetworkInfo::new(127, "factory_127", "Factory 127"),
+    NetworkInfo::new(128, "huobi_eco", "Huobi Eco"),
+    NetworkInfo::new(129, "innovator", "Innovator"),
+    NetworkInfo::new(131, "engram_testnet", "Engram Testnet").testnet(),
+    NetworkInfo::new(132, "namefi", "Namefi"),
+    NetworkInfo::new(133, "hashkey_testnet", "Hashkey Testnet").testnet(),
+    NetworkInfo::new(134, "iexec", "Iexec"),
+    NetworkInfo::new(135, "alyx_testnet", "Alyx Testnet").testnet(),
+    NetworkInfo::new(136, "deam", "Deam"),
+    NetworkInfo::new(137, "polygon", "Polygon Mainnet").aliases(&["matic", "polygon_pos"]).currency("POL").explorer("https://polygonscan.com"),
+    NetworkInfo::new(138, "defi_oracle_meta", "Defi Oracle Meta"),
+    NetworkInfo::new(139, "woop", "Woop"),
+    NetworkInfo::new(140, "eternal", "Eternal"),
+    NetworkInfo::new(141, "openpiece_testnet", "Openpiece Testnet").testnet(),
+    NetworkInfo::new(142, "dax", "Dax"),
+    NetworkInfo::new(144, "phi_v2", "Phi V2"),
+    NetworkInfo::new(145, "soraai_testnet", "Soraai Testnet").testnet(),
+    NetworkInfo::new(147, "flag", "Flag"),
+    NetworkInfo::new(148, "shimmerevm", "Shimmerevm"),
+    NetworkInfo::new(150, "six_protocol_testnet", "Six Protocol Testnet").testnet(),
+    NetworkInfo::new(151, "redbelly", "Redbelly"),
+    NetworkInfo::new(152, "redbelly_devnet", "Redbelly Devnet").testnet(),
+    NetworkInfo::new(153, "redbelly_testnet", "Redbelly Testnet").testnet(),
+    NetworkInfo::new(154, "redbelly_tge", "Redbelly Tge"),
+    NetworkInfo::new(155, "tenet_testnet", "Tenet Testnet").testnet(),
+    NetworkInfo::new(156, "oeblock_testnet", "Oeblock Testnet").testnet(),
+    NetworkInfo::new(157, "puppynet_shibarium", "Puppynet Shibarium"),
+    NetworkInfo::new(158, "roburna", "Roburna"),
+    NetworkInfo::new(159, "roburna_testnet", "Roburna Testnet").testnet(),
+    NetworkInfo::new(160, "armonia_eva", "Armonia Eva"),
+    NetworkInfo::new(161, "armonia_eva_testnet", "Armonia Eva Testnet").testnet(),
+    NetworkInfo::new(162, "lightstreams_testnet", "Lightstreams Testnet").testnet(),
+    NetworkInfo::new(163, "lightstreams", "Lightstreams"),
+    NetworkInfo::new(164, "omni_omega_testnet", "Omni Omega Testnet").testnet(),
+    NetworkInfo::new(166, "omni", "Omni"),
+    NetworkInfo::new(167, "atoshi_testnet", "Atoshi Testnet").testnet(),
+    NetworkInfo::new(168, "aioz", "Aioz"),
+    NetworkInfo::new(169, "manta_pacific", "Manta Pacific"),
+    NetworkInfo::new(170, "hoo_testnet", "Hoo Testnet").testnet(),
+    NetworkInfo::new(171, "co2e_ledger", "Co2e Ledger"),
+    NetworkInfo::new(172, "latam_block_resil_testnet", "Latam Block Resil Testnet").testnet(),
+    NetworkInfo::new(176, "dc", "Dc"),
+    NetworkInfo::new(178, "abey_testnet", "Abey Testnet").testnet(),
+    NetworkInfo::new(179, "abey", "Abey"),
+    NetworkInfo::new(180, "ame", "Ame"),
+    NetworkInfo::new(181, "waterfall", "Waterfall"),
+    NetworkInfo::new(183, "ethernity", "Ethernity"),
+    NetworkInfo::new(184, "dojima_testnet", "Dojima Testnet").testnet(),
+    NetworkInfo::new(185, "mint", "Mint"),
+    NetworkInfo::new(186, "seele", "Seele"),
+    NetworkInfo::new(187, "dojima", "Dojima"),
+    NetworkInfo::new(188, "bmc", "Bmc"),
+    NetworkInfo::new(189, "bmc_testnet", "Bmc Testnet").testnet(),
+    NetworkInfo::new(190, "cmdao_bbq", "Cmdao Bbq"),
+    NetworkInfo::new(191, "filefilego", "Filefilego"),
+    NetworkInfo::new(193, "crypto_emergency", "Crypto Emergency"),
+    NetworkInfo::new(195, "x_layer_testnet", "X Layer Testnet").testnet(),
+    NetworkInfo::new(196, "x_layer", "X Layer"),
+    NetworkInfo::new(197, "neutrinos_testnet", "Neutrinos Testnet").testnet(),
+    NetworkInfo::new(198, "bit", "Bit"),
+    NetworkInfo::new(199, "bittorrent", "Bittorrent"),
+    NetworkInfo::new(200, "arbitrum_on_xdai", "Arbitrum On Xdai"),
+    NetworkInfo::new(201, "moac_testnet", "Moac Testnet").testnet(),
+    NetworkInfo::new(202, "edgeless_testnet", "Edgeless Testnet").testnet(),
+    NetworkInfo::new(204, "opbnb", "Opbnb"),
+    NetworkInfo::new(205, "ekaash", "Ekaash"),
+    NetworkInfo::new(206, "vinu_testnet", "Vinu Testnet").testnet(),
+    NetworkInfo::new(207, "vinu", "Vinu"),
+    NetworkInfo::new(208, "structx", "Structx"),
+    NetworkInfo::new(210, "bitnet", "Bitnet"),
+    NetworkInfo::new(211, "freight_trust", "Freight Trust"),
+    NetworkInfo::new(212, "mapo_makalu", "Mapo Makalu"),
+    NetworkInfo::new(213, "b2_hub", "B2 Hub"),
+    NetworkInfo::new(214, "shinarium", "Shinarium"),
+    NetworkInfo::new(217, "siriusnet_v2", "Siriusnet V2"),
+    NetworkInfo::new(218, "soterone_old", "Soterone Old"),
+    NetworkInfo::new(220, "scalind_testnet", "Scalind Testnet").testnet(),
+    NetworkInfo::new(221, "blockex", "Blockex"),
+    NetworkInfo::new(222, "permission", "Permission"),
+    NetworkInfo::new(223, "b2", "B2"),
+    NetworkInfo::new(224, "viridis_testnet", "Viridis Testnet").testnet(),
+    NetworkInfo::new(225, "la", "La"),
+    NetworkInfo::new(226, "la_testnet", "La Testnet").testnet(),
+    NetworkInfo::new(228, "mind", "Mind"),
+    NetworkInfo::new(230, "swapdex", "Swapdex"),
+    NetworkInfo::new(233, "ethernity_testnet", "Ethernity Testnet").testnet(),
+    NetworkInfo::new(234, "protojumbo_testnet", "Protojumbo Testnet").testnet(),
+    NetworkInfo::new(236, "deam_testnet", "Deam Testnet").testnet(),
+    NetworkInfo::new(238, "blast", "Blast"),
+    NetworkInfo::new(242, "plinga", "Plinga"),
+    NetworkInfo::new(246, "energy_web", "Energy Web"),
+    NetworkInfo::new(248, "oasys", "Oasys"),
+    NetworkInfo::new(250, "fantom_opera", "Fantom Opera").aliases(&["fantom", "ftm"]).currency("FTM").explorer("https://ftmscan.com"),
+    NetworkInfo::new(252, "fraxtal", "Fraxtal"),
+    NetworkInfo::new(254, "swan", "Swan"),
+    NetworkInfo::new(255, "kroma", "Kroma"),
+    NetworkInfo::new(256, "huobi_eco_testnet", "Huobi Eco Testnet").testnet(),
+    NetworkInfo::new(258, "setheum", "Setheum"),
+    NetworkInfo::new(259, "neonlink", "Neonlink"),
+    NetworkInfo::new(261, "guru_testnet", "Guru Testnet").testnet(),
+    NetworkInfo::new(262, "sur_block", "Sur Block"),
+    NetworkInfo::new(266, "neura", "Neura"),
+    NetworkInfo::new(267, "neura_testnet", "Neura Testnet").testnet(),
+    NetworkInfo::new(268, "neura_devnet", "Neura Devnet").testnet(),
+    NetworkInfo::new(269, "high_performance_block", "High Performance Block"),
+    NetworkInfo::new(271, "egoncoin", "Egoncoin"),
+    NetworkInfo::new(278, "xfair.ai", "Xfair.ai"),
+    NetworkInfo::new(279, "bpx_block", "Bpx Block"),
+    NetworkInfo::new(282, "cronos_zkevm_testnet", "Cronos Zkevm Testnet").testnet(),
+    NetworkInfo::new(288, "boba", "Boba"),
+    NetworkInfo::new(291, "orderly", "Orderly"),
+    NetworkInfo::new(295, "hedera", "Hedera"),
+    NetworkInfo::new(296, "hedera_testnet", "Hedera Testnet").testnet(),
+    NetworkInfo::new(297, "hedera_previewnet", "Hedera Previewnet"),
+    NetworkInfo::new(298, "hedera_localnet", "Hedera Localnet"),
+    NetworkInfo::new(300, "zksync_sepolia_testnet", "zkSync Sepolia Testnet").aliases(&["zksync_sepolia"]).testnet().parent(11155111).currency("ETH").explorer("https://sepolia.explorer.zksync.io"),
+    NetworkInfo::new(301, "bobaopera", "Bobaopera"),
+    NetworkInfo::new(302, "zkcandy_sepolia_testnet", "Zkcandy Sepolia Testnet").testnet(),
+    NetworkInfo::new(303, "neuro_testnet", "Neuro Testnet").testnet(),
+    NetworkInfo::new(305, "zksats", "Zksats"),
+    NetworkInfo::new(307, "lovely_testnet", "Lovely Testnet").testnet(),
+    NetworkInfo::new(308, "furtheon", "Furtheon"),
+    NetworkInfo::new(309, "wyzth_testnet", "Wyzth Testnet").testnet(),
+    NetworkInfo::new(311, "omax", "Omax"),
+    NetworkInfo::new(313, "neuro", "Neuro"),
+    NetworkInfo::new(314, "filecoin", "Filecoin"),
+    NetworkInfo::new(321, "kcc", "Kcc"),
+    NetworkInfo::new(322, "kcc_testnet", "Kcc Testnet").testnet(),
+    NetworkInfo::new(323, "cosvm", "Cosvm"),
+    NetworkInfo::new(324, "zksync", "zkSync Mainnet").aliases(&["zksync_era", "era"]).parent(1).currency("ETH").explorer("https://explorer.zksync.io"),
+    NetworkInfo::new(325, "grvt", "Grvt"),
+    NetworkInfo::new(326, "grvt_sepolia_testnet", "Grvt Sepolia Testnet").testnet(),
+    NetworkInfo::new(333, "web3q", "Web3q"),
+    NetworkInfo::new(335, "dfk_test", "Dfk Test").testnet(),
+    NetworkInfo::new(336, "shiden", "Shiden"),
+    NetworkInfo::new(338, "cronos_testnet", "Cronos Testnet").testnet(),
+    NetworkInfo::new(345, "tsc", "Tsc"),
+    NetworkInfo::new(360, "shape", "Shape"),
+    NetworkInfo::new(361, "theta", "Theta"),
+    NetworkInfo::new(363, "theta_sapphire_testnet", "Theta Sapphire Testnet").testnet(),
+    NetworkInfo::new(364, "theta_amber_testnet", "Theta Amber Testnet").testnet(),
+    NetworkInfo::new(365, "theta_testnet", "Theta Testnet").testnet(),
+    NetworkInfo::new(369, "pulse", "Pulse"),
+    NetworkInfo::new(371, "consta_testnet", "Consta Testnet").testnet(),
+    NetworkInfo::new(380, "zkamoeba_testnet", "Zkamoeba Testnet").testnet(),
+    NetworkInfo::new(381, "zkamoeba", "Zkamoeba"),
+    NetworkInfo::new(385, "lisinski", "Lisinski"),
+    NetworkInfo::new(388, "cronos_zkevm", "Cronos Zkevm"),
+    NetworkInfo::new(395, "camdl_testnet", "Camdl Testnet").testnet(),
+    NetworkInfo::new(397, "near", "Near"),
+    NetworkInfo::new(398, "near_testnet", "Near Testnet").testnet(),
+    NetworkInfo::new(399, "nativ3", "Nativ3"),
+    NetworkInfo::new(400, "hyperon_testnet", "Hyperon Testnet").testnet(),
+    NetworkInfo::new(401, "ozone_testnet", "Ozone Testnet").testnet(),
+    NetworkInfo::new(404, "syndr_l3", "Syndr L3"),
+    NetworkInfo::new(411, "pepe", "Pepe"),
+    NetworkInfo::new(416, "sx", "Sx"),
+    NetworkInfo::new(418, "latestnet", "Latestnet").testnet(),
+    NetworkInfo::new(420, "optimism_goerli_testnet", "Optimism Goerli Testnet").testnet(),
+    NetworkInfo::new(422, "viridis", "Viridis"),
+    NetworkInfo::new(424, "pgn_public_goods", "Pgn Public Goods"),
+    NetworkInfo::new(427, "zeeth", "Zeeth"),
+    NetworkInfo::new(428, "geso_verse", "Geso Verse"),
+    NetworkInfo::new(434, "boyaa", "Boyaa"),
+    NetworkInfo::new(443, "ten_testnet", "Ten Testnet").testnet(),
+    NetworkInfo::new(444, "synapse_testnet", "Synapse Testnet").testnet(),
+    NetworkInfo::new(456, "arzio", "Arzio"),
+    NetworkInfo::new(462, "areon_testnet", "Areon Testnet").testnet(),
+    NetworkInfo::new(463, "areon", "Areon"),
+    NetworkInfo::new(480, "world", "World"),
+    NetworkInfo::new(499, "rupaya", "Rupaya"),
+    NetworkInfo::new(500, "camino_c", "Camino C"),
+    NetworkInfo::new(501, "columbus_test", "Columbus Test").testnet(),
+    NetworkInfo::new(510, "syndicate", "Syndicate"),
+    NetworkInfo::new(512, "double_a", "Double A"),
+    NetworkInfo::new(513, "double_a_testnet", "Double A Testnet").testnet(),
+    NetworkInfo::new(516, "gear_zero", "Gear Zero"),
+    NetworkInfo::new(520, "xt", "Xt"),
+    NetworkInfo::new(529, "fire", "Fire"),
+    NetworkInfo::new(530, "fxcore", "Fxcore"),
+    NetworkInfo::new(534, "candle", "Candle"),
+    NetworkInfo::new(537, "optrust", "Optrust"),
+    NetworkInfo::new(542, "paw_testnet", "Paw Testnet").testnet(),
+    NetworkInfo::new(545, "evm_on_flow_testnet", "Evm On Flow Testnet").testnet(),
+    NetworkInfo::new(555, "vela1", "Vela1"),
+    NetworkInfo::new(558, "tao", "Tao"),
+    NetworkInfo::new(568, "doge_testnet", "Doge Testnet").testnet(),
+    NetworkInfo::new(570, "rollux", "Rollux"),
+    NetworkInfo::new(571, "meta", "Meta"),
+    NetworkInfo::new(579, "filenova", "Filenova"),
+    NetworkInfo::new(588, "metis_stardust_testnet", "Metis Stardust Testnet").testnet(),
+    NetworkInfo::new(592, "astar", "Astar"),
+    NetworkInfo::new(595, "acala_mandala_testnet_tc9", "Acala Mandala Testnet Tc9").testnet(),
+    NetworkInfo::new(596, "karura_testnet", "Karura Testnet").testnet(),
+    NetworkInfo::new(597, "acala_testnet", "Acala Testnet").testnet(),
+    NetworkInfo::new(599, "metis_goerli_testnet", "Metis Goerli Testnet").testnet(),
+    NetworkInfo::new(600, "meshnyan_testnet", "Meshnyan Testnet").testnet(),
+    NetworkInfo::new(601, "vine_testnet", "Vine Testnet").testnet(),
+    NetworkInfo::new(610, "darwin_devnet", "Darwin Devnet").testnet(),
+    NetworkInfo::new(612, "eiob", "Eiob"),
+    NetworkInfo::new(614, "graphlinq_block", "Graphlinq Block"),
+    NetworkInfo::new(624, "binary", "Binary"),
+    NetworkInfo::new(625, "binary_sepolia", "Binary Sepolia").testnet(),
+    NetworkInfo::new(634, "avocado", "Avocado"),
+    NetworkInfo::new(646, "previewnet", "Previewnet"),
+    NetworkInfo::new(647, "sx_testnet", "Sx Testnet").testnet(),
+    NetworkInfo::new(648, "endurance", "Endurance"),
+    NetworkInfo::new(653, "kali_testnet", "Kali Testnet").testnet(),
+    NetworkInfo::new(654, "kali", "Kali"),
+    NetworkInfo::new(662, "ultronsmart", "Ultronsmart"),
+    NetworkInfo::new(666, "pixie_testnet", "Pixie Testnet").testnet(),
+    NetworkInfo::new(667, "laos_arrakis", "Laos Arrakis"),
+    NetworkInfo::new(668, "junca", "Junca"),
+    NetworkInfo::new(669, "junca_testnet", "Junca Testnet").testnet(),
+    NetworkInfo::new(686, "karura", "Karura"),
+    NetworkInfo::new(690, "redstone", "Redstone"),
+    NetworkInfo::new(700, "star_social_testnet", "Star Social Testnet").testnet(),
+    NetworkInfo::new(701, "darwinia_koi_testnet", "Darwinia Koi Testnet").testnet(),
+    NetworkInfo::new(707, "block_station", "Block Station"),
+    NetworkInfo::new(708, "block_station_testnet", "Block Station Testnet").testnet(),
+    NetworkInfo::new(710, "highbury", "Highbury"),
+    NetworkInfo::new(711, "tucana", "Tucana"),
+    NetworkInfo::new(712, "birdee_2", "Birdee 2"),
+    NetworkInfo::new(713, "vrcscan", "Vrcscan"),
+    NetworkInfo::new(719, "shibarium_beta", "Shibarium Beta"),
+    NetworkInfo::new(721, "lycan", "Lycan"),
+    NetworkInfo::new(727, "blucrates", "Blucrates"),
+    NetworkInfo::new(730, "lovely", "Lovely"),
+    NetworkInfo::new(740, "canto_testnet", "Canto Testnet").testnet(),
+    NetworkInfo::new(741, "vention_testnet", "Vention Testnet").testnet(),
+    NetworkInfo::new(742, "script_testnet", "Script Testnet").testnet(),
+    NetworkInfo::new(747, "evm_on_flow", "Evm On Flow"),
+    NetworkInfo::new(766, "ql1", "Ql1"),
+    NetworkInfo::new(776, "open_testnet", "Open Testnet").testnet(),
+    NetworkInfo::new(777, "cheapeth", "Cheapeth"),
+    NetworkInfo::new(786, "maal", "Maal"),
+    NetworkInfo::new(787, "acala", "Acala"),
+    NetworkInfo::new(788, "aero_testnet", "Aero Testnet").testnet(),
+    NetworkInfo::new(789, "patex", "Patex"),
+    NetworkInfo::new(799, "rupaya_testnet", "Rupaya Testnet").testnet(),
+    NetworkInfo::new(800, "lucid_block", "Lucid Block"),
+    NetworkInfo::new(803, "haic", "Haic"),
+    NetworkInfo::new(808, "portal_fantasy_test", "Portal Fantasy Test").testnet(),
+    NetworkInfo::new(810, "haven1_testnet", "Haven1 Testnet").testnet(),
+    NetworkInfo::new(813, "qitmeer", "Qitmeer"),
+    NetworkInfo::new(814, "fire_zkevm", "Fire Zkevm"),
+    NetworkInfo::new(818, "beone", "Beone"),
+    NetworkInfo::new(820, "callisto", "Callisto"),
+    NetworkInfo::new(822, "runic_testnet", "Runic Testnet").testnet(),
+    NetworkInfo::new(831, "checkdot_block_devnet", "Checkdot Block Devnet").testnet(),
+    NetworkInfo::new(841, "taraxa", "Taraxa"),
+    NetworkInfo::new(842, "taraxa_testnet", "Taraxa Testnet").testnet(),
+    NetworkInfo::new(852, "hongkong", "Hongkong"),
+    NetworkInfo::new(859, "zeeth_dev", "Zeeth Dev"),
+    NetworkInfo::new(868, "fantasia", "Fantasia"),
+    NetworkInfo::new(876, "bandai_namco_research_verse", "Bandai Namco Research Verse"),
+    NetworkInfo::new(877, "dexit", "Dexit"),
+    NetworkInfo::new(880, "ambros", "Ambros"),
+    NetworkInfo::new(888, "wan", "Wan"),
+    NetworkInfo::new(898, "maxi_testnet", "Maxi Testnet").testnet(),
+    NetworkInfo::new(899, "maxi", "Maxi"),
+    NetworkInfo::new(900, "garizon_testnet_stage0", "Garizon Testnet Stage0").testnet(),
+    NetworkInfo::new(901, "garizon_testnet_stage1", "Garizon Testnet Stage1").testnet(),
+    NetworkInfo::new(902, "garizon_testnet_stage2", "Garizon Testnet Stage2").testnet(),
+    NetworkInfo::new(903, "garizon_testnet_stage3", "Garizon Testnet Stage3").testnet(),
+    NetworkInfo::new(909, "portal_fantasy", "Portal Fantasy"),
+    NetworkInfo::new(910, "decentrabone_layer1_testnet", "Decentrabone Layer1 Testnet").testnet(),
+    NetworkInfo::new(911, "taproot", "Taproot"),
+    NetworkInfo::new(917, "rinia_testnet", "Rinia Testnet").testnet(),
+    NetworkInfo::new(919, "mode_testnet", "Mode Testnet").testnet(),
+    NetworkInfo::new(927, "yidark", "Yidark"),
+    NetworkInfo::new(940, "pulse_testnet", "Pulse Testnet").testnet(),
+    NetworkInfo::new(941, "pulse_testnet_v2b", "Pulse Testnet V2b").testnet(),
+    NetworkInfo::new(942, "pulse_testnet_v3", "Pulse Testnet V3").testnet(),
+    NetworkInfo::new(943, "pulse_testnet_v4", "Pulse Testnet V4").testnet(),
+    NetworkInfo::new(945, "subtensor_evm_testnet", "Subtensor Evm Testnet").testnet(),
+    NetworkInfo::new(956, "munode_testnet", "Munode Testnet").testnet(),
+    NetworkInfo::new(957, "lyra", "Lyra"),
+    NetworkInfo::new(963, "btc20", "Btc20"),
+    NetworkInfo::new(969, "ethxy", "Ethxy"),
+    NetworkInfo::new(970, "oort", "Oort"),
+    NetworkInfo::new(971, "oort_huygens", "Oort Huygens"),
+    NetworkInfo::new(972, "oort_ascraeus", "Oort Ascraeus"),
+    NetworkInfo::new(973, "palm", "Palm"),
+    NetworkInfo::new(977, "nepal_block", "Nepal Block"),
+    NetworkInfo::new(979, "ethxy_testnet", "Ethxy Testnet").testnet(),
+    NetworkInfo::new(980, "top_evm", "Top Evm"),
+    NetworkInfo::new(985, "memo", "Memo"),
+    NetworkInfo::new(989, "top", "Top"),
+    NetworkInfo::new(990, "eliberty", "Eliberty"),
+    NetworkInfo::new(995, "5ire", "5ire"),
+    NetworkInfo::new(996, "bifrost_polkadot", "Bifrost Polkadot"),
+    NetworkInfo::new(997, "5ire_thunder_testnet", "5ire Thunder Testnet").testnet(),
+    NetworkInfo::new(998, "lucky", "Lucky"),
+    NetworkInfo::new(999, "wan_testnet", "Wan Testnet").testnet(),
+    NetworkInfo::new(1000, "gton", "Gton"),
+    NetworkInfo::new(1001, "kaia_testnet_kairos", "Kaia Testnet Kairos").testnet(),
+    NetworkInfo::new(1003, "tectum_emission_token", "Tectum Emission Token"),
+    NetworkInfo::new(1004, "t_ekta", "T Ekta"),
+    NetworkInfo::new(1005, "lemontestnet", "Lemontestnet").testnet(),
+    NetworkInfo::new(1006, "lemon", "Lemon"),
+    NetworkInfo::new(1007, "newton_testnet", "Newton Testnet").testnet(),
+    NetworkInfo::new(1008, "eurus", "Eurus"),
+    NetworkInfo::new(1009, "jumbo", "Jumbo"),
+    NetworkInfo::new(1010, "evrice", "Evrice"),
+    NetworkInfo::new(1011, "rebus", "Rebus"),
+    NetworkInfo::new(1012, "newton", "Newton"),
+    NetworkInfo::new(1022, "sakura", "Sakura"),
+    NetworkInfo::new(1023, "clover_testnet", "Clover Testnet").testnet(),
+    NetworkInfo::new(1024, "clv_para", "Clv Para"),
+    NetworkInfo::new(1028, "bittorrent_testnet", "Bittorrent Testnet").testnet(),
+    NetworkInfo::new(1030, "conflux_espace", "Conflux Espace"),
+    NetworkInfo::new(1031, "proxy_testnet", "Proxy Testnet").testnet(),
+    NetworkInfo::new(1038, "bronos_testnet", "Bronos Testnet").testnet(),
+    NetworkInfo::new(1039, "bronos", "Bronos"),
+    NetworkInfo::new(1073, "shimmerevm_testnet", "Shimmerevm Testnet").testnet(),
+    NetworkInfo::new(1075, "iota_evm_testnet", "Iota Evm Testnet").testnet(),
+    NetworkInfo::new(1079, "mintara_testnet", "Mintara Testnet").testnet(),
+    NetworkInfo::new(1080, "mintara", "Mintara"),
+    NetworkInfo::new(1088, "metis_andromeda", "Metis Andromeda"),
+    NetworkInfo::new(1089, "humans.ai", "Humans.ai"),
+    NetworkInfo::new(1099, "moac", "Moac"),
+    NetworkInfo::new(1100, "dymension", "Dymension"),
+    NetworkInfo::new(1101, "polygon_zkevm", "Polygon zkEVM").parent(1).currency("ETH").explorer("https://zkevm.polygonscan.com"),
+    NetworkInfo::new(1107, "blxq_testnet", "Blxq Testnet").testnet(),
+    NetworkInfo::new(1108, "blxq", "Blxq"),
+    NetworkInfo::new(1111, "wemix", "Wemix"),
+    NetworkInfo::new(1112, "wemix_testnet", "Wemix Testnet").testnet(),
+    NetworkInfo::new(1113, "b2_hub_testnet", "B2 Hub Testnet").testnet(),
+    NetworkInfo::new(1115, "core_block_testnet", "Core Block Testnet").testnet(),
+    NetworkInfo::new(1116, "core_block", "Core Block"),
+    NetworkInfo::new(1117, "dogcoin", "Dogcoin"),
+    NetworkInfo::new(1123, "b2_testnet", "B2 Testnet").testnet(),
+    NetworkInfo::new(1130, "defi_evm", "Defi Evm"),
+    NetworkInfo::new(1131, "defi_evm_testnet", "Defi Evm Testnet").testnet(),
+    NetworkInfo::new(1133, "defimeta_changi_testnet", "Defimeta Changi Testnet").testnet(),
+    NetworkInfo::new(1135, "lisk", "Lisk"),
+    NetworkInfo::new(1138, "amstar_testnet", "Amstar Testnet").testnet(),
+    NetworkInfo::new(1139, "math", "Math"),
+    NetworkInfo::new(1140, "math_testnet", "Math Testnet").testnet(),
+    NetworkInfo::new(1147, "flag_testnet", "Flag Testnet").testnet(),
+    NetworkInfo::new(1149, "symplexia", "Symplexia"),
+    NetworkInfo::new(1170, "origin_testnet", "Origin Testnet").testnet(),
+    NetworkInfo::new(1177, "smart_host_teknoloji_testnet", "Smart Host Teknoloji Testnet").testnet(),
+    NetworkInfo::new(1188, "clubmos", "Clubmos"),
+    NetworkInfo::new(1197, "iora", "Iora"),
+    NetworkInfo::new(1200, "cuckoo", "Cuckoo"),
+    NetworkInfo::new(1201, "evanesco_testnet", "Evanesco Testnet").testnet(),
+    NetworkInfo::new(1202, "world_trade_technical", "World Trade Technical"),
+    NetworkInfo::new(1209, "saitablocksbc", "Saitablocksbc"),
+    NetworkInfo::new(1210, "cuckoo_sepolia", "Cuckoo Sepolia").testnet(),
+    NetworkInfo::new(1213, "popcateum", "Popcateum"),
+    NetworkInfo::new(1214, "enter", "Enter"),
+    NetworkInfo::new(1221, "cycle_testnet", "Cycle Testnet").testnet(),
+    NetworkInfo::new(1223, "cycle_testnet_jellyfish", "Cycle Testnet Jellyfish").testnet(),
+    NetworkInfo::new(1225, "hybrid_testnet", "Hybrid Testnet").testnet(),
+    NetworkInfo::new(1227, "bitcoin_protocol_testnet", "Bitcoin Protocol Testnet").testnet(),
+    NetworkInfo::new(1228, "cycle_testnet_cuttlefish", "Cycle Testnet Cuttlefish").testnet(),
+    NetworkInfo::new(1229, "exzo", "Exzo"),
+    NetworkInfo::new(1230, "ultron_testnet", "Ultron Testnet").testnet(),
+    NetworkInfo::new(1231, "ultron", "Ultron"),
+    NetworkInfo::new(1234, "step", "Step"),
+    NetworkInfo::new(1235, "itx", "Itx"),
+    NetworkInfo::new(1243, "arc", "Arc"),
+    NetworkInfo::new(1244, "arc_testnet", "Arc Testnet").testnet(),
+    NetworkInfo::new(1246, "om_platform", "Om Platform"),
+    NetworkInfo::new(1248, "dogether", "Dogether"),
+    NetworkInfo::new(1252, "cic_testnet", "Cic Testnet").testnet(),
+    NetworkInfo::new(1260, "metacces_testnet", "Metacces Testnet").testnet(),
+    NetworkInfo::new(1280, "halo", "Halo"),
+    NetworkInfo::new(1284, "moonbeam", "Moonbeam"),
+    NetworkInfo::new(1285, "moonriver", "Moonriver"),
+    NetworkInfo::new(1286, "moonrock_old", "Moonrock Old"),
+    NetworkInfo::new(1287, "moonbase_alpha", "Moonbase Alpha"),
+    NetworkInfo::new(1288, "moonrock", "Moonrock"),
+    NetworkInfo::new(1291, "swisstronik_testnet", "Swisstronik Testnet").testnet(),
+    NetworkInfo::new(1294, "bobabeam", "Bobabeam"),
+    NetworkInfo::new(1297, "bobabase_testnet", "Bobabase Testnet").testnet(),
+    NetworkInfo::new(1311, "dos_fuji_subnet", "Dos Fuji Subnet").testnet(),
+    NetworkInfo::new(1313, "jaiho", "Jaiho"),
+    NetworkInfo::new(1314, "alyx", "Alyx"),
+    NetworkInfo::new(1319, "aia", "Aia"),
+    NetworkInfo::new(1320, "aia_testnet", "Aia Testnet").testnet(),
+    NetworkInfo::new(1328, "sei_testnet", "Sei Testnet").testnet(),
+    NetworkInfo::new(1329, "sei", "Sei"),
+    NetworkInfo::new(1337, "geth_testnet", "Geth Testnet").testnet(),
+    NetworkInfo::new(1338, "elysium_testnet", "Elysium Testnet").testnet(),
+    NetworkInfo::new(1339, "elysium", "Elysium"),
+    NetworkInfo::new(1343, "blitz_subnet", "Blitz Subnet"),
+    NetworkInfo::new(1353, "cic", "Cic"),
+    NetworkInfo::new(1369, "zafirium", "Zafirium"),
+    NetworkInfo::new(1370, "ramestta", "Ramestta"),
+    NetworkInfo::new(1377, "pingaksha_testnet", "Pingaksha Testnet").testnet(),
+    NetworkInfo::new(1379, "kalar", "Kalar"),
+    NetworkInfo::new(1388, "amstar", "Amstar"),
+    NetworkInfo::new(1392, "joseon", "Joseon"),
+    NetworkInfo::new(1402, "polygon_zkevm_testnet_old", "Polygon Zkevm Testnet Old").testnet(),
+    NetworkInfo::new(1422, "polygon_zkevm_testnet_pre_audit_upgraded", "Polygon Zkevm Testnet Pre Audit Upgraded").testnet(),
+    NetworkInfo::new(1433, "rikeza", "Rikeza"),
+    NetworkInfo::new(1440, "living_assets", "Living Assets"),
+    NetworkInfo::new(1442, "polygon_zkevm_testnet", "Polygon Zkevm Testnet").testnet(),
+    NetworkInfo::new(1452, "gil_testnet", "Gil Testnet").testnet(),
+    NetworkInfo::new(1453, "meta_istanbul", "Meta Istanbul"),
+    NetworkInfo::new(1455, "ctex_scan_block", "Ctex Scan Block"),
+    NetworkInfo::new(1456, "zkbase", "Zkbase"),
+    NetworkInfo::new(1490, "vitruveo", "Vitruveo"),
+    NetworkInfo::new(1499, "idos_games_testnet", "Idos Games Testnet").testnet(),
+    NetworkInfo::new(1501, "bevm_canary", "Bevm Canary"),
+    NetworkInfo::new(1506, "sherpax", "Sherpax"),
+    NetworkInfo::new(1507, "sherpax_testnet", "Sherpax Testnet").testnet(),
+    NetworkInfo::new(1513, "story_testnet", "Story Testnet").testnet(),
+    NetworkInfo::new(1515, "beagle_messaging", "Beagle Messaging"),
+    NetworkInfo::new(1559, "tenet", "Tenet"),
+    NetworkInfo::new(1570, "star_testnet", "Star Testnet").testnet(),
+    NetworkInfo::new(1578, "star", "Star"),
+    NetworkInfo::new(1617, "ethereum_inscription", "Ethereum Inscription"),
+    NetworkInfo::new(1618, "catecoin", "Catecoin"),
+    NetworkInfo::new(1620, "atheios", "Atheios"),
+    NetworkInfo::new(1625, "gravity_alpha", "Gravity Alpha"),
+    NetworkInfo::new(1648, "pivotal", "Pivotal"),
+    NetworkInfo::new(1657, "bta", "Bta"),
+    NetworkInfo::new(1662, "liqui", "Liqui"),
+    NetworkInfo::new(1663, "horizen_gobi_testnet", "Horizen Gobi Testnet").testnet(),
+    NetworkInfo::new(1686, "mint_testnet", "Mint Testnet").testnet(),
+    NetworkInfo::new(1687, "mint_sepolia_testnet", "Mint Sepolia Testnet").testnet(),
+    NetworkInfo::new(1688, "ludan", "Ludan"),
+    NetworkInfo::new(1701, "anytype_evm", "Anytype Evm"),
+    NetworkInfo::new(1707, "tbsi", "Tbsi"),
+    NetworkInfo::new(1708, "tbsi_testnet", "Tbsi Testnet").testnet(),
+    NetworkInfo::new(1717, "doric", "Doric"),
+    NetworkInfo::new(1718, "palette", "Palette"),
+    NetworkInfo::new(1729, "reya", "Reya"),
+    NetworkInfo::new(1740, "metal_l2_testnet", "Metal L2 Testnet").testnet(),
+    NetworkInfo::new(1750, "metal_l2", "Metal L2"),
+    NetworkInfo::new(1773, "party", "Party"),
+    NetworkInfo::new(1777, "gauss", "Gauss"),
+    NetworkInfo::new(1789, "zkbase_sepolia_testnet", "Zkbase Sepolia Testnet").testnet(),
+    NetworkInfo::new(1804, "kerleano", "Kerleano"),
+    NetworkInfo::new(1807, "rabbit_analog_testnet", "Rabbit Analog Testnet").testnet(),
+    NetworkInfo::new(1811, "lif3_testnet", "Lif3 Testnet").testnet(),
+    NetworkInfo::new(1818, "cube", "Cube"),
+    NetworkInfo::new(1819, "cube_testnet", "Cube Testnet").testnet(),
+    NetworkInfo::new(1821, "ruby", "Ruby"),
+    NetworkInfo::new(1853, "highoctane_subnet", "Highoctane Subnet"),
+    NetworkInfo::new(1856, "teslafunds", "Teslafunds"),
+    NetworkInfo::new(1875, "white", "White"),
+    NetworkInfo::new(1881, "gitshock_cartenz_testnet", "Gitshock Cartenz Testnet").testnet(),
+    NetworkInfo::new(1890, "lightlink_phoenix", "Lightlink Phoenix"),
+    NetworkInfo::new(1891, "lightlink_pegasus_testnet", "Lightlink Pegasus Testnet").testnet(),
+    NetworkInfo::new(1898, "bon", "Bon"),
+    NetworkInfo::new(1899, "redefi_layer_2", "Redefi Layer 2"),
+    NetworkInfo::new(1904, "sports", "Sports"),
+    NetworkInfo::new(1907, "bitci", "Bitci"),
+    NetworkInfo::new(1908, "bitci_testnet", "Bitci Testnet").testnet(),
+    NetworkInfo::new(1909, "merkle_scan", "Merkle Scan"),
+    NetworkInfo::new(1911, "scalind", "Scalind"),
+    NetworkInfo::new(1912, "ruby_testnet", "Ruby Testnet").testnet(),
+    NetworkInfo::new(1918, "upb_crescdi_testnet", "Upb Crescdi Testnet").testnet(),
+    NetworkInfo::new(1945, "onus_testnet", "Onus Testnet").testnet(),
+    NetworkInfo::new(1946, "minato", "Minato"),
+    NetworkInfo::new(1951, "d", "D"),
+    NetworkInfo::new(1953, "selendra_testnet", "Selendra Testnet").testnet(),
+    NetworkInfo::new(1954, "dexilla_testnet", "Dexilla Testnet").testnet(),
+    NetworkInfo::new(1956, "aiw3_testnet", "Aiw3 Testnet").testnet(),
+    NetworkInfo::new(1961, "selendra", "Selendra"),
+    NetworkInfo::new(1967, "eleanor", "Eleanor"),
+    NetworkInfo::new(1969, "super_testnet", "Super Testnet").testnet(),
+    NetworkInfo::new(1970, "super", "Super"),
+    NetworkInfo::new(1971, "atelier", "Atelier"),
+    NetworkInfo::new(1972, "redecoin", "Redecoin"),
+    NetworkInfo::new(1975, "onus", "Onus"),
+    NetworkInfo::new(1984, "eurus_testnet", "Eurus Testnet").testnet(),
+    NetworkInfo::new(1985, "satoshie", "Satoshie"),
+    NetworkInfo::new(1986, "satoshie_testnet", "Satoshie Testnet").testnet(),
+    NetworkInfo::new(1987, "ethergem", "Ethergem"),
+    NetworkInfo::new(1992, "hubble_exchange", "Hubble Exchange"),
+    NetworkInfo::new(1993, "b3_sepolia_testnet", "B3 Sepolia Testnet").testnet(),
+    NetworkInfo::new(1994, "ekta", "Ekta"),
+    NetworkInfo::new(1995, "edexa_testnet", "Edexa Testnet").testnet(),
+    NetworkInfo::new(1996, "sanko", "Sanko"),
+    NetworkInfo::new(1997, "kyoto", "Kyoto"),
+    NetworkInfo::new(1998, "kyoto_testnet", "Kyoto Testnet").testnet(),
+    NetworkInfo::new(2000, "doge", "Doge"),
+    NetworkInfo::new(2001, "milkomeda_c1", "Milkomeda C1"),
+    NetworkInfo::new(2002, "milkomeda_a1", "Milkomeda A1"),
+    NetworkInfo::new(2004, "metalink", "Metalink"),
+    NetworkInfo::new(2008, "cloudwalk_testnet", "Cloudwalk Testnet").testnet(),
+    NetworkInfo::new(2009, "cloudwalk", "Cloudwalk"),
+    NetworkInfo::new(2013, "panarchy", "Panarchy"),
+    NetworkInfo::new(2014, "now_testnet", "Now Testnet").testnet(),
+    NetworkInfo::new(2016, "mainnetz", "Mainnetz"),
+    NetworkInfo::new(2017, "adiri", "Adiri"),
+    NetworkInfo::new(2018, "publicmint_devnet", "Publicmint Devnet").testnet(),
+    NetworkInfo::new(2019, "publicmint_testnet", "Publicmint Testnet").testnet(),
+    NetworkInfo::new(2020, "publicmint", "Publicmint"),
+    NetworkInfo::new(2021, "edgeware_edgeevm", "Edgeware Edgeevm"),
+    NetworkInfo::new(2022, "beresheet_bereevm_testnet", "Beresheet Bereevm Testnet").testnet(),
+    NetworkInfo::new(2023, "taycan_testnet", "Taycan Testnet").testnet(),
+    NetworkInfo::new(2024, "swan_saturn_testnet", "Swan Saturn Testnet").testnet(),
+    NetworkInfo::new(2025, "rangers_protocol", "Rangers Protocol"),
+    NetworkInfo::new(2026, "edgeless", "Edgeless"),
+    NetworkInfo::new(2031, "centrifuge", "Centrifuge"),
+    NetworkInfo::new(2032, "catalyst", "Catalyst"),
+    NetworkInfo::new(2035, "phala", "Phala"),
+    NetworkInfo::new(2037, "kiwi_subnet", "Kiwi Subnet"),
+    NetworkInfo::new(2038, "shrapnel_testnet", "Shrapnel Testnet").testnet(),
+    NetworkInfo::new(2039, "aleph_zero", "Aleph Zero"),
+    NetworkInfo::new(2040, "vanar", "Vanar"),
+    NetworkInfo::new(2043, "neuroweb", "Neuroweb"),
+    NetworkInfo::new(2044, "shrapnel_subnet", "Shrapnel Subnet"),
+    NetworkInfo::new(2045, "aiw3", "Aiw3"),
+    NetworkInfo::new(2047, "stratos_testnet", "Stratos Testnet").testnet(),
+    NetworkInfo::new(2048, "stratos", "Stratos"),
+    NetworkInfo::new(2049, "movo", "Movo"),
+    NetworkInfo::new(2071, "metacces", "Metacces"),
+    NetworkInfo::new(2077, "quokkacoin", "Quokkacoin"),
+    NetworkInfo::new(2088, "altair", "Altair"),
+    NetworkInfo::new(2089, "algol", "Algol"),
+    NetworkInfo::new(2100, "ecoball", "Ecoball"),
+    NetworkInfo::new(2101, "ecoball_testnet_espuma", "Ecoball Testnet Espuma").testnet(),
+    NetworkInfo::new(2109, "exosama", "Exosama"),
+    NetworkInfo::new(2112, "u", "U"),
+    NetworkInfo::new(2121, "catena", "Catena"),
+    NetworkInfo::new(2122, "metaplayerone", "Metaplayerone"),
+    NetworkInfo::new(2124, "metaplayerone_dubai_testnet", "Metaplayerone Dubai Testnet").testnet(),
+    NetworkInfo::new(2136, "bigshortbets_testnet", "Bigshortbets Testnet").testnet(),
+    NetworkInfo::new(2137, "bigshortbets", "Bigshortbets"),
+    NetworkInfo::new(2138, "defi_oracle_meta_testnet", "Defi Oracle Meta Testnet").testnet(),
+    NetworkInfo::new(2140, "oneness", "Oneness"),
+    NetworkInfo::new(2141, "oneness_testnet", "Oneness Testnet").testnet(),
+    NetworkInfo::new(2151, "bosagora", "Bosagora"),
+    NetworkInfo::new(2152, "findora", "Findora"),
+    NetworkInfo::new(2153, "findora_testnet", "Findora Testnet").testnet(),
+    NetworkInfo::new(2154, "findora_forge", "Findora Forge"),
+    NetworkInfo::new(2162, "anime_testnet", "Anime Testnet").testnet(),
+    NetworkInfo::new(2192, "snax", "Snax"),
+    NetworkInfo::new(2199, "moonsama", "Moonsama"),
+    NetworkInfo::new(2202, "antofy", "Antofy"),
+    NetworkInfo::new(2203, "bitcoin_evm", "Bitcoin Evm"),
+    NetworkInfo::new(2213, "evanesco", "Evanesco"),
+    NetworkInfo::new(2221, "kava_testnet", "Kava Testnet").testnet(),
+    NetworkInfo::new(2222, "kava", "Kava"),
+    NetworkInfo::new(2223, "v", "V"),
+    NetworkInfo::new(2241, "krest", "Krest"),
+    NetworkInfo::new(2300, "bomb", "Bomb"),
+    NetworkInfo::new(2306, "ebro", "Ebro"),
+    NetworkInfo::new(2309, "arevia", "Arevia"),
+    NetworkInfo::new(2311, "chronicle_vesuvius_lit_protocol_testnet", "Chronicle Vesuvius Lit Protocol Testnet").testnet(),
+    NetworkInfo::new(2323, "soma_testnet", "Soma Testnet").testnet(),
+    NetworkInfo::new(2330, "altcoin", "Altcoin"),
+    NetworkInfo::new(2331, "rss3_vsl_sepolia_testnet", "Rss3 Vsl Sepolia Testnet").testnet(),
+    NetworkInfo::new(2332, "soma", "Soma"),
+    NetworkInfo::new(2340, "atleta_olympia", "Atleta Olympia"),
+    NetworkInfo::new(2342, "omnia", "Omnia"),
+    NetworkInfo::new(2355, "silicon_zkevm", "Silicon Zkevm"),
+    NetworkInfo::new(2358, "kroma_sepolia", "Kroma Sepolia").testnet(),
+    NetworkInfo::new(2370, "nexis_testnet", "Nexis Testnet").testnet(),
+    NetworkInfo::new(2399, "bomb_testnet", "Bomb Testnet").testnet(),
+    NetworkInfo::new(2400, "tcg_verse", "Tcg Verse"),
+    NetworkInfo::new(2410, "karak", "Karak"),
+    NetworkInfo::new(2415, "xodex", "Xodex"),
+    NetworkInfo::new(2425, "king_of_legends", "King Of Legends"),
+    NetworkInfo::new(2442, "polygon_zkevm_cardona_testnet", "Polygon Zkevm Cardona Testnet").testnet(),
+    NetworkInfo::new(2468, "hybrid", "Hybrid"),
+    NetworkInfo::new(2477, "6degree_of_outreach", "6degree Of Outreach"),
+    NetworkInfo::new(2484, "unicorn_ultra_nebulas_testnet", "Unicorn Ultra Nebulas Testnet").testnet(),
+    NetworkInfo::new(2511, "karak_goerli", "Karak Goerli").testnet(),
+    NetworkInfo::new(2522, "fraxtal_testnet", "Fraxtal Testnet").testnet(),
+    NetworkInfo::new(2525, "inevm", "Inevm"),
+    NetworkInfo::new(2559, "kortho", "Kortho"),
+    NetworkInfo::new(2569, "techpay", "Techpay"),
+    NetworkInfo::new(2606, "pocrnet", "Pocrnet"),
+    NetworkInfo::new(2611, "redlight", "Redlight"),
+    NetworkInfo::new(2612, "ez_c", "Ez C"),
+    NetworkInfo::new(2613, "ez_c_testnet", "Ez C Testnet").testnet(),
+    NetworkInfo::new(2625, "white_testnet", "White Testnet").testnet(),
+    NetworkInfo::new(2648, "ailayer_testnet", "Ailayer Testnet").testnet(),
+    NetworkInfo::new(2649, "ailayer", "Ailayer"),
+    NetworkInfo::new(2662, "apex", "Apex"),
+    NetworkInfo::new(2710, "morph_testnet", "Morph Testnet").testnet(),
+    NetworkInfo::new(2718, "k_laos", "K Laos"),
+    NetworkInfo::new(2730, "xr_sepolia", "Xr Sepolia").testnet(),
+    NetworkInfo::new(2731, "elizabeth_testnet", "Elizabeth Testnet").testnet(),
+    NetworkInfo::new(2748, "nanon", "Nanon"),
+    NetworkInfo::new(2777, "gm", "Gm"),
+    NetworkInfo::new(2810, "morph_holesky", "Morph Holesky").testnet(),
+    NetworkInfo::new(2868, "hyperagi", "Hyperagi"),
+    NetworkInfo::new(2882, "chips", "Chips"),
+    NetworkInfo::new(2888, "boba_goerli_testnet", "Boba Goerli Testnet").testnet(),
+    NetworkInfo::new(2889, "aarma", "Aarma"),
+    NetworkInfo::new(2907, "elux", "Elux"),
+    NetworkInfo::new(2911, "hy", "Hy"),
+    NetworkInfo::new(2941, "xenon_testnet", "Xenon Testnet").testnet(),
+    NetworkInfo::new(2999, "bityuan", "Bityuan"),
+    NetworkInfo::new(3000, "cennznet_rata", "Cennznet Rata"),
+    NetworkInfo::new(3001, "cennznet_nikau", "Cennznet Nikau"),
+    NetworkInfo::new(3003, "canxium", "Canxium"),
+    NetworkInfo::new(3011, "playa3ull_games", "Playa3ull Games"),
+    NetworkInfo::new(3031, "orlando", "Orlando"),
+    NetworkInfo::new(3033, "rebus_testnet", "Rebus Testnet").testnet(),
+    NetworkInfo::new(3068, "bifrost", "Bifrost"),
+    NetworkInfo::new(3073, "movement_evm", "Movement Evm"),
+    NetworkInfo::new(3084, "xl_testnet", "Xl Testnet").testnet(),
+    NetworkInfo::new(3100, "immu3_evm", "Immu3 Evm"),
+    NetworkInfo::new(3102, "vulture_evm_beta", "Vulture Evm Beta"),
+    NetworkInfo::new(3109, "satoshivm_alpha", "Satoshivm Alpha"),
+    NetworkInfo::new(3110, "satoshivm_testnet", "Satoshivm Testnet").testnet(),
+    NetworkInfo::new(3141, "filecoin_hyperspace_testnet", "Filecoin Hyperspace Testnet").testnet(),
+    NetworkInfo::new(3269, "dubxcoin", "Dubxcoin"),
+    NetworkInfo::new(3270, "dubxcoin_testnet", "Dubxcoin Testnet").testnet(),
+    NetworkInfo::new(3306, "debounce_subnet_testnet", "Debounce Subnet Testnet").testnet(),
+    NetworkInfo::new(3331, "zcore_testnet", "Zcore Testnet").testnet(),
+    NetworkInfo::new(3333, "ethstorage_testnet", "Ethstorage Testnet").testnet(),
+    NetworkInfo::new(3334, "web3q_galileo", "Web3q Galileo"),
+    NetworkInfo::new(3335, "ethstorage", "Ethstorage"),
+    NetworkInfo::new(3338, "peaq", "Peaq"),
+    NetworkInfo::new(3400, "paribu_net", "Paribu Net"),
+    NetworkInfo::new(3424, "evolve", "Evolve"),
+    NetworkInfo::new(3434, "secure_testnet", "Secure Testnet").testnet(),
+    NetworkInfo::new(3456, "layeredge_testnet", "Layeredge Testnet").testnet(),
+    NetworkInfo::new(3490, "gtcscan", "Gtcscan"),
+    NetworkInfo::new(3500, "paribu_net_testnet", "Paribu Net Testnet").testnet(),
+    NetworkInfo::new(3501, "jfin", "Jfin"),
+    NetworkInfo::new(3601, "pandoproject", "Pandoproject"),
+    NetworkInfo::new(3602, "pandoproject_testnet", "Pandoproject Testnet").testnet(),
+    NetworkInfo::new(3630, "tycooncoin", "Tycooncoin"),
+    NetworkInfo::new(3636, "botanix_testnet", "Botanix Testnet").testnet(),
+    NetworkInfo::new(3637, "botanix", "Botanix"),
+    NetworkInfo::new(3639, "i", "I"),
+    NetworkInfo::new(3645, "i_testnet", "I Testnet").testnet(),
+    NetworkInfo::new(3666, "jouleverse", "Jouleverse"),
+    NetworkInfo::new(3690, "bittex", "Bittex"),
+    NetworkInfo::new(3693, "empire", "Empire"),
+    NetworkInfo::new(3698, "senjepowers_testnet", "Senjepowers Testnet").testnet(),
+    NetworkInfo::new(3699, "senjepowers", "Senjepowers"),
+    NetworkInfo::new(3701, "xpla_testnet", "Xpla Testnet").testnet(),
+    NetworkInfo::new(3737, "crossbell", "Crossbell"),
+    NetworkInfo::new(3776, "astar_zkevm", "Astar Zkevm"),
+    NetworkInfo::new(3797, "alvey", "Alvey"),
+    NetworkInfo::new(3799, "tangle_testnet", "Tangle Testnet").testnet(),
+    NetworkInfo::new(3885, "fire_zkevm_ghostrider", "Fire Zkevm Ghostrider"),
+    NetworkInfo::new(3888, "kaly", "Kaly"),
+    NetworkInfo::new(3889, "kaly_testnet", "Kaly Testnet").testnet(),
+    NetworkInfo::new(3912, "drac", "Drac"),
+    NetworkInfo::new(3939, "dos_tesnet", "Dos Tesnet"),
+    NetworkInfo::new(3966, "dyno", "Dyno"),
+    NetworkInfo::new(3967, "dyno_testnet", "Dyno Testnet").testnet(),
+    NetworkInfo::new(3993, "apex_testnet", "Apex Testnet").testnet(),
+    NetworkInfo::new(3999, "yuan", "Yuan"),
+    NetworkInfo::new(4000, "ozone", "Ozone"),
+    NetworkInfo::new(4001, "peperium_testnet", "Peperium Testnet").testnet(),
+    NetworkInfo::new(4002, "fantom_testnet", "Fantom Testnet").testnet(),
+    NetworkInfo::new(4003, "x1_fastnet", "X1 Fastnet"),
+    NetworkInfo::new(4040, "carbonium_testnet", "Carbonium Testnet").testnet(),
+    NetworkInfo::new(4048, "gan_testnet", "Gan Testnet").testnet(),
+    NetworkInfo::new(4051, "bobaopera_testnet", "Bobaopera Testnet").testnet(),
+    NetworkInfo::new(4058, "bahamut_ocean", "Bahamut Ocean"),
+    NetworkInfo::new(4061, "nahmii_3", "Nahmii 3"),
+    NetworkInfo::new(4062, "nahmii_3_testnet", "Nahmii 3 Testnet").testnet(),
+    NetworkInfo::new(4078, "muster", "Muster"),
+    NetworkInfo::new(4080, "tobe_testnet", "Tobe Testnet").testnet(),
+    NetworkInfo::new(4088, "zeroth", "Zeroth"),
+    NetworkInfo::new(4090, "fastex_bahamut_oasis_testnet", "Fastex Bahamut Oasis Testnet").testnet(),
+    NetworkInfo::new(4096, "bitindi_testnet", "Bitindi Testnet").testnet(),
+    NetworkInfo::new(4099, "bitindi", "Bitindi"),
+    NetworkInfo::new(4102, "aioz_testnet", "Aioz Testnet").testnet(),
+    NetworkInfo::new(4139, "humans.ai_testnet", "Humans.ai Testnet").testnet(),
+    NetworkInfo::new(4141, "tipboxcoin_testnet", "Tipboxcoin Testnet").testnet(),
+    NetworkInfo::new(4157, "crossfi_testnet", "Crossfi Testnet").testnet(),
+    NetworkInfo::new(4162, "sx_rollup", "Sx Rollup"),
+    NetworkInfo::new(4181, "phi_v1", "Phi V1"),
+    NetworkInfo::new(4200, "merlin", "Merlin"),
+    NetworkInfo::new(4201, "lukso_testnet", "Lukso Testnet").testnet(),
+    NetworkInfo::new(4202, "lisk_sepolia_testnet", "Lisk Sepolia Testnet").testnet(),
+    NetworkInfo::new(4242, "nexi", "Nexi"),
+    NetworkInfo::new(4243, "nexi_v2", "Nexi V2"),
+    NetworkInfo::new(4269, "laika_testnet", "Laika Testnet").testnet(),
+    NetworkInfo::new(4328, "bobafuji_testnet", "Bobafuji Testnet").testnet(),
+    NetworkInfo::new(4337, "beam", "Beam"),
+    NetworkInfo::new(4400, "credit", "Credit"),
+    NetworkInfo::new(4444, "htmlcoin", "Htmlcoin"),
+    NetworkInfo::new(4460, "orderly_sepolia_testnet", "Orderly Sepolia Testnet").testnet(),
+    NetworkInfo::new(4488, "hydra", "Hydra"),
+    NetworkInfo::new(4544, "emoney_testnet", "Emoney Testnet").testnet(),
+    NetworkInfo::new(4613, "very", "Very"),
+    NetworkInfo::new(4653, "gold", "Gold"),
+    NetworkInfo::new(4689, "iotex", "Iotex"),
+    NetworkInfo::new(4690, "iotex_testnet", "Iotex Testnet").testnet(),
+    NetworkInfo::new(4759, "meverse_testnet", "Meverse Testnet").testnet(),
+    NetworkInfo::new(4777, "blackfort_exchange_testnet", "Blackfort Exchange Testnet").testnet(),
+    NetworkInfo::new(4801, "world_sepolia_testnet", "World Sepolia Testnet").testnet(),
+    NetworkInfo::new(4893, "globel", "Globel"),
+    NetworkInfo::new(4918, "venidium_testnet", "Venidium Testnet").testnet(),
+    NetworkInfo::new(4919, "venidium", "Venidium"),
+    NetworkInfo::new(4999, "blackfort_exchange", "Blackfort Exchange"),
+    NetworkInfo::new(5000, "mantle", "Mantle").parent(1).currency("MNT").explorer("https://explorer.mantle.xyz"),
+    NetworkInfo::new(5001, "mantle_testnet", "Mantle Testnet").testnet(),
+    NetworkInfo::new(5002, "treasurenet_alpha", "Treasurenet Alpha"),
+    NetworkInfo::new(5003, "mantle_sepolia_testnet", "Mantle Sepolia Testnet").testnet(),
+    NetworkInfo::new(5005, "treasurenet_testnet", "Treasurenet Testnet").testnet(),
+    NetworkInfo::new(5039, "onigiri_test_subnet", "Onigiri Test Subnet").testnet(),
+    NetworkInfo::new(5040, "onigiri_subnet", "Onigiri Subnet"),
+    NetworkInfo::new(5051, "nollie_skate_testnet", "Nollie Skate Testnet").testnet(),
+    NetworkInfo::new(5100, "syndicate_testnet", "Syndicate Testnet").testnet(),
+    NetworkInfo::new(5101, "syndicate_frame", "Syndicate Frame"),
+    NetworkInfo::new(5102, "sic_testnet", "Sic Testnet").testnet(),
+    NetworkInfo::new(5103, "coordinape_testnet", "Coordinape Testnet").testnet(),
+    NetworkInfo::new(5104, "charmverse_testnet", "Charmverse Testnet").testnet(),
+    NetworkInfo::new(5105, "superloyalty_testnet", "Superloyalty Testnet").testnet(),
+    NetworkInfo::new(5106, "azra_testnet", "Azra Testnet").testnet(),
+    NetworkInfo::new(5112, "ham", "Ham"),
+    NetworkInfo::new(5151, "me_testnet", "Me Testnet").testnet(),
+    NetworkInfo::new(5165, "bahamut", "Bahamut"),
+    NetworkInfo::new(5169, "smart_layer", "Smart Layer"),
+    NetworkInfo::new(5177, "tl", "Tl"),
+    NetworkInfo::new(5197, "eraswap", "Eraswap"),
+    NetworkInfo::new(5234, "humanode", "Humanode"),
+    NetworkInfo::new(5290, "fire_old", "Fire Old"),
+    NetworkInfo::new(5315, "uzmi", "Uzmi"),
+    NetworkInfo::new(5317, "optrust_testnet", "Optrust Testnet").testnet(),
+    NetworkInfo::new(5321, "itx_testnet", "Itx Testnet").testnet(),
+    NetworkInfo::new(5333, "netsbo", "Netsbo"),
+    NetworkInfo::new(5353, "tritanium_testnet", "Tritanium Testnet").testnet(),
+    NetworkInfo::new(5372, "settlus_testnet", "Settlus Testnet").testnet(),
+    NetworkInfo::new(5424, "edexa", "Edexa"),
+    NetworkInfo::new(5439, "ego", "Ego"),
+    NetworkInfo::new(5522, "vex_evm_testnet", "Vex Evm Testnet").testnet(),
+    NetworkInfo::new(5545, "duck", "Duck"),
+    NetworkInfo::new(5551, "nahmii_2", "Nahmii 2"),
+    NetworkInfo::new(5553, "nahmii_2_testnet", "Nahmii 2 Testnet").testnet(),
+    NetworkInfo::new(5555, "verse", "Verse"),
+    NetworkInfo::new(5611, "opbnb_testnet", "Opbnb Testnet").testnet(),
+    NetworkInfo::new(5615, "arcturus_testneet", "Arcturus Testneet").testnet(),
+    NetworkInfo::new(5616, "arcturus_testnet", "Arcturus Testnet").testnet(),
+    NetworkInfo::new(5656, "qie_block", "Qie Block"),
+    NetworkInfo::new(5675, "filenova_testnet", "Filenova Testnet").testnet(),
+    NetworkInfo::new(5678, "tanssi_demo", "Tanssi Demo"),
+    NetworkInfo::new(5700, "syscoin_tanenbaum_testnet", "Syscoin Tanenbaum Testnet").testnet(),
+    NetworkInfo::new(5729, "hika_testnet", "Hika Testnet").testnet(),
+    NetworkInfo::new(5758, "satoshi_testnet", "Satoshi Testnet").testnet(),
+    NetworkInfo::new(5777, "ganache", "Ganache"),
+    NetworkInfo::new(5845, "tangle", "Tangle"),
+    NetworkInfo::new(5851, "ontology_testnet", "Ontology Testnet").testnet(),
+    NetworkInfo::new(5858, "chang_foundation", "Chang Foundation"),
+    NetworkInfo::new(5869, "wego_rubidium", "Wego Rubidium"),
+    NetworkInfo::new(6000, "bouncebit_testnet", "Bouncebit Testnet").testnet(),
+    NetworkInfo::new(6001, "bouncebit", "Bouncebit"),
+    NetworkInfo::new(6065, "tres_testnet", "Tres Testnet").testnet(),
+    NetworkInfo::new(6066, "tres", "Tres"),
+    NetworkInfo::new(6102, "cascadia_testnet", "Cascadia Testnet").testnet(),
+    NetworkInfo::new(6118, "uptn_testnet", "Uptn Testnet").testnet(),
+    NetworkInfo::new(6119, "uptn", "Uptn"),
+    NetworkInfo::new(6283, "laos", "Laos"),
+    NetworkInfo::new(6321, "aura_euphoria_testnet", "Aura Euphoria Testnet").testnet(),
+    NetworkInfo::new(6322, "aura", "Aura"),
+    NetworkInfo::new(6363, "digit_soul", "Digit Soul"),
+    NetworkInfo::new(6398, "connext_sepolia", "Connext Sepolia").testnet(),
+    NetworkInfo::new(6502, "peerpay", "Peerpay"),
+    NetworkInfo::new(6550, "flamma_testnet", "Flamma Testnet").testnet(),
+    NetworkInfo::new(6552, "scolcoin_wei_testnet", "Scolcoin Wei Testnet").testnet(),
+    NetworkInfo::new(6565, "fox_testnet", "Fox Testnet").testnet(),
+    NetworkInfo::new(6626, "pixie", "Pixie"),
+    NetworkInfo::new(6660, "latest_testnet", "Latest Testnet").testnet(),
+    NetworkInfo::new(6661, "cybria", "Cybria"),
+    NetworkInfo::new(6666, "cybria_testnet", "Cybria Testnet").testnet(),
+    NetworkInfo::new(6667, "stor", "Stor"),
+    NetworkInfo::new(6678, "edge_matrix", "Edge Matrix"),
+    NetworkInfo::new(6688, "irishub", "Irishub"),
+    NetworkInfo::new(6699, "ox", "Ox"),
+    NetworkInfo::new(6701, "paxb", "Paxb"),
+    NetworkInfo::new(6779, "compverse", "Compverse"),
+    NetworkInfo::new(6805, "race", "Race"),
+    NetworkInfo::new(6806, "race_testnet", "Race Testnet").testnet(),
+    NetworkInfo::new(6868, "pools", "Pools"),
+    NetworkInfo::new(6880, "mtt", "Mtt"),
+    NetworkInfo::new(6942, "laika", "Laika"),
+    NetworkInfo::new(6969, "tomb", "Tomb"),
+    NetworkInfo::new(6999, "polysmart", "Polysmart"),
+    NetworkInfo::new(7000, "zeta", "Zeta"),
+    NetworkInfo::new(7001, "zeta_testnet", "Zeta Testnet").testnet(),
+    NetworkInfo::new(7007, "bst", "Bst"),
+    NetworkInfo::new(7027, "ella_the_heart", "Ella The Heart"),
+    NetworkInfo::new(7070, "planq", "Planq"),
+    NetworkInfo::new(7077, "planq_atlas_testnet", "Planq Atlas Testnet").testnet(),
+    NetworkInfo::new(7100, "nume", "Nume"),
+    NetworkInfo::new(7118, "help_the_homeless", "Help The Homeless"),
+    NetworkInfo::new(7171, "bitrock", "Bitrock"),
+    NetworkInfo::new(7210, "nibiru_testnet_1", "Nibiru Testnet 1").testnet(),
+    NetworkInfo::new(7222, "nibiru_devnet_3", "Nibiru Devnet 3").testnet(),
+    NetworkInfo::new(7244, "zeus_testnet", "Zeus Testnet").testnet(),
+    NetworkInfo::new(7300, "xpla_verse", "Xpla Verse"),
+    NetworkInfo::new(7331, "klyntar", "Klyntar"),
+    NetworkInfo::new(7332, "horizen_eon", "Horizen Eon"),
+    NetworkInfo::new(7341, "shyft", "Shyft"),
+    NetworkInfo::new(7484, "raba", "Raba"),
+    NetworkInfo::new(7518, "meverse", "Meverse"),
+    NetworkInfo::new(7560, "cyber", "Cyber"),
+    NetworkInfo::new(7575, "adil_testnet", "Adil Testnet").testnet(),
+    NetworkInfo::new(7576, "adil_v2", "Adil V2"),
+    NetworkInfo::new(7668, "the_root", "The Root"),
+    NetworkInfo::new(7672, "the_root_porcini_testnet", "The Root Porcini Testnet").testnet(),
+    NetworkInfo::new(7700, "canto", "Canto"),
+    NetworkInfo::new(7701, "canto_tesnet", "Canto Tesnet"),
+    NetworkInfo::new(7771, "bitrock_testnet", "Bitrock Testnet").testnet(),
+    NetworkInfo::new(7774, "gdcc", "Gdcc"),
+    NetworkInfo::new(7775, "gdcc_testnet", "Gdcc Testnet").testnet(),
+    NetworkInfo::new(7776, "pandasea", "Pandasea"),
+    NetworkInfo::new(7777, "rise_of_the_warbots_testnet", "Rise Of The Warbots Testnet").testnet(),
+    NetworkInfo::new(7778, "orenium_protocol", "Orenium Protocol"),
+    NetworkInfo::new(7798, "openex_long_testnet", "Openex Long Testnet").testnet(),
+    NetworkInfo::new(7860, "maal_testnet", "Maal Testnet").testnet(),
+    NetworkInfo::new(7862, "maal_v2", "Maal V2"),
+    NetworkInfo::new(7863, "maal_testnet_v2", "Maal Testnet V2").testnet(),
+    NetworkInfo::new(7878, "hazlor_testnet", "Hazlor Testnet").testnet(),
+    NetworkInfo::new(7887, "kinto", "Kinto"),
+    NetworkInfo::new(7895, "ardenium_athena", "Ardenium Athena"),
+    NetworkInfo::new(7923, "dot_blox", "Dot Blox"),
+    NetworkInfo::new(7924, "mo", "Mo"),
+    NetworkInfo::new(7979, "dos", "Dos"),
+    NetworkInfo::new(8000, "teleport", "Teleport"),
+    NetworkInfo::new(8001, "teleport_testnet", "Teleport Testnet").testnet(),
+    NetworkInfo::new(8008, "polynomial", "Polynomial"),
+    NetworkInfo::new(8017, "isuncoin", "Isuncoin"),
+    NetworkInfo::new(8029, "mdgl_testnet", "Mdgl Testnet").testnet(),
+    NetworkInfo::new(8047, "boat", "Boat"),
+    NetworkInfo::new(8054, "karak_sepolia", "Karak Sepolia").testnet(),
+    NetworkInfo::new(8080, "shardeum_liberty_1.x", "Shardeum Liberty 1.x"),
+    NetworkInfo::new(8081, "shardeum_liberty_2.x", "Shardeum Liberty 2.x"),
+    NetworkInfo::new(8082, "shardeum_sphinx_1.x", "Shardeum Sphinx 1.x"),
+    NetworkInfo::new(8086, "bitcoin", "Bitcoin"),
+    NetworkInfo::new(8087, "e_dollar", "E Dollar"),
+    NetworkInfo::new(8098, "streamux_block", "Streamux Block"),
+    NetworkInfo::new(8131, "qitmeer_testnet", "Qitmeer Testnet").testnet(),
+    NetworkInfo::new(8132, "qitmeer_mixnet", "Qitmeer Mixnet"),
+    NetworkInfo::new(8133, "qitmeer_privnet", "Qitmeer Privnet"),
+    NetworkInfo::new(8134, "amana", "Amana"),
+    NetworkInfo::new(8135, "flana", "Flana"),
+    NetworkInfo::new(8136, "mizana", "Mizana"),
+    NetworkInfo::new(8181, "testnet_beone", "Testnet Beone").testnet(),
+    NetworkInfo::new(8192, "torus", "Torus"),
+    NetworkInfo::new(8194, "torus_testnet", "Torus Testnet").testnet(),
+    NetworkInfo::new(8217, "kaia", "Kaia"),
+    NetworkInfo::new(8227, "space_subnet", "Space Subnet"),
+    NetworkInfo::new(8272, "blockton_block", "Blockton Block"),
+    NetworkInfo::new(8285, "korthotest", "Korthotest").testnet(),
+    NetworkInfo::new(8329, "lorenzo", "Lorenzo"),
+    NetworkInfo::new(8333, "b3", "B3"),
+    NetworkInfo::new(8387, "dracones_financial_services", "Dracones Financial Services"),
+    NetworkInfo::new(8428, "that", "That"),
+    NetworkInfo::new(8453, "base", "Base").aliases(&["base_mainnet"]).parent(1).currency("ETH").explorer("https://basescan.org"),
+    NetworkInfo::new(8545, "chakra_testnet", "Chakra Testnet").testnet(),
+    NetworkInfo::new(8654, "toki", "Toki"),
+    NetworkInfo::new(8655, "toki_testnet", "Toki Testnet").testnet(),
+    NetworkInfo::new(8668, "hela_official_runtime", "Hela Official Runtime"),
+    NetworkInfo::new(8723, "tool_global", "Tool Global"),
+    NetworkInfo::new(8724, "tool_global_testnet", "Tool Global Testnet").testnet(),
+    NetworkInfo::new(8726, "storage", "Storage"),
+    NetworkInfo::new(8727, "storage_testnet", "Storage Testnet").testnet(),
+    NetworkInfo::new(8732, "bullions", "Bullions"),
+    NetworkInfo::new(8738, "alph", "Alph"),
+    NetworkInfo::new(8768, "tmy", "Tmy"),
+    NetworkInfo::new(8822, "iota_evm", "Iota Evm"),
+    NetworkInfo::new(8844, "hydra_testnet", "Hydra Testnet").testnet(),
+    NetworkInfo::new(8848, "maro_block", "Maro Block"),
+    NetworkInfo::new(8866, "superlumio", "Superlumio"),
+    NetworkInfo::new(8869, "lif3", "Lif3"),
+    NetworkInfo::new(8880, "unique", "Unique"),
+    NetworkInfo::new(8881, "quartz_by_unique", "Quartz By Unique"),
+    NetworkInfo::new(8882, "opal_testnet_by_unique", "Opal Testnet By Unique").testnet(),
+    NetworkInfo::new(8883, "sapphire_by_unique", "Sapphire By Unique"),
+    NetworkInfo::new(8886, "avenium_testnet", "Avenium Testnet").testnet(),
+    NetworkInfo::new(8888, "xana", "Xana"),
+    NetworkInfo::new(8889, "vyvo", "Vyvo"),
+    NetworkInfo::new(8890, "orenium_testnet_protocol", "Orenium Testnet Protocol").testnet(),
+    NetworkInfo::new(8898, "mammoth", "Mammoth"),
+    NetworkInfo::new(8899, "jib", "Jib"),
+    NetworkInfo::new(8911, "algen", "Algen"),
+    NetworkInfo::new(8912, "algen_testnet", "Algen Testnet").testnet(),
+    NetworkInfo::new(8921, "algen_layer2", "Algen Layer2"),
+    NetworkInfo::new(8922, "algen_layer2_testnet", "Algen Layer2 Testnet").testnet(),
+    NetworkInfo::new(8989, "giant_mammoth", "Giant Mammoth"),
+    NetworkInfo::new(8995, "bloxberg", "Bloxberg"),
+    NetworkInfo::new(9000, "evmos_testnet", "Evmos Testnet").testnet(),
+    NetworkInfo::new(9001, "evmos", "Evmos"),
+    NetworkInfo::new(9007, "shido_testnet_block", "Shido Testnet Block").testnet(),
+    NetworkInfo::new(9008, "shido_block", "Shido Block"),
+    NetworkInfo::new(9012, "berylbit", "Berylbit"),
+    NetworkInfo::new(9024, "nexa_testnet_block", "Nexa Testnet Block").testnet(),
+    NetworkInfo::new(9025, "nexa_block", "Nexa Block"),
+    NetworkInfo::new(9069, "apex_fusion_nexus", "Apex Fusion Nexus"),
+    NetworkInfo::new(9070, "apex_fusion_nexus_testnet", "Apex Fusion Nexus Testnet").testnet(),
+    NetworkInfo::new(9100, "genesis_coin", "Genesis Coin"),
+    NetworkInfo::new(9170, "rinia_testnet_old", "Rinia Testnet Old").testnet(),
+    NetworkInfo::new(9223, "codefin", "Codefin"),
+    NetworkInfo::new(9339, "dogcoin_testnet", "Dogcoin Testnet").testnet(),
+    NetworkInfo::new(9372, "oasys_testnet", "Oasys Testnet").testnet(),
+    NetworkInfo::new(9393, "dela_sepolia_testnet", "Dela Sepolia Testnet").testnet(),
+    NetworkInfo::new(9395, "evoke", "Evoke"),
+    NetworkInfo::new(9496, "weavevm_testnet", "Weavevm Testnet").testnet(),
+    NetworkInfo::new(9527, "rangers_protocol_testnet_robin", "Rangers Protocol Testnet Robin").testnet(),
+    NetworkInfo::new(9528, "qeasyweb3_testnet", "Qeasyweb3 Testnet").testnet(),
+    NetworkInfo::new(9559, "neonlink_testnet", "Neonlink Testnet").testnet(),
+    NetworkInfo::new(9700, "oortdev", "Oortdev"),
+    NetworkInfo::new(9728, "boba_bnb_testnet", "Boba Bnb Testnet").testnet(),
+    NetworkInfo::new(9768, "mainnetz_testnet", "Mainnetz Testnet").testnet(),
+    NetworkInfo::new(9779, "pepenetwork", "Pepenetwork"),
+    NetworkInfo::new(9789, "tabi_testnet", "Tabi Testnet").testnet(),
+    NetworkInfo::new(9790, "carbon_evm", "Carbon Evm"),
+    NetworkInfo::new(9792, "carbon_evm_testnet", "Carbon Evm Testnet").testnet(),
+    NetworkInfo::new(9797, "optimusz7", "Optimusz7"),
+    NetworkInfo::new(9818, "imperium_testnet", "Imperium Testnet").testnet(),
+    NetworkInfo::new(9819, "imperium", "Imperium"),
+    NetworkInfo::new(9876, "binary_testnet", "Binary Testnet").testnet(),
+    NetworkInfo::new(9888, "dogelayer", "Dogelayer"),
+    NetworkInfo::new(9897, "arena_z_testnet", "Arena Z Testnet").testnet(),
+    NetworkInfo::new(9898, "larissa", "Larissa"),
+    NetworkInfo::new(9911, "espento", "Espento"),
+    NetworkInfo::new(9966, "uxer_testnet", "Uxer Testnet").testnet(),
+    NetworkInfo::new(9977, "mind_testnet", "Mind Testnet").testnet(),
+    NetworkInfo::new(9980, "combo", "Combo"),
+    NetworkInfo::new(9981, "volley", "Volley"),
+    NetworkInfo::new(9990, "agung", "Agung"),
+    NetworkInfo::new(9997, "altlayer_testnet", "Altlayer Testnet").testnet(),
+    NetworkInfo::new(9998, "ztc", "Ztc"),
+    NetworkInfo::new(9999, "myown_testnet", "Myown Testnet").testnet(),
+    NetworkInfo::new(10000, "smart_bitcoin_cash", "Smart Bitcoin Cash"),
+    NetworkInfo::new(10001, "smart_bitcoin_cash_testnet", "Smart Bitcoin Cash Testnet").testnet(),
+    NetworkInfo::new(10024, "gon", "Gon"),
+    NetworkInfo::new(10081, "japan_open_testnet", "Japan Open Testnet").testnet(),
+    NetworkInfo::new(10086, "sjatsh", "Sjatsh"),
+    NetworkInfo::new(10096, "metanova_verse", "Metanova Verse"),
+    NetworkInfo::new(10101, "block_genesis", "Block Genesis"),
+    NetworkInfo::new(10200, "gnosis_chiado_testnet", "Gnosis Chiado Testnet").testnet(),
+    NetworkInfo::new(10201, "maxx", "Maxx"),
+    NetworkInfo::new(10222, "glscan", "Glscan"),
+    NetworkInfo::new(10242, "arthera", "Arthera"),
+    NetworkInfo::new(10243, "arthera_testnet", "Arthera Testnet").testnet(),
+    NetworkInfo::new(10248, "0xtade", "0xtade"),
+    NetworkInfo::new(10321, "tao_evm", "Tao Evm"),
+    NetworkInfo::new(10324, "tao_evm_testnet", "Tao Evm Testnet").testnet(),
+    NetworkInfo::new(10395, "worldland_testnet", "Worldland Testnet").testnet(),
+    NetworkInfo::new(10507, "numbers", "Numbers"),
+    NetworkInfo::new(10508, "numbers_testnet", "Numbers Testnet").testnet(),
+    NetworkInfo::new(10823, "cryptocoinpay", "Cryptocoinpay"),
+    NetworkInfo::new(10849, "lamina1", "Lamina1"),
+    NetworkInfo::new(10850, "lamina1_identity", "Lamina1 Identity"),
+    NetworkInfo::new(10946, "quadrans_block", "Quadrans Block"),
+    NetworkInfo::new(10947, "quadrans_block_testnet", "Quadrans Block Testnet").testnet(),
+    NetworkInfo::new(11000, "kb", "Kb"),
+    NetworkInfo::new(11011, "shape_sepolia_testnet", "Shape Sepolia Testnet").testnet(),
+    NetworkInfo::new(11110, "astra", "Astra"),
+    NetworkInfo::new(11111, "wagmi", "Wagmi"),
+    NetworkInfo::new(11115, "astra_testnet", "Astra Testnet").testnet(),
+    NetworkInfo::new(11119, "hashbit", "Hashbit"),
+    NetworkInfo::new(11124, "abstract_testnet", "Abstract Testnet").testnet(),
+    NetworkInfo::new(11221, "shine", "Shine"),
+    NetworkInfo::new(11227, "jiritsu_testnet_subnet", "Jiritsu Testnet Subnet").testnet(),
+    NetworkInfo::new(11235, "haqq", "Haqq"),
+    NetworkInfo::new(11437, "shyft_testnet", "Shyft Testnet").testnet(),
+    NetworkInfo::new(11501, "bevm", "Bevm"),
+    NetworkInfo::new(11503, "bevm_testnet", "Bevm Testnet").testnet(),
+    NetworkInfo::new(11521, "sats", "Sats"),
+    NetworkInfo::new(11612, "sardis_testnet", "Sardis Testnet").testnet(),
+    NetworkInfo::new(11822, "artela_testnet", "Artela Testnet").testnet(),
+    NetworkInfo::new(11891, "polygon_supernet_arianee", "Polygon Supernet Arianee"),
+    NetworkInfo::new(12001, "fuse_testnet", "Fuse Testnet").testnet(),
+    NetworkInfo::new(12009, "satoshi", "Satoshi"),
+    NetworkInfo::new(12020, "aternos", "Aternos"),
+    NetworkInfo::new(12051, "singularity_zero_testnet", "Singularity Zero Testnet").testnet(),
+    NetworkInfo::new(12052, "singularity_zero", "Singularity Zero"),
+    NetworkInfo::new(12123, "brc", "Brc"),
+    NetworkInfo::new(12306, "fibonacci", "Fibonacci"),
+    NetworkInfo::new(12321, "blg_testnet", "Blg Testnet").testnet(),
+    NetworkInfo::new(12324, "l3x_protocol", "L3x Protocol"),
+    NetworkInfo::new(12325, "l3x_protocol_testnet", "L3x Protocol Testnet").testnet(),
+    NetworkInfo::new(12345, "step_testnet", "Step Testnet").testnet(),
+    NetworkInfo::new(12553, "rss3_vsl", "Rss3 Vsl"),
+    NetworkInfo::new(12715, "rikeza_testnet", "Rikeza Testnet").testnet(),
+    NetworkInfo::new(12781, "playdapp_testnet", "Playdapp Testnet").testnet(),
+    NetworkInfo::new(12890, "quantum_testnet", "Quantum Testnet").testnet(),
+    NetworkInfo::new(12898, "playfair_testnet_subnet", "Playfair Testnet Subnet").testnet(),
+    NetworkInfo::new(13000, "sps", "Sps"),
+    NetworkInfo::new(13337, "beam_testnet", "Beam Testnet").testnet(),
+    NetworkInfo::new(13370, "cannon_testnet", "Cannon Testnet").testnet(),
+    NetworkInfo::new(13371, "immutable_zkevm", "Immutable Zkevm"),
+    NetworkInfo::new(13381, "phoenix", "Phoenix"),
+    NetworkInfo::new(13396, "masa", "Masa"),
+    NetworkInfo::new(13473, "immutable_zkevm_testnet", "Immutable Zkevm Testnet").testnet(),
+    NetworkInfo::new(13505, "gravity_alpha_testnet_sepolia", "Gravity Alpha Testnet Sepolia").testnet(),
+    NetworkInfo::new(13600, "kronobit", "Kronobit"),
+    NetworkInfo::new(13812, "susono", "Susono"),
+    NetworkInfo::new(14000, "sps_testnet", "Sps Testnet").testnet(),
+    NetworkInfo::new(14088, "zeroth_testnet", "Zeroth Testnet").testnet(),
+    NetworkInfo::new(14324, "evolve_testnet", "Evolve Testnet").testnet(),
+    NetworkInfo::new(14333, "vitruveo_testnet", "Vitruveo Testnet").testnet(),
+    NetworkInfo::new(14800, "vana_moksha_testnet", "Vana Moksha Testnet").testnet(),
+    NetworkInfo::new(14801, "vana_satori_testnet", "Vana Satori Testnet").testnet(),
+    NetworkInfo::new(14853, "humanode_testnet_5_israfel", "Humanode Testnet 5 Israfel").testnet(),
+    NetworkInfo::new(15003, "immutable_zkevm_devnet", "Immutable Zkevm Devnet").testnet(),
+    NetworkInfo::new(15257, "poodl_testnet", "Poodl Testnet").testnet(),
+    NetworkInfo::new(15259, "poodl", "Poodl"),
+    NetworkInfo::new(15430, "kymtc", "Kymtc"),
+    NetworkInfo::new(15551, "loopnetwork", "Loopnetwork"),
+    NetworkInfo::new(15555, "trust_evm_testnet", "Trust Evm Testnet").testnet(),
+    NetworkInfo::new(15557, "eos_evm_testnet", "Eos Evm Testnet").testnet(),
+    NetworkInfo::new(16000, "metadot", "Metadot"),
+    NetworkInfo::new(16001, "metadot_testnet", "Metadot Testnet").testnet(),
+    NetworkInfo::new(16116, "defiverse", "Defiverse"),
+    NetworkInfo::new(16166, "cypherium", "Cypherium"),
+    NetworkInfo::new(16180, "plyr_phi", "Plyr Phi"),
+    NetworkInfo::new(16350, "incentiv_devnet", "Incentiv Devnet").testnet(),
+    NetworkInfo::new(16481, "pivotal_sepolia", "Pivotal Sepolia").testnet(),
+    NetworkInfo::new(16507, "genesys", "Genesys"),
+    NetworkInfo::new(16600, "0g_newton_testnet", "0g Newton Testnet").testnet(),
+    NetworkInfo::new(16688, "irishub_testnet", "Irishub Testnet").testnet(),
+    NetworkInfo::new(16718, "airdao", "Airdao"),
+    NetworkInfo::new(16888, "ivar_testnet", "Ivar Testnet").testnet(),
+    NetworkInfo::new(17000, "holesky", "Holesky").aliases(&["eth_holesky"]).testnet().currency("ETH").explorer("https://holesky.etherscan.io"),
+    NetworkInfo::new(17001, "redstone_holesky_testnet", "Redstone Holesky Testnet").testnet(),
+    NetworkInfo::new(17069, "garnet_holesky", "Garnet Holesky").testnet(),
+    NetworkInfo::new(17071, "on_points", "On Points"),
+    NetworkInfo::new(17117, "defiverse_testnet", "Defiverse Testnet").testnet(),
+    NetworkInfo::new(17171, "g8", "G8"),
+    NetworkInfo::new(17172, "eclipse_subnet", "Eclipse Subnet"),
+    NetworkInfo::new(17180, "palette_testnet", "Palette Testnet").testnet(),
+    NetworkInfo::new(17217, "konet", "Konet"),
+    NetworkInfo::new(17777, "eos_evm", "Eos Evm"),
+    NetworkInfo::new(18000, "frontier_of_dreams_testnet", "Frontier Of Dreams Testnet").testnet(),
+    NetworkInfo::new(18122, "smart_trades", "Smart Trades"),
+    NetworkInfo::new(18159, "proof_of_memes", "Proof Of Memes"),
+    NetworkInfo::new(18181, "g8_testnet", "G8 Testnet").testnet(),
+    NetworkInfo::new(18231, "unreal_old", "Unreal Old"),
+    NetworkInfo::new(18233, "unreal", "Unreal"),
+    NetworkInfo::new(18686, "mxc_zkevm_moon", "Mxc Zkevm Moon"),
+    NetworkInfo::new(18888, "titan_tkx", "Titan Tkx"),
+    NetworkInfo::new(18889, "titan_tkx_testnet", "Titan Tkx Testnet").testnet(),
+    NetworkInfo::new(19011, "home_verse", "Home Verse"),
+    NetworkInfo::new(19077, "blockx_atlantis_testnet", "Blockx Atlantis Testnet").testnet(),
+    NetworkInfo::new(19191, "blockx", "Blockx"),
+    NetworkInfo::new(19224, "decentraconnect_social", "Decentraconnect Social"),
+    NetworkInfo::new(19527, "magnet", "Magnet"),
+    NetworkInfo::new(19600, "lbry", "Lbry"),
+    NetworkInfo::new(19845, "btcix", "Btcix"),
+    NetworkInfo::new(20001, "camelark", "Camelark"),
+    NetworkInfo::new(20041, "niza", "Niza"),
+    NetworkInfo::new(20073, "niza_testnet", "Niza Testnet").testnet(),
+    NetworkInfo::new(20729, "callisto_testnet", "Callisto Testnet").testnet(),
+    NetworkInfo::new(20736, "p12", "P12"),
+    NetworkInfo::new(20765, "jono11_subnet", "Jono11 Subnet"),
+    NetworkInfo::new(21004, "c4ei", "C4ei"),
+    NetworkInfo::new(21133, "all_about_healthy", "All About Healthy"),
+    NetworkInfo::new(21223, "dcpay", "Dcpay"),
+    NetworkInfo::new(21224, "dcpay_testnet", "Dcpay Testnet").testnet(),
+    NetworkInfo::new(21337, "cennznet_azalea", "Cennznet Azalea"),
+    NetworkInfo::new(21363, "lestnet", "Lestnet"),
+    NetworkInfo::new(21816, "om", "Om"),
+    NetworkInfo::new(21912, "bsl", "Bsl"),
+    NetworkInfo::new(22023, "taycan", "Taycan"),
+    NetworkInfo::new(22040, "airdao_testnet", "Airdao Testnet").testnet(),
+    NetworkInfo::new(22222, "nautilus", "Nautilus"),
+    NetworkInfo::new(22324, "goldx_testnet", "Goldx Testnet").testnet(),
+    NetworkInfo::new(22776, "map_protocol", "Map Protocol"),
+    NetworkInfo::new(23006, "antofy_testnet", "Antofy Testnet").testnet(),
+    NetworkInfo::new(23118, "opside_testnet", "Opside Testnet").testnet(),
+    NetworkInfo::new(23294, "oasis_sapphire", "Oasis Sapphire"),
+    NetworkInfo::new(23295, "oasis_sapphire_testnet", "Oasis Sapphire Testnet").testnet(),
+    NetworkInfo::new(23451, "dreyerx", "Dreyerx"),
+    NetworkInfo::new(23452, "dreyerx_testnet", "Dreyerx Testnet").testnet(),
+    NetworkInfo::new(23888, "blast_testnet", "Blast Testnet").testnet(),
+    NetworkInfo::new(24076, "kymtc_testnet", "Kymtc Testnet").testnet(),
+    NetworkInfo::new(24484, "web", "Web"),
+    NetworkInfo::new(24734, "mintme.com", "Mintme.com"),
+    NetworkInfo::new(25186, "liquidlayer", "Liquidlayer"),
+    NetworkInfo::new(25327, "everclear", "Everclear"),
+    NetworkInfo::new(25839, "alvey_testnet", "Alvey Testnet").testnet(),
+    NetworkInfo::new(25888, "hammer", "Hammer"),
+    NetworkInfo::new(25925, "bitkub_testnet", "Bitkub Testnet").testnet(),
+    NetworkInfo::new(26026, "ferrum_testnet", "Ferrum Testnet").testnet(),
+    NetworkInfo::new(26482, "ducatusx_testnet", "Ducatusx Testnet").testnet(),
+    NetworkInfo::new(26483, "ducatusx", "Ducatusx"),
+    NetworkInfo::new(26600, "hertz", "Hertz"),
+    NetworkInfo::new(26863, "oasis", "Oasis"),
+    NetworkInfo::new(27181, "klaos_nova", "Klaos Nova"),
+    NetworkInfo::new(27483, "nanon_sepolia", "Nanon Sepolia").testnet(),
+    NetworkInfo::new(27827, "zeroone_subnet", "Zeroone Subnet"),
+    NetworkInfo::new(28516, "vizing_testnet", "Vizing Testnet").testnet(),
+    NetworkInfo::new(28518, "vizing", "Vizing"),
+    NetworkInfo::new(28528, "optimism_bedrock_goerli_alpha_testnet", "Optimism Bedrock Goerli Alpha Testnet").testnet(),
+    NetworkInfo::new(28882, "boba_sepolia", "Boba Sepolia").testnet(),
+    NetworkInfo::new(29112, "hy_testnet", "Hy Testnet").testnet(),
+    NetworkInfo::new(29223, "nexa_metanet", "Nexa Metanet"),
+    NetworkInfo::new(29536, "kai_testnet", "Kai Testnet").testnet(),
+    NetworkInfo::new(29548, "mch_verse", "Mch Verse"),
+    NetworkInfo::new(30000, "q", "Q"),
+    NetworkInfo::new(30067, "piece_testnet", "Piece Testnet").testnet(),
+    NetworkInfo::new(30088, "miyou", "Miyou"),
+    NetworkInfo::new(30103, "cerium_testnet", "Cerium Testnet").testnet(),
+    NetworkInfo::new(30730, "movement_evm_legacy", "Movement Evm Legacy"),
+    NetworkInfo::new(30731, "movement_evm_devnet", "Movement Evm Devnet").testnet(),
+    NetworkInfo::new(30732, "movement_evm_testnet", "Movement Evm Testnet").testnet(),
+    NetworkInfo::new(31102, "ethersocial", "Ethersocial"),
+    NetworkInfo::new(31223, "cloudtx", "Cloudtx"),
+    NetworkInfo::new(31224, "cloudtx_testnet", "Cloudtx Testnet").testnet(),
+    NetworkInfo::new(31337, "go_testnet", "Go Testnet").testnet(),
+    NetworkInfo::new(31414, "evoke_testnet", "Evoke Testnet").testnet(),
+    NetworkInfo::new(31415, "filecoin_wallaby_testnet", "Filecoin Wallaby Testnet").testnet(),
+    NetworkInfo::new(31753, "x", "X"),
+    NetworkInfo::new(31754, "x_testnet", "X Testnet").testnet(),
+    NetworkInfo::new(32001, "w3gamez_holesky_testnet", "W3gamez Holesky Testnet").testnet(),
+    NetworkInfo::new(32382, "santiment_intelligence", "Santiment Intelligence"),
+    NetworkInfo::new(32520, "bitgert", "Bitgert"),
+    NetworkInfo::new(32659, "fusion", "Fusion"),
+    NetworkInfo::new(32769, "zilliqa_evm", "Zilliqa Evm"),
+    NetworkInfo::new(32990, "zilliqa_evm_isolated_server", "Zilliqa Evm Isolated Server"),
+    NetworkInfo::new(33033, "entangle", "Entangle"),
+    NetworkInfo::new(33101, "zilliqa_evm_testnet", "Zilliqa Evm Testnet").testnet(),
+    NetworkInfo::new(33103, "zilliqa_2_evm_proto_testnet", "Zilliqa 2 Evm Proto Testnet").testnet(),
+    NetworkInfo::new(33111, "curtis", "Curtis"),
+    NetworkInfo::new(33133, "entangle_testnet", "Entangle Testnet").testnet(),
+    NetworkInfo::new(33210, "cloudverse_subnet", "Cloudverse Subnet"),
+    NetworkInfo::new(33333, "aves", "Aves"),
+    NetworkInfo::new(33385, "zilliqa_evm_devnet", "Zilliqa Evm Devnet").testnet(),
+    NetworkInfo::new(33401, "slingshot", "Slingshot"),
+    NetworkInfo::new(33469, "zilliqa_2_evm_devnet", "Zilliqa 2 Evm Devnet").testnet(),
+    NetworkInfo::new(33979, "funki", "Funki"),
+    NetworkInfo::new(34443, "mode", "Mode"),
+    NetworkInfo::new(34504, "zeus", "Zeus"),
+    NetworkInfo::new(35011, "j2o_taro", "J2o Taro"),
+    NetworkInfo::new(35443, "q_testnet", "Q Testnet").testnet(),
+    NetworkInfo::new(38400, "connectormanager", "Connectormanager"),
+    NetworkInfo::new(38401, "connectormanager_robin", "Connectormanager Robin"),
+    NetworkInfo::new(39656, "prm", "Prm"),
+    NetworkInfo::new(39797, "energi", "Energi"),
+    NetworkInfo::new(39815, "oho", "Oho"),
+    NetworkInfo::new(40000, "div", "Div"),
+    NetworkInfo::new(41455, "aleph_zero_evm", "Aleph Zero Evm"),
+    NetworkInfo::new(41500, "opulent_x_beta", "Opulent X Beta"),
+    NetworkInfo::new(42069, "pegglecoin", "Pegglecoin"),
+    NetworkInfo::new(42072, "agentlayer_testnet", "Agentlayer Testnet").testnet(),
+    NetworkInfo::new(42161, "arbitrum", "Arbitrum One").aliases(&["arb", "arb1", "arbitrum_one"]).parent(1).currency("ETH").explorer("https://arbiscan.io"),
+    NetworkInfo::new(42170, "arbitrum_nova", "Arbitrum Nova").parent(1).currency("ETH").explorer("https://nova.arbiscan.io"),
+    NetworkInfo::new(42220, "celo", "Celo"),
+    NetworkInfo::new(42261, "oasis_emerald_testnet", "Oasis Emerald Testnet").testnet(),
+    NetworkInfo::new(42262, "oasis_emerald", "Oasis Emerald"),
+    NetworkInfo::new(42355, "goldx", "Goldx"),
+    NetworkInfo::new(42421, "asset_testnet", "Asset Testnet").testnet(),
+    NetworkInfo::new(42766, "zkfair", "Zkfair"),
+    NetworkInfo::new(42793, "etherlink", "Etherlink"),
+    NetworkInfo::new(42801, "gesoten_verse_testnet", "Gesoten Verse Testnet").testnet(),
+    NetworkInfo::new(42888, "kinto_testnet", "Kinto Testnet").testnet(),
+    NetworkInfo::new(43110, "athereum", "Athereum"),
+    NetworkInfo::new(43111, "hemi", "Hemi"),
+    NetworkInfo::new(43113, "avalanche_fuji_testnet", "Avalanche Fuji Testnet").aliases(&["fuji"]).testnet().currency("AVAX").explorer("https://testnet.snowtrace.io"),
+    NetworkInfo::new(43114, "avalanche", "Avalanche C-Chain").aliases(&["avax", "avalanche_c"]).currency("AVAX").explorer("https://snowtrace.io"),
+    NetworkInfo::new(43288, "boba_avax", "Boba Avax"),
+    NetworkInfo::new(43851, "zkfair_testnet", "Zkfair Testnet").testnet(),
+    NetworkInfo::new(44444, "fren", "Fren"),
+    NetworkInfo::new(44445, "quantum", "Quantum"),
+    NetworkInfo::new(44787, "celo_alfajores_testnet", "Celo Alfajores Testnet").testnet(),
+    NetworkInfo::new(45000, "autobahn", "Autobahn"),
+    NetworkInfo::new(45454, "swamps_l2", "Swamps L2"),
+    NetworkInfo::new(45510, "deelance", "Deelance"),
+    NetworkInfo::new(45513, "blessnet", "Blessnet"),
+    NetworkInfo::new(46688, "fusion_testnet", "Fusion Testnet").testnet(),
+    NetworkInfo::new(47763, "neo_x", "Neo X"),
+    NetworkInfo::new(47803, "redefi_layer_1", "Redefi Layer 1"),
+    NetworkInfo::new(47805, "rei", "Rei"),
+    NetworkInfo::new(48795, "space_subnet_testnet", "Space Subnet Testnet").testnet(),
+    NetworkInfo::new(48899, "zircuit_testnet", "Zircuit Testnet").testnet(),
+    NetworkInfo::new(48900, "zircuit", "Zircuit"),
+    NetworkInfo::new(49049, "wireshape_floripa_testnet", "Wireshape Floripa Testnet").testnet(),
+    NetworkInfo::new(49088, "bifrost_testnet", "Bifrost Testnet").testnet(),
+    NetworkInfo::new(49321, "gunz_testnet", "Gunz Testnet").testnet(),
+    NetworkInfo::new(49797, "energi_testnet", "Energi Testnet").testnet(),
+    NetworkInfo::new(50001, "liveplex_oracleevm", "Liveplex Oracleevm"),
+    NetworkInfo::new(50005, "yooldo_verse", "Yooldo Verse"),
+    NetworkInfo::new(50006, "yooldo_verse_testnet", "Yooldo Verse Testnet").testnet(),
+    NetworkInfo::new(50021, "gton_testnet", "Gton Testnet").testnet(),
+    NetworkInfo::new(50888, "erbie", "Erbie"),
+    NetworkInfo::new(51178, "lumoz_testnet_alpha", "Lumoz Testnet Alpha").testnet(),
+    NetworkInfo::new(51712, "sardis", "Sardis"),
+    NetworkInfo::new(52014, "electroneum", "Electroneum"),
+    NetworkInfo::new(53277, "doid", "Doid"),
+    NetworkInfo::new(53302, "superseed_sepolia_testnet", "Superseed Sepolia Testnet").testnet(),
+    NetworkInfo::new(53457, "dodo_testnet", "Dodo Testnet").testnet(),
+    NetworkInfo::new(53935, "dfk", "Dfk"),
+    NetworkInfo::new(54176, "overprotocol", "Overprotocol"),
+    NetworkInfo::new(54211, "haqq_testnet", "Haqq Testnet").testnet(),
+    NetworkInfo::new(54321, "toronet_testnet", "Toronet Testnet").testnet(),
+    NetworkInfo::new(55004, "titan", "Titan"),
+    NetworkInfo::new(55551, "photon_aurora_testnet", "Photon Aurora Testnet").testnet(),
+    NetworkInfo::new(55556, "rei_testnet", "Rei Testnet").testnet(),
+    NetworkInfo::new(55614, "flamma", "Flamma"),
+    NetworkInfo::new(56026, "lambda", "Lambda"),
+    NetworkInfo::new(56288, "boba_bnb", "Boba Bnb"),
+    NetworkInfo::new(56400, "testnet_zeroone_subnet", "Testnet Zeroone Subnet").testnet(),
+    NetworkInfo::new(56789, "velo_labs", "Velo Labs"),
+    NetworkInfo::new(56797, "doid_testnet", "Doid Testnet").testnet(),
+    NetworkInfo::new(57000, "rollux_testnet", "Rollux Testnet").testnet(),
+    NetworkInfo::new(57451, "coinsec", "Coinsec"),
+    NetworkInfo::new(58008, "sepolia_pgn_public_goods", "Sepolia Pgn Public Goods").testnet(),
+    NetworkInfo::new(58680, "lumoz_quidditch_testnet", "Lumoz Quidditch Testnet").testnet(),
+    NetworkInfo::new(59140, "linea_goerli", "Linea Goerli").testnet(),
+    NetworkInfo::new(59141, "linea_sepolia", "Linea Sepolia").testnet().parent(11155111).currency("ETH").explorer("https://sepolia.lineascan.build"),
+    NetworkInfo::new(59144, "linea", "Linea").aliases(&["linea_mainnet"]).parent(1).currency("ETH").explorer("https://lineascan.build"),
+    NetworkInfo::new(59902, "metis_sepolia_testnet", "Metis Sepolia Testnet").testnet(),
+    NetworkInfo::new(59971, "genesys_code", "Genesys Code"),
+    NetworkInfo::new(60000, "thinkium_testnet_0", "Thinkium Testnet 0").testnet(),
+    NetworkInfo::new(60001, "thinkium_testnet_1", "Thinkium Testnet 1").testnet(),
+    NetworkInfo::new(60002, "thinkium_testnet_2", "Thinkium Testnet 2").testnet(),
+    NetworkInfo::new(60103, "thinkium_testnet_103", "Thinkium Testnet 103").testnet(),
+    NetworkInfo::new(60808, "bob", "Bob"),
+    NetworkInfo::new(61022, "orange", "Orange"),
+    NetworkInfo::new(61406, "kai", "Kai"),
+    NetworkInfo::new(61800, "axel_dev_net", "Axel Dev Net"),
+    NetworkInfo::new(61803, "etica", "Etica"),
+    NetworkInfo::new(61916, "doken_super", "Doken Super"),
+    NetworkInfo::new(62049, "optopia_testnet", "Optopia Testnet").testnet(),
+    NetworkInfo::new(62050, "optopia", "Optopia"),
+    NetworkInfo::new(62298, "citrea_devnet", "Citrea Devnet").testnet(),
+    NetworkInfo::new(62320, "celo_baklava_testnet", "Celo Baklava Testnet").testnet(),
+    NetworkInfo::new(62621, "multivac", "Multivac"),
+    NetworkInfo::new(62831, "plyr_tau_testnet", "Plyr Tau Testnet").testnet(),
+    NetworkInfo::new(62850, "laos_sigma_testnet", "Laos Sigma Testnet").testnet(),
+    NetworkInfo::new(63000, "ecredits", "Ecredits"),
+    NetworkInfo::new(63001, "ecredits_testnet", "Ecredits Testnet").testnet(),
+    NetworkInfo::new(63002, "esync_testnet", "Esync Testnet").testnet(),
+    NetworkInfo::new(65349, "cratd2c_testnet", "Cratd2c Testnet").testnet(),
+    NetworkInfo::new(65357, "vecno", "Vecno"),
+    NetworkInfo::new(65450, "scolcoin", "Scolcoin"),
+    NetworkInfo::new(65536, "automata", "Automata"),
+    NetworkInfo::new(66665, "creator_testnet", "Creator Testnet").testnet(),
+    NetworkInfo::new(66988, "janus_testnet", "Janus Testnet").testnet(),
+    NetworkInfo::new(67390, "siriusnet", "Siriusnet"),
+    NetworkInfo::new(67588, "cosmic", "Cosmic"),
+    NetworkInfo::new(68770, "dm2_verse", "Dm2 Verse"),
+    NetworkInfo::new(68775, "dm2_verse_testnet", "Dm2 Verse Testnet").testnet(),
+    NetworkInfo::new(69420, "condrieu", "Condrieu"),
+    NetworkInfo::new(70000, "thinkium_0", "Thinkium 0"),
+    NetworkInfo::new(70001, "thinkium_1", "Thinkium 1"),
+    NetworkInfo::new(70002, "thinkium_2", "Thinkium 2"),
+    NetworkInfo::new(70103, "thinkium_103", "Thinkium 103"),
+    NetworkInfo::new(70700, "proof_of_play_apex", "Proof Of Play Apex"),
+    NetworkInfo::new(71111, "guapcoinx", "Guapcoinx"),
+    NetworkInfo::new(71393, "polyjuice_testnet", "Polyjuice Testnet").testnet(),
+    NetworkInfo::new(71401, "godwoken_testnet_v1", "Godwoken Testnet V1").testnet(),
+    NetworkInfo::new(71402, "godwoken", "Godwoken"),
+    NetworkInfo::new(72778, "caga_crypto_ankara_testnet", "Caga Crypto Ankara Testnet").testnet(),
+    NetworkInfo::new(72992, "grok", "Grok"),
+    NetworkInfo::new(73114, "icb_testnet", "Icb Testnet").testnet(),
+    NetworkInfo::new(73115, "icb", "Icb"),
+    NetworkInfo::new(73799, "energy_web_volta_testnet", "Energy Web Volta Testnet").testnet(),
+    NetworkInfo::new(73927, "mixin_virtual_machine", "Mixin Virtual Machine"),
+    NetworkInfo::new(75000, "resincoin", "Resincoin"),
+    NetworkInfo::new(75512, "geek_verse", "Geek Verse"),
+    NetworkInfo::new(75513, "geek_verse_testnet", "Geek Verse Testnet").testnet(),
+    NetworkInfo::new(77001, "bora", "Bora"),
+    NetworkInfo::new(77238, "foundry_testnet", "Foundry Testnet").testnet(),
+    NetworkInfo::new(77612, "vention", "Vention"),
+    NetworkInfo::new(77677, "cycle_sailboat", "Cycle Sailboat"),
+    NetworkInfo::new(77777, "toronet", "Toronet"),
+    NetworkInfo::new(78110, "firenze_test", "Firenze Test").testnet(),
+    NetworkInfo::new(78281, "dragonfly_hexapod", "Dragonfly Hexapod"),
+    NetworkInfo::new(78430, "amplify_subnet", "Amplify Subnet"),
+    NetworkInfo::new(78431, "bulletin_subnet", "Bulletin Subnet"),
+    NetworkInfo::new(78432, "conduit_subnet", "Conduit Subnet"),
+    NetworkInfo::new(78600, "vanguard", "Vanguard"),
+    NetworkInfo::new(79879, "gold_testnet", "Gold Testnet").testnet(),
+    NetworkInfo::new(80001, "mumbai", "Mumbai").testnet(),
+    NetworkInfo::new(80002, "amoy", "Amoy").aliases(&["polygon_amoy"]).testnet().currency("POL").explorer("https://amoy.polygonscan.com"),
+    NetworkInfo::new(80008, "polynomia_sepolia", "Polynomia Sepolia").testnet(),
+    NetworkInfo::new(80084, "bera_bartio", "Bera Bartio"),
+    NetworkInfo::new(80085, "bera_artio", "Bera Artio"),
+    NetworkInfo::new(80096, "hizoco", "Hizoco"),
+    NetworkInfo::new(81041, "nordek", "Nordek"),
+    NetworkInfo::new(81341, "amana_testnet", "Amana Testnet").testnet(),
+    NetworkInfo::new(81342, "amana_mixnet", "Amana Mixnet"),
+    NetworkInfo::new(81343, "amana_privnet", "Amana Privnet"),
+    NetworkInfo::new(81351, "flana_testnet", "Flana Testnet").testnet(),
+    NetworkInfo::new(81352, "flana_mixnet", "Flana Mixnet"),
+    NetworkInfo::new(81353, "flana_privnet", "Flana Privnet"),
+    NetworkInfo::new(81361, "mizana_testnet", "Mizana Testnet").testnet(),
+    NetworkInfo::new(81362, "mizana_mixnet", "Mizana Mixnet"),
+    NetworkInfo::new(81363, "mizana_privnet", "Mizana Privnet"),
+    NetworkInfo::new(82459, "smart_layer_testnet", "Smart Layer Testnet").testnet(),
+    NetworkInfo::new(83144, "xprotocol_testnet", "Xprotocol Testnet").testnet(),
+    NetworkInfo::new(83278, "esa", "Esa"),
+    NetworkInfo::new(83872, "zedxion", "Zedxion"),
+    NetworkInfo::new(84531, "base_goerli_testnet", "Base Goerli Testnet").testnet(),
+    NetworkInfo::new(84532, "base_sepolia_testnet", "Base Sepolia Testnet").aliases(&["base_sepolia"]).testnet().parent(11155111).currency("ETH").explorer("https://sepolia.basescan.org"),
+    NetworkInfo::new(84886, "aerie", "Aerie"),
+    NetworkInfo::new(85449, "cybertrust", "Cybertrust"),
+    NetworkInfo::new(88002, "nautilus_proteus_testnet", "Nautilus Proteus Testnet").testnet(),
+    NetworkInfo::new(88559, "inoai", "Inoai"),
+    NetworkInfo::new(88800, "zkasino", "Zkasino"),
+    NetworkInfo::new(88817, "unit_zero_testnet", "Unit Zero Testnet").testnet(),
+    NetworkInfo::new(88819, "unit_zero_stagenet", "Unit Zero Stagenet"),
+    NetworkInfo::new(88880, "chiliz_scoville_testnet", "Chiliz Scoville Testnet").testnet(),
+    NetworkInfo::new(88882, "chiliz_spicy_testnet", "Chiliz Spicy Testnet").testnet(),
+    NetworkInfo::new(88888, "chiliz", "Chiliz"),
+    NetworkInfo::new(90001, "fxcore_testnet", "Fxcore Testnet").testnet(),
+    NetworkInfo::new(90210, "beverly_hills", "Beverly Hills"),
+    NetworkInfo::new(90354, "camp_testnet", "Camp Testnet").testnet(),
+    NetworkInfo::new(91002, "nautilus_trition", "Nautilus Trition"),
+    NetworkInfo::new(91120, "metadap_enterprise", "Metadap Enterprise"),
+    NetworkInfo::new(91715, "combo_testnet", "Combo Testnet").testnet(),
+    NetworkInfo::new(92001, "lambda_testnet", "Lambda Testnet").testnet(),
+    NetworkInfo::new(93572, "liquidlayer_testnet", "Liquidlayer Testnet").testnet(),
+    NetworkInfo::new(93747, "stratovm_testnet", "Stratovm Testnet").testnet(),
+    NetworkInfo::new(96970, "mantis_testnet_hexapod", "Mantis Testnet Hexapod").testnet(),
+    NetworkInfo::new(97053, "tetron_testnet", "Tetron Testnet").testnet(),
+    NetworkInfo::new(97055, "tetron", "Tetron"),
+    NetworkInfo::new(97288, "boba_bnb_old", "Boba Bnb Old"),
+    NetworkInfo::new(97435, "slingshot_testnet", "Slingshot Testnet").testnet(),
+    NetworkInfo::new(97531, "green_testnet", "Green Testnet").testnet(),
+    NetworkInfo::new(97970, "optimusz7_testnet", "Optimusz7 Testnet").testnet(),
+    NetworkInfo::new(98881, "ebi", "Ebi"),
+    NetworkInfo::new(99099, "eliberty_testnet", "Eliberty Testnet").testnet(),
+    NetworkInfo::new(99876, "edge_matrix_testnet", "Edge Matrix Testnet").testnet(),
+    NetworkInfo::new(99998, "ubtestnet", "Ubtestnet").testnet(),
+    NetworkInfo::new(99999, "ub", "Ub"),
+    NetworkInfo::new(100000, "quark_root", "Quark Root"),
+    NetworkInfo::new(100001, "quark_shard_0", "Quark Shard 0"),
+    NetworkInfo::new(100002, "quark_shard_1", "Quark Shard 1"),
+    NetworkInfo::new(100003, "quark_shard_2", "Quark Shard 2"),
+    NetworkInfo::new(100004, "quark_shard_3", "Quark Shard 3"),
+    NetworkInfo::new(100005, "quark_shard_4", "Quark Shard 4"),
+    NetworkInfo::new(100006, "quark_shard_5", "Quark Shard 5"),
+    NetworkInfo::new(100007, "quark_shard_6", "Quark Shard 6"),
+    NetworkInfo::new(100008, "quark_shard_7", "Quark Shard 7"),
+    NetworkInfo::new(100009, "ve", "Ve"),
+    NetworkInfo::new(100010, "ve_testnet", "Ve Testnet").testnet(),
+    NetworkInfo::new(100011, "quark_l2", "Quark L2"),
+    NetworkInfo::new(101010, "global_trust", "Global Trust"),
+    NetworkInfo::new(102030, "creditcoin", "Creditcoin"),
+    NetworkInfo::new(102031, "creditcoin_testnet", "Creditcoin Testnet").testnet(),
+    NetworkInfo::new(102032, "creditcoin_devnet", "Creditcoin Devnet").testnet(),
+    NetworkInfo::new(103090, "crystaleum", "Crystaleum"),
+    NetworkInfo::new(103454, "masa_testnet", "Masa Testnet").testnet(),
+    NetworkInfo::new(104566, "kaspaclassic", "Kaspaclassic"),
+    NetworkInfo::new(105105, "stratis", "Stratis"),
+    NetworkInfo::new(108801, "bro", "Bro"),
+    NetworkInfo::new(110000, "quark_devnet_root", "Quark Devnet Root").testnet(),
+    NetworkInfo::new(110001, "quark_devnet_shard_0", "Quark Devnet Shard 0").testnet(),
+    NetworkInfo::new(110002, "quark_devnet_shard_1", "Quark Devnet Shard 1").testnet(),
+    NetworkInfo::new(110003, "quark_devnet_shard_2", "Quark Devnet Shard 2").testnet(),
+    NetworkInfo::new(110004, "quark_devnet_shard_3", "Quark Devnet Shard 3").testnet(),
+    NetworkInfo::new(110005, "quark_devnet_shard_4", "Quark Devnet Shard 4").testnet(),
+    NetworkInfo::new(110006, "quark_devnet_shard_5", "Quark Devnet Shard 5").testnet(),
+    NetworkInfo::new(110007, "quark_devnet_shard_6", "Quark Devnet Shard 6").testnet(),
+    NetworkInfo::new(110008, "quark_devnet_shard_7", "Quark Devnet Shard 7").testnet(),
+    NetworkInfo::new(110011, "quark_l2_testnet", "Quark L2 Testnet").testnet(),
+    NetworkInfo::new(110110, "mars_credit", "Mars Credit"),
+    NetworkInfo::new(111000, "siberium_test", "Siberium Test").testnet(),
+    NetworkInfo::new(111111, "siberium", "Siberium"),
+    NetworkInfo::new(111188, "re.al", "Re.al"),
+    NetworkInfo::new(112358, "meta_one", "Meta One"),
+    NetworkInfo::new(119139, "metadap_enterprise_testnet", "Metadap Enterprise Testnet").testnet(),
+    NetworkInfo::new(123321, "gem", "Gem"),
+    NetworkInfo::new(123456, "adil_devnet", "Adil Devnet").testnet(),
+    NetworkInfo::new(128123, "etherlink_testnet", "Etherlink Testnet").testnet(),
+    NetworkInfo::new(131313, "odyssey_testnet", "Odyssey Testnet").testnet(),
+    NetworkInfo::new(131419, "etnds", "Etnds"),
+    NetworkInfo::new(132902, "form_testnet", "Form Testnet").testnet(),
+    NetworkInfo::new(141319, "magape_testnet", "Magape Testnet").testnet(),
+    NetworkInfo::new(142857, "icplaza", "Icplaza"),
+    NetworkInfo::new(161212, "playfi", "Playfi"),
+    NetworkInfo::new(165279, "eclat", "Eclat"),
+    NetworkInfo::new(167000, "taiko", "Taiko"),
+    NetworkInfo::new(167004, "taiko_alpha_2_testnet", "Taiko Alpha 2 Testnet").testnet(),
+    NetworkInfo::new(167005, "taiko_grimsvotn_l2", "Taiko Grimsvotn L2"),
+    NetworkInfo::new(167006, "taiko_eldfell_l3", "Taiko Eldfell L3"),
+    NetworkInfo::new(167007, "taiko_jolnir_l2", "Taiko Jolnir L2"),
+    NetworkInfo::new(167008, "taiko_katla_l2", "Taiko Katla L2"),
+    NetworkInfo::new(167009, "taiko_hekla_l2", "Taiko Hekla L2"),
+    NetworkInfo::new(168168, "zs", "Zs"),
+    NetworkInfo::new(171000, "fair_testnet", "Fair Testnet").testnet(),
+    NetworkInfo::new(175177, "chronicle_lit_protocol_testnet", "Chronicle Lit Protocol Testnet").testnet(),
+    NetworkInfo::new(175188, "chronicle_yellowstone_lit_protocol_testnet", "Chronicle Yellowstone Lit Protocol Testnet").testnet(),
+    NetworkInfo::new(188710, "bitica", "Bitica"),
+    NetworkInfo::new(188881, "condor_test", "Condor Test").testnet(),
+    NetworkInfo::new(199991, "mazze_testnet", "Mazze Testnet").testnet(),
+    NetworkInfo::new(200000, "xfair.ai_testnet", "Xfair.ai Testnet").testnet(),
+    NetworkInfo::new(200101, "milkomeda_c1_testnet", "Milkomeda C1 Testnet").testnet(),
+    NetworkInfo::new(200202, "milkomeda_a1_testnet", "Milkomeda A1 Testnet").testnet(),
+    NetworkInfo::new(200625, "akroma", "Akroma"),
+    NetworkInfo::new(200810, "bitlayer_testnet", "Bitlayer Testnet").testnet(),
+    NetworkInfo::new(200901, "bitlayer", "Bitlayer"),
+    NetworkInfo::new(201018, "alaya", "Alaya"),
+    NetworkInfo::new(201030, "alaya_dev_testnet", "Alaya Dev Testnet").testnet(),
+    NetworkInfo::new(201804, "mythical", "Mythical"),
+    NetworkInfo::new(202020, "decimal_testnet", "Decimal Testnet").testnet(),
+    NetworkInfo::new(202105, "duck_testnet", "Duck Testnet").testnet(),
+    NetworkInfo::new(202202, "bethel_sydney", "Bethel Sydney"),
+    NetworkInfo::new(202212, "x1_devnet", "X1 Devnet").testnet(),
+    NetworkInfo::new(202401, "ymtech_besu_testnet", "Ymtech Besu Testnet").testnet(),
+    NetworkInfo::new(202624, "jellie", "Jellie"),
+    NetworkInfo::new(204005, "x1_network", "X1 Network"),
+    NetworkInfo::new(205205, "auroria_testnet", "Auroria Testnet").testnet(),
+    NetworkInfo::new(210049, "gitagi_atlas_testnet", "Gitagi Atlas Testnet").testnet(),
+    NetworkInfo::new(210425, "platon", "Platon"),
+    NetworkInfo::new(212013, "litentry", "Litentry"),
+    NetworkInfo::new(220315, "mas", "Mas"),
+    NetworkInfo::new(221230, "reap", "Reap"),
+    NetworkInfo::new(221231, "reap_testnet", "Reap Testnet").testnet(),
+    NetworkInfo::new(222222, "hydradx", "Hydradx"),
+    NetworkInfo::new(222555, "deepl", "Deepl"),
+    NetworkInfo::new(222666, "deepl_testnet", "Deepl Testnet").testnet(),
+    NetworkInfo::new(224168, "taf_eco", "Taf Eco"),
+    NetworkInfo::new(224422, "conet_sebolia_testnet", "Conet Sebolia Testnet").testnet(),
+    NetworkInfo::new(224433, "conet_holesky", "Conet Holesky").testnet(),
+    NetworkInfo::new(229772, "abyss_protocol", "Abyss Protocol"),
+    NetworkInfo::new(230315, "hashkey_testnetdiscard", "Hashkey Testnetdiscard").testnet(),
+    NetworkInfo::new(234666, "haymo_testnet", "Haymo Testnet").testnet(),
+    NetworkInfo::new(240515, "orange_testnet", "Orange Testnet").testnet(),
+    NetworkInfo::new(246529, "artis_sigma1", "Artis Sigma1"),
+    NetworkInfo::new(246785, "artis_testnet_tau1", "Artis Testnet Tau1").testnet(),
+    NetworkInfo::new(247253, "saakuru_testnet", "Saakuru Testnet").testnet(),
+    NetworkInfo::new(256256, "cmp", "Cmp"),
+    NetworkInfo::new(262371, "eclat_testnet", "Eclat Testnet").testnet(),
+    NetworkInfo::new(266256, "gear_zero_testnet", "Gear Zero Testnet").testnet(),
+    NetworkInfo::new(271271, "egoncoin_testnet", "Egoncoin Testnet").testnet(),
+    NetworkInfo::new(281121, "social", "Social"),
+    NetworkInfo::new(282828, "zillion_sepolia_testnet", "Zillion Sepolia Testnet").testnet(),
+    NetworkInfo::new(292003, "cipherem_testnet", "Cipherem Testnet").testnet(),
+    NetworkInfo::new(309075, "one_world", "One World"),
+    NetworkInfo::new(313313, "saharaai_testnet", "Saharaai Testnet").testnet(),
+    NetworkInfo::new(314159, "filecoin_calibration_testnet", "Filecoin Calibration Testnet").testnet(),
+    NetworkInfo::new(322202, "parex", "Parex"),
+    NetworkInfo::new(323213, "bloom_genesis_testnet", "Bloom Genesis Testnet").testnet(),
+    NetworkInfo::new(327126, "waba_testnet", "Waba Testnet").testnet(),
+    NetworkInfo::new(328527, "nal", "Nal"),
+    NetworkInfo::new(330844, "ttcoin", "Ttcoin"),
+    NetworkInfo::new(333313, "bloom_genesis", "Bloom Genesis"),
+    NetworkInfo::new(333331, "aves_testnet", "Aves Testnet").testnet(),
+    NetworkInfo::new(333333, "nativ3_testnet", "Nativ3 Testnet").testnet(),
+    NetworkInfo::new(333666, "oone_testnet", "Oone Testnet").testnet(),
+    NetworkInfo::new(333777, "oone_devnet", "Oone Devnet").testnet(),
+    NetworkInfo::new(333888, "polis_testnet", "Polis Testnet").testnet(),
+    NetworkInfo::new(333999, "polis", "Polis"),
+    NetworkInfo::new(336655, "up_testnet", "Up Testnet").testnet(),
+    NetworkInfo::new(336666, "up", "Up"),
+    NetworkInfo::new(355110, "bitfinity", "Bitfinity"),
+    NetworkInfo::new(355113, "bitfinity_testnet", "Bitfinity Testnet").testnet(),
+    NetworkInfo::new(360890, "lavita", "Lavita"),
+    NetworkInfo::new(363636, "digit_soul_2", "Digit Soul 2"),
+    NetworkInfo::new(373737, "hap_testnet", "Hap Testnet").testnet(),
+    NetworkInfo::new(381931, "metal_c", "Metal C"),
+    NetworkInfo::new(381932, "metal_tahoe_c", "Metal Tahoe C"),
+    NetworkInfo::new(404040, "tipboxcoin", "Tipboxcoin"),
+    NetworkInfo::new(413413, "aie_testnet", "Aie Testnet").testnet(),
+    NetworkInfo::new(420420, "kek", "Kek"),
+    NetworkInfo::new(420666, "kek_kektest", "Kek Kektest").testnet(),
+    NetworkInfo::new(420692, "alterium_l2_testnet", "Alterium L2 Testnet").testnet(),
+    NetworkInfo::new(421611, "arbitrum_rinkeby", "Arbitrum Rinkeby").testnet(),
+    NetworkInfo::new(421613, "arbitrum_goerli", "Arbitrum Goerli").testnet(),
+    NetworkInfo::new(421614, "arbitrum_sepolia", "Arbitrum Sepolia").aliases(&["arb_sepolia"]).testnet().parent(11155111).currency("ETH").explorer("https://sepolia.arbiscan.io"),
+    NetworkInfo::new(424242, "fastex_testnet", "Fastex Testnet").testnet(),
+    NetworkInfo::new(431140, "markr_go", "Markr Go"),
+    NetworkInfo::new(432201, "dexalot_subnet_testnet", "Dexalot Subnet Testnet").testnet(),
+    NetworkInfo::new(432204, "dexalot_subnet", "Dexalot Subnet"),
+    NetworkInfo::new(444444, "syndr_l3_sepolia", "Syndr L3 Sepolia").testnet(),
+    NetworkInfo::new(444900, "weelink_testnet", "Weelink Testnet").testnet(),
+    NetworkInfo::new(471100, "patex_sepolia_testnet", "Patex Sepolia Testnet").testnet(),
+    NetworkInfo::new(473861, "ultra_pro", "Ultra Pro"),
+    NetworkInfo::new(474142, "open", "Open"),
+    NetworkInfo::new(486487, "gobbl_testnet", "Gobbl Testnet").testnet(),
+    NetworkInfo::new(490000, "autonomys_testnet_nova_domain", "Autonomys Testnet Nova Domain").testnet(),
+    NetworkInfo::new(504441, "playdapp", "Playdapp"),
+    NetworkInfo::new(512512, "cmp_testnet", "Cmp Testnet").testnet(),
+    NetworkInfo::new(513100, "dis", "Dis"),
+    NetworkInfo::new(526916, "docoin_community", "Docoin Community"),
+    NetworkInfo::new(534351, "scroll_sepolia_testnet", "Scroll Sepolia Testnet").aliases(&["scroll_sepolia"]).testnet().parent(11155111).currency("ETH").explorer("https://sepolia.scrollscan.com"),
+    NetworkInfo::new(534352, "scroll", "Scroll").aliases(&["scroll_mainnet"]).parent(1).currency("ETH").explorer("https://scrollscan.com"),
+    NetworkInfo::new(534353, "scroll_alpha_testnet", "Scroll Alpha Testnet").testnet(),
+    NetworkInfo::new(534354, "scroll_pre_alpha_testnet", "Scroll Pre Alpha Testnet").testnet(),
+    NetworkInfo::new(534849, "shinarium_beta", "Shinarium Beta"),
+    NetworkInfo::new(535037, "beaneco_smart", "Beaneco Smart"),
+    NetworkInfo::new(541764, "overprotocol_testnet", "Overprotocol Testnet").testnet(),
+    NetworkInfo::new(552981, "one_world_testnet", "One World Testnet").testnet(),
+    NetworkInfo::new(555555, "pentagon_testnet", "Pentagon Testnet").testnet(),
+    NetworkInfo::new(555666, "eclipse_testnet", "Eclipse Testnet").testnet(),
+    NetworkInfo::new(555888, "dustboy_iot", "Dustboy Iot"),
+    NetworkInfo::new(622277, "hypra", "Hypra"),
+    NetworkInfo::new(622463, "atlas", "Atlas"),
+    NetworkInfo::new(641230, "bear", "Bear"),
+    NetworkInfo::new(651940, "all", "All"),
+    NetworkInfo::new(656476, "open_campus_codex", "Open Campus Codex"),
+    NetworkInfo::new(660279, "xai", "Xai"),
+    NetworkInfo::new(666666, "vision_vpioneer_test", "Vision Vpioneer Test").testnet(),
+    NetworkInfo::new(666888, "hela_official_runtime_testnet", "Hela Official Runtime Testnet").testnet(),
+    NetworkInfo::new(686868, "won", "Won"),
+    NetworkInfo::new(696969, "galadriel_devnet", "Galadriel Devnet").testnet(),
+    NetworkInfo::new(710420, "tiltyard_subnet", "Tiltyard Subnet"),
+    NetworkInfo::new(713715, "sei_devnet", "Sei Devnet").testnet(),
+    NetworkInfo::new(721529, "eram", "Eram"),
+    NetworkInfo::new(723107, "tix_testnet", "Tix Testnet").testnet(),
+    NetworkInfo::new(743111, "hemi_sepolia", "Hemi Sepolia").testnet(),
+    NetworkInfo::new(751230, "bear_testnet", "Bear Testnet").testnet(),
+    NetworkInfo::new(752024, "ternoa_testnet", "Ternoa Testnet").testnet(),
+    NetworkInfo::new(761412, "miexs_smart", "Miexs Smart"),
+    NetworkInfo::new(764984, "lamina1_testnet", "Lamina1 Testnet").testnet(),
+    NetworkInfo::new(767368, "lamina1_identity_testnet", "Lamina1 Identity Testnet").testnet(),
+    NetworkInfo::new(776877, "modularium", "Modularium"),
+    NetworkInfo::new(800001, "octaspace", "Octaspace"),
+    NetworkInfo::new(808080, "biz_testnet", "Biz Testnet").testnet(),
+    NetworkInfo::new(808813, "bob_sepolia", "Bob Sepolia").testnet(),
+    NetworkInfo::new(810180, "zklink_nova", "Zklink Nova"),
+    NetworkInfo::new(810181, "zklink_nova_sepolia_testnet", "Zklink Nova Sepolia Testnet").testnet(),
+    NetworkInfo::new(810182, "zklink_nova_goerli_testnet", "Zklink Nova Goerli Testnet").testnet(),
+    NetworkInfo::new(812397, "sg_verse", "Sg Verse"),
+    NetworkInfo::new(820522, "tsc_testnet", "Tsc Testnet").testnet(),
+    NetworkInfo::new(827431, "curve", "Curve"),
+    NetworkInfo::new(839320, "prm_testnet", "Prm Testnet").testnet(),
+    NetworkInfo::new(840000, "runevm_testnet", "Runevm Testnet").testnet(),
+    NetworkInfo::new(846000, "4goodnetwork", "4goodnetwork"),
+    NetworkInfo::new(855456, "dodao", "Dodao"),
+    NetworkInfo::new(879151, "blocx", "Blocx"),
+    NetworkInfo::new(888882, "rexx", "Rexx"),
+    NetworkInfo::new(888888, "vision", "Vision"),
+    NetworkInfo::new(900000, "posi_shard_0", "Posi Shard 0"),
+    NetworkInfo::new(910000, "posi_testnet_shard_0", "Posi Testnet Shard 0").testnet(),
+    NetworkInfo::new(912559, "astria_evm_dusknet", "Astria Evm Dusknet"),
+    NetworkInfo::new(920000, "posi_devnet_shard_0", "Posi Devnet Shard 0").testnet(),
+    NetworkInfo::new(920001, "posi_devnet_shard_1", "Posi Devnet Shard 1").testnet(),
+    NetworkInfo::new(923018, "fncy_testnet", "Fncy Testnet").testnet(),
+    NetworkInfo::new(955081, "jono12_subnet", "Jono12 Subnet"),
+    NetworkInfo::new(955305, "eluvio_content_fabric", "Eluvio Content Fabric"),
+    NetworkInfo::new(978657, "treasure_ruby", "Treasure Ruby"),
+    NetworkInfo::new(984122, "forma", "Forma"),
+    NetworkInfo::new(984123, "forma_sketchpad", "Forma Sketchpad"),
+    NetworkInfo::new(988207, "ecrox", "Ecrox"),
+    NetworkInfo::new(998899, "supernet_testnet", "Supernet Testnet").testnet(),
+    NetworkInfo::new(999999, "am", "Am"),
+    NetworkInfo::new(1100789, "netmind_testnet", "Netmind Testnet").testnet(),
+    NetworkInfo::new(1234567, "sharecle", "Sharecle"),
+    NetworkInfo::new(1261120, "zkatana", "Zkatana"),
+    NetworkInfo::new(1313114, "etho_protocol", "Etho Protocol"),
+    NetworkInfo::new(1313500, "xerom", "Xerom"),
+    NetworkInfo::new(1337702, "kintsugi", "Kintsugi"),
+    NetworkInfo::new(1337802, "kiln", "Kiln"),
+    NetworkInfo::new(1337803, "zhejiang", "Zhejiang"),
+    NetworkInfo::new(1398243, "automata_testnet", "Automata Testnet").testnet(),
+    NetworkInfo::new(1398244, "automata_orbit_testnet", "Automata Orbit Testnet").testnet(),
+    NetworkInfo::new(1612127, "playfi_albireo_testnet", "Playfi Albireo Testnet").testnet(),
+    NetworkInfo::new(1637450, "xterio_testnet", "Xterio Testnet").testnet(),
+    NetworkInfo::new(1731313, "turkey_demo_dev", "Turkey Demo Dev"),
+    NetworkInfo::new(2021398, "debank_testnet", "Debank Testnet").testnet(),
+    NetworkInfo::new(2099156, "plian_main", "Plian Main"),
+    NetworkInfo::new(2206132, "platon_dev_testnet2", "Platon Dev Testnet2").testnet(),
+    NetworkInfo::new(2611555, "dpu", "Dpu"),
+    NetworkInfo::new(2702128, "xterio", "Xterio"),
+    NetworkInfo::new(3132023, "saharaai", "Saharaai"),
+    NetworkInfo::new(3141592, "filecoin_butterfly_testnet", "Filecoin Butterfly Testnet").testnet(),
+    NetworkInfo::new(3397901, "funki_sepolia_sandbox", "Funki Sepolia Sandbox").testnet(),
+    NetworkInfo::new(3441005, "manta_pacific_testnet", "Manta Pacific Testnet").testnet(),
+    NetworkInfo::new(3441006, "manta_pacific_sepolia_testnet", "Manta Pacific Sepolia Testnet").testnet(),
+    NetworkInfo::new(4000003, "altlayer_zero_gas", "Altlayer Zero Gas"),
+    NetworkInfo::new(4281033, "worlds_caldera", "Worlds Caldera"),
+    NetworkInfo::new(4444444, "altar_testnet", "Altar Testnet").testnet(),
+    NetworkInfo::new(4457845, "zero_testnet_sepolia", "Zero Testnet Sepolia").testnet(),
+    NetworkInfo::new(5112023, "numblock", "Numblock"),
+    NetworkInfo::new(5167003, "mxc_wannsee_zkevm_testnet", "Mxc Wannsee Zkevm Testnet").testnet(),
+    NetworkInfo::new(5167004, "moon_geneva_testnet", "Moon Geneva Testnet").testnet(),
+    NetworkInfo::new(5201420, "electroneum_testnet", "Electroneum Testnet").testnet(),
+    NetworkInfo::new(5318008, "reactive_kopli", "Reactive Kopli"),
+    NetworkInfo::new(5555555, "imversed", "Imversed"),
+    NetworkInfo::new(5555558, "imversed_testnet", "Imversed Testnet").testnet(),
+    NetworkInfo::new(6038361, "astar_zkyoto", "Astar Zkyoto"),
+    NetworkInfo::new(6666665, "safeanwang", "Safeanwang"),
+    NetworkInfo::new(6666666, "safeanwang_testnet", "Safeanwang Testnet").testnet(),
+    NetworkInfo::new(7082400, "coti_testnet", "Coti Testnet").testnet(),
+    NetworkInfo::new(7225878, "saakuru", "Saakuru"),
+    NetworkInfo::new(7355310, "openvessel", "Openvessel"),
+    NetworkInfo::new(7668378, "ql1_testnet", "Ql1 Testnet").testnet(),
+    NetworkInfo::new(7762959, "musicoin", "Musicoin"),
+    NetworkInfo::new(7777777, "zora", "Zora").parent(1).currency("ETH").explorer("https://explorer.zora.energy"),
+    NetworkInfo::new(8007736, "plian_sub_1", "Plian Sub 1"),
+    NetworkInfo::new(8008135, "fhenix_helium", "Fhenix Helium"),
+    NetworkInfo::new(8080808, "hokum", "Hokum"),
+    NetworkInfo::new(8601152, "waterfall_8_test", "Waterfall 8 Test").testnet(),
+    NetworkInfo::new(8794598, "hap", "Hap"),
+    NetworkInfo::new(8888881, "quarix_testnet", "Quarix Testnet").testnet(),
+    NetworkInfo::new(8888888, "quarix", "Quarix"),
+    NetworkInfo::new(9322252, "xcap", "Xcap"),
+    NetworkInfo::new(9322253, "milvine", "Milvine"),
+    NetworkInfo::new(9999999, "fluence", "Fluence"),
+    NetworkInfo::new(10067275, "plian_testnet_sub_1", "Plian Testnet Sub 1").testnet(),
+    NetworkInfo::new(10101010, "soverun", "Soverun"),
+    NetworkInfo::new(10241024, "alienx", "Alienx"),
+    NetworkInfo::new(10241025, "alienx_hal_testnet", "Alienx Hal Testnet").testnet(),
+    NetworkInfo::new(11145513, "blessnet_sepolia", "Blessnet Sepolia").testnet(),
+    NetworkInfo::new(11155111, "sepolia", "Sepolia").aliases(&["eth_sepolia", "ethereum_sepolia"]).testnet().currency("ETH").explorer("https://sepolia.etherscan.io"),
+    NetworkInfo::new(11155420, "op_sepolia_testnet", "OP Sepolia Testnet").aliases(&["op_sepolia", "optimism_sepolia"]).testnet().parent(11155111).currency("ETH").explorer("https://sepolia-optimism.etherscan.io"),
+    NetworkInfo::new(12052024, "memento_testnet", "Memento Testnet").testnet(),
+    NetworkInfo::new(12227331, "neox_testnet_t3", "Neox Testnet T3").testnet(),
+    NetworkInfo::new(12227332, "neo_x_testnet_t4", "Neo X Testnet T4").testnet(),
+    NetworkInfo::new(13068200, "coti_devnet", "Coti Devnet").testnet(),
+    NetworkInfo::new(13371337, "pep_churchill", "Pep Churchill"),
+    NetworkInfo::new(14288640, "andus", "Andus"),
+    NetworkInfo::new(16658437, "plian_testnet_main", "Plian Testnet Main").testnet(),
+    NetworkInfo::new(18071918, "mande", "Mande"),
+    NetworkInfo::new(18289463, "iolite", "Iolite"),
+    NetworkInfo::new(19850818, "deepbrain_testnet", "Deepbrain Testnet").testnet(),
+    NetworkInfo::new(19880818, "deepbrain", "Deepbrain"),
+    NetworkInfo::new(20180427, "stability_testnet", "Stability Testnet").testnet(),
+    NetworkInfo::new(20180430, "smartmesh", "Smartmesh"),
+    NetworkInfo::new(20181205, "quarkblock", "Quarkblock"),
+    NetworkInfo::new(20201022, "pego", "Pego"),
+    NetworkInfo::new(20230825, "vcity_testnet", "Vcity Testnet").testnet(),
+    NetworkInfo::new(20240324, "debank_sepolia_testnet", "Debank Sepolia Testnet").testnet(),
+    NetworkInfo::new(20240603, "dbk", "Dbk"),
+    NetworkInfo::new(20241133, "swan_proxima_testnet", "Swan Proxima Testnet").testnet(),
+    NetworkInfo::new(20482050, "hokum_testnet", "Hokum Testnet").testnet(),
+    NetworkInfo::new(22052002, "excelon", "Excelon"),
+    NetworkInfo::new(24772477, "6degree_of_outreach_testnet", "6degree Of Outreach Testnet").testnet(),
+    NetworkInfo::new(27082017, "excoincial_volta_testnet", "Excoincial Volta Testnet").testnet(),
+    NetworkInfo::new(27082022, "excoincial", "Excoincial"),
+    NetworkInfo::new(28122024, "ancient8_testnet", "Ancient8 Testnet").testnet(),
+    NetworkInfo::new(28945486, "auxilium", "Auxilium"),
+    NetworkInfo::new(29032022, "fla", "Fla"),
+    NetworkInfo::new(31415926, "filecoin_local_testnet", "Filecoin Local Testnet").testnet(),
+    NetworkInfo::new(33626250, "toliman_suave_testnet", "Toliman Suave Testnet").testnet(),
+    NetworkInfo::new(35855456, "joys_digital", "Joys Digital"),
+    NetworkInfo::new(37084624, "skale_nebula_hub_testnet", "Skale Nebula Hub Testnet").testnet(),
+    NetworkInfo::new(39916801, "kingdom", "Kingdom"),
+    NetworkInfo::new(43214913, "maistestsubnet", "Maistestsubnet").testnet(),
+    NetworkInfo::new(52164803, "fluence_testnet", "Fluence Testnet").testnet(),
+    NetworkInfo::new(61717561, "aqua", "Aqua"),
+    NetworkInfo::new(65010000, "autonity_bakerloo_thames_testnet", "Autonity Bakerloo Thames Testnet").testnet(),
+    NetworkInfo::new(65010001, "autonity_bakerloo_barada_testnet", "Autonity Bakerloo Barada Testnet").testnet(),
+    NetworkInfo::new(65010002, "autonity_bakerloo_sumida_testnet", "Autonity Bakerloo Sumida Testnet").testnet(),
+    NetworkInfo::new(65010003, "autonity_bakerloo_yamuna_testnet", "Autonity Bakerloo Yamuna Testnet").testnet(),
+    NetworkInfo::new(65100000, "autonity_piccadilly_thames_testnet", "Autonity Piccadilly Thames Testnet").testnet(),
+    NetworkInfo::new(65100001, "autonity_piccadilly_barada_testnet", "Autonity Piccadilly Barada Testnet").testnet(),
+    NetworkInfo::new(65100002, "autonity_piccadilly_sumida_testnet", "Autonity Piccadilly Sumida Testnet").testnet(),
+    NetworkInfo::new(65100003, "autonity_piccadilly_yamuna_testnet", "Autonity Piccadilly Yamuna Testnet").testnet(),
+    NetworkInfo::new(68840142, "frame_testnet", "Frame Testnet").testnet(),
+    NetworkInfo::new(77787778, "0xhash_testnet", "0xhash Testnet").testnet(),
+    NetworkInfo::new(79479957, "sx_toronto_rollup", "Sx Toronto Rollup"),
+    NetworkInfo::new(88558801, "backstop_testnet", "Backstop Testnet").testnet(),
+    NetworkInfo::new(88888888, "t.e.a.m_block", "T.e.a.m Block"),
+    NetworkInfo::new(94204209, "polygon_blackberry", "Polygon Blackberry"),
+    NetworkInfo::new(99415706, "joys_digital_testnet", "Joys Digital Testnet").testnet(),
+    NetworkInfo::new(100000000, "ethos", "Ethos"),
+    NetworkInfo::new(108160679, "orai", "Orai"),
+    NetworkInfo::new(111557560, "cyber_testnet", "Cyber Testnet").testnet(),
+    NetworkInfo::new(123420111, "op_celestia_raspberry", "Op Celestia Raspberry"),
+    NetworkInfo::new(161221135, "plume_testnet", "Plume Testnet").testnet(),
+    NetworkInfo::new(168587773, "blast_sepolia_testnet", "Blast Sepolia Testnet").testnet(),
+    NetworkInfo::new(192837465, "gather", "Gather"),
+    NetworkInfo::new(222000222, "kanazawa", "Kanazawa"),
+    NetworkInfo::new(245022926, "neon_evm_devnet", "Neon Evm Devnet").testnet(),
+    NetworkInfo::new(245022929, "neon_evm_devnet_rollup", "Neon Evm Devnet Rollup").testnet(),
+    NetworkInfo::new(245022934, "neon_evm", "Neon Evm"),
+    NetworkInfo::new(245022940, "neon_evm_testnet", "Neon Evm Testnet").testnet(),
+    NetworkInfo::new(278611351, "razor_skale", "Razor Skale"),
+    NetworkInfo::new(311752642, "oneledger", "Oneledger"),
+    NetworkInfo::new(328527624, "nal_sepolia_testnet", "Nal Sepolia Testnet").testnet(),
+    NetworkInfo::new(333000333, "meld", "Meld"),
+    NetworkInfo::new(356256156, "gather_testnet", "Gather Testnet").testnet(),
+    NetworkInfo::new(476462898, "skopje_testnet", "Skopje Testnet").testnet(),
+    NetworkInfo::new(486217935, "gather_devnet", "Gather Devnet").testnet(),
+    NetworkInfo::new(531050104, "sophon_testnet", "Sophon Testnet").testnet(),
+    NetworkInfo::new(666666666, "degen", "Degen"),
+    NetworkInfo::new(728126428, "tron", "Tron"),
+    NetworkInfo::new(888888888, "ancient8", "Ancient8"),
+    NetworkInfo::new(889910245, "ptcescan_testnet", "Ptcescan Testnet").testnet(),
+    NetworkInfo::new(889910246, "ptcescan", "Ptcescan"),
+    NetworkInfo::new(974399131, "skale_calypso_hub_testnet", "Skale Calypso Hub Testnet").testnet(),
+    NetworkInfo::new(999999999, "zora_sepolia_testnet", "Zora Sepolia Testnet").testnet(),
+    NetworkInfo::new(1020352220, "skale_titan_hub_testnet", "Skale Titan Hub Testnet").testnet(),
+    NetworkInfo::new(1122334455, "ipos", "Ipos"),
+    NetworkInfo::new(1146703430, "cyberdecknet", "Cyberdecknet"),
+    NetworkInfo::new(1273227453, "human_protocol", "Human Protocol"),
+    NetworkInfo::new(1313161554, "aurora", "Aurora"),
+    NetworkInfo::new(1313161555, "aurora_testnet", "Aurora Testnet").testnet(),
+    NetworkInfo::new(1313161556, "aurora_betanet", "Aurora Betanet"),
+    NetworkInfo::new(1313161560, "powergold", "Powergold"),
+    NetworkInfo::new(1350216234, "skale_titan_hub", "Skale Titan Hub"),
+    NetworkInfo::new(1351057110, "chaos_skale_testnet", "Chaos Skale Testnet").testnet(),
+    NetworkInfo::new(1380012617, "rari", "Rari"),
+    NetworkInfo::new(1380996178, "raptor", "Raptor"),
+    NetworkInfo::new(1444673419, "skale_europa_hub_testnet", "Skale Europa Hub Testnet").testnet(),
+    NetworkInfo::new(1482601649, "skale_nebula_hub", "Skale Nebula Hub"),
+    NetworkInfo::new(1511670449, "gpt", "Gpt"),
+    NetworkInfo::new(1564830818, "skale_calypso_hub", "Skale Calypso Hub"),
+    NetworkInfo::new(1666600000, "harmony_shard_0", "Harmony Shard 0"),
+    NetworkInfo::new(1666600001, "harmony_shard_1", "Harmony Shard 1"),
+    NetworkInfo::new(1666600002, "harmony_shard_2", "Harmony Shard 2"),
+    NetworkInfo::new(1666600003, "harmony_shard_3", "Harmony Shard 3"),
+    NetworkInfo::new(1666700000, "harmony_testnet_shard_0", "Harmony Testnet Shard 0").testnet(),
+    NetworkInfo::new(1666700001, "harmony_testnet_shard_1", "Harmony Testnet Shard 1").testnet(),
+    NetworkInfo::new(1666900000, "harmony_devnet_shard_0", "Harmony Devnet Shard 0").testnet(),
+    NetworkInfo::new(1666900001, "harmony_devnet_shard_1", "Harmony Devnet Shard 1").testnet(),
+    NetworkInfo::new(1722641160, "silicon_zkevm_sepolia_testnet", "Silicon Zkevm Sepolia Testnet").testnet(),
+    NetworkInfo::new(1802203764, "kakarot_sepolia", "Kakarot Sepolia").testnet(),
+    NetworkInfo::new(1903648807, "gemu_testnet", "Gemu Testnet").testnet(),
+    NetworkInfo::new(1918988905, "rari_testnet", "Rari Testnet").testnet(),
+    NetworkInfo::new(2021121117, "datahopper", "Datahopper"),
+    NetworkInfo::new(2046399126, "skale_europa_hub", "Skale Europa Hub"),
+    NetworkInfo::new(3125659152, "pirl", "Pirl"),
+    NetworkInfo::new(4216137055, "oneledger_testnet_frankenstein", "Oneledger Testnet Frankenstein").testnet(),
+    NetworkInfo::new(11297108099, "palm_testnet", "Palm Testnet").testnet(),
+    NetworkInfo::new(28872323069, "gitswarm_test", "Gitswarm Test").testnet(),
+    NetworkInfo::new(37714555429, "xai_testnet_v2", "Xai Testnet V2").testnet(),
+    NetworkInfo::new(88153591557, "arbitrum_blueberry", "Arbitrum Blueberry"),
+    NetworkInfo::new(111222333444, "alphabet", "Alphabet"),
+    NetworkInfo::new(111551119090, "thanos_sepolia", "Thanos Sepolia").testnet(),
+    NetworkInfo::new(123420000220, "fluence_stage", "Fluence Stage"),
+    NetworkInfo::new(197710212030, "ntity", "Ntity"),
+    NetworkInfo::new(197710212031, "haradev_testnet", "Haradev Testnet").testnet(),
+    NetworkInfo::new(202402181627, "gm_testnet", "Gm Testnet").testnet(),
+    NetworkInfo::new(383414847825, "zeniq", "Zeniq"),
+    NetworkInfo::new(666301171999, "pdc", "Pdc"),
+    NetworkInfo::new(6022140761023, "molereum", "Molereum"),
+    NetworkInfo::new(2713017997578000, "d_testnet", "D Testnet").testnet(),
 ];
@@ -1,59 +1,122 @@
-use crate::{ChainId, Endpoint, MescError, Profile, RpcConfig, TryIntoChainId};
+use crate::{
+    directory, query::is_url, ChainId, Endpoint, MescError, OverrideMode, Profile, RpcConfig,
+    TryIntoChainId,
+};
 use std::collections::HashMap;
 
-/// get active overrides
+/// override variables, in the order that they are applied
+pub const OVERRIDE_VARS: [&str; 7] = [
+    "MESC_ENDPOINTS",
+    "MESC_NETWORK_NAMES",
+    "MESC_NETWORK_DEFAULTS",
+    "MESC_PROFILES",
+    "MESC_DEFAULT_ENDPOINT",
+    "MESC_GLOBAL_METADATA",
+    "MESC_ENDPOINT_METADATA",
+];
+
+/// get names of override variables that are set to non-empty values
 pub fn get_active_overrides() -> Vec<String> {
-    let overrides = [
-        "MESC_NETWORK_NAMES",
-        "MESC_NETWORK_DEFAULTS",
-        "MESC_ENDPOINTS",
-        "MESC_DEFAULT_ENDPOINT",
-        "MESC_GLOBAL_METADATA",
-        "MESC_ENDPOINT_METADATA",
-        "MESC_PROFILES",
-    ];
-    overrides.iter().filter_map(|v| std::env::var(v).ok()).collect()
+    OVERRIDE_VARS
+        .iter()
+        .filter(|var| matches!(std::env::var(var).as_deref(), Ok(value) if !value.is_empty()))
+        .map(|var| var.to_string())
+        .collect()
 }
 
 /// apply overrides to config
+///
+/// map-valued overrides are merged key by key into the config, unless their value starts with
+/// `!`, in which case they replace the corresponding config value
 pub fn apply_overrides(config: &mut RpcConfig) -> Result<(), MescError> {
-    if let Some(default_endpoint) = get_default_endpoint_override() {
-        if !default_endpoint.is_empty() {
-            config.default_endpoint = Some(default_endpoint)
-        }
-    }
-    if let Some(network_defaults) = get_network_defaults_override()? {
-        config.network_defaults = network_defaults;
-    }
-    if let Some(network_names) = get_network_names_override()? {
-        config.network_names = network_names
+    apply_overrides_with_mode(config, OverrideMode::Merge)
+}
+
+/// apply overrides to config, using given mode for map-valued overrides
+///
+/// applies to `MESC_NETWORK_DEFAULTS`, `MESC_NETWORK_NAMES`, and `MESC_PROFILES`
+pub fn apply_overrides_with_mode(
+    config: &mut RpcConfig,
+    mode: OverrideMode,
+) -> Result<(), MescError> {
+    for var in OVERRIDE_VARS.iter() {
+        apply_override(config, var, mode)?;
     }
-    if let Some(endpoints) = get_endpoints_override()? {
-        for (endpoint_name, endpoint) in endpoints.into_iter() {
-            if let Some(current_endpoint) = config.endpoints.get_mut(&endpoint_name) {
-                if endpoint.chain_id.is_some() {
-                    current_endpoint.chain_id = endpoint.chain_id;
+    Ok(())
+}
+
+/// apply a single override variable to config
+pub fn apply_override(
+    config: &mut RpcConfig,
+    var: &str,
+    mode: OverrideMode,
+) -> Result<(), MescError> {
+    match var {
+        "MESC_ENDPOINTS" => {
+            if let Some(endpoints) = get_endpoints_override()? {
+                for (endpoint_name, endpoint) in endpoints.into_iter() {
+                    if let Some(current_endpoint) = config.endpoints.get_mut(&endpoint_name) {
+                        if endpoint.chain_id.is_some() {
+                            current_endpoint.chain_id = endpoint.chain_id;
+                        }
+                        current_endpoint.url = endpoint.url;
+                    } else {
+                        config.endpoints.insert(endpoint_name, endpoint);
+                    }
                 }
-                current_endpoint.url = endpoint.url;
-            } else {
-                config.endpoints.insert(endpoint_name, endpoint);
             }
         }
-    }
-    if let Some(profiles) = get_profiles_override()? {
-        config.profiles = profiles
-    }
-    if let Some(global_metadata) = get_global_metadata_override()? {
-        config.global_metadata.extend(global_metadata)
-    }
-    if let Some(endpoint_metadatas) = get_endpoint_metadata_override()? {
-        for (name, metadata) in endpoint_metadatas.into_iter() {
-            if let Some(endpoint) = config.endpoints.get_mut(&name) {
-                endpoint.endpoint_metadata.extend(metadata)
-            } else {
-                return Err(MescError::OverrideError(format!("endpoint does not exist: {}", name)));
+        "MESC_NETWORK_NAMES" => {
+            if let Some(network_names) = get_network_names_override(config, mode)? {
+                config.network_names = network_names
             }
         }
+        "MESC_NETWORK_DEFAULTS" => {
+            if let Some(network_defaults) = get_network_defaults_override(config, mode)? {
+                config.network_defaults = network_defaults;
+            }
+        }
+        "MESC_PROFILES" => {
+            if let Some(profiles) = get_profiles_override(config, mode)? {
+                config.profiles = profiles
+            }
+        }
+        "MESC_DEFAULT_ENDPOINT" => {
+            if let Some(default_endpoint) = get_default_endpoint_override() {
+                if !default_endpoint.is_empty() {
+                    let network_defaults = config.network_defaults.clone();
+                    let endpoint = resolve_default_endpoint(
+                        config,
+                        &default_endpoint,
+                        &network_defaults,
+                        |message| {
+                            MescError::OverrideError(format!("MESC_DEFAULT_ENDPOINT: {}", message))
+                        },
+                    )?;
+                    config.default_endpoint = Some(endpoint)
+                }
+            }
+        }
+        "MESC_GLOBAL_METADATA" => {
+            if let Some(global_metadata) = get_global_metadata_override()? {
+                config.global_metadata.extend(global_metadata)
+            }
+        }
+        "MESC_ENDPOINT_METADATA" => {
+            if let Some(endpoint_metadatas) = get_endpoint_metadata_override()? {
+                for (name, metadata) in endpoint_metadatas.into_iter() {
+                    if let Some(endpoint) = config.endpoints.get_mut(&name) {
+                        endpoint.endpoint_metadata.extend(metadata)
+                    } else {
+                        return Err(MescError::OverrideError(format!(
+                            "endpoint does not exist: {}",
+                            name
+                        )));
+                    }
+                }
+            }
+        }
+        _ => return Err(MescError::OverrideError(format!("unknown override variable: {}", var))),
     }
 
     Ok(())
@@ -63,21 +126,62 @@ fn get_default_endpoint_override() -> Option<String> {
     std::env::var("MESC_DEFAULT_ENDPOINT").ok()
 }
 
-fn get_network_defaults_override() -> Result<Option<HashMap<ChainId, String>>, MescError> {
+/// resolve default endpoint value, which can be an endpoint name, url, or network
+///
+/// networks resolve to their entry in network_defaults
+fn resolve_default_endpoint(
+    config: &mut RpcConfig,
+    value: &str,
+    network_defaults: &HashMap<ChainId, String>,
+    error: impl Fn(String) -> MescError,
+) -> Result<String, MescError> {
+    // by endpoint name
+    if config.endpoints.contains_key(value) {
+        return Ok(value.to_string());
+    }
+
+    // by url
+    if is_url(value) {
+        return insert_url_endpoint(config, value, None);
+    }
+
+    // by chain_id or network name
+    let chain_id = resolve_chain_id(config, value)
+        .ok_or_else(|| error(format!("not an endpoint name, url, or network: {}", value)))?;
+    match network_defaults.get(&chain_id) {
+        Some(endpoint_name) => Ok(endpoint_name.clone()),
+        None => Err(error(format!("no default endpoint for network: {}", value))),
+    }
+}
+
+fn get_network_defaults_override(
+    config: &mut RpcConfig,
+    mode: OverrideMode,
+) -> Result<Option<HashMap<ChainId, String>>, MescError> {
     if let Ok(raw) = std::env::var("MESC_NETWORK_DEFAULTS") {
         if raw.is_empty() {
             return Ok(None);
         }
-        let mut network_defaults = HashMap::new();
-        for piece in raw.split(' ') {
-            match piece.split('=').collect::<Vec<&str>>().as_slice() {
-                [chain_id, endpoint] => {
-                    network_defaults.insert(chain_id.try_into_chain_id()?, endpoint.to_string());
+        let (mode, items) = tokenize_map_override("MESC_NETWORK_DEFAULTS", &raw, mode)?;
+        let mut network_defaults = match mode {
+            OverrideMode::Merge => config.network_defaults.clone(),
+            OverrideMode::Replace => HashMap::new(),
+        };
+        for item in items.iter() {
+            // an empty value removes the network default
+            let (network, endpoint) = match item.split() {
+                Some((network, "")) if !network.is_empty() => (network, None),
+                _ => item.split_pair().map(|(network, endpoint)| (network, Some(endpoint)))?,
+            };
+            let chain_id = resolve_chain_id(config, network)
+                .ok_or_else(|| item.error(0, format!("unknown network: {}", network)))?;
+            match endpoint {
+                Some(endpoint) => {
+                    let endpoint = resolve_endpoint(config, endpoint, Some(chain_id.clone()))?;
+                    network_defaults.insert(chain_id, endpoint);
                 }
-                _ => {
-                    return Err(MescError::OverrideError(
-                        "invalid network default override".to_string(),
-                    ))
+                None => {
+                    network_defaults.remove(&chain_id);
                 }
             }
         }
@@ -87,23 +191,87 @@ fn get_network_defaults_override() -> Result<Option<HashMap<ChainId, String>>, M
     }
 }
 
-fn get_network_names_override() -> Result<Option<HashMap<String, ChainId>>, MescError> {
+/// resolve chain_id or network name into a chain_id
+fn resolve_chain_id(config: &RpcConfig, network: &str) -> Option<ChainId> {
+    if let Ok(chain_id) = network.try_into_chain_id() {
+        Some(chain_id)
+    } else if let Some(chain_id) = config.network_names.get(network) {
+        Some(chain_id.clone())
+    } else {
+        directory::get_network_chain_id(network)
+    }
+}
+
+/// resolve endpoint name or url into endpoint name, creating an endpoint for new url's
+fn resolve_endpoint(
+    config: &mut RpcConfig,
+    value: &str,
+    chain_id: Option<ChainId>,
+) -> Result<String, MescError> {
+    if !config.endpoints.contains_key(value) && is_url(value) {
+        insert_url_endpoint(config, value, chain_id)
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+/// create endpoint for url given in override, reusing an existing endpoint with the same url
+///
+/// existing endpoints are only reused if their chain_id matches, they are never modified
+fn insert_url_endpoint(
+    config: &mut RpcConfig,
+    url: &str,
+    chain_id: Option<ChainId>,
+) -> Result<String, MescError> {
+    let mut matches: Vec<&Endpoint> = config
+        .endpoints
+        .values()
+        .filter(|endpoint| {
+            endpoint.url == url && (chain_id.is_none() || endpoint.chain_id == chain_id)
+        })
+        .collect();
+    matches.sort_by(|e1, e2| e1.name.cmp(&e2.name));
+    if let Some(endpoint) = matches.first() {
+        return Ok(endpoint.name.clone());
+    }
+
+    let base_name = get_default_endpoint_name(url, chain_id.clone())
+        .ok_or(MescError::OverrideError("could not create endpoint name".to_string()))?;
+    let mut name = base_name.clone();
+    let mut suffix = 1;
+    while config.endpoints.contains_key(&name) {
+        suffix += 1;
+        name = format!("{base_name}_{suffix}");
+    }
+    let endpoint = Endpoint {
+        name: name.clone(),
+        url: url.to_string(),
+        chain_id,
+        endpoint_metadata: HashMap::new(),
+    };
+    config.endpoints.insert(name.clone(), endpoint);
+    Ok(name)
+}
+
+fn get_network_names_override(
+    config: &RpcConfig,
+    mode: OverrideMode,
+) -> Result<Option<HashMap<String, ChainId>>, MescError> {
     if let Ok(raw) = std::env::var("MESC_NETWORK_NAMES") {
         if raw.is_empty() {
             return Ok(None);
         }
-        let mut network_names = HashMap::new();
-        for piece in raw.split(' ') {
-            match piece.split('=').collect::<Vec<&str>>().as_slice() {
-                [name, chain_id] => {
-                    network_names.insert(name.to_string(), chain_id.try_into_chain_id()?);
-                }
-                _ => {
-                    return Err(MescError::OverrideError(
-                        "invalid network name override".to_string(),
-                    ))
-                }
-            }
+        let (mode, items) = tokenize_map_override("MESC_NETWORK_NAMES", &raw, mode)?;
+        let mut network_names = match mode {
+            OverrideMode::Merge => config.network_names.clone(),
+            OverrideMode::Replace => HashMap::new(),
+        };
+        for item in items.iter() {
+            let (name, chain_id) = item.split_pair()?;
+            let chain_id = chain_id
+                .try_into_chain_id()
+                .map_err(|_| item.error_at_value(format!("invalid chain_id: {}", chain_id)))?;
+            network_names.insert(name.to_string(), chain_id);
         }
         Ok(Some(network_names))
     } else {
@@ -117,8 +285,8 @@ fn get_endpoints_override() -> Result<Option<HashMap<String, Endpoint>>, MescErr
             return Ok(None);
         }
         let mut endpoints = HashMap::new();
-        for piece in raw.split(' ') {
-            let endpoint = parse_endpoint(piece)?;
+        for item in tokenize_override("MESC_ENDPOINTS", &raw, 0)?.iter() {
+            let endpoint = parse_endpoint(item)?;
             endpoints.insert(endpoint.name.clone(), endpoint);
         }
         Ok(Some(endpoints))
@@ -127,37 +295,54 @@ fn get_endpoints_override() -> Result<Option<HashMap<String, Endpoint>>, MescErr
     }
 }
 
-fn parse_endpoint(input: &str) -> Result<Endpoint, MescError> {
-    let mut parts = input.split('=');
-    let (name_chain, url) = match (parts.next(), parts.next()) {
-        (Some(name), Some(url)) => (name, url),
-        (Some(url), None) => ("", url),
-        _ => return Err(MescError::OverrideError("invalid endpoint override".to_string())),
+/// parse endpoint item of the form `[<name>[:<chain_id>]=]<url>`
+///
+/// the text before the first `=` is only used as a name if it is a valid name, so that url's
+/// containing `=` (e.g. in query parameters) can be given without a name
+fn parse_endpoint(item: &OverrideItem) -> Result<Endpoint, MescError> {
+    let (name_chain, url) = match item.split() {
+        Some((name_chain, url)) if is_endpoint_target(name_chain) => (Some(name_chain), url),
+        _ => (None, item.text.as_str()),
     };
+    if url.is_empty() {
+        return Err(item.error_at_value("missing url".to_string()));
+    }
 
-    let mut name_chain_parts = name_chain.split(':');
-    let name = name_chain_parts
-        .next()
-        .map(|s| s.to_string())
-        .unwrap_or(
-            get_default_endpoint_name(url, None)
-                .ok_or(MescError::OverrideError("could not create endpoint name".to_string()))?,
-        )
-        .to_string();
+    let (name, chain_id) = match name_chain.map(|name_chain| name_chain.split_once(':')) {
+        Some(Some((name, chain_id))) => {
+            let chain_id = chain_id.try_into_chain_id().map_err(|_| {
+                item.error(name.len() + 1, format!("invalid chain_id: {}", chain_id))
+            })?;
+            (name.to_string(), Some(chain_id))
+        }
+        Some(None) => (name_chain.unwrap_or_default().to_string(), None),
+        None => (String::new(), None),
+    };
     let name = if name.is_empty() {
         get_default_endpoint_name(url, None)
-            .ok_or(MescError::OverrideError("could not create endpoint name".to_string()))?
+            .ok_or_else(|| item.error(0, "could not create endpoint name".to_string()))?
     } else {
         name
     };
-    let chain_id = match name_chain_parts.next() {
-        Some(chain_id) => Some(chain_id.try_into_chain_id()?),
-        None => None,
-    };
 
     Ok(Endpoint { name, url: url.to_string(), chain_id, endpoint_metadata: HashMap::new() })
 }
 
+/// whether text is of the form `<name>[:<chain_id>]`, where chain_id may be CAIP-2
+fn is_endpoint_target(text: &str) -> bool {
+    let name = match text.split_once(':') {
+        Some((name, chain_id)) => {
+            let valid = |c: char| c.is_ascii_alphanumeric() || c == ':' || c == '-' || c == '_';
+            if chain_id.is_empty() || !chain_id.chars().all(valid) {
+                return false;
+            }
+            name
+        }
+        None => text,
+    };
+    name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.')
+}
+
 /// get default endpoint name for a url
 pub fn get_default_endpoint_name(url: &str, chain_id: Option<ChainId>) -> Option<String> {
     // Find the start of the main part of the URL, skipping the protocol if present
@@ -170,8 +355,9 @@ pub fn get_default_endpoint_name(url: &str, chain_id: Option<ChainId>) -> Option
     // string
     let end = main_part.find('/').unwrap_or(main_part.len());
 
-    // Extract the domain part
+    // Extract the domain part, dropping the port if present
     let domain_part = &main_part[..end];
+    let domain_part = domain_part.split(':').next().unwrap_or(domain_part);
 
     // Check if the domain part contains a period, which is typical for a hostname
     let hostname = if domain_part.contains('.') {
@@ -184,16 +370,28 @@ pub fn get_default_endpoint_name(url: &str, chain_id: Option<ChainId>) -> Option
     } else {
         domain_part.to_string()
     };
-    let hostname =
-        if let Some(split) = hostname.split('.').last() { split.to_string() } else { hostname };
+    let hostname = if let Some(split) = hostname.split('.').next_back() {
+        split.to_string()
+    } else {
+        hostname
+    };
 
+    // chain id is spelled canonically, so names do not depend on how it was written
     match chain_id {
-        Some(chain_id) => Some(format!("{hostname}_{chain_id}")),
+        Some(chain_id) if chain_id.is_evm() => {
+            Some(format!("{hostname}_{}", chain_id.to_decimal()))
+        }
+        Some(chain_id) => {
+            Some(format!("{hostname}_{}_{}", chain_id.namespace(), chain_id.reference()))
+        }
         None => Some(hostname),
     }
 }
 
-fn get_profiles_override() -> Result<Option<HashMap<String, Profile>>, MescError> {
+fn get_profiles_override(
+    config: &mut RpcConfig,
+    mode: OverrideMode,
+) -> Result<Option<HashMap<String, Profile>>, MescError> {
     let raw = match std::env::var("MESC_PROFILES") {
         Ok(raw) => raw,
         Err(_) => return Ok(None),
@@ -202,59 +400,115 @@ fn get_profiles_override() -> Result<Option<HashMap<String, Profile>>, MescError
         return Ok(None);
     }
 
-    let mut profiles: HashMap<String, Profile> = HashMap::new();
-
-    // Splitting the string into entries
-    let entries = raw.split_whitespace().collect::<Vec<&str>>();
+    let (mode, items) = tokenize_map_override("MESC_PROFILES", &raw, mode)?;
+    let mut profiles: HashMap<String, Profile> = match mode {
+        OverrideMode::Merge => config.profiles.clone(),
+        OverrideMode::Replace => HashMap::new(),
+    };
 
-    for entry in entries {
-        let mut parts = entry.split('=');
-        let (left_side, endpoint) = match (parts.next(), parts.next()) {
-            (Some(l), Some(r)) => (l, r),
-            _ => {
-                return Err(MescError::OverrideError(format!(
-                    "invalid profiles override, bad match: {}",
-                    entry
-                )))
-            }
-        };
+    for item in items.iter() {
+        let (left_side, value) = item.split_pair()?;
 
-        let mut left_parts = left_side.split('.');
-        let (profile_name, key, chain_id) =
+        let mut left_parts = left_side.splitn(3, '.');
+        let (profile_name, key, subkey) =
             match (left_parts.next(), left_parts.next(), left_parts.next()) {
-                (Some(profile), Some(key), Some(chain_id)) => (profile, key, Some(chain_id)),
-                (Some(profile), Some(key), None) => (profile, key, None),
-                _ => {
-                    return Err(MescError::OverrideError(format!(
-                        "invalid profiles override, bad target: {}",
-                        left_side
-                    )))
-                }
+                (Some(profile), Some(key), subkey) if !profile.is_empty() => (profile, key, subkey),
+                _ => return Err(item.error(0, format!("bad target: {}", left_side))),
             };
+        let subkey_offset = profile_name.len() + key.len() + 2;
 
         let profile = profiles
             .entry(profile_name.to_string())
             .or_insert_with(|| Profile::new(profile_name.to_string()));
 
-        match key {
-            "default_endpoint" => profile.default_endpoint = Some(endpoint.to_string()),
-            "network_defaults" => {
-                if let Some(cid) = chain_id {
-                    profile.network_defaults.insert(cid.try_into_chain_id()?, endpoint.to_string());
+        match (key, subkey) {
+            ("default_endpoint", None) => {
+                // networks resolve using network defaults of profile, then of config
+                let mut network_defaults = config.network_defaults.clone();
+                network_defaults.extend(profile.network_defaults.clone());
+                let endpoint =
+                    resolve_default_endpoint(config, value, &network_defaults, |message| {
+                        item.error_at_value(message)
+                    })?;
+                profile.default_endpoint = Some(endpoint)
+            }
+            ("network_defaults", Some(network)) => {
+                let chain_id = resolve_chain_id(config, network).ok_or_else(|| {
+                    item.error(subkey_offset, format!("unknown network: {}", network))
+                })?;
+                let endpoint = resolve_endpoint(config, value, Some(chain_id.clone()))?;
+                profile.network_defaults.insert(chain_id, endpoint);
+            }
+            ("use_mesc", None) => {
+                profile.use_mesc = match value {
+                    "true" => true,
+                    "false" => false,
+                    _ => {
+                        return Err(item.error_at_value(format!(
+                            "use_mesc must be true or false, got: {}",
+                            value
+                        )))
+                    }
                 }
             }
-            _ => {
-                return Err(MescError::OverrideError(format!(
-                    "invalid profile override, bad key: {}",
-                    key
-                )));
+            ("profile_metadata", None) => match parse_metadata_value(value) {
+                serde_json::Value::Object(metadata) => profile.profile_metadata.extend(metadata),
+                _ => {
+                    return Err(
+                        item.error_at_value("profile_metadata must be a JSON object".to_string())
+                    )
+                }
+            },
+            ("profile_metadata", Some(path)) => {
+                insert_metadata_path(
+                    &mut profile.profile_metadata,
+                    path,
+                    parse_metadata_value(value),
+                )
+                .map_err(|message| item.error(subkey_offset, message))?;
             }
+            _ => return Err(item.error(profile_name.len() + 1, format!("bad key: {}", left_side))),
         }
     }
 
     Ok(Some(profiles))
 }
 
+/// parse metadata value given in an override as JSON, treating non-JSON values as strings
+fn parse_metadata_value(value: &str) -> serde_json::Value {
+    serde_json::from_str(value).unwrap_or_else(|_| serde_json::Value::String(value.to_string()))
+}
+
+/// insert value into metadata at a dot-separated path, creating intermediate objects as needed
+fn insert_metadata_path(
+    metadata: &mut Metadata,
+    path: &str,
+    value: serde_json::Value,
+) -> Result<(), String> {
+    let mut keys = path.split('.');
+    let first = keys.next().unwrap_or_default();
+    if first.is_empty() {
+        return Err(format!("bad metadata path: {}", path));
+    }
+    let mut current = metadata.entry(first.to_string()).or_insert(serde_json::Value::Null);
+    for key in keys {
+        if key.is_empty() {
+            return Err(format!("bad metadata path: {}", path));
+        }
+        if current.is_null() {
+            *current = serde_json::Value::Object(serde_json::Map::new());
+        }
+        current = match current {
+            serde_json::Value::Object(map) => {
+                map.entry(key.to_string()).or_insert(serde_json::Value::Null)
+            }
+            _ => return Err(format!("metadata path is not an object: {}", path)),
+        };
+    }
+    *current = value;
+    Ok(())
+}
+
 fn get_global_metadata_override() -> Result<Option<HashMap<String, serde_json::Value>>, MescError> {
     if let Ok(raw) = std::env::var("MESC_GLOBAL_METADATA") {
         if raw.is_empty() {
@@ -282,3 +536,508 @@ fn get_endpoint_metadata_override() -> Result<Option<HashMap<String, Metadata>>,
         Ok(None)
     }
 }
+
+//
+// // tokenizer shared by override variables
+//
+
+/// single whitespace-separated item of an override variable
+///
+/// items can be quoted with `'` or `"`, and `\` escapes the next character. quoted or escaped
+/// characters never act as separators
+#[derive(Debug)]
+struct OverrideItem {
+    /// name of override variable
+    var: &'static str,
+    /// item text, with quotes and escapes removed
+    text: String,
+    /// column of each char of text within the raw variable, 1-indexed
+    columns: Vec<usize>,
+    /// byte offset of first unquoted and unescaped `=` within text
+    separator: Option<usize>,
+    /// column where item starts within the raw variable, used for errors of empty items
+    start: usize,
+}
+
+impl OverrideItem {
+    fn new(var: &'static str, start: usize) -> OverrideItem {
+        OverrideItem { var, text: String::new(), columns: Vec::new(), separator: None, start }
+    }
+
+    /// split item into text before and after the first separator
+    fn split(&self) -> Option<(&str, &str)> {
+        self.separator.map(|index| (&self.text[..index], &self.text[index + 1..]))
+    }
+
+    /// split item of the form `<key>=<value>`, requiring both sides to be non-empty
+    fn split_pair(&self) -> Result<(&str, &str), MescError> {
+        match self.split() {
+            Some(("", _)) => Err(self.error(0, "missing key before '='".to_string())),
+            Some((_, "")) => Err(self.error_at_value("missing value after '='".to_string())),
+            Some(pair) => Ok(pair),
+            None => Err(self.error(0, format!("expected <key>=<value>, got: {}", self.text))),
+        }
+    }
+
+    /// create error located at a byte offset of the item text
+    fn error(&self, offset: usize, message: String) -> MescError {
+        let char_index = self.text.get(..offset).map(|s| s.chars().count()).unwrap_or_default();
+        let column =
+            self.columns.get(char_index).or(self.columns.last()).copied().unwrap_or(self.start);
+        MescError::OverrideError(format!("{} column {}: {}", self.var, column, message))
+    }
+
+    /// create error located at the value of the item
+    fn error_at_value(&self, message: String) -> MescError {
+        self.error(self.separator.map(|index| index + 1).unwrap_or_default(), message)
+    }
+}
+
+/// split raw map-valued override variable into items, detecting a leading `!` replace marker
+fn tokenize_map_override(
+    var: &'static str,
+    raw: &str,
+    mode: OverrideMode,
+) -> Result<(OverrideMode, Vec<OverrideItem>), MescError> {
+    match raw.strip_prefix('!') {
+        Some(raw) => Ok((OverrideMode::Replace, tokenize_override(var, raw, 1)?)),
+        None => Ok((mode, tokenize_override(var, raw, 0)?)),
+    }
+}
+
+/// split raw override variable into items, with columns shifted by offset
+fn tokenize_override(
+    var: &'static str,
+    raw: &str,
+    offset: usize,
+) -> Result<Vec<OverrideItem>, MescError> {
+    let mut items = Vec::new();
+    let mut current: Option<OverrideItem> = None;
+    let mut quote: Option<(char, usize)> = None;
+    let mut chars = raw.chars().enumerate().map(|(index, c)| (offset + index + 1, c));
+
+    while let Some((column, c)) = chars.next() {
+        let (c, literal) = match (c, quote) {
+            (c, Some((open, _))) if c == open => {
+                quote = None;
+                continue;
+            }
+            ('\\', _) => match chars.next() {
+                Some((_, escaped)) => (escaped, true),
+                None => {
+                    return Err(MescError::OverrideError(format!(
+                        "{} column {}: trailing escape character",
+                        var, column
+                    )))
+                }
+            },
+            (c, Some(_)) => (c, true),
+            ('"' | '\'', None) => {
+                quote = Some((c, column));
+                current.get_or_insert_with(|| OverrideItem::new(var, column));
+                continue;
+            }
+            (c, None) if c.is_whitespace() => {
+                items.extend(current.take());
+                continue;
+            }
+            (c, None) => (c, false),
+        };
+        let item = current.get_or_insert_with(|| OverrideItem::new(var, column));
+        if c == '=' && !literal && item.separator.is_none() {
+            item.separator = Some(item.text.len());
+        }
+        item.text.push(c);
+        item.columns.push(column);
+    }
+    if let Some((open, column)) = quote {
+        return Err(MescError::OverrideError(format!(
+            "{} column {}: unterminated quote {}",
+            var, column, open
+        )));
+    }
+    items.extend(current);
+
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(name: &str, url: &str, chain_id: u64) -> Endpoint {
+        Endpoint {
+            name: name.to_string(),
+            url: url.to_string(),
+            chain_id: Some(ChainId::from(chain_id)),
+            endpoint_metadata: HashMap::new(),
+        }
+    }
+
+    fn base_config() -> RpcConfig {
+        let mut config = RpcConfig::default();
+        for endpoint in [
+            endpoint("local_mainnet", "http://localhost:8545", 1),
+            endpoint("my_op", "https://optimism.example.com", 10),
+            endpoint("other_op", "https://op.example.org", 10),
+        ] {
+            config.endpoints.insert(endpoint.name.clone(), endpoint);
+        }
+        config.network_defaults.insert(ChainId::new(1), "local_mainnet".to_string());
+        config.network_defaults.insert(ChainId::new(10), "other_op".to_string());
+        config.network_names.insert("devnet".to_string(), ChainId::new(1337));
+
+        let mut foundry = Profile::new("foundry");
+        foundry.default_endpoint = Some("local_mainnet".to_string());
+        foundry.profile_metadata.insert("verbose".to_string(), serde_json::Value::Bool(true));
+        config.profiles.insert("foundry".to_string(), foundry);
+        config.profiles.insert("xyz".to_string(), Profile::new("xyz"));
+        config
+    }
+
+    fn apply_with_env(vars: &[(&str, &str)], mode: OverrideMode) -> Result<RpcConfig, MescError> {
+        let mut config = base_config();
+        crate::with_env(vars, || apply_overrides_with_mode(&mut config, mode))?;
+        Ok(config)
+    }
+
+    #[test]
+    fn network_defaults_merge_by_default() {
+        let config =
+            apply_with_env(&[("MESC_NETWORK_DEFAULTS", "10=my_op")], OverrideMode::Merge).unwrap();
+        assert_eq!(config.network_defaults.len(), 2);
+        assert_eq!(config.network_defaults[&ChainId::new(1)], "local_mainnet");
+        assert_eq!(config.network_defaults[&ChainId::new(10)], "my_op");
+        config.validate().unwrap();
+    }
+
+    #[test]
+    fn network_defaults_replace_with_marker() {
+        let config =
+            apply_with_env(&[("MESC_NETWORK_DEFAULTS", "!10=my_op")], OverrideMode::Merge).unwrap();
+        assert_eq!(config.network_defaults.len(), 1);
+        assert_eq!(config.network_defaults[&ChainId::new(10)], "my_op");
+    }
+
+    #[test]
+    fn network_names_merge_by_default() {
+        let config =
+            apply_with_env(&[("MESC_NETWORK_NAMES", "zora=7777777")], OverrideMode::Merge).unwrap();
+        assert_eq!(config.network_names["devnet"], ChainId::new(1337));
+        assert_eq!(config.network_names["zora"], ChainId::new(7777777));
+    }
+
+    #[test]
+    fn profiles_merge_by_default() {
+        let vars = [("MESC_PROFILES", "foundry.network_defaults.10=my_op new.use_mesc=false")];
+        let config = apply_with_env(&vars, OverrideMode::Merge).unwrap();
+        assert_eq!(config.profiles.len(), 3);
+        assert!(config.profiles.contains_key("xyz"));
+        let foundry = &config.profiles["foundry"];
+        assert_eq!(foundry.default_endpoint.as_deref(), Some("local_mainnet"));
+        assert_eq!(foundry.network_defaults[&ChainId::new(10)], "my_op");
+        assert_eq!(foundry.profile_metadata["verbose"], serde_json::Value::Bool(true));
+        assert!(!config.profiles["new"].use_mesc);
+    }
+
+    #[test]
+    fn profiles_replace_with_marker() {
+        let vars = [("MESC_PROFILES", "!foundry.network_defaults.10=my_op")];
+        let config = apply_with_env(&vars, OverrideMode::Merge).unwrap();
+        assert_eq!(config.profiles.len(), 1);
+        let foundry = &config.profiles["foundry"];
+        assert_eq!(foundry.default_endpoint, None);
+        assert!(foundry.profile_metadata.is_empty());
+    }
+
+    #[test]
+    fn replace_mode_applies_to_all_map_overrides() {
+        let vars = [
+            ("MESC_NETWORK_DEFAULTS", "10=my_op"),
+            ("MESC_NETWORK_NAMES", "zora=7777777"),
+            ("MESC_PROFILES", "xyz.use_mesc=false"),
+        ];
+        let config = apply_with_env(&vars, OverrideMode::Replace).unwrap();
+        assert_eq!(config.network_defaults.len(), 1);
+        assert_eq!(config.network_names.len(), 1);
+        assert_eq!(config.profiles.len(), 1);
+        assert!(!config.profiles["xyz"].use_mesc);
+    }
+
+    #[test]
+    fn replace_marker_errors_report_original_column() {
+        let vars = [("MESC_NETWORK_NAMES", "!zora=abc")];
+        match apply_with_env(&vars, OverrideMode::Merge) {
+            Err(MescError::OverrideError(message)) => {
+                assert_eq!(message, "MESC_NETWORK_NAMES column 7: invalid chain_id: abc")
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn network_defaults_empty_value_removes_default() {
+        let vars = [("MESC_NETWORK_DEFAULTS", "1=my_op 10=")];
+        let config = apply_with_env(&vars, OverrideMode::Merge).unwrap();
+        assert_eq!(
+            config.network_defaults.get(&ChainId::new(1)).map(|x| x.as_str()),
+            Some("my_op")
+        );
+        assert_eq!(config.network_defaults.get(&ChainId::new(10)), None);
+    }
+
+    #[test]
+    fn default_endpoint_names_use_canonical_chain_ids() {
+        let url = "https://base.example.com";
+        let name = |chain_id: &str| get_default_endpoint_name(url, ChainId::parse(chain_id).ok());
+        for chain_id in ["8453", "0x2105", "eip155:8453"] {
+            assert_eq!(name(chain_id).as_deref(), Some("example_8453"), "{}", chain_id);
+        }
+        let solana = name("solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp").unwrap();
+        assert_eq!(solana, "example_solana_5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp");
+
+        // generated names pass strict name validation
+        let mut config = RpcConfig::default();
+        for name in [name("0x2105").unwrap(), solana] {
+            let endpoint = Endpoint {
+                name: name.clone(),
+                url: url.to_string(),
+                chain_id: None,
+                endpoint_metadata: HashMap::new(),
+            };
+            config.endpoints.insert(name, endpoint);
+        }
+        let report = config.validate_strict();
+        assert!(report.issues.iter().all(|issue| issue.code != crate::ValidationCode::InvalidName));
+    }
+
+    #[test]
+    fn default_endpoint_accepts_urls_and_networks() {
+        let vars = [("MESC_DEFAULT_ENDPOINT", "http://localhost:8545")];
+        let config = apply_with_env(&vars, OverrideMode::Merge).unwrap();
+        assert_eq!(config.default_endpoint.as_deref(), Some("local_mainnet"));
+        assert_eq!(config.endpoints.len(), 3);
+
+        let vars = [("MESC_DEFAULT_ENDPOINT", "localhost:9545")];
+        let config = apply_with_env(&vars, OverrideMode::Merge).unwrap();
+        assert_eq!(config.default_endpoint.as_deref(), Some("localhost"));
+        let endpoint = &config.endpoints["localhost"];
+        assert_eq!((endpoint.url.as_str(), endpoint.chain_id.as_ref()), ("localhost:9545", None));
+        config.validate().unwrap();
+
+        let vars = [("MESC_DEFAULT_ENDPOINT", "anvil:8545")];
+        let config = apply_with_env(&vars, OverrideMode::Merge).unwrap();
+        assert_eq!(config.default_endpoint.as_deref(), Some("anvil"));
+        assert_eq!(config.endpoints["anvil"].url, "anvil:8545");
+
+        for network in ["10", "optimism", "OP Mainnet"] {
+            let vars = [("MESC_DEFAULT_ENDPOINT", network)];
+            let config = apply_with_env(&vars, OverrideMode::Merge).unwrap();
+            assert_eq!(config.default_endpoint.as_deref(), Some("other_op"), "{}", network);
+        }
+
+        let vars = [("MESC_DEFAULT_ENDPOINT", "devnet")];
+        match apply_with_env(&vars, OverrideMode::Merge) {
+            Err(MescError::OverrideError(message)) => assert_eq!(
+                message,
+                "MESC_DEFAULT_ENDPOINT: no default endpoint for network: devnet"
+            ),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn network_defaults_accept_urls_and_networks() {
+        let vars = [(
+            "MESC_NETWORK_DEFAULTS",
+            "optimism=https://optimism.example.com devnet=localhost:8545 base=https://base.example",
+        )];
+        let config = apply_with_env(&vars, OverrideMode::Merge).unwrap();
+        assert_eq!(config.network_defaults[&ChainId::new(10)], "my_op");
+        assert_eq!(config.network_defaults[&ChainId::new(8453)], "base_8453");
+        assert_eq!(config.endpoints["base_8453"].chain_id, Some(ChainId::new(8453)));
+
+        // existing endpoint of another chain is not modified, a new endpoint is created
+        assert_eq!(config.network_defaults[&ChainId::new(1337)], "localhost_1337");
+        assert_eq!(config.endpoints["localhost_1337"].url, "localhost:8545");
+        assert_eq!(config.endpoints["local_mainnet"].chain_id, Some(ChainId::new(1)));
+        config.validate().unwrap();
+    }
+
+    #[test]
+    fn url_endpoints_are_reused_deterministically() {
+        let mut config = base_config();
+        for name in ["b_op", "a_op", "c_op"] {
+            let endpoint = endpoint(name, "https://op.example.net", 10);
+            config.endpoints.insert(name.to_string(), endpoint);
+        }
+        let url = "https://op.example.net";
+        assert_eq!(insert_url_endpoint(&mut config, url, None).unwrap(), "a_op");
+        assert_eq!(insert_url_endpoint(&mut config, url, Some(ChainId::new(10))).unwrap(), "a_op");
+
+        // endpoints without chain_id are reused as is rather than assigned the chain_id
+        let mut endpoint = endpoint("any_chain", "http://localhost:9545", 1);
+        endpoint.chain_id = None;
+        config.endpoints.insert(endpoint.name.clone(), endpoint);
+        let url = "http://localhost:9545";
+        assert_eq!(insert_url_endpoint(&mut config, url, None).unwrap(), "any_chain");
+        let name = insert_url_endpoint(&mut config, url, Some(ChainId::new(8453))).unwrap();
+        assert_eq!(name, "localhost_8453");
+        assert_eq!(config.endpoints["any_chain"].chain_id, None);
+        assert_eq!(config.endpoints[&name].chain_id, Some(ChainId::new(8453)));
+    }
+
+    fn tokenize(raw: &str) -> Result<Vec<String>, MescError> {
+        let items = tokenize_override("MESC_ENDPOINTS", raw, 0)?;
+        Ok(items.into_iter().map(|item| item.text).collect())
+    }
+
+    fn override_error(result: Result<impl std::fmt::Debug, MescError>) -> String {
+        match result {
+            Err(MescError::OverrideError(message)) => message,
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn tokenizer_handles_quotes_and_escapes() {
+        assert_eq!(tokenize(r#"a="x y=z" 'b c'=d"#).unwrap(), ["a=x y=z", "b c=d"]);
+        assert_eq!(
+            tokenize(r#"a="x \"y\"" b='it"s' c=\'d\ e"#).unwrap(),
+            [r#"a=x "y""#, r#"b=it"s"#, "c='d e"]
+        );
+
+        // only the first unquoted and unescaped `=` separates key from value
+        let items = tokenize_override("MESC_ENDPOINTS", r#""k=1"=v=w k\=2=v"#, 0).unwrap();
+        assert_eq!(items[0].split(), Some(("k=1", "v=w")));
+        assert_eq!(items[1].split(), Some(("k=2", "v")));
+        assert_eq!(tokenize_override("MESC_ENDPOINTS", "'='", 0).unwrap()[0].split(), None);
+    }
+
+    #[test]
+    fn tokenizer_handles_empty_items() {
+        assert_eq!(tokenize("").unwrap(), Vec::<String>::new());
+        assert_eq!(tokenize("  a=1 \t  b=2  ").unwrap(), ["a=1", "b=2"]);
+        assert_eq!(tokenize(r#"a=1 "" b=''"#).unwrap(), ["a=1", "", "b="]);
+
+        let items = tokenize_override("MESC_NETWORK_NAMES", r#"a=1 "" b="#, 0).unwrap();
+        assert_eq!(
+            override_error(items[1].split_pair()),
+            "MESC_NETWORK_NAMES column 5: expected <key>=<value>, got: "
+        );
+        assert_eq!(
+            override_error(items[2].split_pair()),
+            "MESC_NETWORK_NAMES column 9: missing value after '='"
+        );
+    }
+
+    #[test]
+    fn tokenizer_errors_report_column() {
+        assert_eq!(
+            override_error(tokenize(r#"a=1 b="2"#)),
+            "MESC_ENDPOINTS column 7: unterminated quote \""
+        );
+        assert_eq!(
+            override_error(tokenize("a=1 b='2 c=3")),
+            "MESC_ENDPOINTS column 7: unterminated quote '"
+        );
+        assert_eq!(
+            override_error(tokenize(r"a=1\")),
+            "MESC_ENDPOINTS column 4: trailing escape character"
+        );
+
+        // columns refer to the raw variable, including quotes and escapes
+        let items = tokenize_override("MESC_ENDPOINTS", r#"a=1 "local":xyz=http://x"#, 0).unwrap();
+        assert_eq!(
+            override_error(parse_endpoint(&items[1])),
+            "MESC_ENDPOINTS column 13: invalid chain_id: xyz"
+        );
+    }
+
+    #[test]
+    fn endpoint_items_parse_name_chain_id_and_url() {
+        let parse = |raw: &str| {
+            let items = tokenize_override("MESC_ENDPOINTS", raw, 0).unwrap();
+            parse_endpoint(&items[0])
+        };
+        let endpoint = parse("local:1=http://localhost:8545").unwrap();
+        assert_eq!(endpoint.name, "local");
+        assert_eq!(endpoint.chain_id, Some(ChainId::new(1)));
+        assert_eq!(endpoint.url, "http://localhost:8545");
+
+        let endpoint = parse("llama=https://eth.llamarpc.com").unwrap();
+        assert_eq!((endpoint.name.as_str(), endpoint.chain_id), ("llama", None));
+
+        // text before `=` that is not a valid name is part of the url
+        let endpoint = parse("https://rpc.example.com/?key=abc").unwrap();
+        assert_eq!(endpoint.name, "example");
+        assert_eq!(endpoint.url, "https://rpc.example.com/?key=abc");
+
+        let endpoint = parse("rpc=\"https://rpc.example.com/?a=1&b=2\"").unwrap();
+        assert_eq!(endpoint.name, "rpc");
+        assert_eq!(endpoint.url, "https://rpc.example.com/?a=1&b=2");
+
+        assert_eq!(override_error(parse("local:1=")), "MESC_ENDPOINTS column 8: missing url");
+    }
+
+    #[test]
+    fn profiles_set_use_mesc_and_metadata() {
+        let vars = [(
+            "MESC_PROFILES",
+            r#"xyz.use_mesc=false foundry.profile_metadata.api_keys.etherscan=abc foundry.profile_metadata.retries=3 xyz.profile_metadata='{"verbose":false}'"#,
+        )];
+        let config = apply_with_env(&vars, OverrideMode::Merge).unwrap();
+        let foundry = &config.profiles["foundry"];
+        assert!(foundry.use_mesc);
+        assert_eq!(foundry.profile_metadata["verbose"], serde_json::json!(true));
+        assert_eq!(foundry.profile_metadata["api_keys"], serde_json::json!({"etherscan": "abc"}));
+        assert_eq!(foundry.profile_metadata["retries"], serde_json::json!(3));
+        let xyz = &config.profiles["xyz"];
+        assert!(!xyz.use_mesc);
+        assert_eq!(xyz.profile_metadata["verbose"], serde_json::json!(false));
+
+        for (value, message) in [
+            ("xyz.use_mesc=no", "MESC_PROFILES column 14: use_mesc must be true or false, got: no"),
+            (
+                "xyz.profile_metadata=3",
+                "MESC_PROFILES column 22: profile_metadata must be a JSON object",
+            ),
+            (
+                "foundry.profile_metadata.verbose.level=1",
+                "MESC_PROFILES column 26: metadata path is not an object: verbose.level",
+            ),
+            ("xyz.profile_metadata..a=1", "MESC_PROFILES column 22: bad metadata path: .a"),
+        ] {
+            let vars = [("MESC_PROFILES", value)];
+            assert_eq!(override_error(apply_with_env(&vars, OverrideMode::Merge)), message);
+        }
+    }
+
+    #[test]
+    fn profile_default_endpoint_resolves_like_global_default() {
+        let vars = [(
+            "MESC_PROFILES",
+            "a.default_endpoint=my_op b.default_endpoint=localhost:9545 c.default_endpoint=optimism \
+             d.network_defaults.10=my_op d.default_endpoint=10",
+        )];
+        let config = apply_with_env(&vars, OverrideMode::Merge).unwrap();
+        let default_endpoint = |profile: &str| config.profiles[profile].default_endpoint.as_deref();
+        assert_eq!(default_endpoint("a"), Some("my_op"));
+        assert_eq!(default_endpoint("b"), Some("localhost"));
+        assert_eq!(config.endpoints["localhost"].url, "localhost:9545");
+        assert_eq!(default_endpoint("c"), Some("other_op"));
+        assert_eq!(default_endpoint("d"), Some("my_op"));
+        config.validate().unwrap();
+
+        let vars = [("MESC_PROFILES", "a.default_endpoint=unknown")];
+        assert_eq!(
+            override_error(apply_with_env(&vars, OverrideMode::Merge)),
+            "MESC_PROFILES column 20: not an endpoint name, url, or network: unknown"
+        );
+        let vars = [("MESC_PROFILES", "a.default_endpoint=devnet")];
+        assert_eq!(
+            override_error(apply_with_env(&vars, OverrideMode::Merge)),
+            "MESC_PROFILES column 20: no default endpoint for network: devnet"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{
+    load::{get_config_path, load_base_config},
+    overrides::{apply_override, OVERRIDE_VARS},
+    ConfigMode, ConfigProvenance, ConfigSource, MescError, OverrideMode, RpcConfig,
+};
+use std::collections::BTreeMap;
+
+/// load config data, along with the source of each effective config value
+pub fn load_config_data_with_provenance() -> Result<(RpcConfig, ConfigProvenance), MescError> {
+    let (mut config, mode) = load_base_config()?;
+    let source = match mode {
+        ConfigMode::Path => Some(ConfigSource::Path(get_config_path()?)),
+        ConfigMode::Env => Some(ConfigSource::Env),
+        _ => None,
+    };
+
+    let mut provenance = ConfigProvenance::new();
+    if let Some(source) = source {
+        for path in get_config_entries(&config).into_keys() {
+            provenance.insert(path, source.clone());
+        }
+    }
+
+    // attribute each value added or changed by an override to that override
+    for var in OVERRIDE_VARS.iter() {
+        let before = get_config_entries(&config);
+        apply_override(&mut config, var, OverrideMode::Merge)?;
+        let after = get_config_entries(&config);
+        provenance.retain(|path, _| after.contains_key(path));
+        for (path, value) in after.into_iter() {
+            if before.get(&path) != Some(&value) {
+                provenance.insert(path, ConfigSource::Override(var.to_string()));
+            }
+        }
+    }
+
+    if mode == ConfigMode::Overrides {
+        config.validate()?;
+    }
+
+    Ok((config, provenance))
+}
+
+/// flatten config into its individual values, keyed by dot-separated config path
+pub fn get_config_entries(config: &RpcConfig) -> BTreeMap<String, serde_json::Value> {
+    let mut entries = BTreeMap::new();
+
+    if let Some(default_endpoint) = config.default_endpoint.as_ref() {
+        entries.insert("default_endpoint".to_string(), default_endpoint.clone().into());
+    }
+    for (chain_id, endpoint) in config.network_defaults.iter() {
+        entries.insert(format!("network_defaults.{}", chain_id), endpoint.clone().into());
+    }
+    for (name, chain_id) in config.network_names.iter() {
+        entries.insert(format!("network_names.{}", name), chain_id.to_string().into());
+    }
+    for (name, endpoint) in config.endpoints.iter() {
+        entries.insert(format!("endpoints.{}.url", name), endpoint.url.clone().into());
+        if let Some(chain_id) = endpoint.chain_id.as_ref() {
+            entries.insert(format!("endpoints.{}.chain_id", name), chain_id.to_string().into());
+        }
+        for (key, value) in endpoint.endpoint_metadata.iter() {
+            entries.insert(format!("endpoints.{}.endpoint_metadata.{}", name, key), value.clone());
+        }
+    }
+    for (name, profile) in config.profiles.iter() {
+        if let Some(default_endpoint) = profile.default_endpoint.as_ref() {
+            entries.insert(
+                format!("profiles.{}.default_endpoint", name),
+                default_endpoint.clone().into(),
+            );
+        }
+        for (chain_id, endpoint) in profile.network_defaults.iter() {
+            entries.insert(
+                format!("profiles.{}.network_defaults.{}", name, chain_id),
+                endpoint.clone().into(),
+            );
+        }
+        for (key, value) in profile.profile_metadata.iter() {
+            entries.insert(format!("profiles.{}.profile_metadata.{}", name, key), value.clone());
+        }
+        entries.insert(format!("profiles.{}.use_mesc", name), profile.use_mesc.into());
+    }
+    for (key, value) in config.global_metadata.iter() {
+        entries.insert(format!("global_metadata.{}", key), value.clone());
+    }
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ChainId;
+
+    fn file_config() -> serde_json::Value {
+        serde_json::json!({
+            "mesc_version": "MESC 1.0",
+            "default_endpoint": "local",
+            "endpoints": {
+                "local": {
+                    "name": "local",
+                    "url": "http://localhost:8545",
+                    "chain_id": "1",
+                    "endpoint_metadata": {}
+                },
+                "other": {
+                    "name": "other",
+                    "url": "http://localhost:9545",
+                    "chain_id": "10",
+                    "endpoint_metadata": {}
+                }
+            },
+            "network_defaults": {"1": "local", "10": "other"},
+            "network_names": {},
+            "profiles": {
+                "foundry": {
+                    "name": "foundry",
+                    "default_endpoint": "local",
+                    "network_defaults": {},
+                    "profile_metadata": {},
+                    "use_mesc": true
+                }
+            },
+            "global_metadata": {"a": 1}
+        })
+    }
+
+    fn source(provenance: &ConfigProvenance, path: &str) -> Option<String> {
+        provenance.get(path).map(|source| source.to_string())
+    }
+
+    #[test]
+    fn provenance_attributes_file_and_override_values() {
+        let path =
+            std::env::temp_dir().join(format!("mesc_provenance_{}.json", std::process::id()));
+        std::fs::write(&path, file_config().to_string()).unwrap();
+        let path = path.to_string_lossy().to_string();
+        let vars = [
+            ("MESC_PATH", path.as_str()),
+            ("MESC_NETWORK_DEFAULTS", "!1=other"),
+            ("MESC_PROFILES", "foundry.use_mesc=false new.default_endpoint=other"),
+            ("MESC_DEFAULT_ENDPOINT", "local"),
+            ("MESC_GLOBAL_METADATA", r#"{"b": 2}"#),
+        ];
+        let result = crate::with_env(&vars, load_config_data_with_provenance);
+        std::fs::remove_file(&path).unwrap();
+        let (config, provenance) = result.unwrap();
+
+        let file = format!("MESC_PATH ({})", path);
+        assert_eq!(source(&provenance, "endpoints.local.url"), Some(file.clone()));
+        assert_eq!(source(&provenance, "endpoints.other.chain_id"), Some(file.clone()));
+        assert_eq!(source(&provenance, "profiles.foundry.default_endpoint"), Some(file.clone()));
+        assert_eq!(source(&provenance, "global_metadata.a"), Some(file.clone()));
+        // overrides that leave a value unchanged keep the original source
+        assert_eq!(source(&provenance, "default_endpoint"), Some(file));
+
+        let network_defaults = Some("MESC_NETWORK_DEFAULTS".to_string());
+        assert_eq!(source(&provenance, "network_defaults.1"), network_defaults);
+        // values removed by a `!` replace override have no source
+        assert_eq!(config.network_defaults.get(&ChainId::new(10)), None);
+        assert_eq!(source(&provenance, "network_defaults.10"), None);
+
+        let profiles = Some("MESC_PROFILES".to_string());
+        assert_eq!(source(&provenance, "profiles.foundry.use_mesc"), profiles);
+        assert_eq!(source(&provenance, "profiles.new.default_endpoint"), profiles);
+        // default values are attributed to the source that created their parent
+        assert_eq!(source(&provenance, "profiles.new.use_mesc"), profiles);
+        assert_eq!(source(&provenance, "global_metadata.b"), Some("MESC_GLOBAL_METADATA".into()));
+
+        // every effective value has exactly one source
+        let entries = get_config_entries(&config);
+        assert!(entries.keys().eq(provenance.keys()));
+    }
+
+    #[test]
+    fn provenance_attributes_env_and_overrides_only_values() {
+        let config_json = file_config().to_string();
+        let vars = [("MESC_ENV", config_json.as_str()), ("MESC_ENDPOINTS", "new=localhost:7545")];
+        let (_, provenance) = crate::with_env(&vars, load_config_data_with_provenance).unwrap();
+        assert_eq!(source(&provenance, "endpoints.local.url"), Some("MESC_ENV".to_string()));
+        assert_eq!(source(&provenance, "endpoints.new.url"), Some("MESC_ENDPOINTS".to_string()));
+
+        let vars =
+            [("MESC_ENDPOINTS", "local:1=localhost:8545"), ("MESC_NETWORK_DEFAULTS", "1=local")];
+        let (config, provenance) =
+            crate::with_env(&vars, load_config_data_with_provenance).unwrap();
+        assert_eq!(get_config_entries(&config).len(), 3);
+        assert_eq!(source(&provenance, "endpoints.local.url"), Some("MESC_ENDPOINTS".into()));
+        assert_eq!(source(&provenance, "endpoints.local.chain_id"), Some("MESC_ENDPOINTS".into()));
+        assert_eq!(source(&provenance, "network_defaults.1"), Some("MESC_NETWORK_DEFAULTS".into()));
+    }
+}
@@ -1,31 +1,61 @@
 use crate::{
-    directory,
+    directory, metadata,
+    overrides::get_default_endpoint_name,
     types::{Endpoint, MescError, RpcConfig},
-    MultiEndpointQuery, TryIntoChainId,
+    ChainId, MescQuery, MescQueryFields, MescQueryResult, MescQueryType, MultiEndpointQuery,
+    ResolutionTrace, SelectionStrategy, TryIntoChainId,
 };
-use std::collections::HashMap;
+use serde::de::DeserializeOwned;
+use std::collections::{HashMap, HashSet};
 
 /// get default endpoint
 pub fn get_default_endpoint(
     config: &RpcConfig,
     profile: Option<&str>,
 ) -> Result<Option<Endpoint>, MescError> {
+    Ok(trace_default_endpoint(config, profile)?.endpoint)
+}
+
+/// get default endpoint, along with each step taken to resolve it
+pub fn trace_default_endpoint(
+    config: &RpcConfig,
+    profile: Option<&str>,
+) -> Result<ResolutionTrace, MescError> {
+    let mut trace = ResolutionTrace::default();
+
     // if using a profile, check if that profile has a default endpoint
     if let Some(profile) = profile {
+        if !trace_profile(config, profile, &mut trace) {
+            return Ok(trace);
+        }
         if let Some(profile_data) = config.profiles.get(profile) {
-            if !profile_data.use_mesc {
-                return Ok(None);
-            }
-            if let Some(endpoint_name) = profile_data.default_endpoint.as_deref() {
-                return get_endpoint_by_name(config, endpoint_name);
+            match profile_data.default_endpoint.as_deref() {
+                Some(endpoint_name) => {
+                    trace.step(
+                        "profile default endpoint",
+                        true,
+                        format!("profile {} default endpoint is {}", profile, endpoint_name),
+                    );
+                    trace.endpoint = get_endpoint_by_name(config, endpoint_name)?;
+                    return Ok(trace);
+                }
+                None => trace.step(
+                    "profile default endpoint",
+                    false,
+                    format!("profile {} has no default endpoint, using global default", profile),
+                ),
             }
         }
     };
 
     match &config.default_endpoint {
-        Some(name) => get_endpoint_by_name(config, name.as_str()),
-        None => Ok(None),
+        Some(name) => {
+            trace.step("global default endpoint", true, format!("global default is {}", name));
+            trace.endpoint = get_endpoint_by_name(config, name.as_str())?;
+        }
+        None => trace.step("global default endpoint", false, "no global default endpoint"),
     }
+    Ok(trace)
 }
 
 /// get endpoint by network
@@ -34,25 +64,65 @@ pub fn get_endpoint_by_network<T: TryIntoChainId + std::fmt::Debug + std::clone:
     chain_id: T,
     profile: Option<&str>,
 ) -> Result<Option<Endpoint>, MescError> {
-    let chain_id = chain_id.try_into_chain_id()?;
+    Ok(trace_endpoint_by_network(config, chain_id, profile)?.endpoint)
+}
 
-    // if using a profile, check if that profile has a default endpoint for chain_id
+/// get endpoint by network, along with each step taken to resolve it
+pub fn trace_endpoint_by_network<T: TryIntoChainId + std::fmt::Debug + std::clone::Clone>(
+    config: &RpcConfig,
+    chain_id: T,
+    profile: Option<&str>,
+) -> Result<ResolutionTrace, MescError> {
+    let chain_id = chain_id.try_into_chain_id()?;
+    let mut trace = ResolutionTrace::default();
     if let Some(profile) = profile {
-        if let Some(profile_data) = config.profiles.get(profile) {
-            if !profile_data.use_mesc {
-                return Ok(None);
-            }
-            if let Some(endpoint_name) = profile_data.network_defaults.get(&chain_id) {
-                return get_endpoint_by_name(config, endpoint_name);
-            }
+        if !trace_profile(config, profile, &mut trace) {
+            return Ok(trace);
         }
-    };
+    }
+    trace_network_defaults(config, &chain_id, profile, &mut trace)?;
+    Ok(trace)
+}
 
-    // check if base configuration has a default endpoint for that chain_id
-    match config.network_defaults.get(&chain_id) {
-        Some(name) => get_endpoint_by_name(config, name.as_str()),
-        None => Ok(None),
+/// get endpoints of network in order of preference
+///
+/// the first endpoint is the one returned by `get_endpoint_by_network`, followed by the profile's
+/// `network_fallbacks`, the global network default, and the global `network_fallbacks`
+pub fn get_endpoints_by_network_ranked<T: TryIntoChainId + std::fmt::Debug + std::clone::Clone>(
+    config: &RpcConfig,
+    chain_id: T,
+    profile: Option<&str>,
+) -> Result<Vec<Endpoint>, MescError> {
+    let chain_id = chain_id.try_into_chain_id()?;
+    let profile_data = profile.and_then(|profile| config.profiles.get(profile));
+    if let Some(profile_data) = profile_data {
+        if !profile_data.use_mesc {
+            return Ok(Vec::new());
+        }
+    }
+
+    let mut names = Vec::new();
+    if let Some(endpoint) = get_endpoint_by_network(config, chain_id.clone(), profile)? {
+        names.push(endpoint.name);
+    }
+    if let Some(profile_data) = profile_data {
+        let mut fallbacks = metadata::get_network_fallbacks(&profile_data.profile_metadata)?;
+        names.extend(fallbacks.remove(&chain_id).unwrap_or_default());
+    }
+    if let Some(name) = config.network_defaults.get(&chain_id) {
+        names.push(name.clone());
+    }
+    let mut fallbacks = metadata::get_network_fallbacks(&config.global_metadata)?;
+    names.extend(fallbacks.remove(&chain_id).unwrap_or_default());
+
+    let mut seen = HashSet::new();
+    let mut endpoints = Vec::new();
+    for name in names.into_iter() {
+        if seen.insert(name.clone()) {
+            endpoints.extend(get_endpoint_by_name(config, &name)?);
+        }
     }
+    Ok(endpoints)
 }
 
 /// get endpoint by name
@@ -64,40 +134,225 @@ pub fn get_endpoint_by_name(config: &RpcConfig, name: &str) -> Result<Option<End
     }
 }
 
-/// parse user query
+/// parse user query, which can be an endpoint name, url, group, chain_id, or network name
+///
+/// groups resolve to their first member
 pub fn get_endpoint_by_query(
     config: &RpcConfig,
     query: &str,
     profile: Option<&str>,
 ) -> Result<Option<Endpoint>, MescError> {
+    Ok(trace_endpoint_by_query(config, query, profile)?.endpoint)
+}
+
+/// parse user query, along with each step taken to resolve it
+pub fn trace_endpoint_by_query(
+    config: &RpcConfig,
+    query: &str,
+    profile: Option<&str>,
+) -> Result<ResolutionTrace, MescError> {
+    let mut trace = ResolutionTrace::default();
     if let Some(profile) = profile {
-        if let Some(profile_data) = config.profiles.get(profile) {
-            if !profile_data.use_mesc {
-                return Ok(None);
-            }
+        if !trace_profile(config, profile, &mut trace) {
+            return Ok(trace);
         }
     }
 
     // by endpoint name
     if let Some(endpoint) = config.endpoints.get(query) {
-        return Ok(Some(endpoint.clone()));
+        trace.step("endpoint name", true, format!("{} is an endpoint name", query));
+        trace.endpoint = Some(endpoint.clone());
+        return Ok(trace);
+    }
+    trace.step("endpoint name", false, format!("no endpoint named {}", query));
+
+    // by url
+    if is_url(query) {
+        let endpoint = get_endpoint_by_url(config, query);
+        let reason = if config.endpoints.contains_key(&endpoint.name) {
+            format!("{} is the url of endpoint {}", query, endpoint.name)
+        } else {
+            format!("{} is a url not in config", query)
+        };
+        trace.step("url", true, reason);
+        trace.endpoint = Some(endpoint);
+        return Ok(trace);
+    }
+    trace.step("url", false, format!("{} is not a url", query));
+
+    // by group name, invalid groups metadata is only an error for queries that name a group
+    match get_group(config, query) {
+        Ok(Some(endpoints)) => {
+            let reason = format!("{} is a group of {} endpoints", query, endpoints.len());
+            trace.step("group", true, reason);
+            trace.endpoint = endpoints.into_iter().next();
+            return Ok(trace);
+        }
+        Ok(None) => trace.step("group", false, format!("no group named {}", query)),
+        Err(e) if names_group(config, query) => return Err(e),
+        Err(e) => trace.step("group", false, format!("could not read groups: {}", e)),
     }
 
     // by chain_id
     if let Ok(chain_id) = query.try_into_chain_id() {
-        if let Ok(Some(endpoint)) = get_endpoint_by_network(config, chain_id, profile) {
-            return Ok(Some(endpoint));
+        trace.step("chain id", true, format!("{} is chain id {}", query, chain_id));
+        match trace_network_defaults(config, &chain_id, profile, &mut trace) {
+            Ok(()) if trace.endpoint.is_some() => return Ok(trace),
+            Ok(()) => {}
+            Err(e) => trace.step("chain id", false, format!("could not resolve chain id: {}", e)),
         }
+    } else {
+        trace.step("chain id", false, format!("{} is not a chain id", query));
     }
 
     // by network name
     if let Some(chain_id) = config.network_names.get(query) {
-        return get_endpoint_by_network(config, chain_id.clone(), profile);
-    } else if let Some(chain_id) = directory::get_network_chain_id(query) {
-        return get_endpoint_by_network(config, chain_id.clone(), profile);
+        trace.step(
+            "custom network name",
+            true,
+            format!("{} is configured as chain id {}", query, chain_id),
+        );
+        trace_network_defaults(config, chain_id, profile, &mut trace)?;
+        return Ok(trace);
+    }
+    trace.step("custom network name", false, format!("{} is not in network_names", query));
+    if let Some(network) = directory::get_network(query) {
+        trace.step(
+            "directory network name",
+            true,
+            format!(
+                "{} is network {} with chain id {} in network directory",
+                query, network.name, network.chain_id
+            ),
+        );
+        trace_network_defaults(config, &network.chain_id(), profile, &mut trace)?;
+        return Ok(trace);
     }
+    trace.step("directory network name", false, format!("{} is not in network directory", query));
 
-    Ok(None)
+    Ok(trace)
+}
+
+/// whether value should be interpreted as a url, including bare `host:port` values
+///
+/// CAIP-2 chain ids such as `eip155:1` also look like `host:port`, so bare values whose host is
+/// a known CAIP-2 namespace (see `CAIP2_NAMESPACES`) are not url's, other hosts such as
+/// `anvil:8545` are, and a scheme can be added to use any value as a url
+pub fn is_url(value: &str) -> bool {
+    if value.contains("://") {
+        return true;
+    }
+    let host_port = value.split('/').next().unwrap_or_default();
+    match host_port.rsplit_once(':') {
+        Some((host, port)) => {
+            !host.is_empty()
+                && !port.is_empty()
+                && port.chars().all(|c| c.is_ascii_digit())
+                && !crate::CAIP2_NAMESPACES.contains(&host)
+        }
+        None => host_port == "localhost",
+    }
+}
+
+/// get endpoint for url, reusing a configured endpoint with the same url if one exists
+pub fn get_endpoint_by_url(config: &RpcConfig, url: &str) -> Endpoint {
+    let normalized = normalize_url(url);
+    let mut matches: Vec<&Endpoint> = config
+        .endpoints
+        .values()
+        .filter(|endpoint| normalize_url(&endpoint.url) == normalized)
+        .collect();
+    matches.sort_by(|e1, e2| e1.name.cmp(&e2.name));
+    match matches.first() {
+        Some(endpoint) => Endpoint { url: url.to_string(), ..(*endpoint).clone() },
+        None => Endpoint {
+            name: get_default_endpoint_name(url, None).unwrap_or_else(|| url.to_string()),
+            url: url.to_string(),
+            chain_id: None,
+            endpoint_metadata: HashMap::new(),
+        },
+    }
+}
+
+/// strip trailing slash and lowercase scheme so that equivalent url's compare equal
+///
+/// the scheme is kept, so `http://` and `https://` url's of the same host are different
+fn normalize_url(url: &str) -> String {
+    let url = url.trim_end_matches('/');
+    match url.split_once("://") {
+        Some((scheme, rest)) => format!("{}://{}", scheme.to_lowercase(), rest),
+        None => url.to_string(),
+    }
+}
+
+/// record profile lookup in trace, returning whether resolution should continue
+fn trace_profile(config: &RpcConfig, profile: &str, trace: &mut ResolutionTrace) -> bool {
+    match config.profiles.get(profile) {
+        Some(profile_data) if !profile_data.use_mesc => {
+            trace.step("profile", true, format!("profile {} has use_mesc=false", profile));
+            false
+        }
+        Some(_) => {
+            trace.step("profile", true, format!("using profile {}", profile));
+            true
+        }
+        None => {
+            trace.step("profile", false, format!("profile {} not found, using globals", profile));
+            true
+        }
+    }
+}
+
+/// resolve network default for chain_id, first from profile then from global defaults
+fn trace_network_defaults(
+    config: &RpcConfig,
+    chain_id: &ChainId,
+    profile: Option<&str>,
+    trace: &mut ResolutionTrace,
+) -> Result<(), MescError> {
+    // if using a profile, check if that profile has a default endpoint for chain_id
+    if let Some(profile_data) = profile.and_then(|profile| config.profiles.get(profile)) {
+        match profile_data.network_defaults.get(chain_id) {
+            Some(endpoint_name) => {
+                trace.step(
+                    "profile network default",
+                    true,
+                    format!(
+                        "profile {} default for chain id {} is {}",
+                        profile_data.name, chain_id, endpoint_name
+                    ),
+                );
+                trace.endpoint = get_endpoint_by_name(config, endpoint_name)?;
+                return Ok(());
+            }
+            None => trace.step(
+                "profile network default",
+                false,
+                format!(
+                    "profile {} has no default for chain id {}, using global default",
+                    profile_data.name, chain_id
+                ),
+            ),
+        }
+    }
+
+    // check if base configuration has a default endpoint for that chain_id
+    match config.network_defaults.get(chain_id) {
+        Some(name) => {
+            trace.step(
+                "network default",
+                true,
+                format!("default for chain id {} is {}", chain_id, name),
+            );
+            trace.endpoint = get_endpoint_by_name(config, name.as_str())?;
+        }
+        None => trace.step(
+            "network default",
+            false,
+            format!("no default endpoint for chain id {}", chain_id),
+        ),
+    }
+    Ok(())
 }
 
 /// find endpoints
@@ -105,6 +360,12 @@ pub fn find_endpoints(
     config: &RpcConfig,
     query: MultiEndpointQuery,
 ) -> Result<Vec<Endpoint>, MescError> {
+    if let Some(profile) = query.profile.as_ref().and_then(|p| config.profiles.get(p)) {
+        if !profile.use_mesc {
+            return Ok(vec![]);
+        }
+    }
+
     let mut candidates: Vec<Endpoint> = config.endpoints.clone().into_values().collect();
 
     if let Some(chain_id) = query.chain_id {
@@ -119,9 +380,144 @@ pub fn find_endpoints(
         candidates.retain(|endpoint| endpoint.url.contains(&url))
     }
 
+    for filter in query.metadata.iter() {
+        candidates.retain(|endpoint| filter.matches(endpoint))
+    }
+
+    for filter in query.filters.iter() {
+        candidates.retain(|endpoint| filter.matches(endpoint))
+    }
+
     Ok(candidates)
 }
 
+/// get endpoints of group, in group order
+pub fn get_group(config: &RpcConfig, name: &str) -> Result<Option<Vec<Endpoint>>, MescError> {
+    match metadata::get_groups(&config.global_metadata)?.remove(name) {
+        Some(endpoint_names) => {
+            let mut endpoints = Vec::new();
+            for endpoint_name in endpoint_names.iter() {
+                endpoints.extend(get_endpoint_by_name(config, endpoint_name)?);
+            }
+            Ok(Some(endpoints))
+        }
+        None => Ok(None),
+    }
+}
+
+/// whether groups metadata has an entry for name, even if the groups metadata is invalid
+fn names_group(config: &RpcConfig, name: &str) -> bool {
+    config.global_metadata.get(metadata::GROUPS_KEY).and_then(|groups| groups.get(name)).is_some()
+}
+
+/// list names of endpoint groups
+pub fn list_groups(config: &RpcConfig) -> Result<Vec<String>, MescError> {
+    let mut names: Vec<String> =
+        metadata::get_groups(&config.global_metadata)?.into_keys().collect();
+    names.sort();
+    Ok(names)
+}
+
+/// select member of endpoint group using strategy
+pub fn select_endpoint(
+    config: &RpcConfig,
+    group: &str,
+    strategy: SelectionStrategy,
+) -> Result<Option<Endpoint>, MescError> {
+    let endpoints = match get_group(config, group)? {
+        Some(endpoints) if !endpoints.is_empty() => endpoints,
+        _ => return Ok(None),
+    };
+    let selected = match strategy {
+        SelectionStrategy::RoundRobin { counter } => {
+            let index = (counter % endpoints.len() as u64) as usize;
+            endpoints.into_iter().nth(index)
+        }
+        SelectionStrategy::WeightedRandom { seed } => {
+            let mut weights = Vec::new();
+            for endpoint in endpoints.iter() {
+                weights.push(get_metadata_number(endpoint, metadata::WEIGHT_KEY)?.unwrap_or(1.0));
+            }
+            let total: f64 = weights.iter().sum();
+            if total <= 0.0 {
+                return Ok(None);
+            }
+            let mut target = random_fraction(seed) * total;
+            let mut selected = None;
+            for (endpoint, weight) in endpoints.into_iter().zip(weights) {
+                if weight > 0.0 {
+                    selected = Some(endpoint);
+                    if target < weight {
+                        break;
+                    }
+                    target -= weight;
+                }
+            }
+            selected
+        }
+        SelectionStrategy::LowestLatency => {
+            let mut selected: Option<(Endpoint, f64)> = None;
+            let mut unobserved = None;
+            for endpoint in endpoints.into_iter() {
+                match get_metadata_number(&endpoint, metadata::LATENCY_KEY)? {
+                    Some(latency) => {
+                        if !matches!(selected, Some((_, lowest)) if lowest <= latency) {
+                            selected = Some((endpoint, latency))
+                        }
+                    }
+                    None => {
+                        unobserved.get_or_insert(endpoint);
+                    }
+                }
+            }
+            selected.map(|(endpoint, _)| endpoint).or(unobserved)
+        }
+        SelectionStrategy::FirstHealthy => {
+            let mut selected = None;
+            for endpoint in endpoints.into_iter() {
+                match endpoint.endpoint_metadata.get(metadata::HEALTHY_KEY) {
+                    None | Some(serde_json::Value::Bool(true)) => {
+                        selected = Some(endpoint);
+                        break;
+                    }
+                    Some(serde_json::Value::Bool(false)) => {}
+                    Some(_) => {
+                        return Err(MescError::IntegrityError(format!(
+                            "{} metadata of endpoint {} must be a bool",
+                            metadata::HEALTHY_KEY,
+                            endpoint.name
+                        )))
+                    }
+                }
+            }
+            selected
+        }
+    };
+    Ok(selected)
+}
+
+fn get_metadata_number(endpoint: &Endpoint, key: &str) -> Result<Option<f64>, MescError> {
+    match endpoint.endpoint_metadata.get(key) {
+        None => Ok(None),
+        Some(value) => match value.as_f64() {
+            Some(number) if number >= 0.0 => Ok(Some(number)),
+            _ => Err(MescError::IntegrityError(format!(
+                "{} metadata of endpoint {} must be a non-negative number",
+                key, endpoint.name
+            ))),
+        },
+    }
+}
+
+/// map seed to a number in [0, 1) using splitmix64
+fn random_fraction(seed: u64) -> f64 {
+    let mut z = seed.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1u64 << 53) as f64
+}
+
 /// get global metadata
 pub fn get_global_metadata(
     config: &RpcConfig,
@@ -141,3 +537,489 @@ pub fn get_global_metadata(
 
     Ok(metadata)
 }
+
+/// execute query, returning the result of the corresponding query function
+pub fn execute(config: &RpcConfig, query: MescQuery) -> Result<MescQueryResult, MescError> {
+    let result = match (query.query_type, query.fields) {
+        (MescQueryType::DefaultEndpoint, MescQueryFields::DefaultEndpoint(fields)) => {
+            MescQueryResult::Endpoint(get_default_endpoint(config, fields.profile.as_deref())?)
+        }
+        (MescQueryType::EndpointByName, MescQueryFields::EndpointName(fields)) => {
+            MescQueryResult::Endpoint(get_endpoint_by_name(config, &fields.name)?)
+        }
+        (MescQueryType::EndpointByNetwork, MescQueryFields::EndpointNetwork(fields)) => {
+            MescQueryResult::Endpoint(get_endpoint_by_network(
+                config,
+                fields.chain_id,
+                fields.profile.as_deref(),
+            )?)
+        }
+        (MescQueryType::UserInput, MescQueryFields::UserInput(fields)) => {
+            MescQueryResult::Endpoint(get_endpoint_by_query(
+                config,
+                &fields.user_input,
+                fields.profile.as_deref(),
+            )?)
+        }
+        (MescQueryType::MultiEndpoint, MescQueryFields::MultiEndpoint(fields)) => {
+            MescQueryResult::Endpoints(find_endpoints(config, fields)?)
+        }
+        (MescQueryType::GlobalMetadata, MescQueryFields::GlobalMetadata(fields)) => {
+            let metadata = get_global_metadata(config, fields.profile.as_deref())?;
+            let value = match fields.path {
+                Some(path) => {
+                    let path: Vec<&str> = path.iter().map(|key| key.as_str()).collect();
+                    metadata::get_metadata_path(&metadata, &path).cloned().unwrap_or_default()
+                }
+                None => serde_json::to_value(metadata)?,
+            };
+            MescQueryResult::Metadata(value)
+        }
+        (query_type, _) => {
+            return Err(MescError::IntegrityError(format!(
+                "fields do not match query_type {:?}",
+                query_type
+            )))
+        }
+    };
+    Ok(result)
+}
+
+/// get effective metadata value, looking in endpoint, then profile, then global metadata
+///
+/// path is dot-separated, e.g. `api_keys.etherscan`
+pub fn get_metadata_value<T: DeserializeOwned>(
+    config: &RpcConfig,
+    path: &str,
+    endpoint: Option<&Endpoint>,
+    profile: Option<&str>,
+) -> Result<Option<T>, MescError> {
+    let path: Vec<&str> = path.split('.').collect();
+    get_metadata_value_at(config, &path, endpoint, profile)
+}
+
+/// get effective metadata value at path of keys, see [`get_metadata_value`]
+pub fn get_metadata_value_at<T: DeserializeOwned>(
+    config: &RpcConfig,
+    path: &[&str],
+    endpoint: Option<&Endpoint>,
+    profile: Option<&str>,
+) -> Result<Option<T>, MescError> {
+    let mut scopes = Vec::new();
+    if let Some(endpoint) = endpoint {
+        scopes.push(&endpoint.endpoint_metadata);
+    }
+    if let Some(profile) = profile.and_then(|profile| config.profiles.get(profile)) {
+        if !profile.use_mesc {
+            return Ok(None);
+        }
+        scopes.push(&profile.profile_metadata);
+    }
+    scopes.push(&config.global_metadata);
+
+    for metadata in scopes.into_iter() {
+        if let Some(value) = metadata::get_metadata_path(metadata, path) {
+            return serde_json::from_value(value.clone()).map(Some).map_err(|_| {
+                MescError::InvalidMetadataType {
+                    key: path.join("."),
+                    expected: metadata::short_type_name::<T>(),
+                    found: metadata::json_type_name(value).to_string(),
+                }
+            });
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group_config(metadata: &[(&str, &str, serde_json::Value)]) -> RpcConfig {
+        let mut config = RpcConfig::default();
+        for name in ["a", "b", "c"] {
+            let endpoint = Endpoint {
+                name: name.to_string(),
+                url: format!("https://{}.example.com", name),
+                chain_id: Some(ChainId::new(1)),
+                endpoint_metadata: HashMap::new(),
+            };
+            config.endpoints.insert(name.to_string(), endpoint);
+        }
+        for (name, key, value) in metadata.iter() {
+            let endpoint = config.endpoints.get_mut(*name).unwrap();
+            endpoint.endpoint_metadata.insert(key.to_string(), value.clone());
+        }
+        config
+            .global_metadata
+            .insert(metadata::GROUPS_KEY.to_string(), serde_json::json!({"pool": ["a", "b", "c"]}));
+        config
+    }
+
+    fn select(config: &RpcConfig, strategy: SelectionStrategy) -> Option<String> {
+        select_endpoint(config, "pool", strategy).unwrap().map(|endpoint| endpoint.name)
+    }
+
+    #[test]
+    fn urls_are_distinguished_from_names_and_chain_ids() {
+        for value in [
+            "http://localhost:8545",
+            "wss://eth.example.com/ws",
+            "localhost",
+            "localhost:8545",
+            "127.0.0.1:8545/rpc",
+            "eth.example.com:443",
+            "cosmos://1234",
+            "anvil:8545",
+            "reth:8545",
+            "geth:8545/rpc",
+            "node:8545",
+        ] {
+            assert!(is_url(value), "{}", value);
+        }
+        for value in [
+            "",
+            "local_mainnet",
+            "eth.example.com",
+            "localhost:",
+            "1",
+            "eip155:1",
+            "eip155:8453",
+            "cosmos:1234",
+            "bip122:000000000019d6689c085ae165831e93",
+            "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp",
+        ] {
+            assert!(!is_url(value), "{}", value);
+        }
+
+        // compose-style hosts resolve as url's even though they also parse as CAIP-2 chain ids
+        assert!(ChainId::parse("anvil:8545").is_ok());
+        let config = RpcConfig::default();
+        for query in ["anvil:8545", "localhost:8545"] {
+            let endpoint = get_endpoint_by_query(&config, query, None).unwrap().unwrap();
+            assert_eq!(endpoint.url, query);
+        }
+    }
+
+    #[test]
+    fn urls_match_endpoints_with_same_scheme() {
+        let mut config = RpcConfig::default();
+        for (name, url) in [("b", "https://eth.example.com/"), ("a", "HTTPS://eth.example.com")] {
+            let endpoint = Endpoint {
+                name: name.to_string(),
+                url: url.to_string(),
+                chain_id: Some(ChainId::new(1)),
+                endpoint_metadata: HashMap::new(),
+            };
+            config.endpoints.insert(name.to_string(), endpoint);
+        }
+
+        let endpoint = get_endpoint_by_url(&config, "https://eth.example.com");
+        assert_eq!(
+            (endpoint.name.as_str(), endpoint.url.as_str()),
+            ("a", "https://eth.example.com")
+        );
+        assert_eq!(endpoint.chain_id, Some(ChainId::new(1)));
+
+        let endpoint = get_endpoint_by_url(&config, "http://eth.example.com");
+        assert_eq!((endpoint.name.as_str(), endpoint.chain_id), ("example", None));
+        let endpoint = get_endpoint_by_url(&config, "eth.example.com:443");
+        assert_eq!((endpoint.name.as_str(), endpoint.chain_id), ("example", None));
+    }
+
+    fn fallback_config() -> RpcConfig {
+        let mut config = RpcConfig::default();
+        for (name, chain_id) in [("a", 10), ("b", 10), ("c", 10), ("d", 10), ("m", 1)] {
+            let endpoint = Endpoint {
+                name: name.to_string(),
+                url: format!("https://{}.example.com", name),
+                chain_id: Some(ChainId::new(chain_id)),
+                endpoint_metadata: HashMap::new(),
+            };
+            config.endpoints.insert(name.to_string(), endpoint);
+        }
+        config.network_defaults.insert(ChainId::new(10), "c".to_string());
+        config.global_metadata.insert(
+            metadata::NETWORK_FALLBACKS_KEY.to_string(),
+            serde_json::json!({"10": ["d", "b"]}),
+        );
+        let mut profile = crate::Profile::new("p");
+        profile.network_defaults.insert(ChainId::new(10), "a".to_string());
+        // fallbacks that repeat higher ranked endpoints are only listed once
+        profile.profile_metadata.insert(
+            metadata::NETWORK_FALLBACKS_KEY.to_string(),
+            serde_json::json!({"10": ["b", "a", "c"]}),
+        );
+        config.profiles.insert("p".to_string(), profile);
+        config
+    }
+
+    #[test]
+    fn ranked_endpoints_follow_defaults_then_fallbacks() {
+        let config = fallback_config();
+        let ranked = |profile: Option<&str>| -> Vec<String> {
+            let endpoints = get_endpoints_by_network_ranked(&config, 10u64, profile).unwrap();
+            endpoints.into_iter().map(|endpoint| endpoint.name).collect()
+        };
+        assert_eq!(ranked(Some("p")), ["a", "b", "c", "d"]);
+        assert_eq!(ranked(None), ["c", "d", "b"]);
+        assert_eq!(ranked(Some("unknown")), ["c", "d", "b"]);
+        assert_eq!(get_endpoints_by_network_ranked(&config, 1u64, None).unwrap(), []);
+
+        let mut config = fallback_config();
+        config.profiles.get_mut("p").unwrap().use_mesc = false;
+        assert_eq!(get_endpoints_by_network_ranked(&config, 10u64, Some("p")).unwrap(), []);
+    }
+
+    #[test]
+    fn ranked_endpoints_require_valid_fallbacks() {
+        let mut config = fallback_config();
+        let fallbacks = serde_json::json!({"10": ["missing"]});
+        config.global_metadata.insert(metadata::NETWORK_FALLBACKS_KEY.to_string(), fallbacks);
+        assert!(matches!(
+            get_endpoints_by_network_ranked(&config, 10u64, None),
+            Err(MescError::MissingEndpoint(name)) if name == "missing"
+        ));
+
+        let fallbacks = serde_json::json!({"10": "d"});
+        config.global_metadata.insert(metadata::NETWORK_FALLBACKS_KEY.to_string(), fallbacks);
+        assert!(matches!(
+            get_endpoints_by_network_ranked(&config, 10u64, None),
+            Err(MescError::IntegrityError(_))
+        ));
+    }
+
+    #[test]
+    fn caip2_chain_ids_resolve_networks() {
+        let solana = ChainId::caip2("solana", "5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp").unwrap();
+        let mut config = RpcConfig::default();
+        for (name, chain_id) in [("eth", ChainId::new(1)), ("sol", solana.clone())] {
+            let endpoint = Endpoint {
+                name: name.to_string(),
+                url: format!("https://{}.example.com", name),
+                chain_id: Some(chain_id.clone()),
+                endpoint_metadata: HashMap::new(),
+            };
+            config.endpoints.insert(name.to_string(), endpoint);
+            config.network_defaults.insert(chain_id, name.to_string());
+        }
+        config.network_names.insert("solana".to_string(), solana);
+        config.validate().unwrap();
+
+        let name = |query: &str| {
+            get_endpoint_by_query(&config, query, None).unwrap().map(|endpoint| endpoint.name)
+        };
+        assert_eq!(name("eip155:1"), Some("eth".to_string()));
+        assert_eq!(name("solana"), Some("sol".to_string()));
+        assert_eq!(name("solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp"), Some("sol".to_string()));
+        assert_eq!(name("solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1"), None);
+        let endpoint = get_endpoint_by_network(&config, "eip155:1", None).unwrap();
+        assert_eq!(endpoint.map(|endpoint| endpoint.name), Some("eth".to_string()));
+    }
+
+    #[test]
+    fn round_robin_cycles_through_group() {
+        let config = group_config(&[]);
+        let selected: Vec<_> = (0..4)
+            .map(|counter| select(&config, SelectionStrategy::RoundRobin { counter }).unwrap())
+            .collect();
+        assert_eq!(selected, vec!["a", "b", "c", "a"]);
+    }
+
+    #[test]
+    fn weighted_random_is_deterministic_and_respects_weights() {
+        let config = group_config(&[
+            ("a", metadata::WEIGHT_KEY, 0.into()),
+            ("b", metadata::WEIGHT_KEY, 3.into()),
+            ("c", metadata::WEIGHT_KEY, 1.into()),
+        ]);
+        let mut counts = HashMap::new();
+        for seed in 0..1000 {
+            let name = select(&config, SelectionStrategy::WeightedRandom { seed }).unwrap();
+            assert_eq!(
+                Some(name.clone()),
+                select(&config, SelectionStrategy::WeightedRandom { seed })
+            );
+            *counts.entry(name).or_insert(0) += 1;
+        }
+        assert_eq!(counts.get("a"), None);
+        assert!(counts["b"] > 2 * counts["c"]);
+    }
+
+    #[test]
+    fn lowest_latency_prefers_observed_endpoints() {
+        let config = group_config(&[
+            ("b", metadata::LATENCY_KEY, 40.into()),
+            ("c", metadata::LATENCY_KEY, 12.5.into()),
+        ]);
+        assert_eq!(select(&config, SelectionStrategy::LowestLatency).as_deref(), Some("c"));
+        let config = group_config(&[]);
+        assert_eq!(select(&config, SelectionStrategy::LowestLatency).as_deref(), Some("a"));
+    }
+
+    #[test]
+    fn first_healthy_skips_unhealthy_endpoints() {
+        let config = group_config(&[
+            ("a", metadata::HEALTHY_KEY, false.into()),
+            ("b", metadata::HEALTHY_KEY, true.into()),
+        ]);
+        assert_eq!(select(&config, SelectionStrategy::FirstHealthy).as_deref(), Some("b"));
+        let config = group_config(&[("a", metadata::HEALTHY_KEY, "yes".into())]);
+        assert!(select_endpoint(&config, "pool", SelectionStrategy::FirstHealthy).is_err());
+    }
+
+    #[test]
+    fn groups_resolve_to_first_member() {
+        let mut config = group_config(&[]);
+        let query = |config: &RpcConfig, query: &str| {
+            get_endpoint_by_query(config, query, None).map(|endpoint| endpoint.map(|e| e.name))
+        };
+        assert_eq!(query(&config, "pool").unwrap(), Some("a".to_string()));
+        let names = get_group(&config, "pool").unwrap().unwrap();
+        assert_eq!(names.iter().map(|e| e.name.as_str()).collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(list_groups(&config).unwrap(), ["pool"]);
+
+        let groups = serde_json::json!({"pool": ["missing", "a"]});
+        config.global_metadata.insert(metadata::GROUPS_KEY.to_string(), groups);
+        assert!(matches!(query(&config, "pool"), Err(MescError::MissingEndpoint(_))));
+        assert_eq!(query(&config, "b").unwrap(), Some("b".to_string()));
+    }
+
+    #[test]
+    fn invalid_groups_only_fail_group_queries() {
+        let mut config = group_config(&[]);
+        config.network_defaults.insert(ChainId::new(1), "b".to_string());
+        let query = |config: &RpcConfig, query: &str| {
+            get_endpoint_by_query(config, query, None).map(|endpoint| endpoint.map(|e| e.name))
+        };
+
+        let groups = serde_json::json!({"pool": "a", "other": ["c"]});
+        config.global_metadata.insert(metadata::GROUPS_KEY.to_string(), groups);
+        assert!(matches!(query(&config, "pool"), Err(MescError::IntegrityError(_))));
+        assert!(matches!(query(&config, "other"), Err(MescError::IntegrityError(_))));
+        assert_eq!(query(&config, "a").unwrap(), Some("a".to_string()));
+        assert_eq!(query(&config, "1").unwrap(), Some("b".to_string()));
+        assert_eq!(query(&config, "ethereum").unwrap(), Some("b".to_string()));
+
+        let groups = serde_json::json!(["pool"]);
+        config.global_metadata.insert(metadata::GROUPS_KEY.to_string(), groups);
+        assert_eq!(query(&config, "pool").unwrap(), None);
+        let trace = trace_endpoint_by_query(&config, "1", None).unwrap();
+        let step = trace.steps.iter().find(|step| step.method == "group").unwrap();
+        assert!(!step.matched);
+        assert!(step.reason.starts_with("could not read groups: "), "{}", step.reason);
+        assert_eq!(trace.endpoint.map(|endpoint| endpoint.name), Some("b".to_string()));
+    }
+
+    #[test]
+    fn missing_group_selects_nothing() {
+        let config = group_config(&[]);
+        let selected = select_endpoint(&config, "missing", SelectionStrategy::FirstHealthy);
+        assert_eq!(selected.unwrap(), None);
+    }
+
+    #[test]
+    fn metadata_value_cascades_from_endpoint_to_profile_to_global() {
+        let mut config = RpcConfig::default();
+        let mut endpoints = Vec::new();
+        for (name, metadata) in
+            [("a", serde_json::json!({"rate_limit_rps": 100})), ("b", serde_json::json!({}))]
+        {
+            let endpoint = Endpoint {
+                name: name.to_string(),
+                url: format!("https://{}.example.com", name),
+                chain_id: Some(ChainId::new(1)),
+                endpoint_metadata: serde_json::from_value(metadata).unwrap(),
+            };
+            endpoints.push(endpoint.clone());
+            config.endpoints.insert(name.to_string(), endpoint);
+        }
+        let [a, b] = [&endpoints[0], &endpoints[1]];
+        config.global_metadata.insert("rate_limit_rps".to_string(), 10.into());
+        config
+            .global_metadata
+            .insert("api_keys".to_string(), serde_json::json!({"etherscan": "global"}));
+        let mut profile = crate::Profile::new("xyz");
+        profile
+            .profile_metadata
+            .insert("api_keys".to_string(), serde_json::json!({"etherscan": "profile"}));
+        config.profiles.insert("xyz".to_string(), profile);
+
+        let rps: Option<f64> =
+            get_metadata_value(&config, "rate_limit_rps", Some(a), None).unwrap();
+        assert_eq!(rps, Some(100.0));
+        let rps: Option<f64> =
+            get_metadata_value(&config, "rate_limit_rps", Some(b), None).unwrap();
+        assert_eq!(rps, Some(10.0));
+        let key: Option<String> =
+            get_metadata_value(&config, "api_keys.etherscan", Some(a), Some("xyz")).unwrap();
+        assert_eq!(key.as_deref(), Some("profile"));
+        let key: Option<String> =
+            get_metadata_value(&config, "api_keys.etherscan", None, None).unwrap();
+        assert_eq!(key.as_deref(), Some("global"));
+        let missing: Option<String> =
+            get_metadata_value(&config, "api_keys.other", None, None).unwrap();
+        assert_eq!(missing, None);
+        let error = get_metadata_value::<String>(&config, "rate_limit_rps", Some(a), None);
+        assert!(matches!(
+            error,
+            Err(MescError::InvalidMetadataType { expected, found, .. })
+                if expected == "String" && found == "number"
+        ));
+
+        // profiles with use_mesc disabled resolve no metadata, like they resolve no endpoints
+        config.profiles.get_mut("xyz").unwrap().use_mesc = false;
+        let key: Option<String> =
+            get_metadata_value(&config, "api_keys.etherscan", Some(a), Some("xyz")).unwrap();
+        assert_eq!(key, None);
+    }
+
+    #[test]
+    fn execute_json_queries() {
+        let mut config = RpcConfig::default();
+        for (name, metadata) in
+            [("a", serde_json::json!({"host": "alchemy"})), ("b", serde_json::json!({}))]
+        {
+            let endpoint = Endpoint {
+                name: name.to_string(),
+                url: format!("https://{}.example.com", name),
+                chain_id: Some(ChainId::new(1)),
+                endpoint_metadata: serde_json::from_value(metadata).unwrap(),
+            };
+            config.endpoints.insert(name.to_string(), endpoint);
+        }
+        config.default_endpoint = Some("b".to_string());
+        config
+            .global_metadata
+            .insert("explorer".to_string(), serde_json::json!({"1": ["etherscan"]}));
+        let run = |query: serde_json::Value| {
+            let query: MescQuery = serde_json::from_value(query).unwrap();
+            serde_json::to_value(execute(&config, query).unwrap()).unwrap()
+        };
+
+        let endpoint = run(serde_json::json!({"query_type": "default_endpoint", "fields": {}}));
+        assert_eq!(endpoint["name"], "b");
+        let endpoint = run(serde_json::json!({
+            "query_type": "endpoint_by_network",
+            "fields": {"chain_id": 1, "profile": null}
+        }));
+        assert_eq!(endpoint, serde_json::Value::Null);
+        let endpoints = run(serde_json::json!({
+            "query_type": "multi_endpoint",
+            "fields": {"chain_id": 1, "metadata": [{"equals": ["host", "alchemy"]}]}
+        }));
+        assert_eq!(endpoints[0]["name"], "a");
+        assert_eq!(endpoints.as_array().map(|x| x.len()), Some(1));
+        let explorers = run(serde_json::json!({
+            "query_type": "global_metadata",
+            "fields": {"path": ["explorer", "1"]}
+        }));
+        assert_eq!(explorers, serde_json::json!(["etherscan"]));
+
+        let query = serde_json::json!({"query_type": "user_input", "fields": {"user_input": "a", "profile": null}});
+        let parsed: MescQuery = serde_json::from_value(query.clone()).unwrap();
+        assert_eq!(serde_json::to_value(parsed).unwrap(), query);
+        let invalid = serde_json::json!({"query_type": "endpoint_by_name", "fields": {"name": 1}});
+        assert!(serde_json::from_value::<MescQuery>(invalid).is_err());
+    }
+}
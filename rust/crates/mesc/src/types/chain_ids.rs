@@ -1,16 +1,114 @@
 use crate::MescError;
 use serde::{Deserialize, Serialize};
 
-/// ChainId is a string representation of an integer chain id
+/// ChainId is a 256-bit unsigned integer chain id, or a CAIP-2 chain id of a non-EVM chain
 /// - TryFrom conversions allow specifying as String, &str, uint, or binary data
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+/// - strings can be decimal, `0x`-prefixed hex, or CAIP-2 (`<namespace>:<reference>`)
+/// - CAIP-2 chain ids in the `eip155` namespace are the same as the integer chain id, so
+///   `eip155:1` equals `1`
+/// - the format a chain id was parsed from is kept when displaying and serializing it, but
+///   is ignored when comparing and hashing
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(into = "ChainIdSerde", try_from = "ChainIdSerde")]
-pub struct ChainId(u64);
+pub struct ChainId {
+    value: ChainIdValue,
+    format: ChainIdFormat,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum ChainIdValue {
+    Evm([u8; 32]),
+    Caip2 { namespace: String, reference: String },
+}
+
+/// ChainIdFormat, the string representation of a chain id
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChainIdFormat {
+    /// decimal, e.g. `8453`
+    Decimal,
+    /// `0x`-prefixed hex without leading zeros, e.g. `0x2105`
+    Hex,
+    /// `0x`-prefixed hex zero-padded to 256 bits
+    Hex256,
+    /// CAIP-2, e.g. `eip155:8453` or `solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp`
+    Caip2,
+}
+
+/// CAIP-2 namespace of EVM chains
+pub const EVM_NAMESPACE: &str = "eip155";
+
+/// CAIP-2 namespaces registered by chain agnostic standards, bare `<namespace>:<number>` values
+/// of these namespaces are chain ids rather than `host:port` url's
+pub const CAIP2_NAMESPACES: &[&str] = &[
+    "algorand", "aptos", "bip122", "cosmos", "eip155", "hedera", "near", "polkadot", "solana",
+    "stacks", "starknet", "stellar", "sui", "tezos", "tron", "xrpl",
+];
+
+impl PartialEq for ChainId {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for ChainId {}
+
+impl PartialOrd for ChainId {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// EVM chain ids are ordered numerically, before non-EVM chain ids
+impl Ord for ChainId {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl std::hash::Hash for ChainId {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
 
 impl ChainId {
     /// create new chain id
     pub fn new(chain_id: u64) -> ChainId {
-        ChainId(chain_id)
+        ChainId::from(chain_id)
+    }
+
+    /// create chain id from 32 big-endian bytes
+    pub fn from_be_bytes(bytes: [u8; 32]) -> ChainId {
+        ChainId { value: ChainIdValue::Evm(bytes), format: ChainIdFormat::Decimal }.normalized()
+    }
+
+    /// create chain id from CAIP-2 namespace and reference
+    pub fn caip2(namespace: &str, reference: &str) -> Result<ChainId, MescError> {
+        ChainId::parse(&format!("{}:{}", namespace, reference))
+    }
+
+    /// format used when displaying and serializing chain id
+    pub fn format(&self) -> ChainIdFormat {
+        self.format
+    }
+
+    /// same chain id, displayed and serialized using given format
+    ///
+    /// non-EVM chain ids are always displayed as CAIP-2
+    pub fn with_format(&self, format: ChainIdFormat) -> ChainId {
+        match self.value {
+            ChainIdValue::Evm(_) => ChainId { value: self.value.clone(), format },
+            ChainIdValue::Caip2 { .. } => self.clone(),
+        }
+    }
+
+    /// same chain id, using the format recommended by the specification: decimal for values
+    /// that fit in a u64, and hex zero-padded to 256 bits for larger values
+    pub fn normalized(&self) -> ChainId {
+        match self.as_u64() {
+            Some(_) => self.with_format(ChainIdFormat::Decimal),
+            None => self.with_format(ChainIdFormat::Hex256),
+        }
     }
 
     /// parse chain id from string
@@ -18,27 +116,132 @@ impl ChainId {
         chain_id.try_into_chain_id()
     }
 
+    /// whether chain id is an EVM chain id
+    pub fn is_evm(&self) -> bool {
+        matches!(self.value, ChainIdValue::Evm(_))
+    }
+
+    /// CAIP-2 namespace of chain id, `eip155` for EVM chain ids
+    pub fn namespace(&self) -> &str {
+        match &self.value {
+            ChainIdValue::Evm(_) => EVM_NAMESPACE,
+            ChainIdValue::Caip2 { namespace, .. } => namespace,
+        }
+    }
+
+    /// CAIP-2 reference of chain id, the decimal chain id for EVM chain ids
+    pub fn reference(&self) -> String {
+        match &self.value {
+            ChainIdValue::Evm(_) => self.to_decimal(),
+            ChainIdValue::Caip2 { reference, .. } => reference.clone(),
+        }
+    }
+
+    /// convert to CAIP-2 representation
+    pub fn to_caip2(&self) -> String {
+        format!("{}:{}", self.namespace(), self.reference())
+    }
+
+    /// get EVM chain id as 32 big-endian bytes
+    pub fn to_be_bytes(&self) -> Option<[u8; 32]> {
+        match self.value {
+            ChainIdValue::Evm(bytes) => Some(bytes),
+            ChainIdValue::Caip2 { .. } => None,
+        }
+    }
+
+    /// get EVM chain id value, or None if it does not fit in a u64
+    pub fn as_u64(&self) -> Option<u64> {
+        let bytes = self.to_be_bytes()?;
+        let (high, low) = bytes.split_at(24);
+        if high.iter().all(|byte| *byte == 0) {
+            Some(u64::from_be_bytes(low.try_into().expect("slice has 8 bytes")))
+        } else {
+            None
+        }
+    }
+
     /// get chain id value
+    ///
+    /// panics if chain id is not an EVM chain id or does not fit in a u64
+    #[deprecated(note = "chain ids can exceed u64, use `as_u64()` instead")]
     pub fn get(&self) -> u64 {
-        self.0
+        self.as_u64().unwrap_or_else(|| panic!("chain id {} does not fit in a u64", self))
     }
 
-    /// convert to hex representation
+    /// convert to hex representation, non-EVM chain ids are returned as CAIP-2
     pub fn to_hex(&self) -> String {
-        let ChainId(chain_id) = self;
-        format!("{chain_id:#x}")
+        if !self.is_evm() {
+            return self.to_caip2();
+        }
+        let digits = self.to_hex_256();
+        let digits = digits[2..].trim_start_matches('0');
+        if digits.is_empty() {
+            "0x0".to_string()
+        } else {
+            format!("0x{}", digits)
+        }
     }
 
-    /// convert to hex representation, zero-padded to 256 bits
+    /// convert to hex representation zero-padded to 256 bits, non-EVM chain ids are returned
+    /// as CAIP-2
     pub fn to_hex_256(&self) -> String {
-        let ChainId(chain_id) = self;
-        format!("0x{chain_id:064x}")
+        match self.to_be_bytes() {
+            Some(bytes) => {
+                let digits: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+                format!("0x{}", digits)
+            }
+            None => self.to_caip2(),
+        }
     }
+
+    /// convert to decimal representation, non-EVM chain ids are returned as CAIP-2
+    pub fn to_decimal(&self) -> String {
+        if let Some(chain_id) = self.as_u64() {
+            return chain_id.to_string();
+        }
+        let Some(mut value) = self.to_be_bytes() else {
+            return self.to_caip2();
+        };
+        let mut digits = Vec::new();
+        while value.iter().any(|byte| *byte != 0) {
+            digits.push(b'0' + div_rem(&mut value, 10));
+        }
+        digits.reverse();
+        String::from_utf8(digits).expect("digits are ascii")
+    }
+}
+
+/// multiply big-endian value in place and add to it, returning false on overflow
+fn mul_add(value: &mut [u8; 32], mul: u16, add: u16) -> bool {
+    let mut carry = add as u32;
+    for byte in value.iter_mut().rev() {
+        let x = (*byte as u32) * (mul as u32) + carry;
+        *byte = x as u8;
+        carry = x >> 8;
+    }
+    carry == 0
+}
+
+/// divide big-endian value in place, returning the remainder
+fn div_rem(value: &mut [u8; 32], div: u8) -> u8 {
+    let mut remainder = 0u32;
+    for byte in value.iter_mut() {
+        let x = (remainder << 8) | *byte as u32;
+        *byte = (x / div as u32) as u8;
+        remainder = x % div as u32;
+    }
+    remainder as u8
 }
 
 impl std::fmt::Display for ChainId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.0.fmt(f)
+        match self.format {
+            ChainIdFormat::Decimal => self.to_decimal().fmt(f),
+            ChainIdFormat::Hex => self.to_hex().fmt(f),
+            ChainIdFormat::Hex256 => self.to_hex_256().fmt(f),
+            ChainIdFormat::Caip2 => self.to_caip2().fmt(f),
+        }
     }
 }
 
@@ -47,14 +250,23 @@ macro_rules! impl_from_uint_for_chainid {
         $(
             impl From<$t> for ChainId {
                 fn from(value: $t) -> ChainId {
-                    ChainId(value as _)
+                    let mut bytes = [0u8; 32];
+                    let value = value.to_be_bytes();
+                    bytes[32 - value.len()..].copy_from_slice(&value);
+                    ChainId::from_be_bytes(bytes)
                 }
             }
         )*
     };
 }
 
-impl_from_uint_for_chainid!(u8, u16, u32, u64, usize);
+impl_from_uint_for_chainid!(u8, u16, u32, u64, u128, usize);
+
+impl From<[u8; 32]> for ChainId {
+    fn from(bytes: [u8; 32]) -> ChainId {
+        ChainId::from_be_bytes(bytes)
+    }
+}
 
 /// use custom trait instead of TryInto so that Error type is always the same
 pub trait TryIntoChainId {
@@ -76,19 +288,57 @@ impl TryIntoChainId for String {
 
 impl TryIntoChainId for &str {
     fn try_into_chain_id(self) -> Result<ChainId, MescError> {
-        if !self.is_empty() {
-            let (radix, s) = match self.get(0..2) {
-                Some("0x") => (16, &self[2..]),
-                _ => (10, self),
-            };
-            if let Ok(chain_id) = u64::from_str_radix(s, radix) {
-                return Ok(ChainId(chain_id));
-            }
+        if let Some((namespace, reference)) = self.split_once(':') {
+            return parse_caip2(namespace, reference)
+                .ok_or_else(|| MescError::InvalidChainId(self.to_string()));
+        }
+        let (radix, digits) = match self.get(0..2) {
+            Some("0x") => (16, &self[2..]),
+            _ => (10, self),
+        };
+        let format = match radix {
+            16 if digits.len() == 64 => ChainIdFormat::Hex256,
+            16 => ChainIdFormat::Hex,
+            _ => ChainIdFormat::Decimal,
+        };
+        match parse_integer(digits, radix) {
+            Some(value) => Ok(ChainId { value: ChainIdValue::Evm(value), format }),
+            None => Err(MescError::InvalidChainId(self.to_string())),
         }
-        Err(MescError::InvalidChainId(self.to_string()))
     }
 }
 
+/// parse unsigned 256-bit integer digits
+fn parse_integer(digits: &str, radix: u32) -> Option<[u8; 32]> {
+    let mut value = [0u8; 32];
+    if digits.is_empty() {
+        return None;
+    }
+    for c in digits.chars() {
+        if !mul_add(&mut value, radix as u16, c.to_digit(radix)? as u16) {
+            return None;
+        }
+    }
+    Some(value)
+}
+
+/// parse CAIP-2 chain id, the reference of `eip155` chain ids must be a decimal integer
+fn parse_caip2(namespace: &str, reference: &str) -> Option<ChainId> {
+    let valid_namespace = (3..=8).contains(&namespace.len())
+        && namespace.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    let valid_reference = (1..=32).contains(&reference.len())
+        && reference.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid_namespace || !valid_reference {
+        return None;
+    }
+    let value = if namespace == EVM_NAMESPACE {
+        ChainIdValue::Evm(parse_integer(reference, 10)?)
+    } else {
+        ChainIdValue::Caip2 { namespace: namespace.to_string(), reference: reference.to_string() }
+    };
+    Some(ChainId { value, format: ChainIdFormat::Caip2 })
+}
+
 macro_rules! impl_try_into_chain_id_for_integer {
     ($($t:ty),*) => {
         $(
@@ -101,15 +351,25 @@ macro_rules! impl_try_into_chain_id_for_integer {
     };
 }
 
-impl_try_into_chain_id_for_integer!(u8, u16, u32, u64, usize);
+impl_try_into_chain_id_for_integer!(u8, u16, u32, u64, u128, usize);
 
+/// binary data is interpreted as a big-endian integer of at most 32 significant bytes
 impl TryIntoChainId for &[u8] {
     fn try_into_chain_id(self) -> Result<ChainId, MescError> {
-        Err(MescError::NotImplemented("binary chain_id".to_string()))
+        let start = self.iter().position(|byte| *byte != 0).unwrap_or(self.len());
+        let significant = &self[start..];
+        if self.is_empty() || significant.len() > 32 {
+            let hex: String = self.iter().map(|byte| format!("{byte:02x}")).collect();
+            return Err(MescError::InvalidChainId(format!("0x{}", hex)));
+        }
+        let mut bytes = [0u8; 32];
+        bytes[32 - significant.len()..].copy_from_slice(significant);
+        Ok(ChainId::from_be_bytes(bytes))
     }
 }
 
 #[derive(Serialize, Deserialize)]
+#[serde(transparent)]
 struct ChainIdSerde(String);
 
 impl From<ChainId> for ChainIdSerde {
@@ -125,3 +385,169 @@ impl TryFrom<ChainIdSerde> for ChainId {
         value.0.try_into_chain_id()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chain_ids_use_256_bits() {
+        let max = format!("0x{}", "f".repeat(64));
+        let chain_id = ChainId::parse(&max).unwrap();
+        assert_eq!(chain_id.to_hex(), max);
+        assert_eq!(chain_id.as_u64(), None);
+        assert_eq!(
+            chain_id.to_decimal(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(ChainId::parse(&chain_id.to_decimal()).unwrap(), chain_id);
+        assert!(ChainId::parse(&format!("0x1{}", "0".repeat(64))).is_err());
+        assert!(ChainId::parse(&format!("{}0", chain_id.to_decimal())).is_err());
+
+        let base = ChainId::parse("0x2105").unwrap();
+        assert_eq!((base.to_decimal(), base.to_hex()), ("8453".to_string(), "0x2105".to_string()));
+        assert_eq!(base.to_hex_256(), format!("0x{:064x}", 8453));
+        assert_eq!(base.as_u64(), Some(8453));
+        assert!(base < chain_id && ChainId::new(9) < ChainId::new(10));
+
+        let bytes: &[u8] = &[0, 0, 0x21, 0x05];
+        assert_eq!(bytes.try_into_chain_id().unwrap(), base);
+        assert!([1u8; 33].as_slice().try_into_chain_id().is_err());
+        assert_eq!(
+            u128::MAX.try_into_chain_id().unwrap().to_hex(),
+            format!("0x{}", "f".repeat(32))
+        );
+        for invalid in ["", "0x", "-1", "1.5", "0xg"] {
+            assert!(ChainId::parse(invalid).is_err());
+        }
+    }
+
+    fn u128_bytes(value: u128) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[16..].copy_from_slice(&value.to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn mul_add_carries_across_boundaries() {
+        let mut value = u128_bytes(u64::MAX as u128);
+        assert!(mul_add(&mut value, 1, 1));
+        assert_eq!(value, u128_bytes(1 << 64));
+        let mut value = u128_bytes(u64::MAX as u128);
+        assert!(mul_add(&mut value, 10, 9));
+        assert_eq!(value, u128_bytes(u64::MAX as u128 * 10 + 9));
+
+        let mut value = u128_bytes(u128::MAX);
+        assert!(mul_add(&mut value, 256, 255));
+        assert_eq!(value, [[0u8; 15].as_slice(), &[0xff; 17]].concat().as_slice());
+        let mut value = u128_bytes(u128::MAX);
+        assert!(mul_add(&mut value, 1, 1));
+        assert_eq!(value, [[0u8; 15].as_slice(), &[1], &[0; 16]].concat().as_slice());
+
+        let mut value = [0xff; 32];
+        assert!(mul_add(&mut value, 1, 0));
+        assert!(!mul_add(&mut value.clone(), 1, 1));
+        assert!(!mul_add(&mut value.clone(), 2, 0));
+        assert!(!mul_add(&mut value, 10, 0));
+    }
+
+    #[test]
+    fn div_rem_handles_boundaries() {
+        let mut value = u128_bytes(u64::MAX as u128);
+        assert_eq!(div_rem(&mut value, 10), 5);
+        assert_eq!(value, u128_bytes(u64::MAX as u128 / 10));
+        let mut value = u128_bytes(1 << 64);
+        assert_eq!(div_rem(&mut value, 16), 0);
+        assert_eq!(value, u128_bytes(1 << 60));
+
+        let mut value = u128_bytes(u128::MAX);
+        assert_eq!(div_rem(&mut value, 10), 5);
+        assert_eq!(value, u128_bytes(u128::MAX / 10));
+
+        let mut value = [0xff; 32];
+        assert_eq!(div_rem(&mut value, 255), 0);
+        assert_eq!(value, [1; 32]);
+        let mut value = [0xff; 32];
+        assert_eq!(div_rem(&mut value, 10), 5);
+        assert!(mul_add(&mut value, 10, 5));
+        assert_eq!(value, [0xff; 32]);
+    }
+
+    #[test]
+    fn byte_slices_are_big_endian_integers() {
+        let max = ChainId::from_be_bytes([0xff; 32]);
+        let padded_max = [[0u8].as_slice(), &[0xff; 32]].concat();
+        let cases: [(&[u8], Option<ChainId>); 7] = [
+            (&[], None),
+            (&[0], Some(ChainId::new(0))),
+            (&[0, 0, 0x21, 0x05], Some(ChainId::new(8453))),
+            (&[0xff; 8], Some(ChainId::new(u64::MAX))),
+            (&[0xff; 32], Some(max.clone())),
+            (&padded_max, Some(max)),
+            (&[1; 33], None),
+        ];
+        for (bytes, expected) in cases {
+            assert_eq!(bytes.try_into_chain_id().ok(), expected, "{:?}", bytes);
+        }
+        assert!(matches!(
+            [1u8; 33].as_slice().try_into_chain_id(),
+            Err(MescError::InvalidChainId(hex)) if hex == format!("0x{}", "01".repeat(33))
+        ));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn get_returns_u64_chain_ids() {
+        assert_eq!(ChainId::new(8453).get(), 8453);
+        assert_eq!(ChainId::parse("eip155:10").unwrap().get(), 10);
+        assert!(std::panic::catch_unwind(|| ChainId::from(u128::MAX).get()).is_err());
+    }
+
+    #[test]
+    fn chain_ids_keep_format() {
+        let full_width = format!("0x{:064x}", 8453);
+        for written in ["8453", "0x2105", full_width.as_str()] {
+            let chain_id = ChainId::parse(written).unwrap();
+            assert_eq!(chain_id, ChainId::new(8453));
+            assert_eq!(serde_json::to_value(&chain_id).unwrap(), written);
+            assert_eq!(chain_id.normalized().to_string(), "8453");
+        }
+
+        let hashed = ChainId::parse(&format!("0xab{}", "0".repeat(62))).unwrap();
+        assert_eq!(hashed.format(), ChainIdFormat::Hex256);
+        let hashed = hashed.with_format(ChainIdFormat::Decimal);
+        assert_eq!(hashed.normalized().to_string(), format!("0xab{}", "0".repeat(62)));
+        assert_eq!(ChainId::from(u128::MAX).format(), ChainIdFormat::Hex256);
+    }
+
+    #[test]
+    fn chain_ids_accept_caip2() {
+        let base = ChainId::parse("eip155:8453").unwrap();
+        assert_eq!(base, ChainId::new(8453));
+        assert_eq!(
+            (base.to_string(), base.normalized().to_string()),
+            ("eip155:8453".into(), "8453".into())
+        );
+        assert_eq!(ChainId::new(1).to_caip2(), "eip155:1");
+        assert_eq!(ChainId::parse("eip155:1").unwrap(), ChainId::new(1));
+        assert_eq!(ChainId::caip2("eip155", "8453").unwrap(), base);
+
+        let solana = ChainId::parse("solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp").unwrap();
+        assert!(!solana.is_evm());
+        assert_eq!((solana.namespace(), solana.as_u64()), ("solana", None));
+        assert_eq!(
+            serde_json::to_value(&solana).unwrap(),
+            "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp"
+        );
+        assert_eq!(solana.normalized(), solana);
+        assert_eq!(solana, ChainId::caip2("solana", "5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp").unwrap());
+        assert_eq!(solana.reference(), "5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp");
+        assert_ne!(solana, ChainId::caip2("solana", "EtWTRABZaYq6iMfeYKouRu166VU2xqa1").unwrap());
+        assert!(ChainId::new(u64::MAX) < solana);
+        assert_ne!(solana, ChainId::caip2("cosmos", "cosmoshub-4").unwrap());
+
+        for invalid in ["eip155:0x1", "eip155:", "EIP155:1", "ab:1", "solana:a.b", "a:b:c"] {
+            assert!(ChainId::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
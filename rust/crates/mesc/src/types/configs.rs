@@ -1,6 +1,6 @@
-use crate::{validate, ChainId, MescError};
+use crate::{validate, ChainId, EndpointMetadata, MescError, ValidationReport};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Endpoint
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
@@ -20,6 +20,21 @@ impl Endpoint {
     pub fn chain_id_string(&self) -> String {
         self.chain_id.clone().map(|x| x.to_string()).unwrap_or("-".to_string())
     }
+
+    /// typed view of endpoint metadata
+    pub fn metadata(&self) -> EndpointMetadata<&HashMap<String, serde_json::Value>> {
+        (&self.endpoint_metadata).into()
+    }
+
+    /// typed view of endpoint metadata that modifies the endpoint
+    pub fn metadata_mut(&mut self) -> EndpointMetadata<&mut HashMap<String, serde_json::Value>> {
+        (&mut self.endpoint_metadata).into()
+    }
+
+    /// replace endpoint metadata
+    pub fn set_metadata(&mut self, metadata: EndpointMetadata) {
+        self.endpoint_metadata = metadata.into();
+    }
 }
 
 /// Profile
@@ -51,16 +66,59 @@ impl Profile {
 }
 
 /// ConfigMode
-#[derive(Debug)]
+///
+/// new modes may be added, so matches need a wildcard arm
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum ConfigMode {
     /// Path
     Path,
     /// Env
     Env,
+    /// Overrides only, no underlying config
+    Overrides,
     /// Disabled
     Disabled,
 }
 
+/// OverrideMode
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum OverrideMode {
+    /// Merge override values into config key by key
+    #[default]
+    Merge,
+    /// Replace config values with override values
+    Replace,
+}
+
+/// ConfigSource
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigSource {
+    /// config file at MESC_PATH
+    Path(String),
+    /// config data in MESC_ENV
+    Env,
+    /// override variable
+    Override(String),
+}
+
+impl std::fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigSource::Path(path) => write!(f, "MESC_PATH ({})", path),
+            ConfigSource::Env => write!(f, "MESC_ENV"),
+            ConfigSource::Override(var) => write!(f, "{}", var),
+        }
+    }
+}
+
+/// source of each effective config value, keyed by dot-separated config path
+pub type ConfigProvenance = BTreeMap<String, ConfigSource>;
+
+/// value of `mesc_version` required by the specification
+pub const MESC_VERSION: &str = "MESC 1.0";
+
 /// RpcConfig
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 pub struct RpcConfig {
@@ -83,7 +141,7 @@ pub struct RpcConfig {
 impl Default for RpcConfig {
     fn default() -> Self {
         Self {
-            mesc_version: env!("CARGO_PKG_VERSION").to_string(),
+            mesc_version: MESC_VERSION.to_string(),
             default_endpoint: None,
             network_defaults: HashMap::new(),
             network_names: HashMap::new(),
@@ -100,8 +158,48 @@ impl RpcConfig {
         Ok(serde_json::to_string(self)?)
     }
 
-    /// validate config
+    /// validate config, returning the first error found
     pub fn validate(&self) -> Result<(), MescError> {
         validate::validate_config(self)
     }
+
+    /// validate config, returning every error and warning found
+    pub fn validate_all(&self) -> ValidationReport {
+        validate::validate_config_all(self)
+    }
+
+    /// validate config, also checking names, mesc_version, and network_names strictly
+    pub fn validate_strict(&self) -> ValidationReport {
+        validate::validate_config_strict(self)
+    }
+
+    /// validate raw config json strictly, also checking for missing and unknown keys
+    ///
+    /// `origin` names where the data came from (a file path or MESC_ENV) and is included in errors
+    pub fn validate_strict_json(
+        config_json: &str,
+        origin: &str,
+    ) -> Result<ValidationReport, MescError> {
+        validate::validate_config_json_strict(config_json, origin)
+    }
+
+    /// rewrite chain ids of endpoints, network defaults, and network names using the format
+    /// recommended by the specification, see `ChainId::normalized()`
+    pub fn normalize_chain_ids(&mut self) {
+        for endpoint in self.endpoints.values_mut() {
+            endpoint.chain_id = endpoint.chain_id.as_ref().map(ChainId::normalized);
+        }
+        self.network_defaults = normalize_keys(std::mem::take(&mut self.network_defaults));
+        for chain_id in self.network_names.values_mut() {
+            *chain_id = chain_id.normalized();
+        }
+        for profile in self.profiles.values_mut() {
+            profile.network_defaults =
+                normalize_keys(std::mem::take(&mut profile.network_defaults));
+        }
+    }
+}
+
+fn normalize_keys(map: HashMap<ChainId, String>) -> HashMap<ChainId, String> {
+    map.into_iter().map(|(chain_id, value)| (chain_id.normalized(), value)).collect()
 }
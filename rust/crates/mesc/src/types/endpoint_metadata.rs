@@ -0,0 +1,345 @@
+use crate::MescError;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    collections::HashMap,
+};
+
+type Metadata = HashMap<String, serde_json::Value>;
+
+/// endpoint metadata keys defined by the MESC specification
+pub const ENDPOINT_METADATA_KEYS: [&str; 13] = [
+    "rate_limit_rps",
+    "rate_limit_cups",
+    "rate_limit_per_method",
+    "api_key",
+    "jwt_secret",
+    "host",
+    "ecosystem",
+    "node_client",
+    "namespaces",
+    "explorer",
+    "location",
+    "cloud_region",
+    "labels",
+];
+
+/// typed view of endpoint metadata
+///
+/// getters return `Ok(None)` for missing keys and an error for values of the wrong type,
+/// keys not defined by the specification are kept as is
+///
+/// metadata is either owned or borrowed from an endpoint, see `Endpoint::metadata()` and
+/// `Endpoint::metadata_mut()`
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq)]
+#[serde(transparent)]
+pub struct EndpointMetadata<M = Metadata>(M);
+
+impl From<Metadata> for EndpointMetadata {
+    fn from(metadata: Metadata) -> Self {
+        Self(metadata)
+    }
+}
+
+impl From<EndpointMetadata> for Metadata {
+    fn from(metadata: EndpointMetadata) -> Self {
+        metadata.0
+    }
+}
+
+impl<'a> From<&'a Metadata> for EndpointMetadata<&'a Metadata> {
+    fn from(metadata: &'a Metadata) -> Self {
+        Self(metadata)
+    }
+}
+
+impl<'a> From<&'a mut Metadata> for EndpointMetadata<&'a mut Metadata> {
+    fn from(metadata: &'a mut Metadata) -> Self {
+        Self(metadata)
+    }
+}
+
+impl EndpointMetadata {
+    /// create empty metadata
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<M: Borrow<Metadata>> EndpointMetadata<M> {
+    /// raw metadata entries
+    pub fn as_map(&self) -> &Metadata {
+        self.0.borrow()
+    }
+
+    /// raw value of key
+    pub fn get_value(&self, key: &str) -> Option<&serde_json::Value> {
+        self.as_map().get(key)
+    }
+
+    /// keys not defined by the specification
+    pub fn unknown_keys(&self) -> Vec<&str> {
+        let mut keys: Vec<&str> = self
+            .as_map()
+            .keys()
+            .map(|key| key.as_str())
+            .filter(|key| !ENDPOINT_METADATA_KEYS.contains(key))
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    /// check that every key defined by the specification has the correct type
+    pub fn validate(&self) -> Result<(), MescError> {
+        self.rate_limit_rps()?;
+        self.rate_limit_cups()?;
+        self.rate_limit_per_method()?;
+        self.api_key()?;
+        self.jwt_secret()?;
+        self.host()?;
+        self.ecosystem()?;
+        self.node_client()?;
+        self.namespaces()?;
+        self.explorer()?;
+        self.location()?;
+        self.cloud_region()?;
+        self.labels()?;
+        Ok(())
+    }
+
+    /// ratelimit in requests per second
+    pub fn rate_limit_rps(&self) -> Result<Option<f64>, MescError> {
+        self.get("rate_limit_rps", "number")
+    }
+
+    /// ratelimit in compute units per second
+    pub fn rate_limit_cups(&self) -> Result<Option<f64>, MescError> {
+        self.get("rate_limit_cups", "number")
+    }
+
+    /// ratelimit in requests per second for each method
+    pub fn rate_limit_per_method(&self) -> Result<Option<HashMap<String, f64>>, MescError> {
+        self.get("rate_limit_per_method", "map of method to number")
+    }
+
+    /// api key
+    pub fn api_key(&self) -> Result<Option<String>, MescError> {
+        self.get("api_key", "string")
+    }
+
+    /// jwt secret
+    pub fn jwt_secret(&self) -> Result<Option<String>, MescError> {
+        self.get("jwt_secret", "string")
+    }
+
+    /// name of provider host
+    pub fn host(&self) -> Result<Option<String>, MescError> {
+        self.get("host", "string")
+    }
+
+    /// ecosystem of chain
+    pub fn ecosystem(&self) -> Result<Option<String>, MescError> {
+        self.get("ecosystem", "string")
+    }
+
+    /// versioned node client
+    pub fn node_client(&self) -> Result<Option<String>, MescError> {
+        self.get("node_client", "string")
+    }
+
+    /// rpc namespaces enabled for endpoint
+    pub fn namespaces(&self) -> Result<Option<Vec<String>>, MescError> {
+        self.get("namespaces", "list of strings")
+    }
+
+    /// block explorer url
+    pub fn explorer(&self) -> Result<Option<String>, MescError> {
+        self.get("explorer", "string")
+    }
+
+    /// geographic region
+    pub fn location(&self) -> Result<Option<String>, MescError> {
+        self.get("location", "string")
+    }
+
+    /// cloud provider region
+    pub fn cloud_region(&self) -> Result<Option<String>, MescError> {
+        self.get("cloud_region", "string")
+    }
+
+    /// tags
+    pub fn labels(&self) -> Result<Option<Vec<String>>, MescError> {
+        self.get("labels", "list of strings")
+    }
+
+    fn get<T: DeserializeOwned>(&self, key: &str, expected: &str) -> Result<Option<T>, MescError> {
+        match self.as_map().get(key) {
+            Some(value) => serde_json::from_value(value.clone()).map(Some).map_err(|_| {
+                MescError::InvalidMetadataType {
+                    key: key.to_string(),
+                    expected: expected.to_string(),
+                    found: crate::metadata::json_type_name(value).to_string(),
+                }
+            }),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<M: BorrowMut<Metadata>> EndpointMetadata<M> {
+    /// set raw value of key
+    pub fn set_value<T: Into<String>>(&mut self, key: T, value: serde_json::Value) {
+        self.0.borrow_mut().insert(key.into(), value);
+    }
+
+    /// remove key, returning its raw value
+    pub fn remove(&mut self, key: &str) -> Option<serde_json::Value> {
+        self.0.borrow_mut().remove(key)
+    }
+
+    /// set ratelimit in requests per second
+    pub fn set_rate_limit_rps<T: Into<serde_json::Number>>(&mut self, value: T) {
+        self.set("rate_limit_rps", value.into())
+    }
+
+    /// set ratelimit in compute units per second
+    pub fn set_rate_limit_cups<T: Into<serde_json::Number>>(&mut self, value: T) {
+        self.set("rate_limit_cups", value.into())
+    }
+
+    /// set ratelimit in requests per second for each method
+    pub fn set_rate_limit_per_method(&mut self, value: HashMap<String, serde_json::Number>) {
+        self.set("rate_limit_per_method", value)
+    }
+
+    /// set api key
+    pub fn set_api_key<T: Into<String>>(&mut self, value: T) {
+        self.set("api_key", value.into())
+    }
+
+    /// set jwt secret
+    pub fn set_jwt_secret<T: Into<String>>(&mut self, value: T) {
+        self.set("jwt_secret", value.into())
+    }
+
+    /// set name of provider host
+    pub fn set_host<T: Into<String>>(&mut self, value: T) {
+        self.set("host", value.into())
+    }
+
+    /// set ecosystem of chain
+    pub fn set_ecosystem<T: Into<String>>(&mut self, value: T) {
+        self.set("ecosystem", value.into())
+    }
+
+    /// set versioned node client
+    pub fn set_node_client<T: Into<String>>(&mut self, value: T) {
+        self.set("node_client", value.into())
+    }
+
+    /// set rpc namespaces enabled for endpoint
+    pub fn set_namespaces(&mut self, value: Vec<String>) {
+        self.set("namespaces", value)
+    }
+
+    /// set block explorer url
+    pub fn set_explorer<T: Into<String>>(&mut self, value: T) {
+        self.set("explorer", value.into())
+    }
+
+    /// set geographic region
+    pub fn set_location<T: Into<String>>(&mut self, value: T) {
+        self.set("location", value.into())
+    }
+
+    /// set cloud provider region
+    pub fn set_cloud_region<T: Into<String>>(&mut self, value: T) {
+        self.set("cloud_region", value.into())
+    }
+
+    /// set tags
+    pub fn set_labels(&mut self, value: Vec<String>) {
+        self.set("labels", value)
+    }
+
+    fn set<T: Serialize>(&mut self, key: &str, value: T) {
+        self.0.borrow_mut().insert(key.to_string(), serde_json::json!(value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ChainId, Endpoint};
+
+    #[test]
+    fn rate_limits_keep_number_type() {
+        let mut metadata = EndpointMetadata::new();
+        metadata.set_rate_limit_rps(10);
+        metadata.set_rate_limit_cups(serde_json::Number::from_f64(2.5).unwrap());
+        let per_method = HashMap::from([("eth_call".to_string(), 5.into())]);
+        metadata.set_rate_limit_per_method(per_method);
+        assert_eq!(
+            serde_json::to_value(&metadata).unwrap(),
+            serde_json::json!({
+                "rate_limit_rps": 10,
+                "rate_limit_cups": 2.5,
+                "rate_limit_per_method": {"eth_call": 5}
+            })
+        );
+        assert_eq!(serde_json::to_string(&metadata.get_value("rate_limit_rps")).unwrap(), "10");
+        assert_eq!(metadata.rate_limit_rps().unwrap(), Some(10.0));
+        assert_eq!(metadata.rate_limit_cups().unwrap(), Some(2.5));
+        assert_eq!(metadata.rate_limit_per_method().unwrap().unwrap()["eth_call"], 5.0);
+    }
+
+    #[test]
+    fn getters_check_types_and_keep_unknown_keys() {
+        let raw = serde_json::json!({
+            "api_key": 123,
+            "labels": ["archive"],
+            "custom": true,
+            "another": null
+        });
+        let mut metadata: EndpointMetadata = serde_json::from_value(raw.clone()).unwrap();
+        assert_eq!(metadata.labels().unwrap(), Some(vec!["archive".to_string()]));
+        assert_eq!(metadata.host().unwrap(), None);
+        match metadata.api_key() {
+            Err(MescError::InvalidMetadataType { key, expected, found }) => {
+                assert_eq!(
+                    (key.as_str(), expected.as_str(), found.as_str()),
+                    ("api_key", "string", "number")
+                )
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(metadata.validate().is_err());
+        assert_eq!(metadata.unknown_keys(), ["another", "custom"]);
+
+        metadata.set_api_key("abc");
+        metadata.validate().unwrap();
+        assert_eq!(metadata.remove("custom"), Some(serde_json::Value::Bool(true)));
+        assert_eq!(metadata.unknown_keys(), ["another"]);
+    }
+
+    #[test]
+    fn endpoint_metadata_views_borrow_endpoint() {
+        let mut endpoint = Endpoint {
+            name: "local".to_string(),
+            url: "http://localhost:8545".to_string(),
+            chain_id: Some(ChainId::new(1)),
+            endpoint_metadata: HashMap::new(),
+        };
+        endpoint.metadata_mut().set_rate_limit_rps(25);
+        endpoint.metadata_mut().set_labels(vec!["private".to_string()]);
+        assert_eq!(endpoint.endpoint_metadata["rate_limit_rps"], serde_json::json!(25));
+        assert_eq!(endpoint.metadata().rate_limit_rps().unwrap(), Some(25.0));
+        assert_eq!(endpoint.metadata().as_map(), &endpoint.endpoint_metadata);
+
+        let mut metadata = EndpointMetadata::new();
+        metadata.set_host("alchemy");
+        endpoint.set_metadata(metadata);
+        assert_eq!(endpoint.metadata().host().unwrap().as_deref(), Some("alchemy"));
+        assert_eq!(endpoint.metadata().rate_limit_rps().unwrap(), None);
+    }
+}
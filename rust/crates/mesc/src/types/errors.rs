@@ -36,9 +36,42 @@ pub enum MescError {
     IOError(#[from] std::io::Error),
 
     /// Error indicating an issue with JSON formatting.
+    #[deprecated(note = "config json errors are reported as `InvalidConfigJson`")]
     #[error("Invalid JSON format")]
     InvalidJson,
 
+    /// Error for config data that is not valid JSON, with location of the problem.
+    #[error("Invalid JSON in {origin} at line {line} column {column}: {message}\n{snippet}")]
+    InvalidConfigJson {
+        /// config file path, or MESC_ENV
+        origin: String,
+        /// 1-based line of problem
+        line: usize,
+        /// 1-based column of problem
+        column: usize,
+        /// description of problem
+        message: String,
+        /// offending line, with a caret under the problem
+        snippet: String,
+        /// underlying parse error
+        #[source]
+        error: serde_json::Error,
+    },
+
+    /// Error for config data that does not match the MESC schema, with json path of the problem.
+    #[error("Invalid config in {origin}: {path}: {message}")]
+    InvalidConfigSchema {
+        /// config file path, or MESC_ENV
+        origin: String,
+        /// dot-separated json path of problem, e.g. `endpoints.foo.chain_id`
+        path: String,
+        /// description of problem
+        message: String,
+        /// underlying deserialization error
+        #[source]
+        error: serde_json::Error,
+    },
+
     /// Error wrapper for environment variable read errors.
     #[error(transparent)]
     EnvReadError(#[from] std::env::VarError),
@@ -55,6 +88,26 @@ pub enum MescError {
     #[error("Invalid input")]
     InvalidInput,
 
+    /// Error for metadata value whose type does not match the specification.
+    #[error("Invalid metadata type for {key}: expected {expected}, found {found}")]
+    InvalidMetadataType {
+        /// metadata key
+        key: String,
+        /// expected type
+        expected: String,
+        /// type found in metadata
+        found: String,
+    },
+
+    /// Error for invalid filter expression, with 1-based column of the problem.
+    #[error("Invalid filter at column {column}: {message}")]
+    InvalidFilter {
+        /// column of filter expression
+        column: usize,
+        /// description of problem
+        message: String,
+    },
+
     /// Error for override conflicts, with a description of the conflict.
     #[error("Override error: {0}")]
     OverrideError(String),
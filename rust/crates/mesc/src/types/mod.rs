@@ -2,12 +2,18 @@
 mod chain_ids;
 /// configs types module
 mod configs;
+/// endpoint metadata types module
+mod endpoint_metadata;
 /// errors types modules
 mod errors;
 /// queries types modules
 mod queries;
+/// validation types module
+mod validation;
 
 pub use chain_ids::*;
 pub use configs::*;
+pub use endpoint_metadata::*;
 pub use errors::*;
 pub use queries::*;
+pub use validation::*;
@@ -1,14 +1,25 @@
-use crate::{ChainId, MescError, TryIntoChainId};
+use crate::{filter::EndpointFilter, ChainId, Endpoint, MescError, TryIntoChainId};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Multi Endpoint Query
-#[derive(Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct MultiEndpointQuery {
     /// chain_id
+    #[serde(default, deserialize_with = "deserialize_optional_chain_id")]
     pub chain_id: Option<ChainId>,
     /// name_contains
     pub name_contains: Option<String>,
     /// url_contains
     pub url_contains: Option<String>,
+    /// metadata filters, all of which must match
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub metadata: Vec<MetadataFilter>,
+    /// profile, no endpoints match if profile has use_mesc disabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// filter expressions, all of which must match
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filters: Vec<EndpointFilter>,
 }
 
 /// builder for MultiEndpointQuery
@@ -35,6 +46,124 @@ impl MultiEndpointQuery {
         self.url_contains = Some(query.as_ref().to_string());
         Ok(self)
     }
+
+    /// add metadata filter
+    pub fn metadata(mut self, filter: MetadataFilter) -> Result<Self, MescError> {
+        self.metadata.push(filter);
+        Ok(self)
+    }
+
+    /// require metadata key to be present
+    pub fn metadata_exists<T: AsRef<str>>(self, key: T) -> Result<Self, MescError> {
+        self.metadata(MetadataFilter::Exists(key.as_ref().to_string()))
+    }
+
+    /// require metadata key to equal value
+    pub fn metadata_equals<T: AsRef<str>, V: Into<serde_json::Value>>(
+        self,
+        key: T,
+        value: V,
+    ) -> Result<Self, MescError> {
+        self.metadata(MetadataFilter::Equals(key.as_ref().to_string(), value.into()))
+    }
+
+    /// require label to be present in `labels` metadata
+    pub fn label<T: AsRef<str>>(self, label: T) -> Result<Self, MescError> {
+        self.metadata(MetadataFilter::Label(label.as_ref().to_string()))
+    }
+
+    /// require namespace to be present in `namespaces` metadata
+    pub fn namespace<T: AsRef<str>>(self, namespace: T) -> Result<Self, MescError> {
+        self.metadata(MetadataFilter::Namespace(namespace.as_ref().to_string()))
+    }
+
+    /// require `host` metadata to equal host
+    pub fn host<T: AsRef<str>>(self, host: T) -> Result<Self, MescError> {
+        self.metadata_equals("host", host.as_ref())
+    }
+
+    /// set profile
+    pub fn profile<T: AsRef<str>>(mut self, profile: T) -> Result<Self, MescError> {
+        self.profile = Some(profile.as_ref().to_string());
+        Ok(self)
+    }
+
+    /// add filter expression, see [`crate::filter`] for syntax
+    pub fn filter<T: AsRef<str>>(mut self, expression: T) -> Result<Self, MescError> {
+        self.filters.push(EndpointFilter::parse(expression.as_ref())?);
+        Ok(self)
+    }
+}
+
+/// predicate on endpoint metadata
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MetadataFilter {
+    /// key is present
+    Exists(String),
+    /// key is present and equal to value
+    Equals(String, serde_json::Value),
+    /// label is present in `labels`
+    Label(String),
+    /// namespace is present in `namespaces`
+    Namespace(String),
+}
+
+impl MetadataFilter {
+    /// parse filter from `key` or `key=value`
+    ///
+    /// `label=X` and `namespace=X` check list membership, other values are parsed as json
+    /// and fall back to strings
+    pub fn parse(filter: &str) -> Result<Self, MescError> {
+        match filter.split_once('=') {
+            None if filter.is_empty() => Err(MescError::InvalidInput),
+            None => Ok(MetadataFilter::Exists(filter.to_string())),
+            Some(("", _)) => Err(MescError::InvalidInput),
+            Some(("label" | "labels", label)) => Ok(MetadataFilter::Label(label.to_string())),
+            Some(("namespace" | "namespaces", namespace)) => {
+                Ok(MetadataFilter::Namespace(namespace.to_string()))
+            }
+            Some((key, value)) => {
+                let value = serde_json::from_str(value)
+                    .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+                Ok(MetadataFilter::Equals(key.to_string(), value))
+            }
+        }
+    }
+
+    /// whether endpoint matches filter
+    pub fn matches(&self, endpoint: &Endpoint) -> bool {
+        let metadata = &endpoint.endpoint_metadata;
+        let contains = |key: &str, item: &str| match metadata.get(key) {
+            Some(serde_json::Value::Array(items)) => items.iter().any(|x| x.as_str() == Some(item)),
+            _ => false,
+        };
+        match self {
+            MetadataFilter::Exists(key) => metadata.contains_key(key),
+            MetadataFilter::Equals(key, value) => metadata.get(key) == Some(value),
+            MetadataFilter::Label(label) => contains("labels", label),
+            MetadataFilter::Namespace(namespace) => contains("namespaces", namespace),
+        }
+    }
+}
+
+/// strategy for selecting a member of an endpoint group
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectionStrategy {
+    /// select member at position counter, wrapping around the group
+    RoundRobin {
+        /// number of previous selections
+        counter: u64,
+    },
+    /// select random member, weighted by `weight` endpoint metadata (default 1)
+    WeightedRandom {
+        /// seed of random number generator
+        seed: u64,
+    },
+    /// select member with lowest `latency_ms` endpoint metadata
+    LowestLatency,
+    /// select first member not marked `"healthy": false` in endpoint metadata
+    FirstHealthy,
 }
 
 //
@@ -42,7 +171,8 @@ impl MultiEndpointQuery {
 //
 
 /// EndpointQuery
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(from = "EndpointQuerySerde", into = "EndpointQuerySerde")]
 pub struct EndpointQuery {
     /// query_type
     pub query_type: EndpointQueryType,
@@ -51,7 +181,8 @@ pub struct EndpointQuery {
 }
 
 /// EndpointQueryType
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
 pub enum EndpointQueryType {
     /// DefeaultEndpoint
     DefaultEndpoint,
@@ -64,7 +195,8 @@ pub enum EndpointQueryType {
 }
 
 /// EndpointQueryFields
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
 pub enum EndpointQueryFields {
     /// DefaultEndpoint
     DefaultEndpoint(DefaultEndpointQuery),
@@ -77,30 +209,31 @@ pub enum EndpointQueryFields {
 }
 
 /// DefaultEndpointQuery
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct DefaultEndpointQuery {
     /// profile
     pub profile: Option<String>,
 }
 
 /// EndpointNameQuery
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct EndpointNameQuery {
     /// name
     pub name: String,
 }
 
 /// EndpointNetworkQuery
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct EndpointNetworkQuery {
     /// profile
     pub profile: Option<String>,
     /// chain_id
+    #[serde(deserialize_with = "deserialize_string_or_number")]
     pub chain_id: String,
 }
 
 /// UserInputQuery
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct UserInputQuery {
     /// profile
     pub profile: Option<String>,
@@ -108,12 +241,47 @@ pub struct UserInputQuery {
     pub user_input: String,
 }
 
+//
+// // resolution traces
+//
+
+/// ResolutionTrace
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ResolutionTrace {
+    /// endpoint chosen by resolution, if any
+    pub endpoint: Option<Endpoint>,
+    /// each lookup tried during resolution, in order
+    pub steps: Vec<ResolutionStep>,
+}
+
+/// ResolutionStep
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResolutionStep {
+    /// what was tried, e.g. "endpoint name" or "profile network default"
+    pub method: String,
+    /// whether the lookup of this step succeeded
+    pub matched: bool,
+    /// why the lookup succeeded or was skipped
+    pub reason: String,
+}
+
+impl ResolutionTrace {
+    pub(crate) fn step<T: Into<String>, R: Into<String>>(
+        &mut self,
+        method: T,
+        matched: bool,
+        reason: R,
+    ) {
+        self.steps.push(ResolutionStep { method: method.into(), matched, reason: reason.into() })
+    }
+}
+
 //
 // // global metadata
 //
 
 /// GlobalMetadataQuery
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GlobalMetadataQuery {
     /// profile
     pub profile: Option<String>,
@@ -125,8 +293,9 @@ pub struct GlobalMetadataQuery {
 // // general MESC queries
 //
 
-/// MescQuery
-#[derive(Debug, Clone)]
+/// MescQuery, serialized as `{"query_type": ..., "fields": {...}}`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(from = "MescQuerySerde", into = "MescQuerySerde")]
 pub struct MescQuery {
     /// query_type
     pub query_type: MescQueryType,
@@ -135,7 +304,8 @@ pub struct MescQuery {
 }
 
 /// MescQueryType
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
 pub enum MescQueryType {
     /// DefaultEndpoint
     DefaultEndpoint,
@@ -152,7 +322,8 @@ pub enum MescQueryType {
 }
 
 /// MescQueryFields
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
 pub enum MescQueryFields {
     /// DefaultEndpoint
     DefaultEndpoint(DefaultEndpointQuery),
@@ -167,3 +338,213 @@ pub enum MescQueryFields {
     /// GlobalMetadata
     GlobalMetadata(GlobalMetadataQuery),
 }
+
+/// MescQueryResult, serialized as the bare endpoint, list of endpoints, or metadata
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum MescQueryResult {
+    /// result of default_endpoint, endpoint_by_name, endpoint_by_network, or user_input
+    Endpoint(Option<Endpoint>),
+    /// result of multi_endpoint
+    Endpoints(Vec<Endpoint>),
+    /// result of global_metadata
+    Metadata(serde_json::Value),
+}
+
+//
+// // serialization
+//
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "query_type", content = "fields", rename_all = "snake_case")]
+enum EndpointQuerySerde {
+    DefaultEndpoint(DefaultEndpointQuery),
+    EndpointByName(EndpointNameQuery),
+    EndpointByNetwork(EndpointNetworkQuery),
+    UserInput(UserInputQuery),
+}
+
+impl From<EndpointQuerySerde> for EndpointQuery {
+    fn from(query: EndpointQuerySerde) -> EndpointQuery {
+        let (query_type, fields) = match query {
+            EndpointQuerySerde::DefaultEndpoint(fields) => {
+                (EndpointQueryType::DefaultEndpoint, EndpointQueryFields::DefaultEndpoint(fields))
+            }
+            EndpointQuerySerde::EndpointByName(fields) => {
+                (EndpointQueryType::EndpointByName, EndpointQueryFields::EndpointName(fields))
+            }
+            EndpointQuerySerde::EndpointByNetwork(fields) => {
+                (EndpointQueryType::EndpointByNetwork, EndpointQueryFields::EndpointNetwork(fields))
+            }
+            EndpointQuerySerde::UserInput(fields) => {
+                (EndpointQueryType::UserInput, EndpointQueryFields::UserInput(fields))
+            }
+        };
+        EndpointQuery { query_type, fields }
+    }
+}
+
+impl From<EndpointQuery> for EndpointQuerySerde {
+    fn from(query: EndpointQuery) -> EndpointQuerySerde {
+        match query.fields {
+            EndpointQueryFields::DefaultEndpoint(fields) => {
+                EndpointQuerySerde::DefaultEndpoint(fields)
+            }
+            EndpointQueryFields::EndpointName(fields) => EndpointQuerySerde::EndpointByName(fields),
+            EndpointQueryFields::EndpointNetwork(fields) => {
+                EndpointQuerySerde::EndpointByNetwork(fields)
+            }
+            EndpointQueryFields::UserInput(fields) => EndpointQuerySerde::UserInput(fields),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "query_type", content = "fields", rename_all = "snake_case")]
+enum MescQuerySerde {
+    DefaultEndpoint(DefaultEndpointQuery),
+    EndpointByName(EndpointNameQuery),
+    EndpointByNetwork(EndpointNetworkQuery),
+    UserInput(UserInputQuery),
+    MultiEndpoint(MultiEndpointQuery),
+    GlobalMetadata(GlobalMetadataQuery),
+}
+
+impl From<MescQuerySerde> for MescQuery {
+    fn from(query: MescQuerySerde) -> MescQuery {
+        let (query_type, fields) = match query {
+            MescQuerySerde::DefaultEndpoint(fields) => {
+                (MescQueryType::DefaultEndpoint, MescQueryFields::DefaultEndpoint(fields))
+            }
+            MescQuerySerde::EndpointByName(fields) => {
+                (MescQueryType::EndpointByName, MescQueryFields::EndpointName(fields))
+            }
+            MescQuerySerde::EndpointByNetwork(fields) => {
+                (MescQueryType::EndpointByNetwork, MescQueryFields::EndpointNetwork(fields))
+            }
+            MescQuerySerde::UserInput(fields) => {
+                (MescQueryType::UserInput, MescQueryFields::UserInput(fields))
+            }
+            MescQuerySerde::MultiEndpoint(fields) => {
+                (MescQueryType::MultiEndpoint, MescQueryFields::MultiEndpoint(fields))
+            }
+            MescQuerySerde::GlobalMetadata(fields) => {
+                (MescQueryType::GlobalMetadata, MescQueryFields::GlobalMetadata(fields))
+            }
+        };
+        MescQuery { query_type, fields }
+    }
+}
+
+impl From<MescQuery> for MescQuerySerde {
+    fn from(query: MescQuery) -> MescQuerySerde {
+        match query.fields {
+            MescQueryFields::DefaultEndpoint(fields) => MescQuerySerde::DefaultEndpoint(fields),
+            MescQueryFields::EndpointName(fields) => MescQuerySerde::EndpointByName(fields),
+            MescQueryFields::EndpointNetwork(fields) => MescQuerySerde::EndpointByNetwork(fields),
+            MescQueryFields::UserInput(fields) => MescQuerySerde::UserInput(fields),
+            MescQueryFields::MultiEndpoint(fields) => MescQuerySerde::MultiEndpoint(fields),
+            MescQueryFields::GlobalMetadata(fields) => MescQuerySerde::GlobalMetadata(fields),
+        }
+    }
+}
+
+/// chain_id's in queries may be given as json strings or numbers
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrNumber {
+    String(String),
+    Number(u64),
+}
+
+impl From<StringOrNumber> for String {
+    fn from(value: StringOrNumber) -> String {
+        match value {
+            StringOrNumber::String(value) => value,
+            StringOrNumber::Number(value) => value.to_string(),
+        }
+    }
+}
+
+fn deserialize_string_or_number<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<String, D::Error> {
+    Ok(StringOrNumber::deserialize(deserializer)?.into())
+}
+
+fn deserialize_optional_chain_id<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<ChainId>, D::Error> {
+    match Option::<StringOrNumber>::deserialize(deserializer)? {
+        Some(value) => {
+            String::from(value).try_into_chain_id().map(Some).map_err(serde::de::Error::custom)
+        }
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metadata_filters_parse_keys_and_values() {
+        let parse = |filter: &str| MetadataFilter::parse(filter).unwrap();
+        assert_eq!(parse("archive"), MetadataFilter::Exists("archive".to_string()));
+        assert_eq!(parse("label=private"), MetadataFilter::Label("private".to_string()));
+        assert_eq!(parse("labels=private"), MetadataFilter::Label("private".to_string()));
+        assert_eq!(parse("namespaces=debug"), MetadataFilter::Namespace("debug".to_string()));
+        assert_eq!(
+            parse("rate_limit_rps=10"),
+            MetadataFilter::Equals("rate_limit_rps".to_string(), 10.into())
+        );
+        assert_eq!(
+            parse("archive=true"),
+            MetadataFilter::Equals("archive".to_string(), true.into())
+        );
+        assert_eq!(
+            parse("host=alchemy"),
+            MetadataFilter::Equals("host".to_string(), "alchemy".into())
+        );
+        assert_eq!(parse("host=\"10\""), MetadataFilter::Equals("host".to_string(), "10".into()));
+        // only the first `=` separates key and value
+        assert_eq!(parse("note=a=b"), MetadataFilter::Equals("note".to_string(), "a=b".into()));
+        assert_eq!(parse("note="), MetadataFilter::Equals("note".to_string(), "".into()));
+    }
+
+    #[test]
+    fn metadata_filters_reject_missing_key() {
+        for filter in ["", "=", "=value", "=label"] {
+            assert!(
+                matches!(MetadataFilter::parse(filter), Err(MescError::InvalidInput)),
+                "{}",
+                filter
+            );
+        }
+    }
+
+    #[test]
+    fn metadata_filters_match_endpoints() {
+        let endpoint = Endpoint {
+            name: "local".to_string(),
+            url: "http://localhost:8545".to_string(),
+            chain_id: None,
+            endpoint_metadata: serde_json::from_value(serde_json::json!({
+                "rate_limit_rps": 10,
+                "labels": ["private"],
+                "namespaces": "debug"
+            }))
+            .unwrap(),
+        };
+        let matches = |filter: &str| MetadataFilter::parse(filter).unwrap().matches(&endpoint);
+        assert!(matches("rate_limit_rps"));
+        assert!(matches("rate_limit_rps=10"));
+        assert!(!matches("rate_limit_rps=10.5"));
+        assert!(!matches("rate_limit_rps=\"10\""));
+        assert!(matches("label=private"));
+        assert!(!matches("label=archive"));
+        // membership filters require lists
+        assert!(!matches("namespace=debug"));
+        assert!(!matches("host"));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::MescError;
+use serde::{Deserialize, Serialize};
+
+/// ValidationSeverity
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationSeverity {
+    /// config is usable, but likely not what was intended
+    Warning,
+    /// config is invalid
+    Error,
+}
+
+/// ValidationCode
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationCode {
+    /// referenced endpoint does not exist
+    MissingEndpoint,
+    /// endpoint chain_id does not match the network it is used for
+    ChainIdMismatch,
+    /// map key does not match name field
+    NameMismatch,
+    /// metadata used by mesc has invalid structure
+    InvalidMetadata,
+    /// spec-defined endpoint metadata has the wrong type
+    InvalidMetadataType,
+    /// multiple endpoints share a url
+    DuplicateUrl,
+    /// endpoint group has no members
+    EmptyGroup,
+    /// name of endpoint, profile, or network uses characters not allowed by the specification
+    InvalidName,
+    /// mesc_version is not the version required by the specification
+    InvalidMescVersion,
+    /// key is not defined by the specification
+    UnknownKey,
+    /// key required by the specification is missing
+    MissingKey,
+    /// network name conflicts with a chain_id or with the network directory
+    NetworkNameConflict,
+}
+
+impl ValidationCode {
+    /// code as snake_case string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValidationCode::MissingEndpoint => "missing_endpoint",
+            ValidationCode::ChainIdMismatch => "chain_id_mismatch",
+            ValidationCode::NameMismatch => "name_mismatch",
+            ValidationCode::InvalidMetadata => "invalid_metadata",
+            ValidationCode::InvalidMetadataType => "invalid_metadata_type",
+            ValidationCode::DuplicateUrl => "duplicate_url",
+            ValidationCode::EmptyGroup => "empty_group",
+            ValidationCode::InvalidName => "invalid_name",
+            ValidationCode::InvalidMescVersion => "invalid_mesc_version",
+            ValidationCode::UnknownKey => "unknown_key",
+            ValidationCode::MissingKey => "missing_key",
+            ValidationCode::NetworkNameConflict => "network_name_conflict",
+        }
+    }
+}
+
+/// ValidationIssue
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// dot-separated json path of problem, e.g. `profiles.foundry.network_defaults.5`
+    pub path: String,
+    /// severity
+    pub severity: ValidationSeverity,
+    /// code
+    pub code: ValidationCode,
+    /// description of problem
+    pub message: String,
+    /// endpoint referenced by problem, if any
+    pub endpoint: Option<String>,
+}
+
+impl ValidationIssue {
+    /// convert into the error returned by `RpcConfig::validate()`
+    pub fn to_error(&self) -> MescError {
+        match (self.code, self.endpoint.as_ref()) {
+            (ValidationCode::MissingEndpoint, Some(endpoint)) => {
+                MescError::MissingEndpoint(endpoint.clone())
+            }
+            _ => MescError::IntegrityError(self.message.clone()),
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let severity = match self.severity {
+            ValidationSeverity::Warning => "warning",
+            ValidationSeverity::Error => "error",
+        };
+        write!(f, "{} [{}] {}: {}", severity, self.code.as_str(), self.path, self.message)
+    }
+}
+
+/// ValidationReport, listing every problem found in a config
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// issues, errors first and then ordered by path
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// whether report has no errors, warnings are allowed
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    /// issues with error severity
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(|issue| issue.severity == ValidationSeverity::Error)
+    }
+
+    /// issues with warning severity
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(|issue| issue.severity == ValidationSeverity::Warning)
+    }
+
+    /// return first error, if any
+    pub fn into_result(self) -> Result<(), MescError> {
+        match self.errors().next() {
+            Some(issue) => Err(issue.to_error()),
+            None => Ok(()),
+        }
+    }
+}
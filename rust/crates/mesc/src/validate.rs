@@ -1,73 +1,567 @@
-use crate::{MescError, RpcConfig};
+use crate::{
+    directory, metadata, ChainId, MescError, RpcConfig, ValidationCode, ValidationIssue,
+    ValidationReport, ValidationSeverity, MESC_VERSION,
+};
+use std::collections::HashMap;
+
+const CONFIG_KEYS: [&str; 7] = [
+    "mesc_version",
+    "default_endpoint",
+    "endpoints",
+    "network_defaults",
+    "network_names",
+    "profiles",
+    "global_metadata",
+];
+const ENDPOINT_KEYS: [&str; 4] = ["name", "url", "chain_id", "endpoint_metadata"];
+const PROFILE_KEYS: [&str; 5] =
+    ["name", "default_endpoint", "network_defaults", "profile_metadata", "use_mesc"];
 
 pub(crate) fn validate_config(config: &RpcConfig) -> Result<(), MescError> {
-    // referenced endpoints exist
-    if let Some(endpoint) = config.default_endpoint.as_ref() {
-        if !config.endpoints.contains_key(endpoint.as_str()) {
-            return Err(MescError::MissingEndpoint(endpoint.to_string()));
+    // first error is taken in check order rather than report order
+    let mut issues = Issues::default();
+    check_config(&mut issues, config);
+    issues.into_result()
+}
+
+pub(crate) fn validate_config_all(config: &RpcConfig) -> ValidationReport {
+    let mut issues = Issues::default();
+    check_config(&mut issues, config);
+    issues.into_report()
+}
+
+pub(crate) fn validate_config_strict(config: &RpcConfig) -> ValidationReport {
+    let mut issues = Issues::default();
+    check_config(&mut issues, config);
+    check_config_strict(&mut issues, config);
+    issues.into_report()
+}
+
+pub(crate) fn validate_config_json_strict(
+    config_json: &str,
+    origin: &str,
+) -> Result<ValidationReport, MescError> {
+    let raw: serde_json::Value = serde_json::from_str(config_json)
+        .map_err(|error| crate::load::json_syntax_error(config_json, origin, error))?;
+    let mut issues = Issues::default();
+    issues.check_keys(&raw, "", &CONFIG_KEYS);
+    if let Some(endpoints) = raw.get("endpoints").and_then(|value| value.as_object()) {
+        for (name, endpoint) in endpoints.iter() {
+            issues.check_keys(endpoint, &format!("endpoints.{}.", name), &ENDPOINT_KEYS);
         }
     }
-    for endpoint in config.network_defaults.values() {
-        if !config.endpoints.contains_key(endpoint) {
-            return Err(MescError::MissingEndpoint(endpoint.clone()));
+    if let Some(profiles) = raw.get("profiles").and_then(|value| value.as_object()) {
+        for (name, profile) in profiles.iter() {
+            issues.check_keys(profile, &format!("profiles.{}.", name), &PROFILE_KEYS);
         }
     }
-    for profile in config.profiles.values() {
-        if let Some(endpoint) = profile.default_endpoint.as_ref() {
-            if !config.endpoints.contains_key(endpoint.as_str()) {
-                return Err(MescError::MissingEndpoint(endpoint.to_string()));
-            }
+
+    // remaining checks need a config that deserializes
+    match crate::load::parse_config_json(config_json, origin) {
+        Ok(config) => {
+            check_config(&mut issues, &config);
+            check_config_strict(&mut issues, &config);
         }
-        for endpoint in profile.network_defaults.values() {
-            if !config.endpoints.contains_key(endpoint) {
-                return Err(MescError::MissingEndpoint(endpoint.clone()));
+        Err(e) if issues.0.is_empty() => return Err(e),
+        Err(_) => {}
+    }
+    Ok(issues.into_report())
+}
+
+fn check_config_strict(issues: &mut Issues, config: &RpcConfig) {
+    if config.mesc_version != MESC_VERSION {
+        issues.error(
+            "mesc_version".to_string(),
+            ValidationCode::InvalidMescVersion,
+            format!("mesc_version must be {:?}, found {:?}", MESC_VERSION, config.mesc_version),
+            None,
+        );
+    }
+
+    // names use only characters allowed by the specification
+    for name in config.endpoints.keys() {
+        issues.check_name(format!("endpoints.{}", name), "endpoint", name);
+    }
+    for name in config.profiles.keys() {
+        issues.check_name(format!("profiles.{}", name), "profile", name);
+    }
+    for name in config.network_names.keys() {
+        issues.check_name(format!("network_names.{}", name), "network", name);
+    }
+
+    // network names are consistent with chain ids and the network directory
+    for (name, chain_id) in config.network_names.iter() {
+        let path = format!("network_names.{}", name);
+        if ChainId::parse(name).is_ok() {
+            issues.error(
+                path,
+                ValidationCode::NetworkNameConflict,
+                format!("network name {} is also a chain_id", name),
+                None,
+            );
+        } else if let Some(directory_chain_id) = directory::get_network_chain_id(name) {
+            if &directory_chain_id != chain_id {
+                issues.warning(
+                    path,
+                    ValidationCode::NetworkNameConflict,
+                    format!(
+                        "network name {} is chain_id {} in network directory, not {}",
+                        name, directory_chain_id, chain_id
+                    ),
+                    None,
+                );
             }
         }
     }
+}
+
+fn check_config(issues: &mut Issues, config: &RpcConfig) {
+    // referenced endpoints exist
+    if let Some(endpoint) = config.default_endpoint.as_ref() {
+        issues.check_endpoint_exists(config, "default_endpoint".to_string(), endpoint);
+    }
+    for (chain_id, endpoint) in config.network_defaults.iter() {
+        let path = format!("network_defaults.{}", chain_id);
+        issues.check_endpoint_exists(config, path, endpoint);
+    }
+    for (name, profile) in config.profiles.iter() {
+        if let Some(endpoint) = profile.default_endpoint.as_ref() {
+            let path = format!("profiles.{}.default_endpoint", name);
+            issues.check_endpoint_exists(config, path, endpoint);
+        }
+        for (chain_id, endpoint) in profile.network_defaults.iter() {
+            let path = format!("profiles.{}.network_defaults.{}", name, chain_id);
+            issues.check_endpoint_exists(config, path, endpoint);
+        }
+    }
 
     // default endpoints of each network actually use that specified network
     for (chain_id, endpoint_name) in config.network_defaults.iter() {
-        if let Some(endpoint) = config.endpoints.get(endpoint_name) {
-            if Some(chain_id) != endpoint.chain_id.as_ref() {
-                return Err(MescError::IntegrityError(format!(
-                    "endpoint {} chain_id does not match default chain_id",
-                    endpoint_name
-                )));
-            }
-        }
+        let path = format!("network_defaults.{}", chain_id);
+        issues.check_chain_id(config, path, chain_id, endpoint_name, "default");
     }
-    for profile in config.profiles.values() {
+    for (name, profile) in config.profiles.iter() {
         for (chain_id, endpoint_name) in profile.network_defaults.iter() {
-            if let Some(endpoint) = config.endpoints.get(endpoint_name) {
-                if Some(chain_id) != endpoint.chain_id.as_ref() {
-                    return Err(MescError::IntegrityError(format!(
-                        "endpoint {} chain_id does not match default chain_id",
-                        endpoint_name
-                    )));
+            let path = format!("profiles.{}.network_defaults.{}", name, chain_id);
+            issues.check_chain_id(config, path, chain_id, endpoint_name, "default");
+        }
+    }
+
+    // network fallbacks reference existing endpoints of the specified network
+    issues.check_network_fallbacks(config, &config.global_metadata, "global_metadata".to_string());
+    for (name, profile) in config.profiles.iter() {
+        let path = format!("profiles.{}.profile_metadata", name);
+        issues.check_network_fallbacks(config, &profile.profile_metadata, path);
+    }
+
+    // groups reference existing endpoints
+    let groups_path = format!("global_metadata.{}", metadata::GROUPS_KEY);
+    match metadata::get_groups(&config.global_metadata) {
+        Ok(groups) => {
+            for (group, endpoint_names) in groups.iter() {
+                if endpoint_names.is_empty() {
+                    issues.warning(
+                        format!("{}.{}", groups_path, group),
+                        ValidationCode::EmptyGroup,
+                        format!("group {} has no endpoints", group),
+                        None,
+                    );
+                }
+                for (i, endpoint_name) in endpoint_names.iter().enumerate() {
+                    let path = format!("{}.{}.{}", groups_path, group, i);
+                    issues.check_endpoint_exists(config, path, endpoint_name);
                 }
             }
         }
+        Err(e) => issues.invalid_metadata(groups_path, e),
     }
 
     // endpoint map keys match endpoint name fields
     for (name, endpoint) in config.endpoints.iter() {
         if name != endpoint.name.as_str() {
-            return Err(MescError::IntegrityError(format!(
-                "map key does not match name field for endpoint, {} != {}",
-                name, endpoint.name
-            )));
+            issues.error(
+                format!("endpoints.{}.name", name),
+                ValidationCode::NameMismatch,
+                format!(
+                    "map key does not match name field for endpoint, {} != {}",
+                    name, endpoint.name
+                ),
+                None,
+            );
         }
     }
 
     // profile map keys match profile name fields
     for (name, profile) in config.profiles.iter() {
         if name != profile.name.as_str() {
-            return Err(MescError::IntegrityError(format!(
-                "map key does not match name field for profile, {} != {}",
-                name, profile.name
-            )));
+            issues.error(
+                format!("profiles.{}.name", name),
+                ValidationCode::NameMismatch,
+                format!(
+                    "map key does not match name field for profile, {} != {}",
+                    name, profile.name
+                ),
+                None,
+            );
+        }
+    }
+
+    // spec-defined endpoint metadata has the correct type
+    for (name, endpoint) in config.endpoints.iter() {
+        if let Err(MescError::InvalidMetadataType { key, expected, found }) =
+            endpoint.metadata().validate()
+        {
+            issues.warning(
+                format!("endpoints.{}.endpoint_metadata.{}", name, key),
+                ValidationCode::InvalidMetadataType,
+                format!("expected {}, found {}", expected, found),
+                Some(name.clone()),
+            );
+        }
+    }
+
+    // endpoints have distinct urls
+    let mut names_by_url: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (name, endpoint) in config.endpoints.iter() {
+        names_by_url.entry(endpoint.url.as_str()).or_default().push(name.as_str());
+    }
+    for names in names_by_url.values_mut().filter(|names| names.len() > 1) {
+        names.sort();
+        for name in names.iter() {
+            issues.warning(
+                format!("endpoints.{}.url", name),
+                ValidationCode::DuplicateUrl,
+                format!("url is shared by endpoints {}", names.join(", ")),
+                Some(name.to_string()),
+            );
         }
     }
+}
+
+#[derive(Default)]
+struct Issues(Vec<ValidationIssue>);
+
+impl Issues {
+    fn into_result(self) -> Result<(), MescError> {
+        match self.0.iter().find(|issue| issue.severity == ValidationSeverity::Error) {
+            Some(issue) => Err(issue.to_error()),
+            None => Ok(()),
+        }
+    }
+
+    fn into_report(self) -> ValidationReport {
+        let mut issues = self.0;
+        issues.sort_by(|a, b| b.severity.cmp(&a.severity).then_with(|| a.path.cmp(&b.path)));
+        ValidationReport { issues }
+    }
+
+    fn push(
+        &mut self,
+        severity: ValidationSeverity,
+        path: String,
+        code: ValidationCode,
+        message: String,
+        endpoint: Option<String>,
+    ) {
+        self.0.push(ValidationIssue { path, severity, code, message, endpoint })
+    }
+
+    fn error(
+        &mut self,
+        path: String,
+        code: ValidationCode,
+        message: String,
+        endpoint: Option<String>,
+    ) {
+        self.push(ValidationSeverity::Error, path, code, message, endpoint)
+    }
+
+    fn warning(
+        &mut self,
+        path: String,
+        code: ValidationCode,
+        message: String,
+        endpoint: Option<String>,
+    ) {
+        self.push(ValidationSeverity::Warning, path, code, message, endpoint)
+    }
+
+    fn invalid_metadata(&mut self, path: String, error: MescError) {
+        let message = match error {
+            MescError::IntegrityError(message) => message,
+            error => error.to_string(),
+        };
+        self.error(path, ValidationCode::InvalidMetadata, message, None)
+    }
+
+    fn check_keys(&mut self, value: &serde_json::Value, prefix: &str, keys: &[&str]) {
+        let Some(map) = value.as_object() else {
+            return;
+        };
+        for key in map.keys().filter(|key| !keys.contains(&key.as_str())) {
+            self.error(
+                format!("{}{}", prefix, key),
+                ValidationCode::UnknownKey,
+                format!("key {} is not defined by the specification", key),
+                None,
+            )
+        }
+        for key in keys.iter().filter(|key| !map.contains_key(**key)) {
+            self.error(
+                format!("{}{}", prefix, key),
+                ValidationCode::MissingKey,
+                format!("required key {} is missing", key),
+                None,
+            )
+        }
+    }
+
+    fn check_name(&mut self, path: String, kind: &str, name: &str) {
+        let valid = !name.is_empty()
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.');
+        if !valid {
+            self.error(
+                path,
+                ValidationCode::InvalidName,
+                format!(
+                    "{} name {:?} must be non-empty and only use alphanumerics, -, _, and .",
+                    kind, name
+                ),
+                None,
+            )
+        }
+    }
+
+    fn check_endpoint_exists(&mut self, config: &RpcConfig, path: String, endpoint: &str) {
+        if !config.endpoints.contains_key(endpoint) {
+            self.error(
+                path,
+                ValidationCode::MissingEndpoint,
+                format!("endpoint {} does not exist", endpoint),
+                Some(endpoint.to_string()),
+            )
+        }
+    }
+
+    fn check_chain_id(
+        &mut self,
+        config: &RpcConfig,
+        path: String,
+        chain_id: &ChainId,
+        endpoint_name: &str,
+        usage: &str,
+    ) {
+        if let Some(endpoint) = config.endpoints.get(endpoint_name) {
+            if Some(chain_id) != endpoint.chain_id.as_ref() {
+                self.error(
+                    path,
+                    ValidationCode::ChainIdMismatch,
+                    format!(
+                        "endpoint {} chain_id does not match {} chain_id",
+                        endpoint_name, usage
+                    ),
+                    Some(endpoint_name.to_string()),
+                )
+            }
+        }
+    }
+
+    fn check_network_fallbacks(
+        &mut self,
+        config: &RpcConfig,
+        metadata: &HashMap<String, serde_json::Value>,
+        path: String,
+    ) {
+        let path = format!("{}.{}", path, metadata::NETWORK_FALLBACKS_KEY);
+        let fallbacks = match metadata::get_network_fallbacks(metadata) {
+            Ok(fallbacks) => fallbacks,
+            Err(e) => return self.invalid_metadata(path, e),
+        };
+        for (chain_id, endpoint_names) in fallbacks.iter() {
+            for (i, endpoint_name) in endpoint_names.iter().enumerate() {
+                let path = format!("{}.{}.{}", path, chain_id, i);
+                self.check_endpoint_exists(config, path.clone(), endpoint_name);
+                self.check_chain_id(config, path, chain_id, endpoint_name, "fallback");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Endpoint, Profile};
+
+    #[test]
+    fn report_collects_every_problem() {
+        let mut config = RpcConfig::default();
+        let endpoint = Endpoint {
+            name: "local".to_string(),
+            url: "http://localhost:8545".to_string(),
+            chain_id: Some(ChainId::new(1)),
+            endpoint_metadata: HashMap::new(),
+        };
+        config.endpoints.insert("local".to_string(), endpoint);
+        config.default_endpoint = Some("missing".to_string());
+        config.network_defaults.insert(ChainId::new(5), "local".to_string());
+        let mut foundry = Profile::new("foundry");
+        foundry.network_defaults.insert(ChainId::new(5), "other".to_string());
+        config.profiles.insert("foundry".to_string(), foundry);
+
+        let report = config.validate_all();
+        let issues: Vec<_> =
+            report.issues.iter().map(|issue| (issue.path.as_str(), issue.code)).collect();
+        assert_eq!(
+            issues,
+            vec![
+                ("default_endpoint", ValidationCode::MissingEndpoint),
+                ("network_defaults.5", ValidationCode::ChainIdMismatch),
+                ("profiles.foundry.network_defaults.5", ValidationCode::MissingEndpoint),
+            ]
+        );
+        assert!(
+            matches!(config.validate(), Err(MescError::MissingEndpoint(name)) if name == "missing")
+        );
+    }
 
-    Ok(())
+    #[test]
+    fn validate_returns_first_error_in_check_order() {
+        let mut config = RpcConfig::default();
+        let endpoint = Endpoint {
+            name: "renamed".to_string(),
+            url: "http://localhost:8545".to_string(),
+            chain_id: Some(ChainId::new(1)),
+            endpoint_metadata: HashMap::new(),
+        };
+        config.endpoints.insert("local".to_string(), endpoint);
+        config.network_defaults.insert(ChainId::new(5), "local".to_string());
+
+        // report lists the name mismatch first, but chain ids are checked before names
+        let report = config.validate_all();
+        let issues: Vec<_> =
+            report.issues.iter().map(|issue| (issue.path.as_str(), issue.code)).collect();
+        assert_eq!(
+            issues,
+            vec![
+                ("endpoints.local.name", ValidationCode::NameMismatch),
+                ("network_defaults.5", ValidationCode::ChainIdMismatch),
+            ]
+        );
+        assert!(matches!(
+            config.validate(),
+            Err(MescError::IntegrityError(message))
+                if message == "endpoint local chain_id does not match default chain_id"
+        ));
+    }
+
+    #[test]
+    fn strict_report_checks_keys_names_and_version() {
+        let config_json = r#"{
+            "mesc_version": "0.3.0",
+            "default_endpoint": null,
+            "endpoints": {
+                "local node": {
+                    "name": "local node",
+                    "url": "http://localhost:8545",
+                    "chain_id": "1",
+                    "endpoint_metadata": {},
+                    "extra": true
+                }
+            },
+            "network_defaults": {},
+            "network_names": {"5": "5", "ethereum": "5"},
+            "profiles": {}
+        }"#;
+        let report = RpcConfig::validate_strict_json(config_json, "mesc.json").unwrap();
+        let issues: Vec<_> =
+            report.issues.iter().map(|issue| (issue.path.as_str(), issue.code)).collect();
+        assert_eq!(
+            issues,
+            vec![
+                ("endpoints.local node.extra", ValidationCode::UnknownKey),
+                ("global_metadata", ValidationCode::MissingKey),
+            ]
+        );
+
+        let config_json =
+            config_json.replace(r#""profiles": {}"#, r#""profiles": {}, "global_metadata": {}"#);
+        let config: RpcConfig = serde_json::from_str(&config_json).unwrap();
+        let report = config.validate_strict();
+        let issues: Vec<_> =
+            report.issues.iter().map(|issue| (issue.path.as_str(), issue.code)).collect();
+        assert_eq!(
+            issues,
+            vec![
+                ("endpoints.local node", ValidationCode::InvalidName),
+                ("mesc_version", ValidationCode::InvalidMescVersion),
+                ("network_names.5", ValidationCode::NetworkNameConflict),
+                ("network_names.ethereum", ValidationCode::NetworkNameConflict),
+            ]
+        );
+        assert!(config.validate_all().is_valid());
+        assert!(RpcConfig::default().validate_strict().is_valid());
+    }
+
+    #[test]
+    fn strict_json_errors_name_origin() {
+        let result = RpcConfig::validate_strict_json("{", "/home/user/mesc.json");
+        assert!(matches!(
+            result,
+            Err(MescError::InvalidConfigJson { origin, .. }) if origin == "/home/user/mesc.json"
+        ));
+
+        let mut config = serde_json::to_value(RpcConfig::default()).unwrap();
+        config["network_names"] = serde_json::json!({"x": "abc"});
+        let result = RpcConfig::validate_strict_json(&config.to_string(), "MESC_ENV");
+        assert!(matches!(
+            result,
+            Err(MescError::InvalidConfigSchema { origin, path, .. })
+                if origin == "MESC_ENV" && path == "network_names.x"
+        ));
+    }
+
+    #[test]
+    fn report_checks_network_fallbacks() {
+        let mut config = RpcConfig::default();
+        for (name, chain_id) in [("op", 10), ("mainnet", 1)] {
+            let endpoint = Endpoint {
+                name: name.to_string(),
+                url: format!("https://{}.example.com", name),
+                chain_id: Some(ChainId::new(chain_id)),
+                endpoint_metadata: HashMap::new(),
+            };
+            config.endpoints.insert(name.to_string(), endpoint);
+        }
+        config.network_defaults.insert(ChainId::new(10), "op".to_string());
+        // fallbacks may repeat the network default or each other
+        config.global_metadata.insert(
+            metadata::NETWORK_FALLBACKS_KEY.to_string(),
+            serde_json::json!({"10": ["op", "op"]}),
+        );
+        assert!(config.validate_all().is_valid());
+
+        config.global_metadata.insert(
+            metadata::NETWORK_FALLBACKS_KEY.to_string(),
+            serde_json::json!({"10": ["op", "missing", "mainnet"]}),
+        );
+        let mut foundry = Profile::new("foundry");
+        foundry
+            .profile_metadata
+            .insert(metadata::NETWORK_FALLBACKS_KEY.to_string(), serde_json::json!(["op"]));
+        config.profiles.insert("foundry".to_string(), foundry);
+        let report = config.validate_all();
+        let issues: Vec<_> =
+            report.issues.iter().map(|issue| (issue.path.as_str(), issue.code)).collect();
+        assert_eq!(
+            issues,
+            vec![
+                ("global_metadata.network_fallbacks.10.1", ValidationCode::MissingEndpoint),
+                ("global_metadata.network_fallbacks.10.2", ValidationCode::ChainIdMismatch),
+                (
+                    "profiles.foundry.profile_metadata.network_fallbacks",
+                    ValidationCode::InvalidMetadata
+                ),
+            ]
+        );
+    }
 }
@@ -1,4 +1,8 @@
-use crate::{MescError, RpcConfig, TryIntoChainId};
+use crate::{
+    metadata::{GROUPS_KEY, NETWORK_FALLBACKS_KEY},
+    MescError, RpcConfig, TryIntoChainId,
+};
+use std::collections::HashMap;
 use std::{fs::File, path::Path};
 
 /// write config to file
@@ -59,6 +63,15 @@ pub fn update_endpoint_name(
         }
     }
 
+    // update network fallbacks and groups
+    update_endpoint_lists(config, |names| {
+        for name in names.iter_mut() {
+            if name.as_str() == Some(old_name) {
+                *name = new_name.into();
+            }
+        }
+    });
+
     Ok(())
 }
 
@@ -126,5 +139,121 @@ pub fn delete_endpoint(config: &mut RpcConfig, endpoint: &str) -> Result<(), Mes
         }
     }
 
+    // remove from network fallbacks and groups
+    update_endpoint_lists(config, |names| names.retain(|name| name.as_str() != Some(endpoint)));
+
     Ok(())
 }
+
+/// set members of endpoint group, creating the group if it does not exist
+pub fn set_group(
+    config: &mut RpcConfig,
+    group: &str,
+    endpoints: Vec<String>,
+) -> Result<(), MescError> {
+    for endpoint in endpoints.iter() {
+        if !config.endpoints.contains_key(endpoint) {
+            return Err(MescError::MissingEndpoint(endpoint.clone()));
+        }
+    }
+    let groups = config
+        .global_metadata
+        .entry(GROUPS_KEY.to_string())
+        .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+    match groups {
+        serde_json::Value::Object(groups) => {
+            groups.insert(group.to_string(), endpoints.into());
+            Ok(())
+        }
+        _ => Err(MescError::IntegrityError(format!("{} metadata must be a map", GROUPS_KEY))),
+    }
+}
+
+/// delete endpoint group
+pub fn delete_group(config: &mut RpcConfig, group: &str) -> Result<(), MescError> {
+    if let Some(serde_json::Value::Object(groups)) = config.global_metadata.get_mut(GROUPS_KEY) {
+        groups.remove(group);
+    }
+    Ok(())
+}
+
+/// modify each list of endpoint names in network fallbacks and groups
+fn update_endpoint_lists<F: Fn(&mut Vec<serde_json::Value>)>(config: &mut RpcConfig, f: F) {
+    let update = |metadata: &mut HashMap<String, serde_json::Value>, key: &str| {
+        if let Some(serde_json::Value::Object(lists)) = metadata.get_mut(key) {
+            for names in lists.values_mut() {
+                if let serde_json::Value::Array(names) = names {
+                    f(names)
+                }
+            }
+        }
+    };
+    update(&mut config.global_metadata, NETWORK_FALLBACKS_KEY);
+    update(&mut config.global_metadata, GROUPS_KEY);
+    for profile in config.profiles.values_mut() {
+        update(&mut profile.profile_metadata, NETWORK_FALLBACKS_KEY);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{metadata, ChainId, Endpoint};
+
+    fn config() -> RpcConfig {
+        let mut config = RpcConfig::default();
+        for name in ["a", "b", "c"] {
+            let endpoint = Endpoint {
+                name: name.to_string(),
+                url: format!("https://{}.example.com", name),
+                chain_id: Some(ChainId::new(1)),
+                endpoint_metadata: HashMap::new(),
+            };
+            config.endpoints.insert(name.to_string(), endpoint);
+        }
+        config
+    }
+
+    fn groups(config: &RpcConfig) -> HashMap<String, Vec<String>> {
+        metadata::get_groups(&config.global_metadata).unwrap()
+    }
+
+    #[test]
+    fn groups_are_created_updated_and_deleted() {
+        let mut config = config();
+        set_group(&mut config, "pool", vec!["a".to_string(), "b".to_string()]).unwrap();
+        set_group(&mut config, "solo", vec!["c".to_string()]).unwrap();
+        assert_eq!(groups(&config)["pool"], ["a", "b"]);
+        config.validate().unwrap();
+
+        set_group(&mut config, "pool", vec!["b".to_string()]).unwrap();
+        assert_eq!(groups(&config)["pool"], ["b"]);
+        assert!(matches!(
+            set_group(&mut config, "pool", vec!["missing".to_string()]),
+            Err(MescError::MissingEndpoint(name)) if name == "missing"
+        ));
+        assert_eq!(groups(&config)["pool"], ["b"]);
+
+        delete_group(&mut config, "pool").unwrap();
+        assert_eq!(groups(&config).into_keys().collect::<Vec<_>>(), ["solo"]);
+        delete_group(&mut config, "pool").unwrap();
+
+        config.global_metadata.insert(GROUPS_KEY.to_string(), serde_json::json!(["a"]));
+        assert!(matches!(
+            set_group(&mut config, "pool", vec!["a".to_string()]),
+            Err(MescError::IntegrityError(_))
+        ));
+    }
+
+    #[test]
+    fn endpoint_edits_update_groups() {
+        let mut config = config();
+        let members = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        set_group(&mut config, "pool", members).unwrap();
+
+        update_endpoint_name(&mut config, "b", "d").unwrap();
+        assert_eq!(groups(&config)["pool"], ["a", "d", "c"]);
+        delete_endpoint(&mut config, "a").unwrap();
+        assert_eq!(groups(&config)["pool"], ["d", "c"]);
+    }
+}
@@ -8,13 +8,16 @@ pub(crate) async fn run_cli() -> Result<(), MescCliError> {
         Commands::Setup(args) => setup_command(args).await,
         Commands::Import(args) => import_command(args).await,
         Commands::Set(args) => set_command(args).await,
+        Commands::Group(args) => group_command(args),
         Commands::Status(args) => status_command(args),
         Commands::Ls(args) => ls_command(args),
         Commands::Defaults(args) => defaults_command(args),
         Commands::Ping(args) => ping_command(args).await,
         Commands::Endpoint(args) => endpoint_command(args),
         Commands::Metadata(args) => metadata_command(args),
+        Commands::Networks(args) => networks_command(args),
         Commands::Url(args) => url_command(args),
+        Commands::Which(args) => which_command(args),
         Commands::Help(args) => help_command(args),
     }
 }
@@ -52,6 +55,8 @@ pub(crate) enum Commands {
     ///
     /// This command is idempotent
     Set(SetArgs),
+    /// Create, list, or edit endpoint groups
+    Group(GroupArgs),
     /// Ping endpoints and fetch metadata
     Ping(PingArgs),
     /// Print list of defaults
@@ -64,10 +69,14 @@ pub(crate) enum Commands {
     Ls(LsArgs),
     /// Print metadata
     Metadata(MetadataArgs),
+    /// Print list of builtin and custom network names
+    Networks(NetworksArgs),
     /// Print status of configuration
     Status(StatusArgs),
     /// Print endpoint URL
     Url(UrlArgs),
+    /// Explain how a query resolves to an endpoint
+    Which(WhichArgs),
 }
 
 /// Arguments for the `setup` subcommand
@@ -138,16 +147,67 @@ pub(crate) struct SetArgs {
     pub(crate) path: Option<String>,
 }
 
+/// Arguments for the `group` subcommand
+#[derive(Parser)]
+pub(crate) struct GroupArgs {
+    /// group action
+    #[clap(subcommand)]
+    pub(crate) command: Option<GroupCommands>,
+
+    /// config path to use [default: MESC_PATH]
+    #[clap(long, global = true)]
+    pub(crate) path: Option<String>,
+}
+
+/// Subcommands of the `group` subcommand
+#[derive(Subcommand)]
+pub(crate) enum GroupCommands {
+    /// Print list of groups
+    Ls,
+    /// Create group, replacing any existing group of the same name
+    Create {
+        /// group name
+        name: String,
+        /// endpoint names
+        endpoints: Vec<String>,
+    },
+    /// Add endpoints to group
+    Add {
+        /// group name
+        name: String,
+        /// endpoint names
+        #[clap(required = true)]
+        endpoints: Vec<String>,
+    },
+    /// Remove endpoints from group
+    Remove {
+        /// group name
+        name: String,
+        /// endpoint names
+        #[clap(required = true)]
+        endpoints: Vec<String>,
+    },
+    /// Delete group
+    Delete {
+        /// group name
+        name: String,
+    },
+}
+
 /// Arguments for the `status` subcommand
 #[derive(Parser)]
 pub(crate) struct StatusArgs {
-    /// reveal all endpoint url's in output
+    /// reveal all endpoint url's and metadata secrets in output
     #[clap(short, long)]
     pub(crate) reveal: bool,
 
     /// verbose, show all endpoints and defaults
     #[clap(short, long)]
     pub(crate) verbose: bool,
+
+    /// also check names, mesc_version, network names, and unknown or missing keys
+    #[clap(long)]
+    pub(crate) strict: bool,
 }
 
 /// Arguments for the `ls` subcommand
@@ -169,10 +229,19 @@ pub(crate) struct LsArgs {
     #[clap(long)]
     pub(crate) url: Option<String>,
 
-    /// metadata, space-separated key=value pairs
-    #[clap(long)]
+    /// filter by metadata, space-separated KEY or KEY=VALUE pairs
+    /// (label=X and namespace=X check membership in labels and namespaces)
+    #[clap(long, num_args = 1.., verbatim_doc_comment)]
     pub(crate) metadata: Vec<String>,
 
+    /// filter by profile, no endpoints match if profile disables mesc
+    #[clap(short, long)]
+    pub(crate) profile: Option<String>,
+
+    /// filter by expression, e.g. 'chain_id in (1, 10) and "archive" in labels'
+    #[clap(long = "where", value_name = "EXPRESSION")]
+    pub(crate) filters: Vec<String>,
+
     /// output as json
     #[clap(long)]
     pub(crate) json: bool,
@@ -182,6 +251,22 @@ pub(crate) struct LsArgs {
     pub(crate) urls: bool,
 }
 
+/// Arguments for the `networks` subcommand
+#[derive(Parser)]
+pub(crate) struct NetworksArgs {
+    /// search chain ids, and names and aliases by exact, prefix, or substring match
+    #[clap()]
+    pub(crate) search: Option<String>,
+
+    /// profile used to resolve default endpoints
+    #[clap(short, long)]
+    pub(crate) profile: Option<String>,
+
+    /// output as json
+    #[clap(long)]
+    pub(crate) json: bool,
+}
+
 /// Arguments for the `ls` subcommand
 #[derive(Parser)]
 pub(crate) struct DefaultsArgs {
@@ -210,6 +295,19 @@ pub(crate) struct PingArgs {
     #[clap(long)]
     pub(crate) network: Option<String>,
 
+    /// filter endpoints by metadata, space-separated KEY or KEY=VALUE pairs
+    /// (label=X and namespace=X check membership in labels and namespaces)
+    #[clap(long, num_args = 1.., verbatim_doc_comment)]
+    pub(crate) metadata: Vec<String>,
+
+    /// filter endpoints by profile, no endpoints match if profile disables mesc
+    #[clap(short, long)]
+    pub(crate) profile: Option<String>,
+
+    /// filter by expression, e.g. 'chain_id in (1, 10) and "archive" in labels'
+    #[clap(long = "where", value_name = "EXPRESSION")]
+    pub(crate) filters: Vec<String>,
+
     /// timeout, in seconds
     #[clap(long, default_value_t = 1)]
     pub(crate) timeout: u64,
@@ -279,6 +377,30 @@ pub(crate) struct UrlArgs {
     pub(crate) profile: Option<String>,
 }
 
+/// Arguments for the `which` subcommand
+#[derive(Parser)]
+pub(crate) struct WhichArgs {
+    /// query
+    #[clap()]
+    pub(crate) query: Option<String>,
+
+    /// network
+    #[clap(long)]
+    pub(crate) network: Option<String>,
+
+    /// profile
+    #[clap(short, long)]
+    pub(crate) profile: Option<String>,
+
+    /// reveal endpoint url in output
+    #[clap(short, long)]
+    pub(crate) reveal: bool,
+
+    /// print as json
+    #[clap(short, long)]
+    pub(crate) json: bool,
+}
+
 /// Arguments for the `help` subcommand
 #[derive(Parser)]
 pub(crate) struct HelpArgs {
@@ -0,0 +1,89 @@
+use crate::{GroupArgs, GroupCommands, MescCliError};
+use mesc::RpcConfig;
+use toolstr::Colorize;
+
+pub(crate) fn group_command(args: GroupArgs) -> Result<(), MescCliError> {
+    match args.command {
+        None | Some(GroupCommands::Ls) => print_groups(args.path),
+        Some(GroupCommands::Create { name, endpoints }) => {
+            edit_groups(args.path, |config| mesc::write::set_group(config, &name, endpoints))
+        }
+        Some(GroupCommands::Add { name, endpoints }) => edit_groups(args.path, |config| {
+            let mut members = get_group_members(config, &name)?;
+            for endpoint in endpoints.into_iter() {
+                if !members.contains(&endpoint) {
+                    members.push(endpoint)
+                }
+            }
+            mesc::write::set_group(config, &name, members)
+        }),
+        Some(GroupCommands::Remove { name, endpoints }) => edit_groups(args.path, |config| {
+            let mut members = get_group_members(config, &name)?;
+            members.retain(|member| !endpoints.contains(member));
+            mesc::write::set_group(config, &name, members)
+        }),
+        Some(GroupCommands::Delete { name }) => edit_groups(args.path, |config| {
+            get_group_members(config, &name)?;
+            mesc::write::delete_group(config, &name)
+        }),
+    }
+}
+
+fn print_groups(path: Option<String>) -> Result<(), MescCliError> {
+    let config = match path {
+        Some(path) => mesc::load::load_file_config(Some(path))?,
+        None => mesc::load::load_config_data()?,
+    };
+    let groups = mesc::metadata::get_groups(&config.global_metadata)?;
+    if groups.is_empty() {
+        println!("[none]");
+        return Ok(());
+    }
+    for name in mesc::query::list_groups(&config)?.iter() {
+        let members = groups.get(name).cloned().unwrap_or_default();
+        println!("{}: {}", name.bold(), members.join(", "));
+    }
+    Ok(())
+}
+
+fn get_group_members(config: &RpcConfig, name: &str) -> Result<Vec<String>, mesc::MescError> {
+    match mesc::metadata::get_groups(&config.global_metadata)?.remove(name) {
+        Some(members) => Ok(members),
+        None => Err(mesc::MescError::IntegrityError(format!("no group named {}", name))),
+    }
+}
+
+fn edit_groups<F>(path: Option<String>, f: F) -> Result<(), MescCliError>
+where
+    F: FnOnce(&mut RpcConfig) -> Result<(), mesc::MescError>,
+{
+    // load old config data
+    let path = match (path, mesc::load::get_config_mode()) {
+        (Some(path), _) => path,
+        (_, Ok(mesc::ConfigMode::Path)) => mesc::load::get_config_path()?,
+        _ => {
+            eprintln!("to edit groups, must be in MESC_MODE=PATH or use the --path argument");
+            std::process::exit(1);
+        }
+    };
+    let mut config = mesc::load::load_file_config(Some(path.clone()))?;
+    let old_config = config.clone();
+
+    // edit
+    f(&mut config)?;
+
+    // check that new config is valid
+    if let Err(e) = config.validate() {
+        eprintln!("Aborting because these updates resulted in an invalid config: {}", e);
+        std::process::exit(1);
+    }
+
+    // write new file
+    if config != old_config {
+        mesc::write::write_config(config, path)?;
+        println!("Updated {}", "groups".green().bold());
+    } else {
+        println!("Updates did not change the content of the config");
+    }
+    Ok(())
+}
@@ -100,8 +100,8 @@ fn print_env_help() {
         ),
         (
             "MESC_PROFILES",
-            format!("space-separated items of {}", "PROFILE.KEY[.CHAIN_ID]=ENDPOINT".bold()),
-            "tool_xyz.default_endpoint=local_goerli",
+            format!("space-separated items of {}", "PROFILE.KEY[.SUBKEY]=VALUE".bold()),
+            "tool_xyz.default_endpoint=local_goerli tool_abc.use_mesc=false",
         ),
         (
             "MESC_GLOBAL_METADATA",
@@ -12,6 +12,15 @@ pub(crate) fn ls_command(args: LsArgs) -> Result<(), MescCliError> {
     if let Some(url) = args.url {
         query = query.url(url)?;
     }
+    for filter in args.metadata.iter() {
+        query = query.metadata(parse_metadata_filter(filter)?)?;
+    }
+    if let Some(profile) = args.profile {
+        query = query.profile(profile)?;
+    }
+    for filter in args.filters.iter() {
+        query = add_filter(query, filter)?;
+    }
     let endpoints = mesc::find_endpoints(query)?;
 
     if args.urls {
@@ -36,3 +45,41 @@ pub(crate) fn ls_command(args: LsArgs) -> Result<(), MescCliError> {
         print_endpoints(&endpoints, reveal)
     }
 }
+
+pub(crate) fn parse_metadata_filter(filter: &str) -> Result<mesc::MetadataFilter, MescCliError> {
+    mesc::MetadataFilter::parse(filter)
+        .map_err(|_| MescCliError::InvalidInput(format!("invalid metadata filter: {}", filter)))
+}
+
+pub(crate) fn add_filter(
+    query: mesc::MultiEndpointQuery,
+    filter: &str,
+) -> Result<mesc::MultiEndpointQuery, MescCliError> {
+    match query.filter(filter) {
+        Ok(query) => Ok(query),
+        Err(mesc::MescError::InvalidFilter { column, message }) => {
+            Err(MescCliError::InvalidInput(format!(
+                "invalid filter: {}\n    {}\n    {}^",
+                message,
+                filter,
+                " ".repeat(column.saturating_sub(1))
+            )))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_filters_report_column() {
+        let error = add_filter(mesc::MultiEndpointQuery::new(), "chain_id == 1 and").unwrap_err();
+        let message = error.to_string();
+        let mut lines = message.lines();
+        assert!(lines.next().unwrap().starts_with("Invalid input: invalid filter: "));
+        assert_eq!(lines.next(), Some("    chain_id == 1 and"));
+        assert_eq!(lines.next(), Some(format!("    {}^", " ".repeat(17)).as_str()));
+    }
+}
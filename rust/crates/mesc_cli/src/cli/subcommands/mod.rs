@@ -1,23 +1,29 @@
 mod defaults;
 mod endpoint;
+mod group;
 mod help;
 mod import;
 mod ls;
 mod metadata;
+mod networks;
 mod ping;
 mod set;
 mod setup;
 mod status;
 mod url;
+mod which;
 
 pub(crate) use defaults::*;
 pub(crate) use endpoint::*;
+pub(crate) use group::*;
 pub(crate) use help::*;
 pub(crate) use import::*;
 pub(crate) use ls::*;
 pub(crate) use metadata::*;
+pub(crate) use networks::*;
 pub(crate) use ping::*;
 pub(crate) use set::*;
 pub(crate) use setup::*;
 pub(crate) use status::*;
 pub(crate) use url::*;
+pub(crate) use which::*;
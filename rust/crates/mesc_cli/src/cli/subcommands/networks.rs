@@ -0,0 +1,230 @@
+use crate::{MescCliError, NetworksArgs};
+use mesc::{directory, ChainId, MescError, RpcConfig};
+use serde::Serialize;
+use toolstr::ColumnFormatShorthand;
+
+#[derive(Serialize)]
+struct NetworkRow {
+    chain_id: ChainId,
+    name: String,
+    source: &'static str,
+    default_endpoint: Option<String>,
+}
+
+pub(crate) fn networks_command(args: NetworksArgs) -> Result<(), MescCliError> {
+    // builtin networks are still listed if config cannot be loaded
+    let config = if mesc::is_mesc_enabled() {
+        match mesc::load::load_config_data() {
+            Ok(config) => Some(config),
+            Err(e) => {
+                eprintln!("could not load config, only showing builtin networks: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let rows = get_rows(config.as_ref(), args.search.as_deref(), args.profile.as_deref())?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&rows)?);
+        Ok(())
+    } else {
+        print_networks(&rows)
+    }
+}
+
+/// gather custom and builtin networks, ranked by how well they match search
+fn get_rows(
+    config: Option<&RpcConfig>,
+    search: Option<&str>,
+    profile: Option<&str>,
+) -> Result<Vec<NetworkRow>, MescError> {
+    let mut rows = Vec::new();
+    if let Some(config) = config {
+        for (name, chain_id) in config.network_names.iter() {
+            if let Some(rank) = search_rank(search, chain_id, &[name]) {
+                rows.push((rank, new_row(chain_id.clone(), name, "custom", config, profile)?));
+            }
+        }
+    }
+    for network in directory::get_networks().iter() {
+        let chain_id = network.chain_id();
+        let mut names = vec![network.name, network.display_name];
+        names.extend(network.aliases.iter());
+        if let Some(rank) = search_rank(search, &chain_id, &names) {
+            let row = match config {
+                Some(config) => new_row(chain_id, network.name, "builtin", config, profile)?,
+                None => NetworkRow {
+                    chain_id,
+                    name: network.name.to_string(),
+                    source: "builtin",
+                    default_endpoint: None,
+                },
+            };
+            rows.push((rank, row));
+        }
+    }
+    // custom networks come before builtin networks of the same chain id
+    rows.sort_by_key(|(rank, row)| {
+        (*rank, row.chain_id.clone(), row.source != "custom", row.name.clone())
+    });
+    Ok(rows.into_iter().map(|(_, row)| row).collect())
+}
+
+/// default endpoint is resolved like `mesc url --network`, so profiles are taken into account
+fn new_row(
+    chain_id: ChainId,
+    name: &str,
+    source: &'static str,
+    config: &RpcConfig,
+    profile: Option<&str>,
+) -> Result<NetworkRow, MescError> {
+    let endpoint = mesc::query::get_endpoint_by_network(config, chain_id.clone(), profile)?;
+    let default_endpoint = endpoint.map(|endpoint| endpoint.name);
+    Ok(NetworkRow { chain_id, name: name.to_string(), source, default_endpoint })
+}
+
+/// rank of match between search and network, lower is better, None if there is no match
+///
+/// exact matches rank before prefix matches, which rank before substring matches
+fn search_rank(search: Option<&str>, chain_id: &ChainId, names: &[&str]) -> Option<u8> {
+    let Some(search) = search else {
+        return Some(0);
+    };
+    if ChainId::parse(search).ok().as_ref() == Some(chain_id) {
+        return Some(0);
+    }
+    let search = directory::normalize_network_name(search);
+    names
+        .iter()
+        .filter_map(|name| {
+            let name = directory::normalize_network_name(name);
+            if name == search {
+                Some(0)
+            } else if name.starts_with(&search) {
+                Some(1)
+            } else if name.contains(&search) {
+                Some(2)
+            } else {
+                None
+            }
+        })
+        .min()
+}
+
+fn print_networks(rows: &[NetworkRow]) -> Result<(), MescCliError> {
+    if rows.is_empty() {
+        println!("[none]");
+        return Ok(());
+    }
+
+    let mut title_style = crate::metadata::get_theme_font_style("title")?;
+    title_style.bold();
+    let metavar_style = crate::metadata::get_theme_font_style("metavar")?;
+    let mut description_style = crate::metadata::get_theme_font_style("description")?;
+    description_style.bold();
+    let option_style = crate::metadata::get_theme_font_style("option")?;
+    let comment_style = crate::metadata::get_theme_font_style("comment")?;
+
+    let mut chain_ids = Vec::new();
+    let mut names = Vec::new();
+    let mut sources = Vec::new();
+    let mut default_endpoints = Vec::new();
+    for row in rows.iter() {
+        chain_ids.push(row.chain_id.to_string());
+        names.push(row.name.clone());
+        sources.push(row.source.to_string());
+        default_endpoints.push(row.default_endpoint.clone().unwrap_or("-".to_string()));
+    }
+
+    let format = toolstr::TableFormat::default();
+    let mut format = format.border_font_style(comment_style).label_font_style(title_style);
+    let mut table = toolstr::Table::default();
+    table.add_column("chain id", chain_ids)?;
+    format
+        .add_column(ColumnFormatShorthand::new().name("chain id").font_style(option_style.clone()));
+    table.add_column("name", names)?;
+    format.add_column(ColumnFormatShorthand::new().name("name").font_style(description_style));
+    table.add_column("source", sources)?;
+    format.add_column(ColumnFormatShorthand::new().name("source").font_style(option_style));
+    table.add_column("default endpoint", default_endpoints)?;
+    format.add_column(
+        ColumnFormatShorthand::new().name("default endpoint").font_style(metavar_style),
+    );
+    format.print(table)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mesc::{Endpoint, Profile};
+
+    #[test]
+    fn search_rank_prefers_exact_then_prefix_then_substring() {
+        let optimism = ChainId::new(10);
+        let names = ["optimism", "OP Mainnet", "op"];
+        let rank = |search| search_rank(search, &optimism, &names);
+        assert_eq!(rank(None), Some(0));
+        assert_eq!(rank(Some("10")), Some(0));
+        assert_eq!(rank(Some("eip155:10")), Some(0));
+        assert_eq!(rank(Some("op")), Some(0));
+        assert_eq!(rank(Some("op-mainnet")), Some(0));
+        assert_eq!(rank(Some("Opti")), Some(1));
+        assert_eq!(rank(Some("mainnet")), Some(2));
+        assert_eq!(rank(Some("1")), None);
+        assert_eq!(rank(Some("arbitrum")), None);
+    }
+
+    fn network_config() -> RpcConfig {
+        let mut config = RpcConfig::default();
+        for name in ["op_a", "op_b"] {
+            let endpoint = Endpoint {
+                name: name.to_string(),
+                url: format!("https://{}.example.com", name),
+                chain_id: Some(ChainId::new(10)),
+                endpoint_metadata: Default::default(),
+            };
+            config.endpoints.insert(name.to_string(), endpoint);
+        }
+        config.network_defaults.insert(ChainId::new(10), "op_a".to_string());
+        let mut profile = Profile::new("xyz");
+        profile.network_defaults.insert(ChainId::new(10), "op_b".to_string());
+        config.profiles.insert("xyz".to_string(), profile);
+        config.network_names.insert("optimism".to_string(), ChainId::new(10));
+        config.network_names.insert("optimism_fork".to_string(), ChainId::new(10));
+        config
+    }
+
+    #[test]
+    fn custom_networks_come_before_builtin_networks() {
+        let config = network_config();
+        let rows = get_rows(Some(&config), Some("optimism"), None).unwrap();
+        let rows: Vec<_> = rows
+            .iter()
+            .map(|row| (row.chain_id.to_string(), row.name.as_str(), row.source))
+            .collect();
+        assert_eq!(rows[0], ("10".to_string(), "optimism", "custom"));
+        assert_eq!(rows[1], ("10".to_string(), "optimism", "builtin"));
+        assert_eq!(rows[2], ("10".to_string(), "optimism_fork", "custom"));
+        assert!(rows[3..].iter().all(|(_, _, source)| *source == "builtin"));
+
+        let rows = get_rows(None, Some("optimism"), None).unwrap();
+        assert_eq!((rows[0].name.as_str(), rows[0].source), ("optimism", "builtin"));
+        assert!(rows.iter().all(|row| row.default_endpoint.is_none()));
+    }
+
+    #[test]
+    fn default_endpoints_resolve_with_profile() {
+        let config = network_config();
+        let default_endpoint = |profile| {
+            let rows = get_rows(Some(&config), Some("10"), profile).unwrap();
+            let row = rows.into_iter().find(|row| row.source == "builtin").unwrap();
+            assert_eq!(row.chain_id, ChainId::new(10));
+            row.default_endpoint
+        };
+        assert_eq!(default_endpoint(None).as_deref(), Some("op_a"));
+        assert_eq!(default_endpoint(Some("xyz")).as_deref(), Some("op_b"));
+    }
+}
@@ -1,3 +1,4 @@
+use super::{add_filter, parse_metadata_filter};
 use crate::{network, network::EndpointNetworkInfo, MescCliError, PingArgs};
 use futures::stream::{FuturesUnordered, StreamExt};
 use std::collections::HashSet;
@@ -37,6 +38,15 @@ pub(crate) async fn ping_command(args: PingArgs) -> Result<(), MescCliError> {
     if let Some(url) = args.url {
         query = query.url(url)?;
     }
+    for filter in args.metadata.iter() {
+        query = query.metadata(parse_metadata_filter(filter)?)?;
+    }
+    if let Some(profile) = args.profile {
+        query = query.profile(profile)?;
+    }
+    for filter in args.filters.iter() {
+        query = add_filter(query, filter)?;
+    }
     let endpoints = mesc::find_endpoints(query)?;
     let n_endpoints = endpoints.len();
 
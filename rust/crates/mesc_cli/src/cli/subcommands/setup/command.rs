@@ -1,4 +1,4 @@
-use crate::{MescCliError, SetupArgs};
+use crate::{print_validation_report, MescCliError, SetupArgs};
 use mesc::{MescError, RpcConfig};
 use std::path::PathBuf;
 use toolstr::Colorize;
@@ -194,25 +194,25 @@ fn load_config_data(mode: &ConfigWriteMode) -> Result<RpcConfig, MescCliError> {
     // ensure that config data is valid
     let mut config = config.clone();
     loop {
-        match config.validate() {
-            Ok(()) => break,
-            Err(e) => {
-                eprintln!("Config data is not valid: {}", format!("{}", e).red());
-                let prompt = "What do you want to do?";
-                let options =
-                    vec!["Create new config from scratch", "Edit the data manually", "Exit setup"];
-                match inquire::Select::new(prompt, options).prompt() {
-                    Ok("Create new config from scratch") => config = RpcConfig::default(),
-                    Ok("Edit the data manually") => {
-                        let new_config = edit::edit(serde_json::to_string(&config)?)?;
-                        if let Ok(new_config) = serde_json::from_str(new_config.as_str()) {
-                            config = new_config
-                        }
-                    }
-                    Ok("Exit setup") | Ok(_) | Err(_) => std::process::exit(1),
+        let report = config.validate_all();
+        if report.is_valid() {
+            break;
+        }
+        eprintln!("{}", "Config data is not valid:".red());
+        print_validation_report(&report);
+        let prompt = "What do you want to do?";
+        let options =
+            vec!["Create new config from scratch", "Edit the data manually", "Exit setup"];
+        match inquire::Select::new(prompt, options).prompt() {
+            Ok("Create new config from scratch") => config = RpcConfig::default(),
+            Ok("Edit the data manually") => {
+                let new_config = edit::edit(serde_json::to_string(&config)?)?;
+                if let Ok(new_config) = serde_json::from_str(new_config.as_str()) {
+                    config = new_config
                 }
             }
-        };
+            Ok("Exit setup") | Ok(_) | Err(_) => std::process::exit(1),
+        }
     }
 
     Ok(config)
@@ -1,4 +1,4 @@
-use crate::MescCliError;
+use crate::{print_validation_report, MescCliError};
 use inquire::InquireError;
 use mesc::RpcConfig;
 
@@ -56,14 +56,13 @@ pub(crate) async fn modify_existing_config(
         };
 
         // validation
-        match config.validate() {
-            Ok(()) => {}
-            Err(e) => {
-                println!("Invalid data: {:?}", e);
-                println!("Reverting to previous config state");
-                config = valid_config.clone();
-                continue;
-            }
+        let report = config.validate_all();
+        if !report.is_valid() {
+            println!("Invalid data:");
+            print_validation_report(&report);
+            println!("Reverting to previous config state");
+            config = valid_config.clone();
+            continue;
         };
 
         valid_config = config.clone();
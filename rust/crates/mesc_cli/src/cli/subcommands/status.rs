@@ -1,5 +1,8 @@
-use crate::{print_defaults, print_endpoints, MescCliError, StatusArgs};
-use mesc::MescError;
+use crate::{
+    print_defaults, print_effective_config, print_endpoints, print_validation_report, MescCliError,
+    StatusArgs,
+};
+use mesc::{MescError, RpcConfig, ValidationReport};
 use toolstr::Colorize;
 
 pub(crate) fn status_command(args: StatusArgs) -> Result<(), MescCliError> {
@@ -55,7 +58,7 @@ pub(crate) fn status_command(args: StatusArgs) -> Result<(), MescCliError> {
                     values.push(format!("{}", "false".to_string().red()));
                 }
             } else {
-                println!("{}", format!("could not load config: {:?}", e).red());
+                println!("{}", format!("could not load config: {}", e).red());
                 println!();
             };
             None
@@ -69,15 +72,21 @@ pub(crate) fn status_command(args: StatusArgs) -> Result<(), MescCliError> {
 
     // validate config
     keys.push("config valid");
-    if let Some(config) = config.clone() {
-        match config.validate() {
-            Ok(()) => {
-                values.push(format!("{}", "true".to_string().green()));
-            }
-            Err(e) => {
-                values.push(format!("{}", "false".to_string().red()));
-                println!("{:?}", e);
-            }
+    let report = if args.strict {
+        strict_report(config.as_ref())
+    } else {
+        config.as_ref().map(|config| config.validate_all())
+    };
+    if let Some(report) = report.as_ref() {
+        let n_warnings = report.warnings().count();
+        match (report.is_valid(), n_warnings) {
+            (true, 0) => values.push(format!("{}", "true".to_string().green())),
+            (true, _) => values.push(format!(
+                "{} ({} warnings)",
+                "true".to_string().green(),
+                n_warnings.to_string().yellow()
+            )),
+            (false, _) => values.push(format!("{}", "false".to_string().red())),
         };
     } else {
         values.push("[no config]".to_string());
@@ -106,6 +115,15 @@ pub(crate) fn status_command(args: StatusArgs) -> Result<(), MescCliError> {
     println!();
     crate::printing::print_environment_variables(0);
 
+    // print validation problems
+    if let Some(report) = report.filter(|report| !report.issues.is_empty()) {
+        println!();
+        println!();
+        toolstr::print_header("Validation", &theme);
+        println!();
+        print_validation_report(&report);
+    }
+
     // print endpoint info
     if args.verbose {
         println!();
@@ -120,6 +138,18 @@ pub(crate) fn status_command(args: StatusArgs) -> Result<(), MescCliError> {
         }
     };
 
+    // print effective config values and their sources
+    if args.verbose {
+        println!();
+        println!();
+        toolstr::print_header("Effective Config", &theme);
+        println!();
+        match mesc::provenance::load_config_data_with_provenance() {
+            Ok((config, provenance)) => print_effective_config(&config, &provenance, args.reveal)?,
+            Err(_) => println!("[no config]"),
+        }
+    };
+
     // print defaults
     if args.verbose {
         println!();
@@ -144,3 +174,16 @@ pub(crate) fn status_command(args: StatusArgs) -> Result<(), MescCliError> {
 
     Ok(())
 }
+
+/// strict report of raw config data if available, falling back to loaded config
+fn strict_report(config: Option<&RpcConfig>) -> Option<ValidationReport> {
+    match mesc::load::load_config_json() {
+        Ok(Some((origin, config_json))) => {
+            match RpcConfig::validate_strict_json(&config_json, &origin) {
+                Ok(report) => Some(report),
+                Err(_) => config.map(|config| config.validate_strict()),
+            }
+        }
+        _ => config.map(|config| config.validate_strict()),
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{MescCliError, WhichArgs};
+use toolstr::Colorize;
+
+pub(crate) fn which_command(args: WhichArgs) -> Result<(), MescCliError> {
+    let config = mesc::load::load_config_data()?;
+    let profile = args.profile.as_deref();
+
+    // trace endpoint resolution
+    let trace = match (args.network, args.query) {
+        (Some(_), Some(_)) => {
+            return Err(MescCliError::InvalidInput("specify either query or --network".to_string()))
+        }
+        (Some(network), None) => {
+            mesc::query::trace_endpoint_by_network(&config, network.as_str(), profile)?
+        }
+        (None, Some(query)) => mesc::query::trace_endpoint_by_query(&config, &query, profile)?,
+        (None, None) => mesc::query::trace_default_endpoint(&config, profile)?,
+    };
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&trace)?);
+        return Ok(());
+    }
+
+    // print steps
+    for step in trace.steps.iter() {
+        let marker = if step.matched { "✓".green() } else { "✗".red() };
+        println!("{} {}: {}", marker, step.method.bold(), step.reason);
+    }
+
+    // print chosen endpoint
+    println!();
+    match trace.endpoint {
+        Some(endpoint) => {
+            let url = if args.reveal { endpoint.url.clone() } else { "*".repeat(8) };
+            println!(
+                "resolved to {} ({}, chain id {})",
+                endpoint.name.bold().green(),
+                url,
+                endpoint.chain_id_string()
+            )
+        }
+        None => println!("{}", "no endpoint matched".red()),
+    }
+    Ok(())
+}
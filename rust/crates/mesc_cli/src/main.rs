@@ -20,6 +20,9 @@ pub(crate) use printing::*;
 use types::*;
 
 #[tokio::main]
-async fn main() -> Result<(), MescCliError> {
-    cli::run_cli().await
+async fn main() {
+    if let Err(e) = cli::run_cli().await {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
 }
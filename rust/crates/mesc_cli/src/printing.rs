@@ -1,5 +1,5 @@
 use crate::MescCliError;
-use mesc::{Endpoint, RpcConfig};
+use mesc::{ConfigProvenance, Endpoint, RpcConfig, ValidationReport, ValidationSeverity};
 use toolstr::{Colorize, ColumnFormatShorthand};
 
 pub(crate) fn print_endpoint_json(endpoint: Endpoint) {
@@ -60,7 +60,7 @@ pub(crate) fn print_endpoints(
             let network_name = endpoint
                 .chain_id
                 .as_ref()
-                .and_then(|chain_id| all_network_names.get(&chain_id).map(ToString::to_string))
+                .and_then(|chain_id| all_network_names.get(chain_id).map(ToString::to_string))
                 .unwrap_or_else(|| endpoint.chain_id_string());
             network_names.push(network_name);
             if reveal {
@@ -180,6 +180,113 @@ pub(crate) fn print_defaults(config: &RpcConfig) -> Result<(), MescCliError> {
     Ok(())
 }
 
+pub(crate) fn print_effective_config(
+    config: &RpcConfig,
+    provenance: &ConfigProvenance,
+    reveal: bool,
+) -> Result<(), MescCliError> {
+    let entries = mesc::provenance::get_config_entries(config);
+    if entries.is_empty() {
+        println!("[none]");
+        return Ok(());
+    }
+
+    let mut keys = Vec::new();
+    let mut values = Vec::new();
+    let mut sources = Vec::new();
+    for (key, value) in entries.into_iter() {
+        let value = match value {
+            _ if !reveal && key.starts_with("endpoints.") && key.ends_with(".url") => {
+                "*".repeat(8).into()
+            }
+            value if !reveal => match get_metadata_key(&key) {
+                Some(metadata_key) => mask_secrets(value, SECRET_KEYS.contains(&metadata_key)),
+                None => value,
+            },
+            value => value,
+        };
+        let value = match value {
+            serde_json::Value::String(value) => value,
+            value => value.to_string(),
+        };
+        let source = match provenance.get(&key) {
+            Some(source) => source.to_string(),
+            None => "-".to_string(),
+        };
+        keys.push(key);
+        values.push(value);
+        sources.push(source);
+    }
+
+    let mut title_style = crate::metadata::get_theme_font_style("title")?;
+    title_style.bold();
+    let metavar_style = crate::metadata::get_theme_font_style("metavar")?;
+    let mut description_style = crate::metadata::get_theme_font_style("description")?;
+    description_style.bold();
+    let option_style = crate::metadata::get_theme_font_style("option")?;
+    let comment_style = crate::metadata::get_theme_font_style("comment")?;
+
+    let format = toolstr::TableFormat::default();
+    let mut format =
+        format.border_font_style(comment_style.clone()).label_font_style(title_style.clone());
+    let mut table = toolstr::Table::default();
+    table.add_column("key", keys)?;
+    format.add_column(ColumnFormatShorthand::new().name("key").font_style(metavar_style));
+    table.add_column("value", values)?;
+    format.add_column(ColumnFormatShorthand::new().name("value").font_style(description_style));
+    table.add_column("source", sources)?;
+    format.add_column(ColumnFormatShorthand::new().name("source").font_style(option_style));
+    format.print(table)?;
+
+    Ok(())
+}
+
+/// metadata keys whose values are credentials
+const SECRET_KEYS: [&str; 4] = ["api_key", "api_keys", "jwt_secret", "auth"];
+
+/// get metadata key of config entry, or None if entry is not a metadata value
+fn get_metadata_key(key: &str) -> Option<&str> {
+    ["global_metadata.", ".endpoint_metadata.", ".profile_metadata."]
+        .iter()
+        .find_map(|marker| key.find(marker).map(|index| &key[index + marker.len()..]))
+}
+
+/// mask every leaf of secret values, and every url valued leaf
+fn mask_secrets(value: serde_json::Value, secret: bool) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => map
+            .into_iter()
+            .map(|(key, value)| {
+                let secret = secret || SECRET_KEYS.contains(&key.as_str());
+                (key, mask_secrets(value, secret))
+            })
+            .collect(),
+        serde_json::Value::Array(values) => {
+            values.into_iter().map(|value| mask_secrets(value, secret)).collect()
+        }
+        serde_json::Value::Null => serde_json::Value::Null,
+        serde_json::Value::String(value) if mesc::query::is_url(&value) => "*".repeat(8).into(),
+        _ if secret => "*".repeat(8).into(),
+        value => value,
+    }
+}
+
+pub(crate) fn print_validation_report(report: &ValidationReport) {
+    for issue in report.issues.iter() {
+        let severity = match issue.severity {
+            ValidationSeverity::Error => "error".red().bold(),
+            ValidationSeverity::Warning => "warning".yellow().bold(),
+        };
+        println!(
+            "- {} [{}] {}: {}",
+            severity,
+            issue.code.as_str(),
+            issue.path.bold(),
+            issue.message
+        );
+    }
+}
+
 pub(crate) fn print_environment_variables(indent: usize) {
     let indentation = " ".repeat(indent);
     let indentation2 = " ".repeat(indent + 4);
@@ -1,4 +1,5 @@
 export * from '#/types.ts'
+export * from '#/directory.ts'
 import { getRpcConfig } from '#/transform.ts'
 import type { Endpoint, Maybe } from '#/types.ts'
 import type { ChainId } from '#/schemas/rpc-config.ts'